@@ -1,4 +1,4 @@
-use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use nu_cli::eval_source;
 use nu_parser::parse;
 use nu_plugin::{EncodingType, PluginResponse};
@@ -109,6 +109,114 @@ fn eval_benchmarks(c: &mut Criterion) {
     });
 }
 
+fn eval_script_benchmarks(c: &mut Criterion) {
+    // representative pipelines exercising the evaluator hot paths
+    let scripts = [
+        (
+            "eval for_loop",
+            "mut x = 0; for i in 1..1000 { $x = $x + $i }; $x",
+        ),
+        ("eval each_pipeline", "1..1000 | each {|x| $x * 2 } | math sum"),
+        ("eval where_filter", "1..1000 | where ($it mod 2) == 0 | length"),
+    ];
+
+    for (name, script) in scripts {
+        c.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let mut engine_state = nu_command::create_default_context();
+                    engine_state.add_env_var(
+                        "PWD".into(),
+                        Value::string("/some/dir".to_string(), Span::test_data()),
+                    );
+                    (engine_state, nu_protocol::engine::Stack::new())
+                },
+                |(mut engine_state, mut stack)| {
+                    eval_source(
+                        &mut engine_state,
+                        &mut stack,
+                        script.as_bytes(),
+                        name,
+                        PipelineData::empty(),
+                        false,
+                    )
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+fn parser_large_file_benchmark(c: &mut Criterion) {
+    let mut engine_state = nu_command::create_default_context();
+    engine_state.add_env_var(
+        "PWD".into(),
+        Value::string("/some/dir".to_string(), Span::test_data()),
+    );
+
+    // a large but simple script; every line is its own pipeline
+    let large_script: String = (0..10_000).map(|i| format!("let x{i} = {i}
+")).collect();
+
+    c.bench_function("parse_large_script", |b| {
+        b.iter_batched(
+            || nu_protocol::engine::StateWorkingSet::new(&engine_state),
+            |mut working_set| parse(&mut working_set, None, large_script.as_bytes(), false, &[]),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn table_benchmarks(c: &mut Criterion) {
+    // long and wide tables rendered through the `table` command; `ignore`
+    // keeps the output from being written anywhere
+    let scripts = [
+        (
+            "table_long",
+            "0..10000 | each {|i| {index: $i, value: ($i * 2)} } | table | ignore",
+        ),
+        (
+            "table_wide",
+            "1..200 | each { {a:1 b:2 c:3 d:4 e:5 f:6 g:7 h:8 i:9 j:10 k:11 l:12 m:13 n:14 o:15 p:16 q:17 r:18 s:19 t:20} } | table | ignore",
+        ),
+    ];
+
+    for (name, script) in scripts {
+        c.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let mut engine_state = nu_command::create_default_context();
+                    engine_state.add_env_var(
+                        "PWD".into(),
+                        Value::string("/some/dir".to_string(), Span::test_data()),
+                    );
+                    (engine_state, nu_protocol::engine::Stack::new())
+                },
+                |(mut engine_state, mut stack)| {
+                    eval_source(
+                        &mut engine_state,
+                        &mut stack,
+                        script.as_bytes(),
+                        name,
+                        PipelineData::empty(),
+                        false,
+                    )
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+fn value_clone_benchmarks(c: &mut Criterion) {
+    for (row_cnt, col_cnt) in [(1000, 15), (10000, 15)] {
+        let value = encoding_test_data(row_cnt, col_cnt);
+        c.bench_function(&format!("value_clone {row_cnt} * {col_cnt}"), |b| {
+            b.iter(|| black_box(&value).clone())
+        });
+    }
+}
+
 // generate a new table data with `row_cnt` rows, `col_cnt` columns.
 fn encoding_test_data(row_cnt: usize, col_cnt: usize) -> Value {
     let columns: Vec<String> = (0..col_cnt).map(|x| format!("col_{x}")).collect();
@@ -184,7 +292,11 @@ fn decoding_benchmarks(c: &mut Criterion) {
 criterion_group!(
     benches,
     parser_benchmarks,
+    parser_large_file_benchmark,
     eval_benchmarks,
+    eval_script_benchmarks,
+    table_benchmarks,
+    value_clone_benchmarks,
     encoding_benchmarks,
     decoding_benchmarks
 );
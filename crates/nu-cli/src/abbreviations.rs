@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+/// Expands configured abbreviations (fish-style) in the given line before it
+/// is parsed. Only tokens in command position are expanded: the first word of
+/// the line and the first word after `;`, `|`, or an opening `(`/`{`. Tokens
+/// inside quotes are left alone.
+pub fn expand_abbreviations(line: &str, abbreviations: &HashMap<String, String>) -> String {
+    if abbreviations.is_empty() {
+        return line.to_string();
+    }
+
+    let mut output = String::with_capacity(line.len());
+    let mut command_position = true;
+    let mut token = String::new();
+    let mut in_quote: Option<char> = None;
+
+    let flush = |token: &mut String, output: &mut String, command_position: &mut bool| {
+        if !token.is_empty() {
+            if *command_position {
+                match abbreviations.get(token.as_str()) {
+                    Some(expansion) => output.push_str(expansion),
+                    None => output.push_str(token),
+                }
+            } else {
+                output.push_str(token);
+            }
+            *command_position = false;
+            token.clear();
+        }
+    };
+
+    for c in line.chars() {
+        match in_quote {
+            Some(quote) => {
+                token.push(c);
+                if c == quote {
+                    in_quote = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' | '`' => {
+                    in_quote = Some(c);
+                    token.push(c);
+                }
+                ' ' | '\t' | '\n' | '\r' => {
+                    flush(&mut token, &mut output, &mut command_position);
+                    output.push(c);
+                }
+                ';' | '|' | '(' | '{' => {
+                    flush(&mut token, &mut output, &mut command_position);
+                    output.push(c);
+                    command_position = true;
+                }
+                _ => token.push(c),
+            },
+        }
+    }
+    flush(&mut token, &mut output, &mut command_position);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abbreviations() -> HashMap<String, String> {
+        [("gco", "git checkout"), ("ll", "ls -l")]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn expands_in_command_position() {
+        assert_eq!(
+            expand_abbreviations("gco main", &abbreviations()),
+            "git checkout main"
+        );
+    }
+
+    #[test]
+    fn expands_after_pipes_and_semicolons() {
+        assert_eq!(
+            expand_abbreviations("ll; gco main | ll", &abbreviations()),
+            "ls -l; git checkout main | ls -l"
+        );
+    }
+
+    #[test]
+    fn does_not_expand_arguments_or_quotes() {
+        assert_eq!(
+            expand_abbreviations("echo gco 'gco'", &abbreviations()),
+            "echo gco 'gco'"
+        );
+    }
+}
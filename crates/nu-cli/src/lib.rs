@@ -1,8 +1,10 @@
+mod abbreviations;
 mod commands;
 mod completions;
 mod config_files;
 mod eval_file;
 mod menus;
+mod nu_env;
 mod nu_highlight;
 mod print;
 mod prompt;
@@ -13,18 +15,23 @@ mod syntax_highlight;
 mod util;
 mod validation;
 
+pub use abbreviations::expand_abbreviations;
 pub use commands::evaluate_commands;
 pub use completions::{FileCompletion, NuCompleter};
 pub use config_files::eval_config_contents;
 pub use eval_file::evaluate_file;
 pub use menus::{DescriptionMenu, NuHelpCompleter};
+pub use nu_env::apply_nu_env;
 pub use nu_highlight::NuHighlight;
 pub use print::Print;
 pub use prompt::NushellPrompt;
 pub use repl::evaluate_repl;
 pub use repl::{eval_env_change_hook, eval_hook};
 pub use syntax_highlight::NuHighlighter;
-pub use util::{eval_source, gather_parent_env_vars, get_init_cwd, report_error, report_error_new};
+pub use util::{
+    eval_source, gather_parent_env_vars, get_init_cwd, report_error, report_error_new,
+    store_last_output,
+};
 pub use validation::NuValidator;
 
 #[cfg(feature = "plugin")]
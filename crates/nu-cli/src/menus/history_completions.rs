@@ -0,0 +1,111 @@
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use nu_protocol::{engine::EngineState, HistoryFileFormat};
+use reedline::{
+    menu_functions::parse_selection_char, Completer, FileBackedHistory, History, HistoryItem,
+    SearchDirection, SearchQuery, SqliteBackedHistory, Span, Suggestion,
+};
+use std::sync::Arc;
+
+const SELECTION_CHAR: char = '!';
+
+/// A completer that searches the shell history for a menu. Entries are fuzzy
+/// matched across all of their lines, so multi-line commands can be found by
+/// any part of their body. With the sqlite history the working directory and
+/// exit status of each entry are shown as its description.
+///
+/// Appending `!<n>` to the search inserts only line `n` of the matched entry
+/// instead of the whole command.
+pub struct NuHistoryCompleter {
+    engine_state: Arc<EngineState>,
+}
+
+impl NuHistoryCompleter {
+    pub fn new(engine_state: Arc<EngineState>) -> Self {
+        Self { engine_state }
+    }
+
+    fn open_history(&self) -> Option<Box<dyn History>> {
+        let format = self.engine_state.config.history_file_format;
+        let path = crate::config_files::get_history_path("nushell", format)?;
+
+        match format {
+            HistoryFileFormat::Sqlite => SqliteBackedHistory::with_file(path)
+                .ok()
+                .map(|h| Box::new(h) as Box<dyn History>),
+            HistoryFileFormat::PlainText => FileBackedHistory::with_file(
+                self.engine_state.config.max_history_size as usize,
+                path,
+            )
+            .ok()
+            .map(|h| Box::new(h) as Box<dyn History>),
+        }
+    }
+}
+
+impl Completer for NuHistoryCompleter {
+    fn complete(&mut self, line: &str, pos: usize) -> Vec<Suggestion> {
+        let parsed = parse_selection_char(line, SELECTION_CHAR);
+        let query = parsed.remainder.trim();
+
+        let entries = match self
+            .open_history()
+            .and_then(|h| h.search(SearchQuery::everything(SearchDirection::Backward)).ok())
+        {
+            Some(entries) => entries,
+            None => return Vec::new(),
+        };
+
+        let matcher = SkimMatcherV2::default();
+        let reedline_span = Span::new(pos - parsed.remainder.len(), pos);
+
+        let mut matches: Vec<(i64, Suggestion)> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let score = if query.is_empty() {
+                    0
+                } else {
+                    matcher.fuzzy_match(&entry.command_line, query)?
+                };
+                Some((score, create_suggestion(entry, parsed.index, reedline_span)))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.0.cmp(&a.0));
+        matches.dedup_by(|a, b| a.1.value == b.1.value);
+        matches.into_iter().map(|(_, suggestion)| suggestion).collect()
+    }
+}
+
+fn create_suggestion(entry: HistoryItem, line: Option<usize>, span: Span) -> Suggestion {
+    // with `!<n>` insert only the requested line of a multi-line entry
+    let value = match line {
+        Some(line) => entry
+            .command_line
+            .lines()
+            .nth(line)
+            .unwrap_or(&entry.command_line)
+            .trim()
+            .to_string(),
+        None => entry.command_line,
+    };
+
+    let mut description = Vec::new();
+    if let Some(cwd) = entry.cwd {
+        description.push(cwd);
+    }
+    if let Some(exit_status) = entry.exit_status {
+        description.push(format!("exit {exit_status}"));
+    }
+
+    Suggestion {
+        value,
+        description: if description.is_empty() {
+            None
+        } else {
+            Some(description.join(" | "))
+        },
+        extra: None,
+        span,
+        append_whitespace: false,
+    }
+}
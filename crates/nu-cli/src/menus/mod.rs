@@ -1,7 +1,9 @@
 mod description_menu;
 mod help_completions;
+mod history_completions;
 mod menu_completions;
 
 pub use description_menu::DescriptionMenu;
 pub use help_completions::NuHelpCompleter;
+pub use history_completions::NuHistoryCompleter;
 pub use menu_completions::NuMenuCompleter;
@@ -0,0 +1,169 @@
+//! Loading and unloading of per-directory `.nu-env` files.
+//!
+//! After every prompt the file governing the current directory is compared
+//! with what is currently loaded: leaving a directory unloads the variables
+//! its file set, entering one loads the file if the user has trusted it with
+//! `env trust`. Untrusted (or edited) files are never applied; a one-time
+//! notice tells the user how to allow them.
+
+use nu_command::nu_env::{content_hash, find_env_file, trusted_hash, ENV_FILE_NAME};
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{ShellError, Span, Value};
+use std::path::PathBuf;
+
+const ACTIVE_FILE: &str = "NU_ENV_FILE";
+const ACTIVE_HASH: &str = "NU_ENV_HASH";
+const ACTIVE_VARS: &str = "NU_ENV_VARS";
+const ACTIVE_SHADOWED: &str = "NU_ENV_SHADOWED";
+const DENIED_FILE: &str = "NU_ENV_DENIED";
+
+/// Bring the environment in line with the `.nu-env` file governing the
+/// current directory, if any
+pub fn apply_nu_env(engine_state: &EngineState, stack: &mut Stack) -> Result<(), ShellError> {
+    let span = Span::unknown();
+
+    let cwd = match stack
+        .get_env_var(engine_state, "PWD")
+        .and_then(|pwd| pwd.as_string().ok())
+    {
+        Some(pwd) => PathBuf::from(pwd),
+        None => return Ok(()),
+    };
+
+    // what should be loaded for this directory, if anything
+    let mut desired: Option<(String, String, String)> = None;
+    let mut denied: Option<String> = None;
+    if let Some(file) = find_env_file(&cwd) {
+        if let Ok(contents) = std::fs::read_to_string(&file) {
+            let hash = content_hash(&contents);
+            let path = file.to_string_lossy().to_string();
+            if trusted_hash(&file).as_deref() == Some(hash.as_str()) {
+                desired = Some((path, hash, contents));
+            } else {
+                denied = Some(path);
+            }
+        }
+    }
+
+    let active_file = get_string(engine_state, stack, ACTIVE_FILE);
+    let active_hash = get_string(engine_state, stack, ACTIVE_HASH);
+
+    let up_to_date = match (&active_file, &desired) {
+        (Some(active), Some((path, hash, _))) => {
+            active == path && active_hash.as_deref() == Some(hash.as_str())
+        }
+        (None, None) => true,
+        _ => false,
+    };
+
+    if !up_to_date {
+        if active_file.is_some() {
+            unload(engine_state, stack);
+        }
+        if let Some((path, hash, contents)) = desired {
+            load(engine_state, stack, &path, &hash, &contents, span)?;
+        }
+    }
+
+    // tell the user about an untrusted file, but only once per file
+    let warned = get_string(engine_state, stack, DENIED_FILE);
+    match denied {
+        Some(path) => {
+            if warned.as_deref() != Some(path.as_str()) {
+                eprintln!(
+                    "Found an untrusted {ENV_FILE_NAME} file at {path}; run `env trust` to load it"
+                );
+                stack.add_env_var(DENIED_FILE.into(), Value::string(path, span));
+            }
+        }
+        None => {
+            if warned.is_some() {
+                stack.remove_env_var(engine_state, DENIED_FILE);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn get_string(engine_state: &EngineState, stack: &Stack, name: &str) -> Option<String> {
+    stack
+        .get_env_var(engine_state, name)
+        .and_then(|value| value.as_string().ok())
+}
+
+fn load(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    path: &str,
+    hash: &str,
+    contents: &str,
+    span: Span,
+) -> Result<(), ShellError> {
+    let record = nu_command::from_nuon_string_to_value(contents, span)?;
+    let (cols, vals) = match record {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        other => {
+            return Err(ShellError::GenericError(
+                format!("Invalid {ENV_FILE_NAME} file"),
+                format!("{path} must contain a NUON record of environment variables"),
+                None,
+                Some(format!("found {}", other.get_type())),
+                vec![],
+            ))
+        }
+    };
+
+    let mut loaded = vec![];
+    let mut shadowed_cols = vec![];
+    let mut shadowed_vals = vec![];
+    for (name, value) in cols.into_iter().zip(vals) {
+        // never let a directory redefine the shell's own bookkeeping
+        if name == "PWD" || name.starts_with("NU_ENV_") {
+            continue;
+        }
+        // remember what we overwrite so `unload` can put it back
+        if let Some(old) = stack.get_env_var(engine_state, &name) {
+            shadowed_cols.push(name.clone());
+            shadowed_vals.push(old);
+        } else {
+            loaded.push(Value::string(name.clone(), span));
+        }
+        stack.add_env_var(name, value);
+    }
+
+    stack.add_env_var(ACTIVE_FILE.into(), Value::string(path, span));
+    stack.add_env_var(ACTIVE_HASH.into(), Value::string(hash, span));
+    stack.add_env_var(ACTIVE_VARS.into(), Value::List { vals: loaded, span });
+    stack.add_env_var(
+        ACTIVE_SHADOWED.into(),
+        Value::Record {
+            cols: shadowed_cols,
+            vals: shadowed_vals,
+            span,
+        },
+    );
+    Ok(())
+}
+
+fn unload(engine_state: &EngineState, stack: &mut Stack) {
+    // variables the file introduced are removed; variables it shadowed get
+    // their previous values back
+    if let Some(Value::List { vals, .. }) = stack.get_env_var(engine_state, ACTIVE_VARS) {
+        for name in vals {
+            if let Ok(name) = name.as_string() {
+                stack.remove_env_var(engine_state, &name);
+            }
+        }
+    }
+    if let Some(Value::Record { cols, vals, .. }) = stack.get_env_var(engine_state, ACTIVE_SHADOWED)
+    {
+        for (name, value) in cols.into_iter().zip(vals) {
+            stack.add_env_var(name, value);
+        }
+    }
+    stack.remove_env_var(engine_state, ACTIVE_FILE);
+    stack.remove_env_var(engine_state, ACTIVE_HASH);
+    stack.remove_env_var(engine_state, ACTIVE_VARS);
+    stack.remove_env_var(engine_state, ACTIVE_SHADOWED);
+}
@@ -1,5 +1,8 @@
 use super::DescriptionMenu;
-use crate::{menus::NuMenuCompleter, NuHelpCompleter};
+use crate::{
+    menus::{NuHistoryCompleter, NuMenuCompleter},
+    NuHelpCompleter,
+};
 use crossterm::event::{KeyCode, KeyModifiers};
 use nu_color_config::{color_record_to_nustyle, lookup_ansi_color_style};
 use nu_engine::eval_block;
@@ -138,8 +141,9 @@ fn add_menu(
             "columnar" => add_columnar_menu(line_editor, menu, engine_state, stack, config),
             "list" => add_list_menu(line_editor, menu, engine_state, stack, config),
             "description" => add_description_menu(line_editor, menu, engine_state, stack, config),
+            "history" => add_history_menu(line_editor, menu, engine_state, stack, config),
             _ => Err(ShellError::UnsupportedConfigValue(
-                "columnar, list or description".to_string(),
+                "columnar, list, description or history".to_string(),
                 menu.menu_type.into_abbreviated_string(config),
                 menu.menu_type.span()?,
             )),
@@ -358,6 +362,80 @@ pub(crate) fn add_list_menu(
     }
 }
 
+// Adds a history search menu to the line editor, backed by a completer that
+// fuzzy matches across multi-line history entries
+pub(crate) fn add_history_menu(
+    line_editor: Reedline,
+    menu: &ParsedMenu,
+    engine_state: Arc<EngineState>,
+    _stack: &Stack,
+    config: &Config,
+) -> Result<Reedline, ShellError> {
+    let name = menu.name.into_string("", config);
+    let mut history_menu = ListMenu::default().with_name(&name);
+
+    if let Value::Record { cols, vals, span } = &menu.menu_type {
+        history_menu = match extract_value("page_size", cols, vals, span) {
+            Ok(page_size) => {
+                let page_size = page_size.as_integer()?;
+                history_menu.with_page_size(page_size as usize)
+            }
+            Err(_) => history_menu,
+        };
+    }
+
+    if let Value::Record { cols, vals, span } = &menu.style {
+        add_style!(
+            "text",
+            cols,
+            vals,
+            span,
+            config,
+            history_menu,
+            ListMenu::with_text_style
+        );
+        add_style!(
+            "selected_text",
+            cols,
+            vals,
+            span,
+            config,
+            history_menu,
+            ListMenu::with_selected_text_style
+        );
+        add_style!(
+            "description_text",
+            cols,
+            vals,
+            span,
+            config,
+            history_menu,
+            ListMenu::with_description_text_style
+        );
+    }
+
+    let marker = menu.marker.into_string("", config);
+    history_menu = history_menu.with_marker(marker);
+
+    let only_buffer_difference = menu.only_buffer_difference.as_bool()?;
+    history_menu = history_menu.with_only_buffer_difference(only_buffer_difference);
+
+    match &menu.source {
+        Value::Nothing { .. } => {
+            let completer = Box::new(NuHistoryCompleter::new(engine_state));
+            Ok(line_editor.with_menu(ReedlineMenu::WithCompleter {
+                menu: Box::new(history_menu),
+                completer,
+            }))
+        }
+        _ => Err(ShellError::UnsupportedConfigValue(
+            "omitted value".to_string(),
+            menu.source.into_abbreviated_string(config),
+            menu.source.span()?,
+        )),
+    }
+}
+
 // Adds a description menu to the line editor
 pub(crate) fn add_description_menu(
     line_editor: Reedline,
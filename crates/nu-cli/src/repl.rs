@@ -413,6 +413,13 @@ pub fn evaluate_repl(
             use_color,
         );
 
+        start_time = std::time::Instant::now();
+        // Load or unload the .nu-env file governing the new directory, if any
+        if let Err(error) = crate::nu_env::apply_nu_env(engine_state, stack) {
+            report_error_new(engine_state, &error)
+        }
+        perf("nu-env", start_time, file!(), line!(), column!(), use_color);
+
         start_time = std::time::Instant::now();
         let config = &engine_state.get_config().clone();
         let prompt = prompt_update::update_prompt(config, engine_state, stack, &mut nu_prompt);
@@ -464,6 +471,10 @@ pub fn evaluate_repl(
                     .lock()
                     .expect("repl buffer state mutex")
                     .replace(line_editor.current_buffer_contents().to_string());
+                *engine_state
+                    .repl_cursor_pos
+                    .lock()
+                    .expect("repl cursor pos mutex") = line_editor.current_insertion_point();
 
                 // Right before we start running the code the user gave us,
                 // fire the "pre_execution" hook
@@ -558,6 +569,13 @@ pub fn evaluate_repl(
                         },
                     );
                 } else if !s.trim().is_empty() {
+                    // Expand any configured abbreviations before the line is
+                    // parsed, so `gco main` can become `git checkout main`
+                    let s = crate::abbreviations::expand_abbreviations(
+                        &s,
+                        &engine_state.get_config().abbreviations,
+                    );
+
                     trace!("eval source: {}", s);
 
                     eval_source(
@@ -579,6 +597,20 @@ pub fn evaluate_repl(
                     },
                 );
 
+                if let Some(recording) = engine_state
+                    .session_recording
+                    .lock()
+                    .expect("session recording mutex")
+                    .as_mut()
+                {
+                    // output text is only available when keep_last_output collects it
+                    let output = stack
+                        .get_env_var(engine_state, "LAST_OUTPUT")
+                        .map(|value| value.into_string(", ", engine_state.get_config()))
+                        .unwrap_or_default();
+                    recording.record(&s, &output, cmd_duration.as_millis() as i64);
+                }
+
                 if history_supports_meta && !s.is_empty() && line_editor.has_last_command_context()
                 {
                     line_editor
@@ -643,6 +675,9 @@ pub fn evaluate_repl(
                         }
                         ReplOperation::Replace(s) => line_editor
                             .run_edit_commands(&[EditCommand::Clear, EditCommand::InsertString(s)]),
+                        ReplOperation::MoveCursor(pos) => {
+                            line_editor.run_edit_commands(&[EditCommand::MoveToPosition(pos)])
+                        }
                     }
                 }
             }
@@ -879,12 +914,14 @@ pub fn eval_hook(
     let condition_path = PathMember::String {
         val: "condition".to_string(),
         span: value_span,
+        optional: false,
     };
     let mut output = PipelineData::empty();
 
     let code_path = PathMember::String {
         val: "code".to_string(),
         span: value_span,
+        optional: false,
     };
 
     match value {
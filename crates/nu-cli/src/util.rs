@@ -249,6 +249,26 @@ pub fn eval_source(
             } = pipeline_data
             {
                 result = print_if_stream(stream, stderr_stream, false, exit_code);
+            } else if config.keep_last_output {
+                // collecting the stream is the price of keeping the value
+                // around; only paid when the user opted in
+                let metadata = pipeline_data.metadata();
+                let value = pipeline_data.into_value(Span::unknown());
+                store_last_output(engine_state, stack, &value);
+                let pipeline_data = PipelineData::Value(value, metadata);
+
+                if let Some(hook) = config.hooks.display_output.clone() {
+                    match eval_hook(engine_state, stack, Some(pipeline_data), vec![], &hook) {
+                        Err(err) => {
+                            result = Err(err);
+                        }
+                        Ok(val) => {
+                            result = val.print(engine_state, stack, false, false);
+                        }
+                    }
+                } else {
+                    result = pipeline_data.print(engine_state, stack, true, false);
+                }
             } else if let Some(hook) = config.hooks.display_output.clone() {
                 match eval_hook(engine_state, stack, Some(pipeline_data), vec![], &hook) {
                     Err(err) => {
@@ -303,6 +323,40 @@ pub fn eval_source(
     true
 }
 
+/// Remember the last pipeline's value in `$env.LAST_OUTPUT` so it can be
+/// refined interactively without re-running the command.
+///
+/// Only meaningful when `keep_last_output` is enabled. Values above
+/// `last_output_max_size` drop the variable instead of storing it, so it never
+/// refers to a stale result.
+pub fn store_last_output(engine_state: &EngineState, stack: &mut Stack, value: &Value) {
+    // commands with no output (cd, let, ...) leave the previous value alone
+    if value.is_nothing() {
+        return;
+    }
+
+    let limit = engine_state.get_config().last_output_max_size;
+    if limit < 0 || estimated_size(value) <= limit as usize {
+        stack.add_env_var("LAST_OUTPUT".into(), value.clone());
+    } else {
+        stack.remove_env_var(engine_state, "LAST_OUTPUT");
+    }
+}
+
+/// A rough, allocation-free measure of how much memory a value holds
+fn estimated_size(value: &Value) -> usize {
+    match value {
+        Value::String { val, .. } => val.len(),
+        Value::Binary { val, .. } => val.len(),
+        Value::List { vals, .. } => vals.iter().map(estimated_size).sum(),
+        Value::Record { cols, vals, .. } => {
+            cols.iter().map(|col| col.len()).sum::<usize>()
+                + vals.iter().map(estimated_size).sum::<usize>()
+        }
+        _ => std::mem::size_of::<Value>(),
+    }
+}
+
 fn set_last_exit_code(stack: &mut Stack, exit_code: i64) {
     stack.add_env_var(
         "LAST_EXIT_CODE".to_string(),
@@ -314,7 +368,9 @@ pub fn report_error(
     working_set: &StateWorkingSet,
     error: &(dyn miette::Diagnostic + Send + Sync + 'static),
 ) {
-    eprintln!("Error: {:?}", CliError(error, working_set));
+    let message = format!("Error: {:?}", CliError(error, working_set));
+    // swap known English diagnostics for the user's locale, if a catalog exists
+    eprintln!("{}", nu_utils::localize_rendered(&message));
     // reset vt processing, aka ansi because illbehaved externals can break it
     #[cfg(windows)]
     {
@@ -12,7 +12,12 @@ impl Validator for NuValidator {
         let mut working_set = StateWorkingSet::new(&self.engine_state);
         let (_, err) = parse(&mut working_set, None, line.as_bytes(), false, &[]);
 
-        if matches!(err, Some(ParseError::UnexpectedEof(..))) {
+        // An unclosed block, paren, or quote means the user is still in the
+        // middle of an expression, so keep reading instead of failing the line.
+        if matches!(
+            err,
+            Some(ParseError::UnexpectedEof(..) | ParseError::Unclosed(..))
+        ) {
             ValidationResult::Incomplete
         } else {
             ValidationResult::Complete
@@ -32,6 +32,11 @@ impl Command for Commandline {
                 "replaces the current contents of the buffer (default)",
                 Some('r'),
             )
+            .switch(
+                "cursor",
+                "gets the cursor position, or moves it when an argument is given",
+                Some('c'),
+            )
             .optional(
                 "cmd",
                 SyntaxShape::String,
@@ -44,6 +49,10 @@ impl Command for Commandline {
         "View or modify the current command line input buffer."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Meant to be used from keybinding closures and hooks: edits queued here are applied to the line editor once the current pipeline finishes."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["repl", "interactive"]
     }
@@ -60,7 +69,16 @@ impl Command for Commandline {
                 .repl_operation_queue
                 .lock()
                 .expect("repl op queue mutex");
-            ops.push_back(if call.has_flag("append") {
+            ops.push_back(if call.has_flag("cursor") {
+                let pos =
+                    cmd.as_string()?
+                        .parse::<usize>()
+                        .map_err(|_| ShellError::TypeMismatch {
+                            err_message: "expected an integer cursor position".into(),
+                            span: cmd.span()?,
+                        })?;
+                ReplOperation::MoveCursor(pos)
+            } else if call.has_flag("append") {
                 ReplOperation::Append(cmd.as_string()?)
             } else if call.has_flag("insert") {
                 ReplOperation::Insert(cmd.as_string()?)
@@ -68,6 +86,16 @@ impl Command for Commandline {
                 ReplOperation::Replace(cmd.as_string()?)
             });
             Ok(Value::Nothing { span: call.head }.into_pipeline_data())
+        } else if call.has_flag("cursor") {
+            let pos = *engine_state
+                .repl_cursor_pos
+                .lock()
+                .expect("repl cursor pos mutex");
+            Ok(Value::Int {
+                val: pos as i64,
+                span: call.head,
+            }
+            .into_pipeline_data())
         } else if let Some(ref cmd) = *engine_state
             .repl_buffer_state
             .lock()
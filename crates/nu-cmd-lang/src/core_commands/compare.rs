@@ -0,0 +1,102 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct Compare;
+
+impl Command for Compare {
+    fn name(&self) -> &str {
+        "compare"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("compare")
+            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .required("left", SyntaxShape::Any, "the value to order")
+            .required("right", SyntaxShape::Any, "the value to order it against")
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Compare two values, returning -1, 0, or 1."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Returns -1 when the left value sorts before the right one, 0 when they
+compare equal, and 1 when it sorts after, which makes the result easy to
+feed into a custom comparator. Values that cannot be compared error."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["order", "ordering", "cmp", "sort"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let left: Value = call.req(engine_state, stack, 0)?;
+        let right: Value = call.req(engine_state, stack, 1)?;
+
+        let ordering = left
+            .partial_cmp(&right)
+            .ok_or_else(|| ShellError::OperatorMismatch {
+                op_span: call.head,
+                lhs_ty: left.get_type(),
+                lhs_span: left.span().unwrap_or(call.head),
+                rhs_ty: right.get_type(),
+                rhs_span: right.span().unwrap_or(call.head),
+            })?;
+
+        let val = match ordering {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        Ok(Value::Int {
+            val,
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compare two numbers",
+                example: "compare 1 2",
+                result: Some(Value::test_int(-1)),
+            },
+            Example {
+                description: "Equal values compare as 0",
+                example: "compare 'nu' 'nu'",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "Use as a sort comparator",
+                example: "[3 1 2] | sort-by --custom {|a, b| (compare $a $b) < 0 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::Compare;
+        use crate::test_examples;
+        test_examples(Compare {})
+    }
+}
@@ -0,0 +1,60 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type};
+
+#[derive(Clone)]
+pub struct Defer;
+
+impl Command for Defer {
+    fn name(&self) -> &str {
+        "defer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defer")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "the closure to run when the enclosing block exits",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Run a closure when the enclosing block finishes."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Deferred closures run in reverse registration order, and they run whether
+the block succeeds, errors, or returns early, so cleanup like removing temp
+files or releasing locks is not skipped. The closure's output is discarded."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cleanup", "finally", "scope", "guard"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        stack.defers.push(closure);
+
+        Ok(PipelineData::new_with_metadata(None, call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove a temp file when the block is done, even if it errors",
+            example: "do { touch tmp.lock; defer { rm tmp.lock }; 'working' }",
+            result: None,
+        }]
+    }
+}
@@ -25,6 +25,12 @@ impl Command for ExportUse {
                 SyntaxShape::Any,
                 "Which members of the module to import",
             )
+            .named(
+                "sha256",
+                SyntaxShape::String,
+                "the content hash a module loaded from a URL must match",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -37,6 +37,7 @@ impl Command for For {
                 "return a numbered item ($it.index and $it.item)",
                 Some('n'),
             )
+            .deprecated_flag("numbered", "enumerate", "0.78.0")
             .creates_scope()
             .category(Category::Core)
     }
@@ -35,6 +35,11 @@ impl Command for Help {
                 "string to find in command names, usage, and search terms",
                 Some('f'),
             )
+            .switch(
+                "deprecated",
+                "list deprecated commands and flags along with their replacements",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -57,6 +62,14 @@ impl Command for Help {
         let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
         let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
 
+        if call.has_flag("deprecated") {
+            return Ok(Value::List {
+                vals: build_deprecations(engine_state, head),
+                span: head,
+            }
+            .into_pipeline_data());
+        }
+
         if rest.is_empty() && find.is_none() {
             let msg = r#"Welcome to Nushell.
 
@@ -132,10 +145,52 @@ You can also learn more at https://www.nushell.sh/book/"#;
                 example: "help --find char",
                 result: None,
             },
+            Example {
+                description: "list deprecated commands and flags",
+                example: "help --deprecated",
+                result: None,
+            },
         ]
     }
 }
 
+fn build_deprecations(engine_state: &EngineState, span: Span) -> Vec<Value> {
+    let mut deprecations = Vec::new();
+
+    for (name_bytes, decl_id) in engine_state.get_decls_sorted(false) {
+        let name = String::from_utf8_lossy(&name_bytes).to_string();
+        let decl = engine_state.get_decl(decl_id);
+
+        for deprecation in decl.signature().deprecations {
+            let flag = match &deprecation.flag {
+                Some(flag) => Value::string(format!("--{flag}"), span),
+                None => Value::nothing(span),
+            };
+            let replacement = match &deprecation.replacement {
+                Some(replacement) => Value::string(replacement, span),
+                None => Value::nothing(span),
+            };
+            let removed_in = match &deprecation.removal_version {
+                Some(version) => Value::string(version, span),
+                None => Value::nothing(span),
+            };
+
+            deprecations.push(Value::Record {
+                cols: vec![
+                    "name".into(),
+                    "flag".into(),
+                    "replacement".into(),
+                    "removed_in".into(),
+                ],
+                vals: vec![Value::string(&name, span), flag, replacement, removed_in],
+                span,
+            });
+        }
+    }
+
+    deprecations
+}
+
 pub fn highlight_search_in_table(
     table: Vec<Value>, // list of records
     search_string: &str,
@@ -0,0 +1,345 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct HelpGenerate;
+
+impl Command for HelpGenerate {
+    fn name(&self) -> &str {
+        "help generate"
+    }
+
+    fn usage(&self) -> &str {
+        "Render command help as man pages or markdown."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Everything is generated from the registered signatures and examples, so packagers can ship documentation that always matches the binary. Without --output the rendered text is returned; with it, one file per command is written into the given directory."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("help generate")
+            .category(Category::Core)
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "the commands to generate documentation for (defaults to all)",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "the output format: md (default) or man",
+                Some('f'),
+            )
+            .named(
+                "category",
+                SyntaxShape::String,
+                "only generate documentation for commands in this category",
+                Some('c'),
+            )
+            .named(
+                "output",
+                SyntaxShape::Directory,
+                "write one file per command into this directory",
+                Some('o'),
+            )
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+        let category: Option<String> = call.get_flag(engine_state, stack, "category")?;
+        let output: Option<Spanned<String>> = call.get_flag(engine_state, stack, "output")?;
+
+        let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
+        let format = match format {
+            None => Format::Markdown,
+            Some(format) => match format.item.as_str() {
+                "md" | "markdown" => Format::Markdown,
+                "man" => Format::Man,
+                other => {
+                    return Err(ShellError::UnsupportedInput(
+                        format!("unknown format '{other}'; expected md or man"),
+                        "value originates from here".into(),
+                        head,
+                        format.span,
+                    ))
+                }
+            },
+        };
+
+        let selected: Vec<(Signature, Vec<Example>)> = engine_state
+            .get_signatures_with_examples(false)
+            .into_iter()
+            .filter(|(signature, _, _, _, _)| {
+                if let Some(category) = &category {
+                    if !signature
+                        .category
+                        .to_string()
+                        .eq_ignore_ascii_case(category)
+                    {
+                        return false;
+                    }
+                }
+                rest.is_empty() || rest.iter().any(|r| r.item == signature.name)
+            })
+            .map(|(signature, examples, _, _, _)| (signature, examples))
+            .collect();
+
+        if selected.is_empty() {
+            return Err(ShellError::GenericError(
+                "No matching commands".into(),
+                "no registered command matches the given names or category".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let mut rows = Vec::new();
+        for (signature, examples) in selected {
+            let content = match format {
+                Format::Markdown => render_markdown(&signature, &examples),
+                Format::Man => render_man(&signature, &examples),
+            };
+
+            let (mut cols, mut vals) = (
+                vec!["name".to_string()],
+                vec![Value::string(&signature.name, head)],
+            );
+            match &output {
+                Some(directory) => {
+                    let file_name = format!(
+                        "{}.{}",
+                        signature.name.replace(' ', "-"),
+                        match format {
+                            Format::Markdown => "md",
+                            Format::Man => "1",
+                        }
+                    );
+                    let path = std::path::Path::new(&directory.item).join(file_name);
+                    std::fs::write(&path, content).map_err(|e| {
+                        ShellError::IOError(format!("Unable to write {}: {e}", path.display()))
+                    })?;
+                    cols.push("file".into());
+                    vals.push(Value::string(path.to_string_lossy(), head));
+                }
+                None => {
+                    cols.push("content".into());
+                    vals.push(Value::string(content, head));
+                }
+            }
+            rows.push(Value::Record {
+                cols,
+                vals,
+                span: head,
+            });
+        }
+
+        Ok(rows
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render the documentation of one command as markdown",
+                example: "help generate ls | get content.0",
+                result: None,
+            },
+            Example {
+                description: "Write man pages for every filesystem command",
+                example: "help generate --category filesystem --format man --output ./man1",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Markdown,
+    Man,
+}
+
+fn synopsis(signature: &Signature) -> String {
+    let mut out = signature.name.clone();
+    for positional in &signature.required_positional {
+        out.push_str(&format!(" <{}>", positional.name));
+    }
+    for positional in &signature.optional_positional {
+        out.push_str(&format!(" [{}]", positional.name));
+    }
+    if let Some(rest) = &signature.rest_positional {
+        out.push_str(&format!(" ...{}", rest.name));
+    }
+    if !signature.named.is_empty() {
+        out.push_str(" {flags}");
+    }
+    out
+}
+
+// One "--flag, -f <shape>" label and its description per named argument,
+// skipping the implicit help flag.
+fn flag_entries(signature: &Signature) -> Vec<(String, String)> {
+    signature
+        .named
+        .iter()
+        .filter(|flag| flag.long != "help")
+        .map(|flag| {
+            let mut label = format!("--{}", flag.long);
+            if let Some(short) = flag.short {
+                label.push_str(&format!(", -{short}"));
+            }
+            if let Some(arg) = &flag.arg {
+                label.push_str(&format!(" <{arg}>"));
+            }
+            (label, flag.desc.clone())
+        })
+        .collect()
+}
+
+fn render_markdown(signature: &Signature, examples: &[Example]) -> String {
+    let mut out = format!("# {}\n\n{}\n", signature.name, signature.usage);
+    if !signature.extra_usage.is_empty() {
+        out.push_str(&format!("\n{}\n", signature.extra_usage));
+    }
+
+    out.push_str(&format!(
+        "\n## Synopsis\n\n```\n{}\n```\n",
+        synopsis(signature)
+    ));
+
+    let positionals = signature
+        .required_positional
+        .iter()
+        .chain(&signature.optional_positional)
+        .chain(&signature.rest_positional);
+    let positionals: Vec<_> = positionals.collect();
+    if !positionals.is_empty() {
+        out.push_str("\n## Parameters\n\n");
+        for positional in positionals {
+            out.push_str(&format!("- `{}`: {}\n", positional.name, positional.desc));
+        }
+    }
+
+    let flags = flag_entries(signature);
+    if !flags.is_empty() {
+        out.push_str("\n## Flags\n\n");
+        for (label, desc) in flags {
+            out.push_str(&format!("- `{label}`: {desc}\n"));
+        }
+    }
+
+    if !examples.is_empty() {
+        out.push_str("\n## Examples\n\n");
+        for example in examples {
+            out.push_str(&format!(
+                "{}\n```\n{}\n```\n\n",
+                example.description, example.example
+            ));
+        }
+    }
+
+    out
+}
+
+// Escape the characters that troff treats specially at the start of a line or
+// inside text.
+fn man_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('-', "\\-")
+}
+
+fn render_man(signature: &Signature, examples: &[Example]) -> String {
+    let name = &signature.name;
+    let mut out = format!(
+        ".TH \"{}\" 1 \"\" \"nushell {}\" \"Nushell Manual\"\n",
+        name.to_uppercase(),
+        env!("CARGO_PKG_VERSION")
+    );
+    out.push_str(&format!(
+        ".SH NAME\n{} \\- {}\n",
+        man_escape(name),
+        man_escape(&signature.usage)
+    ));
+    out.push_str(&format!(
+        ".SH SYNOPSIS\n.B {}\n",
+        man_escape(&synopsis(signature))
+    ));
+
+    if !signature.extra_usage.is_empty() {
+        out.push_str(&format!(
+            ".SH DESCRIPTION\n{}\n",
+            man_escape(&signature.extra_usage)
+        ));
+    }
+
+    let flags = flag_entries(signature);
+    if !flags.is_empty() {
+        out.push_str(".SH OPTIONS\n");
+        for (label, desc) in flags {
+            out.push_str(&format!(
+                ".TP\n\\fB{}\\fR\n{}\n",
+                man_escape(&label),
+                man_escape(&desc)
+            ));
+        }
+    }
+
+    if !examples.is_empty() {
+        out.push_str(".SH EXAMPLES\n");
+        for example in examples {
+            out.push_str(&format!(
+                ".TP\n{}\n.nf\n{}\n.fi\n",
+                man_escape(example.description),
+                man_escape(example.example)
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(HelpGenerate {})
+    }
+
+    #[test]
+    fn markdown_covers_flags_and_examples() {
+        let md = render_markdown(&HelpGenerate.signature(), &HelpGenerate.examples());
+        assert!(md.starts_with("# help generate\n"));
+        assert!(md.contains("## Flags"));
+        assert!(md.contains("`--format, -f <string>`"));
+        assert!(md.contains("## Examples"));
+    }
+
+    #[test]
+    fn man_output_has_the_standard_sections() {
+        let man = render_man(&HelpGenerate.signature(), &[]);
+        assert!(man.starts_with(".TH \"HELP GENERATE\" 1"));
+        assert!(man.contains(".SH NAME"));
+        assert!(man.contains(".SH SYNOPSIS"));
+        assert!(man.contains(".SH OPTIONS"));
+    }
+}
@@ -126,6 +126,20 @@ fn generate_operator_info() -> Vec<OperatorInfo> {
             description: "Checks if two values are not equal.".into(),
             precedence: 80,
         },
+        OperatorInfo {
+            op_type: "Comparison".into(),
+            operator: "===".into(),
+            name: "StrictEqual".into(),
+            description: "Checks if two values are equal without coercing across types.".into(),
+            precedence: 80,
+        },
+        OperatorInfo {
+            op_type: "Comparison".into(),
+            operator: "!==".into(),
+            name: "StrictNotEqual".into(),
+            description: "Checks if two values differ in type or content.".into(),
+            precedence: 80,
+        },
         OperatorInfo {
             op_type: "Comparison".into(),
             operator: "<".into(),
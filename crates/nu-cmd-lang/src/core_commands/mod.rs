@@ -1,10 +1,12 @@
 mod alias;
 mod break_;
 mod commandline;
+mod compare;
 mod const_;
 mod continue_;
 mod def;
 mod def_env;
+mod defer;
 mod describe;
 mod do_;
 mod echo;
@@ -20,6 +22,8 @@ mod for_;
 pub mod help;
 pub mod help_aliases;
 pub mod help_commands;
+pub mod help_externs;
+pub mod help_generate;
 pub mod help_modules;
 mod help_operators;
 mod hide;
@@ -36,14 +40,17 @@ mod try_;
 mod use_;
 mod version;
 mod while_;
+mod with_resource;
 
 pub use alias::Alias;
 pub use break_::Break;
 pub use commandline::Commandline;
+pub use compare::Compare;
 pub use const_::Const;
 pub use continue_::Continue;
 pub use def::Def;
 pub use def_env::DefEnv;
+pub use defer::Defer;
 pub use describe::Describe;
 pub use do_::Do;
 pub use echo::Echo;
@@ -59,6 +66,8 @@ pub use for_::For;
 pub use help::Help;
 pub use help_aliases::HelpAliases;
 pub use help_commands::HelpCommands;
+pub use help_externs::HelpExterns;
+pub use help_generate::HelpGenerate;
 pub use help_modules::HelpModules;
 pub use help_operators::HelpOperators;
 pub use hide::Hide;
@@ -75,6 +84,7 @@ pub use try_::Try;
 pub use use_::Use;
 pub use version::Version;
 pub use while_::While;
+pub use with_resource::WithResource;
 //#[cfg(feature = "plugin")]
 mod register;
 
@@ -26,6 +26,12 @@ impl Command for Use {
                 SyntaxShape::Any,
                 "Which members of the module to import",
             )
+            .named(
+                "sha256",
+                SyntaxShape::String,
+                "the content hash a module loaded from a URL must match",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -129,6 +135,11 @@ impl Command for Use {
                 example: r#"module foo { export def-env bar [] { let-env FOO_BAR = "BAZ" } }; use foo bar; bar; $env.FOO_BAR"#,
                 result: Some(Value::test_string("BAZ")),
             },
+            Example {
+                description: "Import a module from a URL, pinned to a known content hash (requires allow_url_sources)",
+                example: r#"use https://example.com/utils.nu --sha256 0e52af38cb6e7e7f1e93f8668e2333823c6b390b0cebee97eee2bd1267227957"#,
+                result: None,
+            },
         ]
     }
 }
@@ -58,10 +58,11 @@ pub fn version(
     // - cargo_version
     // - build_time
     // - build_rust_channel
+    // - plugin_protocol_version
     // - features
     // - installed_plugins
-    let mut cols = Vec::with_capacity(12);
-    let mut vals = Vec::with_capacity(12);
+    let mut cols = Vec::with_capacity(13);
+    let mut vals = Vec::with_capacity(13);
 
     cols.push("version".to_string());
     vals.push(Value::string(env!("CARGO_PKG_VERSION"), call.head));
@@ -69,7 +70,9 @@ pub fn version(
     cols.push("branch".to_string());
     vals.push(Value::string(build::BRANCH, call.head));
 
-    let commit_hash = option_env!("NU_COMMIT_HASH");
+    let commit_hash = option_env!("NU_COMMIT_HASH")
+        .map(String::from)
+        .or_else(|| Some(build::COMMIT_HASH.to_string()).filter(|x| !x.is_empty()));
     if let Some(commit_hash) = commit_hash {
         cols.push("commit_hash".to_string());
         vals.push(Value::string(commit_hash, call.head));
@@ -117,6 +120,10 @@ pub fn version(
         vals.push(Value::string(build_rust_channel, call.head));
     }
 
+    // plugins speak the protocol of the nu release they were built against
+    cols.push("plugin_protocol_version".to_string());
+    vals.push(Value::string(env!("CARGO_PKG_VERSION"), call.head));
+
     cols.push("features".to_string());
     vals.push(Value::String {
         val: features_enabled().join(", "),
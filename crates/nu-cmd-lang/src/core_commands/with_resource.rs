@@ -0,0 +1,124 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct WithResource;
+
+impl Command for WithResource {
+    fn name(&self) -> &str {
+        "with-resource"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("with-resource")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "open",
+                SyntaxShape::Closure(None),
+                "acquires the resource; its output is handed to the other closures",
+            )
+            .required(
+                "use",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "works with the resource; its output becomes the command's output",
+            )
+            .required(
+                "close",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "releases the resource; runs even if the use closure fails",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Acquire a resource, use it, and release it whether the use succeeds or not."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cleanup", "finally", "defer", "bracket"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let open: Closure = call.req(engine_state, stack, 0)?;
+        let use_: Closure = call.req(engine_state, stack, 1)?;
+        let close: Closure = call.req(engine_state, stack, 2)?;
+        let span = call.head;
+
+        let resource = run_closure(engine_state, stack, call, open, None, PipelineData::empty())?;
+
+        // collect the result now, so the resource is not released under a lazy stream
+        let result = run_closure(
+            engine_state,
+            stack,
+            call,
+            use_,
+            Some(resource.clone()),
+            input,
+        );
+
+        let closed = run_closure(
+            engine_state,
+            stack,
+            call,
+            close,
+            Some(resource),
+            PipelineData::empty(),
+        );
+
+        let value = result?;
+        closed?;
+
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Work with a scratch file that is always removed afterwards",
+            example:
+                "with-resource { 'scratch.txt' } {|f| 'data' | save -f $f; open $f } {|f| rm $f }",
+            result: None,
+        }]
+    }
+}
+
+fn run_closure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    closure: Closure,
+    arg: Option<Value>,
+    input: PipelineData,
+) -> Result<Value, ShellError> {
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut stack = stack.captures_to_stack(&closure.captures);
+
+    if let Some(arg) = arg {
+        if let Some(var) = block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, arg);
+            }
+        }
+    }
+
+    eval_block_with_early_return(
+        engine_state,
+        &mut stack,
+        &block,
+        input,
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )
+    .map(|data| data.into_value(call.head))
+}
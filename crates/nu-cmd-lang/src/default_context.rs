@@ -19,10 +19,12 @@ pub fn create_default_context() -> EngineState {
             Alias,
             Break,
             Commandline,
+            Compare,
             Const,
             Continue,
             Def,
             DefEnv,
+            Defer,
             Describe,
             Do,
             Echo,
@@ -38,6 +40,8 @@ pub fn create_default_context() -> EngineState {
             Help,
             HelpAliases,
             HelpCommands,
+            HelpExterns,
+            HelpGenerate,
             HelpModules,
             HelpOperators,
             Hide,
@@ -58,6 +62,7 @@ pub fn create_default_context() -> EngineState {
             Use,
             Version,
             While,
+            WithResource,
         };
 
         //#[cfg(feature = "plugin")]
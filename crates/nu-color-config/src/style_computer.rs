@@ -3,13 +3,15 @@ use nu_ansi_term::{Color, Style};
 use nu_engine::eval_block;
 use nu_protocol::{
     engine::{EngineState, Stack, StateWorkingSet},
-    CliError, IntoPipelineData, Value,
+    CliError, IntoPipelineData, Span, Value,
 };
 use tabled::alignment::AlignmentHorizontal;
 
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
 };
 
 // ComputableStyle represents the valid user style types: a single color value, or a closure which
@@ -40,6 +42,11 @@ pub struct StyleComputer<'a> {
     engine_state: &'a EngineState,
     stack: &'a Stack,
     map: StyleMapping,
+    // the safety budget for a single style closure run; once a closure blows
+    // it, closures are skipped for the rest of this computer's lifetime so a
+    // slow rule can't hang the render of every remaining cell
+    closure_budget: Duration,
+    closures_disabled: AtomicBool,
 }
 
 impl<'a> StyleComputer<'a> {
@@ -54,11 +61,19 @@ impl<'a> StyleComputer<'a> {
             engine_state,
             stack,
             map,
+            closure_budget: Duration::from_millis(500),
+            closures_disabled: AtomicBool::new(false),
         }
     }
     // The main method. Takes a string name which maps to a color_config style name,
     // and a Nu value to pipe into any closures that may have been defined there.
     pub fn compute(&self, style_name: &str, value: &Value) -> Style {
+        self.compute_in_row(style_name, value, None)
+    }
+
+    // Like compute(), but also hands the row the value came from to a style
+    // closure's second parameter, so rules can look at sibling columns.
+    pub fn compute_in_row(&self, style_name: &str, value: &Value, row: Option<&Value>) -> Style {
         match self.map.get(style_name) {
             // Static values require no computation.
             Some(ComputableStyle::Static(s)) => *s,
@@ -68,6 +83,10 @@ impl<'a> StyleComputer<'a> {
                 captures,
                 span,
             })) => {
+                if self.closures_disabled.load(Ordering::Relaxed) {
+                    return Style::default();
+                }
+
                 let block = self.engine_state.get_block(*block_id).clone();
                 // Because captures_to_stack() clones, we don't need to use with_env() here
                 // (contrast with_env() usage in `each` or `do`).
@@ -79,16 +98,35 @@ impl<'a> StyleComputer<'a> {
                         stack.add_var(*var_id, value.clone());
                     }
                 }
+                // A second parameter receives the enclosing row, if there is one.
+                if let Some(var) = block.signature.get_positional(1) {
+                    if let Some(var_id) = &var.var_id {
+                        let row = row
+                            .cloned()
+                            .unwrap_or_else(|| Value::nothing(Span::unknown()));
+                        stack.add_var(*var_id, row);
+                    }
+                }
 
                 // Run the block.
-                match eval_block(
+                let start = Instant::now();
+                let result = eval_block(
                     self.engine_state,
                     &mut stack,
                     &block,
                     value.clone().into_pipeline_data(),
                     false,
                     false,
-                ) {
+                );
+                if start.elapsed() > self.closure_budget {
+                    eprintln!(
+                        "Warning: style closure for '{style_name}' took longer than {}ms; skipping style closures from here on",
+                        self.closure_budget.as_millis()
+                    );
+                    self.closures_disabled.store(true, Ordering::Relaxed);
+                }
+
+                match result {
                     Ok(v) => {
                         let value = v.into_value(*span);
                         // These should be the same color data forms supported by color_config.
@@ -118,7 +156,12 @@ impl<'a> StyleComputer<'a> {
 
     // Used only by the `table` command.
     pub fn style_primitive(&self, value: &Value) -> TextStyle {
-        let s = self.compute(&value.get_type().get_non_specified_string(), value);
+        self.style_primitive_in_row(value, None)
+    }
+
+    // Row-aware variant of style_primitive(), for cells rendered as part of a table row.
+    pub fn style_primitive_in_row(&self, value: &Value, row: Option<&Value>) -> TextStyle {
+        let s = self.compute_in_row(&value.get_type().get_non_specified_string(), value, row);
         match *value {
             Value::Bool { .. } => TextStyle::with_style(AlignmentHorizontal::Left, s),
 
@@ -204,7 +247,10 @@ impl<'a> StyleComputer<'a> {
                 _ => (),
             }
         }
-        StyleComputer::new(engine_state, stack, map)
+        let mut style_computer = StyleComputer::new(engine_state, stack, map);
+        style_computer.closure_budget =
+            Duration::from_millis(config.style_closure_timeout_ms.max(0) as u64);
+        style_computer
     }
 }
 
@@ -283,3 +329,40 @@ fn test_computable_style_closure_errors() {
     // Check that the value was printed
     assert!(actual_repl.out.contains("bell"));
 }
+
+#[test]
+fn test_computable_style_closure_sees_the_row() {
+    use nu_test_support::{nu, nu_repl_code, playground::Playground};
+    Playground::setup("computable_style_closure_row", |dirs, _| {
+        let inp = [
+            r#"let-env config = {
+                color_config: {
+                    string: {|e, row| touch ($row.tag + '.obj'); 'red' }
+                }
+            };"#,
+            "[[tag status]; [bell ok]] | table | ignore",
+            "ls | get name | to nuon",
+        ];
+        let actual_repl = nu!(cwd: dirs.test(), nu_repl_code(&inp));
+        assert_eq!(actual_repl.err, "");
+        assert_eq!(actual_repl.out, "[bell.obj]");
+    });
+}
+
+#[test]
+fn test_computable_style_closure_timeout_disables_closures() {
+    use nu_test_support::{nu, nu_repl_code};
+    let inp = [
+        r#"let-env config = {
+            style_closure_timeout_ms: 0,
+            color_config: {
+                string: {|e| 'red' }
+            }
+        };"#,
+        "[bell book] | table",
+    ];
+    let actual_repl = nu!(cwd: ".", nu_repl_code(&inp));
+    // the budget of zero trips on the first cell and the warning is printed once
+    assert!(actual_repl.err.contains("skipping style closures"));
+    assert!(actual_repl.out.contains("bell"));
+}
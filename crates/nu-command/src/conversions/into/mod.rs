@@ -8,6 +8,7 @@ mod filesize;
 mod int;
 mod record;
 mod string;
+mod table;
 
 pub use self::bool::SubCommand as IntoBool;
 pub use self::filesize::SubCommand as IntoFilesize;
@@ -18,4 +19,5 @@ pub use decimal::SubCommand as IntoDecimal;
 pub use duration::SubCommand as IntoDuration;
 pub use int::SubCommand as IntoInt;
 pub use record::SubCommand as IntoRecord;
+pub use table::SubCommand as IntoTable;
 pub use string::SubCommand as IntoString;
@@ -21,6 +21,7 @@ impl Command for SubCommand {
                 (Type::List(Box::new(Type::Any)), Type::Record(vec![])),
                 (Type::Range, Type::Record(vec![])),
                 (Type::Record(vec![]), Type::Record(vec![])),
+                (Type::String, Type::Record(vec![])),
                 (Type::Table(vec![]), Type::Record(vec![])),
             ])
             .category(Category::Conversions)
@@ -115,6 +116,34 @@ impl Command for SubCommand {
                     span,
                 }),
             },
+            Example {
+                description: "convert a list of key-value pairs to record",
+                example: "[{key: a, value: 1} {key: b, value: 2}] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string(), "b".to_string()],
+                    vals: vec![Value::Int { val: 1, span }, Value::Int { val: 2, span }],
+                    span,
+                }),
+            },
+            Example {
+                description: "convert env-style key=value lines to record",
+                example: r#""FOO=1
+BAR=skip" | into record"#,
+                result: Some(Value::Record {
+                    cols: vec!["FOO".to_string(), "BAR".to_string()],
+                    vals: vec![
+                        Value::String {
+                            val: "1".into(),
+                            span,
+                        },
+                        Value::String {
+                            val: "skip".into(),
+                            span,
+                        },
+                    ],
+                    span,
+                }),
+            },
             Example {
                 description: "convert date to record",
                 example: "2020-04-12T22:10:57+02:00 | into record",
@@ -159,19 +188,22 @@ fn into_record(
         Value::Duration { val, span } => parse_duration_into_record(val, span),
         Value::List { mut vals, span } => match input_type {
             Type::Table(..) if vals.len() == 1 => vals.pop().expect("already checked 1 item"),
-            _ => {
-                let mut cols = vec![];
-                let mut values = vec![];
-                for (idx, val) in vals.into_iter().enumerate() {
-                    cols.push(format!("{idx}"));
-                    values.push(val);
-                }
-                Value::Record {
-                    cols,
-                    vals: values,
-                    span,
+            _ => match record_from_pairs(&vals, span) {
+                Some(record) => record,
+                None => {
+                    let mut cols = vec![];
+                    let mut values = vec![];
+                    for (idx, val) in vals.into_iter().enumerate() {
+                        cols.push(format!("{idx}"));
+                        values.push(val);
+                    }
+                    Value::Record {
+                        cols,
+                        vals: values,
+                        span,
+                    }
                 }
-            }
+            },
         },
         Value::Range { val, span } => {
             let mut cols = vec![];
@@ -183,6 +215,7 @@ fn into_record(
             Value::Record { cols, vals, span }
         }
         Value::Record { cols, vals, span } => Value::Record { cols, vals, span },
+        Value::String { val, span } => record_from_kv_lines(&val, span, call.head),
         Value::Error { .. } => input,
         other => Value::Error {
             error: ShellError::OnlySupportsThisInputType {
@@ -196,6 +229,60 @@ fn into_record(
     Ok(res.into_pipeline_data())
 }
 
+// A list whose elements are all {key value} records folds into one record,
+// so `reduce` isn't needed to rebuild what `transpose` or `items` split up.
+fn record_from_pairs(vals: &[Value], span: Span) -> Option<Value> {
+    let mut cols = vec![];
+    let mut values = vec![];
+    for val in vals {
+        match val {
+            Value::Record {
+                cols: pair_cols,
+                vals: pair_vals,
+                ..
+            } if pair_cols.len() == 2 && pair_cols[0] == "key" && pair_cols[1] == "value" => {
+                cols.push(pair_vals[0].as_string().ok()?);
+                values.push(pair_vals[1].clone());
+            }
+            _ => return None,
+        }
+    }
+    (!cols.is_empty()).then_some(Value::Record {
+        cols,
+        vals: values,
+        span,
+    })
+}
+
+// Parses env-style `key=value` lines; blank lines and #-comments are skipped.
+fn record_from_kv_lines(s: &str, span: Span, head: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once('=') {
+            Some((key, value)) => {
+                cols.push(key.trim().to_string());
+                vals.push(Value::string(value.trim(), span));
+            }
+            None => {
+                return Value::Error {
+                    error: ShellError::CantConvert {
+                        to_type: "record".into(),
+                        from_type: "string".into(),
+                        span: head,
+                        help: Some(format!("line '{line}' has no '='")),
+                    },
+                }
+            }
+        }
+    }
+    Value::Record { cols, vals, span }
+}
+
 fn parse_date_into_record(date: Result<DateTime<FixedOffset>, Value>, span: Span) -> Value {
     let cols = vec![
         "year".into(),
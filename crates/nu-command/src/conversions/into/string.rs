@@ -53,6 +53,12 @@ impl Command for SubCommand {
                 "decimal digits to which to round",
                 Some('d'),
             )
+            .named(
+                "precision",
+                SyntaxShape::Int,
+                "decimal digits to which to round (alias of --decimals)",
+                None,
+            )
             .category(Category::Conversions)
     }
 
@@ -108,6 +114,11 @@ impl Command for SubCommand {
                 //     ),
                 // }),
             },
+            Example {
+                description: "round a float with the --precision alias",
+                example: "0.1 + 0.2 | into string --precision 2",
+                result: Some(Value::test_string("0.30")),
+            },
             Example {
                 description: "convert decimal to string",
                 example: "4.3 | into string",
@@ -149,9 +160,12 @@ fn string_helper(
     call: &Call,
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
-    let decimals = call.has_flag("decimals");
+    let decimals = call.has_flag("decimals") || call.has_flag("precision");
     let head = call.head;
-    let decimals_value: Option<i64> = call.get_flag(engine_state, stack, "decimals")?;
+    let decimals_value: Option<i64> = match call.get_flag(engine_state, stack, "precision")? {
+        Some(precision) => Some(precision),
+        None => call.get_flag(engine_state, stack, "decimals")?,
+    };
     if let Some(decimal_val) = decimals_value {
         if decimals && decimal_val.is_negative() {
             return Err(ShellError::TypeMismatch {
@@ -0,0 +1,152 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "into table"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into table")
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Table(vec![])),
+                (Type::List(Box::new(Type::Any)), Type::Table(vec![])),
+            ])
+            .named(
+                "key-column",
+                SyntaxShape::String,
+                "the column the record keys become (defaults to 'key')",
+                Some('k'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a record of records to a table, one row per key."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each key becomes a row holding its nested record's columns plus the key itself; non-record values end up in a 'value' column. Lists pass through unchanged."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert", "rows", "transpose"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let key_column: Option<String> = call.get_flag(engine_state, stack, "key-column")?;
+        let key_column = key_column.unwrap_or_else(|| "key".to_string());
+
+        let res = match input.into_value(head) {
+            Value::Record { cols, vals, span } => {
+                let rows = cols
+                    .into_iter()
+                    .zip(vals)
+                    .map(|(key, val)| {
+                        let (mut row_cols, mut row_vals) =
+                            (vec![key_column.clone()], vec![Value::string(key, span)]);
+                        match val {
+                            Value::Record { cols, vals, .. } => {
+                                row_cols.extend(cols);
+                                row_vals.extend(vals);
+                            }
+                            val => {
+                                row_cols.push("value".to_string());
+                                row_vals.push(val);
+                            }
+                        }
+                        Value::Record {
+                            cols: row_cols,
+                            vals: row_vals,
+                            span,
+                        }
+                    })
+                    .collect();
+                Value::List { vals: rows, span }
+            }
+            list @ Value::List { .. } => list,
+            err @ Value::Error { .. } => err,
+            other => Value::Error {
+                error: ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "record or list".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                },
+            },
+        };
+
+        Ok(res.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let span = Span::test_data();
+        vec![
+            Example {
+                description: "Convert a record of records to a table, keeping the keys",
+                example: "{nu: {lang: rust}, fish: {lang: c}} | into table --key-column shell",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["shell".to_string(), "lang".to_string()],
+                            vals: vec![Value::test_string("nu"), Value::test_string("rust")],
+                            span,
+                        },
+                        Value::Record {
+                            cols: vec!["shell".to_string(), "lang".to_string()],
+                            vals: vec![Value::test_string("fish"), Value::test_string("c")],
+                            span,
+                        },
+                    ],
+                    span,
+                }),
+            },
+            Example {
+                description: "Flat values are put in a 'value' column",
+                example: "{a: 1, b: 2} | into table",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["key".to_string(), "value".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_int(1)],
+                            span,
+                        },
+                        Value::Record {
+                            cols: vec!["key".to_string(), "value".to_string()],
+                            vals: vec![Value::test_string("b"), Value::test_int(2)],
+                            span,
+                        },
+                    ],
+                    span,
+                }),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
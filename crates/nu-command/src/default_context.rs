@@ -36,6 +36,8 @@ pub fn create_default_context() -> EngineState {
             All,
             Any,
             Append,
+            Bottom,
+            ChunkBy,
             Collect,
             Columns,
             Compact,
@@ -57,9 +59,12 @@ pub fn create_default_context() -> EngineState {
             GroupBy,
             Headers,
             Insert,
+            Join,
             SplitBy,
+            SummarizeFooter,
             Take,
             Merge,
+            MergeDeep,
             Move,
             TakeWhile,
             TakeUntil,
@@ -68,6 +73,9 @@ pub fn create_default_context() -> EngineState {
             Lines,
             ParEach,
             Prepend,
+            Query,
+            QueryJson,
+            QueryXml,
             Range,
             Reduce,
             Reject,
@@ -79,6 +87,8 @@ pub fn create_default_context() -> EngineState {
             RollLeft,
             RollRight,
             Rotate,
+            Sample,
+            Scan,
             Select,
             Shuffle,
             Skip,
@@ -87,7 +97,10 @@ pub fn create_default_context() -> EngineState {
             Sort,
             SortBy,
             SplitList,
+            Tee,
+            Top,
             Transpose,
+            Unflatten,
             Uniq,
             UniqBy,
             Upsert,
@@ -105,6 +118,24 @@ pub fn create_default_context() -> EngineState {
             History,
             Tutor,
             HistorySession,
+            HistoryStats,
+        };
+
+        // Package manager
+        bind_command! {
+            Package,
+            PackageInstall,
+            PackageList,
+            PackageUpdate,
+        };
+
+        // Session recording
+        bind_command! {
+            Session,
+            SessionExport,
+            SessionRecord,
+            SessionReplay,
+            SessionStop,
         };
 
         // Path
@@ -125,6 +156,7 @@ pub fn create_default_context() -> EngineState {
         bind_command! {
             Complete,
             External,
+            ExternStub,
             NuCheck,
             Sys,
         };
@@ -161,6 +193,9 @@ pub fn create_default_context() -> EngineState {
         #[cfg(feature = "which-support")]
         bind_command! { Which };
 
+        #[cfg(feature = "self-update")]
+        bind_command! { SelfUpdate };
+
         // Strings
         bind_command! {
             Char,
@@ -170,6 +205,7 @@ pub fn create_default_context() -> EngineState {
             EncodeBase64,
             DetectColumns,
             Format,
+            FormatTemplate,
             FileSize,
             Parse,
             Size,
@@ -179,27 +215,42 @@ pub fn create_default_context() -> EngineState {
             SplitRow,
             SplitWords,
             Str,
+            StrBetween,
             StrCamelCase,
+            StrCase,
             StrCapitalize,
+            StrCoalesce,
             StrCollect,
             StrContains,
+            StrDedent,
             StrDistance,
             StrDowncase,
             StrEndswith,
+            StrEscape,
+            StrExpand,
             StrJoin,
             StrReplace,
             StrIndexOf,
             StrKebabCase,
             StrLength,
+            StrMatch,
+            StrNormalize,
+            StrPad,
             StrPascalCase,
             StrReverse,
+            StrScore,
             StrScreamingSnakeCase,
             StrSnakeCase,
             StrStartsWith,
+            StrStats,
             StrSubstring,
             StrTrim,
+            StrTruncate,
+            StrUnescape,
+            StrUnidecode,
             StrTitleCase,
-            StrUpcase
+            StrUpcase,
+            StrWrap
         };
 
         // Bits
@@ -260,6 +311,7 @@ pub fn create_default_context() -> EngineState {
             KeybindingsListen,
             Keybindings,
             Kill,
+            Progress,
             KeybindingsList,
             Sleep,
             TermSize,
@@ -308,6 +360,7 @@ pub fn create_default_context() -> EngineState {
             ToJson,
             ToMd,
             ToNuon,
+            ToSql,
             ToText,
             ToToml,
             ToTsv,
@@ -339,14 +392,23 @@ pub fn create_default_context() -> EngineState {
             IntoInt,
             IntoRecord,
             IntoString,
+            IntoTable,
         };
 
         // Env
         bind_command! {
+            Env,
+            EnvDiff,
+            EnvSnapshot,
+            EnvTrust,
+            EnvUntrust,
             ExportEnv,
             LetEnv,
             LoadEnv,
             SourceEnv,
+            State,
+            StateLoad,
+            StateSave,
             WithEnv,
             ConfigNu,
             ConfigEnv,
@@ -392,6 +454,8 @@ pub fn create_default_context() -> EngineState {
 
         // Network
         bind_command! {
+            Dns,
+            DnsQuery,
             Http,
             HttpDelete,
             HttpGet,
@@ -399,12 +463,19 @@ pub fn create_default_context() -> EngineState {
             HttpPatch,
             HttpPost,
             HttpPut,
+            HttpRequest,
+            HttpToken,
+            Net,
+            NetListen,
+            NetSend,
             Url,
             UrlBuildQuery,
             UrlEncode,
             UrlJoin,
             UrlParse,
             Port,
+            PortScan,
+            VersionCheck,
         }
 
         // Random
@@ -429,6 +500,7 @@ pub fn create_default_context() -> EngineState {
         // Hash
         bind_command! {
             Hash,
+            HashDir,
             HashMd5::default(),
             HashSha256::default(),
         };
@@ -0,0 +1,228 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct EnvDiff;
+
+impl Command for EnvDiff {
+    fn name(&self) -> &str {
+        "env diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env diff")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required(
+                "before",
+                SyntaxShape::Record,
+                "the earlier snapshot, as produced by `env snapshot`",
+            )
+            .required(
+                "after",
+                SyntaxShape::Record,
+                "the later snapshot to compare against",
+            )
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Compare two environment snapshots and report what was added, removed, or changed."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["compare", "debug", "overlay"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let before: Value = call.req(engine_state, stack, 0)?;
+        let after: Value = call.req(engine_state, stack, 1)?;
+
+        let mut rows = vec![];
+        diff_vars(&before, &after, span, &mut rows);
+        diff_overlays(&before, &after, span, &mut rows);
+        diff_pwd(&before, &after, span, &mut rows);
+
+        Ok(Value::List { vals: rows, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "See which environment variables a command mutated",
+                example: r#"let before = (env snapshot); do-the-thing; env diff $before (env snapshot)"#,
+                result: None,
+            },
+            Example {
+                description: "Diff two snapshot records directly",
+                example: "env diff {vars: {A: 1, B: 3}, overlays: [], pwd: '/'} {vars: {A: 2, B: 3}, overlays: [], pwd: '/'}",
+                result: Some(Value::List {
+                    vals: vec![Value::test_record(
+                        vec!["kind", "name", "change", "before", "after"],
+                        vec![
+                            Value::test_string("var"),
+                            Value::test_string("A"),
+                            Value::test_string("changed"),
+                            Value::test_int(1),
+                            Value::test_int(2),
+                        ],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn diff_row(
+    kind: &str,
+    name: &str,
+    change: &str,
+    before: Value,
+    after: Value,
+    span: Span,
+) -> Value {
+    Value::Record {
+        cols: vec![
+            "kind".into(),
+            "name".into(),
+            "change".into(),
+            "before".into(),
+            "after".into(),
+        ],
+        vals: vec![
+            Value::string(kind, span),
+            Value::string(name, span),
+            Value::string(change, span),
+            before,
+            after,
+        ],
+        span,
+    }
+}
+
+fn field(snapshot: &Value, name: &str) -> Option<Value> {
+    snapshot.get_data_by_key(name)
+}
+
+fn diff_vars(before: &Value, after: &Value, span: Span, rows: &mut Vec<Value>) {
+    let empty = Value::Record {
+        cols: vec![],
+        vals: vec![],
+        span,
+    };
+    let before = field(before, "vars").unwrap_or_else(|| empty.clone());
+    let after = field(after, "vars").unwrap_or(empty);
+
+    let mut names: Vec<String> = vec![];
+    for snapshot in [&before, &after] {
+        if let Value::Record { cols, .. } = snapshot {
+            for col in cols {
+                if !names.contains(col) {
+                    names.push(col.clone());
+                }
+            }
+        }
+    }
+    names.sort();
+
+    for name in names {
+        let old = before.get_data_by_key(&name);
+        let new = after.get_data_by_key(&name);
+        match (old, new) {
+            (Some(old), None) => {
+                rows.push(diff_row(
+                    "var",
+                    &name,
+                    "removed",
+                    old,
+                    Value::nothing(span),
+                    span,
+                ));
+            }
+            (None, Some(new)) => {
+                rows.push(diff_row(
+                    "var",
+                    &name,
+                    "added",
+                    Value::nothing(span),
+                    new,
+                    span,
+                ));
+            }
+            (Some(old), Some(new)) if old != new => {
+                rows.push(diff_row("var", &name, "changed", old, new, span));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn diff_overlays(before: &Value, after: &Value, span: Span, rows: &mut Vec<Value>) {
+    let names = |snapshot: &Value| -> Vec<String> {
+        match field(snapshot, "overlays") {
+            Some(Value::List { vals, .. }) => {
+                vals.iter().filter_map(|v| v.as_string().ok()).collect()
+            }
+            _ => vec![],
+        }
+    };
+    let before = names(before);
+    let after = names(after);
+
+    for name in &before {
+        if !after.contains(name) {
+            rows.push(diff_row(
+                "overlay",
+                name,
+                "removed",
+                Value::nothing(span),
+                Value::nothing(span),
+                span,
+            ));
+        }
+    }
+    for name in &after {
+        if !before.contains(name) {
+            rows.push(diff_row(
+                "overlay",
+                name,
+                "added",
+                Value::nothing(span),
+                Value::nothing(span),
+                span,
+            ));
+        }
+    }
+}
+
+fn diff_pwd(before: &Value, after: &Value, span: Span, rows: &mut Vec<Value>) {
+    let before = field(before, "pwd").unwrap_or_else(|| Value::nothing(span));
+    let after = field(after, "pwd").unwrap_or_else(|| Value::nothing(span));
+    if before != after {
+        rows.push(diff_row("pwd", "PWD", "changed", before, after, span));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(EnvDiff {})
+    }
+}
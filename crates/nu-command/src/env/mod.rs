@@ -1,16 +1,27 @@
 mod config;
+mod diff;
+mod env_;
 mod export_env;
 mod let_env;
 mod load_env;
+pub mod nu_env;
+mod snapshot;
 mod source_env;
+mod trust;
+mod untrust;
 mod with_env;
 
 pub use config::ConfigEnv;
 pub use config::ConfigMeta;
 pub use config::ConfigNu;
 pub use config::ConfigReset;
+pub use diff::EnvDiff;
+pub use env_::Env;
 pub use export_env::ExportEnv;
 pub use let_env::LetEnv;
 pub use load_env::LoadEnv;
+pub use snapshot::EnvSnapshot;
 pub use source_env::SourceEnv;
+pub use trust::EnvTrust;
+pub use untrust::EnvUntrust;
 pub use with_env::WithEnv;
@@ -0,0 +1,118 @@
+//! Shared pieces of the per-directory environment subsystem.
+//!
+//! A `.nu-env` file holds a NUON record of environment variables for the
+//! directory that contains it. Files are only loaded once the user has
+//! trusted them with `env trust`; the trust store remembers a hash of the
+//! contents, so an edited file has to be trusted again before it is applied.
+
+use nu_protocol::{ShellError, Span};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// The file name looked for in the current directory and its parents
+pub const ENV_FILE_NAME: &str = ".nu-env";
+
+/// The `.nu-env` file governing `dir`: the nearest one walking up from it
+pub fn find_env_file(dir: &Path) -> Option<PathBuf> {
+    let mut dir = dir.to_path_buf();
+    loop {
+        let candidate = dir.join(ENV_FILE_NAME);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// The hash stored in the trust store and compared before every load
+pub fn content_hash(contents: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(contents.as_bytes());
+    let hash = hasher.finalize();
+    hash.iter().fold(String::new(), |mut output, b| {
+        output.push_str(&format!("{b:02x}"));
+        output
+    })
+}
+
+/// Where trusted hashes live: one `<hash> <path>` line per file
+pub fn trust_store_path() -> Option<PathBuf> {
+    nu_path::config_dir().map(|mut path| {
+        path.push("nushell");
+        path.push("nu-env-trust");
+        path
+    })
+}
+
+fn read_store() -> Vec<(String, String)> {
+    let Some(store) = trust_store_path() else {
+        return vec![];
+    };
+    let Ok(contents) = std::fs::read_to_string(store) else {
+        return vec![];
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            line.split_once(' ')
+                .map(|(hash, path)| (hash.to_string(), path.to_string()))
+        })
+        .collect()
+}
+
+fn write_store(entries: &[(String, String)], span: Span) -> Result<(), ShellError> {
+    let Some(store) = trust_store_path() else {
+        return Err(ShellError::GenericError(
+            "Could not find the config directory".into(),
+            "the trust store for .nu-env files could not be located".into(),
+            Some(span),
+            None,
+            vec![],
+        ));
+    };
+    if let Some(parent) = store.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+    }
+    let mut contents = String::new();
+    for (hash, path) in entries {
+        contents.push_str(hash);
+        contents.push(' ');
+        contents.push_str(path);
+        contents.push('\n');
+    }
+    std::fs::write(store, contents).map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))
+}
+
+/// The hash recorded for `file` when it was trusted, if any
+pub fn trusted_hash(file: &Path) -> Option<String> {
+    let file = file.to_string_lossy().to_string();
+    read_store()
+        .into_iter()
+        .find(|(_, path)| path == &file)
+        .map(|(hash, _)| hash)
+}
+
+/// Record `hash` as the trusted contents of `file`
+pub fn trust(file: &Path, hash: &str, span: Span) -> Result<(), ShellError> {
+    let file = file.to_string_lossy().to_string();
+    let mut entries = read_store();
+    entries.retain(|(_, path)| path != &file);
+    entries.push((hash.to_string(), file));
+    write_store(&entries, span)
+}
+
+/// Drop `file` from the trust store; false if it was not there
+pub fn untrust(file: &Path, span: Span) -> Result<bool, ShellError> {
+    let file = file.to_string_lossy().to_string();
+    let mut entries = read_store();
+    let before = entries.len();
+    entries.retain(|(_, path)| path != &file);
+    if entries.len() == before {
+        return Ok(false);
+    }
+    write_store(&entries, span)?;
+    Ok(true)
+}
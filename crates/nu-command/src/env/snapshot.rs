@@ -0,0 +1,84 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct EnvSnapshot;
+
+impl Command for EnvSnapshot {
+    fn name(&self) -> &str {
+        "env snapshot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env snapshot")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Capture the environment variables, active overlays, and working directory as a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Take one snapshot before and one after the commands under suspicion, then compare them with `env diff`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["capture", "debug", "overlay"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let mut env_vars: Vec<(String, Value)> =
+            stack.get_env_vars(engine_state).into_iter().collect();
+        env_vars.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut cols = vec![];
+        let mut vals = vec![];
+        let mut pwd = Value::nothing(span);
+        for (name, value) in env_vars {
+            // the working directory gets its own field
+            if name == "PWD" {
+                pwd = value;
+                continue;
+            }
+            cols.push(name);
+            vals.push(value);
+        }
+        let vars = Value::Record { cols, vals, span };
+
+        let overlays = Value::List {
+            vals: engine_state
+                .active_overlay_names(&[])
+                .iter()
+                .map(|name| Value::string(String::from_utf8_lossy(name), span))
+                .collect(),
+            span,
+        };
+
+        Ok(Value::Record {
+            cols: vec!["vars".into(), "overlays".into(), "pwd".into()],
+            vals: vec![vars, overlays, pwd],
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "See which environment variables a command mutated",
+            example: r#"let before = (env snapshot); do-the-thing; env diff $before (env snapshot)"#,
+            result: None,
+        }]
+    }
+}
@@ -24,6 +24,12 @@ impl Command for SourceEnv {
                 SyntaxShape::String, // type is string to avoid automatically canonicalizing the path
                 "the filepath to the script file to source the environment from",
             )
+            .named(
+                "sha256",
+                SyntaxShape::String,
+                "the content hash a file loaded from a URL must match",
+                None,
+            )
             .category(Category::Core)
     }
 
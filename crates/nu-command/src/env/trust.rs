@@ -0,0 +1,81 @@
+use super::nu_env;
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct EnvTrust;
+
+impl Command for EnvTrust {
+    fn name(&self) -> &str {
+        "env trust"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env trust")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "path",
+                SyntaxShape::Filepath,
+                "the .nu-env file to trust (defaults to the one governing the current directory)",
+            )
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Allow a per-directory .nu-env file to be loaded automatically."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A hash of the file's contents is recorded; if the file changes it must be trusted again before it is loaded."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["direnv", "allow", "nu-env"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+
+        let file = match path {
+            Some(path) => nu_path::expand_path_with(path.item, &cwd),
+            None => match nu_env::find_env_file(&cwd) {
+                Some(file) => file,
+                None => {
+                    return Err(ShellError::GenericError(
+                        format!("No {} file found", nu_env::ENV_FILE_NAME),
+                        "neither this directory nor any parent contains one".into(),
+                        Some(span),
+                        None,
+                        vec![],
+                    ))
+                }
+            },
+        };
+
+        let contents = std::fs::read_to_string(&file)
+            .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+        nu_env::trust(&file, &nu_env::content_hash(&contents), span)?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Trust the .nu-env file governing the current directory",
+            example: "env trust",
+            result: None,
+        }]
+    }
+}
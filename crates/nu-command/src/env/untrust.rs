@@ -0,0 +1,83 @@
+use super::nu_env;
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct EnvUntrust;
+
+impl Command for EnvUntrust {
+    fn name(&self) -> &str {
+        "env untrust"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env untrust")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "path",
+                SyntaxShape::Filepath,
+                "the .nu-env file to stop loading (defaults to the one governing the current directory)",
+            )
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Stop a per-directory .nu-env file from being loaded automatically."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["direnv", "deny", "revoke", "nu-env"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+
+        let file = match path {
+            Some(path) => nu_path::expand_path_with(path.item, &cwd),
+            None => match nu_env::find_env_file(&cwd) {
+                Some(file) => file,
+                None => {
+                    return Err(ShellError::GenericError(
+                        format!("No {} file found", nu_env::ENV_FILE_NAME),
+                        "neither this directory nor any parent contains one".into(),
+                        Some(span),
+                        None,
+                        vec![],
+                    ))
+                }
+            },
+        };
+
+        if !nu_env::untrust(&file, span)? {
+            return Err(ShellError::GenericError(
+                "File was not trusted".into(),
+                format!("{} is not in the trust store", file.display()),
+                Some(span),
+                None,
+                vec![],
+            ));
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Revoke trust for the .nu-env file governing the current directory",
+            example: "env untrust",
+            result: None,
+        }]
+    }
+}
@@ -63,6 +63,11 @@ impl Command for Ls {
                 Some('D'),
             )
             .switch("mime-type", "Show mime-type in type column instead of 'file' (based on filenames only; files' contents are not examined)", Some('m'))
+            .switch(
+                "git-status",
+                "Show each entry's git status in a git_status column (in a git repository; the repository is scanned once per listing)",
+                Some('g'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -80,9 +85,15 @@ impl Command for Ls {
         let du = call.has_flag("du");
         let directory = call.has_flag("directory");
         let use_mime_type = call.has_flag("mime-type");
+        let use_git_status = call.has_flag("git-status");
         let ctrl_c = engine_state.ctrlc.clone();
         let call_span = call.head;
         let cwd = current_dir(engine_state, stack)?;
+        let git_cache = if use_git_status {
+            Some(git_status_cache(&cwd))
+        } else {
+            None
+        };
 
         let pattern_arg: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
 
@@ -241,6 +252,15 @@ impl Command for Ls {
                         )
                     });
 
+                    let git_status = git_cache.as_ref().map(|cache| match cache {
+                        Some(cache) => Value::String {
+                            val: cache.status(&path),
+                            span: call_span,
+                        },
+                        // the listing is not inside a git repository
+                        None => Value::nothing(call_span),
+                    });
+
                     match display_name {
                         Ok(name) => {
                             let entry = dir_entry_dict(
@@ -252,6 +272,7 @@ impl Command for Ls {
                                 du,
                                 ctrl_c.clone(),
                                 use_mime_type,
+                                git_status,
                             );
                             match entry {
                                 Ok(value) => Some(value),
@@ -309,6 +330,11 @@ impl Command for Ls {
                 example: "ls -as ~ | where type == dir and modified < ((date now) - 7day)",
                 result: None,
             },
+            Example {
+                description: "List modified and untracked files in a git repository",
+                example: "ls --git-status | where git_status in [modified untracked]",
+                result: None,
+            },
             Example {
                 description: "List given paths and show directories themselves",
                 example: "['/path/to/directory' '/path/to/file'] | each { ls -D $in } | flatten",
@@ -318,6 +344,65 @@ impl Command for Ls {
     }
 }
 
+/// Every interesting path in the repository, mapped to a short status label.
+/// Computed once per listing so each entry is a plain lookup.
+struct GitStatusCache {
+    statuses: std::collections::HashMap<PathBuf, String>,
+}
+
+impl GitStatusCache {
+    fn status(&self, path: &Path) -> String {
+        self.statuses
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| "clean".into())
+    }
+}
+
+fn git_status_cache(cwd: &Path) -> Option<GitStatusCache> {
+    let repo = git2::Repository::discover(cwd).ok()?;
+    let workdir = repo.workdir()?.to_path_buf();
+
+    let mut options = git2::StatusOptions::new();
+    options.include_untracked(true).include_ignored(true);
+    let statuses = repo.statuses(Some(&mut options)).ok()?;
+
+    let mut map = std::collections::HashMap::new();
+    for entry in statuses.iter() {
+        if let Some(path) = entry.path() {
+            // untracked and ignored directories are reported with a trailing
+            // slash
+            map.insert(
+                workdir.join(path.trim_end_matches('/')),
+                git_status_label(entry.status()).to_string(),
+            );
+        }
+    }
+    Some(GitStatusCache { statuses: map })
+}
+
+fn git_status_label(status: git2::Status) -> &'static str {
+    if status.is_conflicted() {
+        "conflict"
+    } else if status.is_wt_new() {
+        "untracked"
+    } else if status.is_ignored() {
+        "ignored"
+    } else if status.is_wt_deleted() || status.is_index_deleted() {
+        "deleted"
+    } else if status.is_wt_renamed() || status.is_index_renamed() {
+        "renamed"
+    } else if status.is_wt_modified() || status.is_index_modified() {
+        "modified"
+    } else if status.is_index_new() {
+        "added"
+    } else if status.is_wt_typechange() || status.is_index_typechange() {
+        "typechange"
+    } else {
+        "clean"
+    }
+}
+
 fn permission_denied(dir: impl AsRef<Path>) -> bool {
     match dir.as_ref().read_dir() {
         Err(e) => matches!(e.kind(), std::io::ErrorKind::PermissionDenied),
@@ -416,6 +501,7 @@ pub(crate) fn dir_entry_dict(
     du: bool,
     ctrl_c: Option<Arc<AtomicBool>>,
     use_mime_type: bool,
+    git_status: Option<Value>,
 ) -> Result<Value, ShellError> {
     #[cfg(windows)]
     if metadata.is_none() {
@@ -449,6 +535,11 @@ pub(crate) fn dir_entry_dict(
         vals.push(Value::nothing(span));
     }
 
+    if let Some(status) = git_status {
+        cols.push("git_status".into());
+        vals.push(status);
+    }
+
     if long {
         cols.push("target".into());
         if let Some(md) = metadata {
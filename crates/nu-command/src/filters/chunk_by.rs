@@ -0,0 +1,194 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::{Block, Call};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ChunkBy;
+
+impl Command for ChunkBy {
+    fn name(&self) -> &str {
+        "chunk-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunk-by")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::List(Box::new(Type::Any)))),
+            )])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "the closure whose result decides where one run ends and the next begins",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Split the input into lists of consecutive values for which the closure returns the same result."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Chunks are emitted lazily as the input is consumed, so an unbounded stream can be segmented as it arrives."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["run", "segment", "split", "partition"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let engine_state = engine_state.clone();
+        let capture_block: Closure = call.req(&engine_state, stack, 0)?;
+        let stack = stack.captures_to_stack(&capture_block.captures);
+        let block: Block = engine_state.get_block(capture_block.block_id).clone();
+
+        let metadata = input.metadata();
+        let ctrlc = engine_state.ctrlc.clone();
+
+        Ok(ChunkByIterator {
+            input: Box::new(input.into_iter()),
+            pending: None,
+            engine_state,
+            stack,
+            block,
+            redirect_stdout: call.redirect_stdout,
+            redirect_stderr: call.redirect_stderr,
+            span: call.head,
+        }
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a list into runs of equal values",
+                example: "[1 1 2 2 1] | chunk-by {|it| $it}",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::List {
+                            vals: vec![Value::test_int(1), Value::test_int(1)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(2), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(1)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Segment values into buckets of ten",
+                example: "[1 3 10 11 29] | chunk-by {|it| $it // 10}",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::List {
+                            vals: vec![Value::test_int(1), Value::test_int(3)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(10), Value::test_int(11)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(29)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+struct ChunkByIterator {
+    input: Box<dyn Iterator<Item = Value> + Send>,
+    /// The first value of the next chunk, pulled while closing the current one
+    pending: Option<(Value, Value)>,
+    engine_state: EngineState,
+    stack: Stack,
+    block: Block,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+    span: Span,
+}
+
+impl ChunkByIterator {
+    fn key_for(&mut self, value: &Value) -> Value {
+        if let Some(var) = self.block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                self.stack.add_var(*var_id, value.clone());
+            }
+        }
+        match eval_block(
+            &self.engine_state,
+            &mut self.stack,
+            &self.block,
+            value.clone().into_pipeline_data(),
+            self.redirect_stdout,
+            self.redirect_stderr,
+        ) {
+            Ok(pd) => pd.into_value(self.span),
+            Err(error) => Value::Error { error },
+        }
+    }
+}
+
+impl Iterator for ChunkByIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, key) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let value = self.input.next()?;
+                let key = self.key_for(&value);
+                (value, key)
+            }
+        };
+
+        let mut chunk = vec![first];
+        for value in self.input.by_ref() {
+            let next_key = self.key_for(&value);
+            if next_key == key {
+                chunk.push(value);
+            } else {
+                self.pending = Some((value, next_key));
+                break;
+            }
+        }
+
+        Some(Value::List {
+            vals: chunk,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ChunkBy {})
+    }
+}
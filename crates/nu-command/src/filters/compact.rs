@@ -1,7 +1,8 @@
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call, engine::Command, engine::EngineState, engine::Stack, Category, Example,
-    PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
 };
 
 #[derive(Clone)]
@@ -32,6 +33,16 @@ impl Command for Compact {
                 SyntaxShape::Any,
                 "the columns to compact from the table",
             )
+            .switch(
+                "empty",
+                "also compact empty strings, lists and records",
+                Some('e'),
+            )
+            .switch(
+                "recurse",
+                "clean removable values out of nested lists and records too",
+                Some('r'),
+            )
             .category(Category::Filters)
     }
 
@@ -39,6 +50,10 @@ impl Command for Compact {
         "Creates a table with non-empty rows."
     }
 
+    fn extra_usage(&self) -> &str {
+        "By default only null counts as empty. A nested value that --recurse leaves empty is itself removed when --empty is given."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -79,6 +94,26 @@ impl Command for Compact {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Also filter out empty strings",
+                example: r#"[1, "", 2] | compact --empty"#,
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Drop null values inside nested records",
+                example: r#"[{a: 1, b: null}] | compact --recurse"#,
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["a".into()],
+                        vals: vec![Value::test_int(1)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -90,34 +125,64 @@ pub fn compact(
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+    let empty = call.has_flag("empty");
+    let recurse = call.has_flag("recurse");
     let metadata = input.metadata();
-    input
-        .filter(
-            move |item| {
-                match item {
-                    // Nothing is filtered out
-                    Value::Nothing { .. } => false,
-                    Value::Record { .. } => {
-                        for column in columns.iter() {
-                            match item.get_data_by_key(column) {
-                                None => return false,
-                                Some(x) => {
-                                    if let Value::Nothing { .. } = x {
-                                        return false;
-                                    }
-                                }
-                            }
-                        }
-                        // No defined columns contained Nothing
-                        true
-                    }
-                    // Any non-Nothing, non-record should be kept
-                    _ => true,
-                }
-            },
-            engine_state.ctrlc.clone(),
-        )
-        .map(|m| m.set_metadata(metadata))
+    let ctrlc = engine_state.ctrlc.clone();
+    Ok(input
+        .into_interruptible_iter(ctrlc.clone())
+        .filter_map(move |item| {
+            let item = if recurse {
+                compact_value(item, empty)
+            } else {
+                item
+            };
+            let keep = match &item {
+                Value::Record { .. } if !columns.is_empty() => columns.iter().all(|column| {
+                    !item
+                        .get_data_by_key(column)
+                        .map_or(true, |x| is_removable(&x, empty))
+                }),
+                // Anything removable is filtered out; the rest is kept
+                item => !is_removable(item, empty),
+            };
+            keep.then_some(item)
+        })
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+}
+
+fn is_removable(value: &Value, empty: bool) -> bool {
+    match value {
+        Value::Nothing { .. } => true,
+        Value::String { val, .. } => empty && val.is_empty(),
+        Value::List { vals, .. } => empty && vals.is_empty(),
+        Value::Record { cols, .. } => empty && cols.is_empty(),
+        _ => false,
+    }
+}
+
+fn compact_value(value: Value, empty: bool) -> Value {
+    match value {
+        Value::List { vals, span } => Value::List {
+            vals: vals
+                .into_iter()
+                .map(|v| compact_value(v, empty))
+                .filter(|v| !is_removable(v, empty))
+                .collect(),
+            span,
+        },
+        Value::Record { cols, vals, span } => {
+            let (cols, vals) = cols
+                .into_iter()
+                .zip(vals)
+                .map(|(col, v)| (col, compact_value(v, empty)))
+                .filter(|(_, v)| !is_removable(v, empty))
+                .unzip();
+            Value::Record { cols, vals, span }
+        }
+        other => other,
+    }
 }
 
 #[cfg(test)]
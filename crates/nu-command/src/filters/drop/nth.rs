@@ -1,10 +1,9 @@
-use itertools::Either;
 use nu_engine::CallExt;
 use nu_protocol::ast::{Call, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, PipelineData, PipelineIterator,
-    Range, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+    Range, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -27,7 +26,11 @@ impl Command for DropNth {
                 SyntaxShape::Any,
                 "the number of the row to drop or a range to drop consecutive rows",
             )
-            .rest("rest", SyntaxShape::Any, "the number of the row to drop")
+            .rest(
+                "rest",
+                SyntaxShape::Any,
+                "the numbers of additional rows to drop, or ranges of rows",
+            )
             .category(Category::Filters)
     }
 
@@ -97,6 +100,14 @@ impl Command for DropNth {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: "[0,1,2,3,4,5] | drop nth 0 2..3",
+                description: "Mix single rows and ranges",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(4), Value::test_int(5)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -107,102 +118,94 @@ impl Command for DropNth {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let number_or_range = extract_int_or_range(engine_state, stack, call)?;
-        let mut lower_bound = None;
-        let rows = match number_or_range {
-            Either::Left(row_number) => {
-                let and_rows: Vec<Spanned<i64>> = call.rest(engine_state, stack, 1)?;
-                let mut rows: Vec<_> = and_rows.into_iter().map(|x| x.item as usize).collect();
-                rows.push(row_number as usize);
-                rows.sort_unstable();
-                rows
-            }
-            Either::Right(row_range) => {
-                let from = row_range.from.as_integer()?; // as usize;
-                let to = row_range.to.as_integer()?; // as usize;
+        // every positional may be a row number or a range, in any combination
+        let args: Vec<Value> = call.rest(engine_state, stack, 0)?;
+        let input_len = if let PipelineData::Value(Value::List { ref vals, .. }, _) = input {
+            Some(vals.len())
+        } else {
+            None
+        };
 
-                // check for negative range inputs, e.g., (2..-5)
-                if from.is_negative() || to.is_negative() {
-                    let span: Spanned<Range> = call.req(engine_state, stack, 0)?;
+        let mut rows = Vec::new();
+        // everything from this row on is dropped, so the stream can stop there
+        let mut cutoff: Option<usize> = None;
+        for value in args {
+            if let Ok(row_number) = value.as_integer() {
+                if row_number.is_negative() {
                     return Err(ShellError::TypeMismatch {
                         err_message: "drop nth accepts only positive integers".to_string(),
-                        span: span.span,
-                    });
-                }
-                // check if the upper bound is smaller than the lower bound, e.g., do not accept 4..2
-                if to < from {
-                    let span: Spanned<Range> = call.req(engine_state, stack, 0)?;
-                    return Err(ShellError::TypeMismatch {
-                        err_message:
-                            "The upper bound needs to be equal or larger to the lower bound"
-                                .to_string(),
-                        span: span.span,
+                        span: value.span()?,
                     });
                 }
+                rows.push(row_number as usize);
+                continue;
+            }
 
-                // check for equality to isize::MAX because for some reason,
-                // the parser returns isize::MAX when we provide a range without upper bound (e.g., 5.. )
-                let mut to = to as usize;
-                let from = from as usize;
-
-                if let PipelineData::Value(Value::List { ref vals, span: _ }, _) = input {
-                    let max = from + vals.len() - 1;
-                    if to > max {
-                        to = max;
-                    }
-                };
-
-                if to > 0 && to as isize == isize::MAX {
-                    lower_bound = Some(from);
-                    vec![from]
-                } else if matches!(row_range.inclusion, RangeInclusion::Inclusive) {
-                    (from..=to).collect()
-                } else {
-                    (from..to).collect()
+            let row_range: Range =
+                FromValue::from_value(&value).map_err(|_| ShellError::TypeMismatch {
+                    err_message: "int or range".into(),
+                    span: value.span().unwrap_or_else(|_| Span::new(0, 0)),
+                })?;
+            let from = row_range.from.as_integer()?;
+            let to = row_range.to.as_integer()?;
+
+            // check for negative range inputs, e.g., (2..-5)
+            if from.is_negative() || to.is_negative() {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "drop nth accepts only positive integers".to_string(),
+                    span: value.span()?,
+                });
+            }
+            // check if the upper bound is smaller than the lower bound, e.g., do not accept 4..2
+            if to < from {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "The upper bound needs to be equal or larger to the lower bound"
+                        .to_string(),
+                    span: value.span()?,
+                });
+            }
+
+            let from = from as usize;
+            let mut to = to as usize;
+
+            if let Some(input_len) = input_len {
+                let max = (from + input_len).saturating_sub(1);
+                if to > max {
+                    to = max;
                 }
             }
-        };
 
-        if let Some(lower_bound) = lower_bound {
-            Ok(input
-                .into_iter()
-                .take(lower_bound)
-                .collect::<Vec<_>>()
-                .into_pipeline_data(engine_state.ctrlc.clone()))
-        } else {
-            Ok(DropNthIterator {
-                input: input.into_iter(),
-                rows,
-                current: 0,
+            // check for equality to isize::MAX because for some reason,
+            // the parser returns isize::MAX when we provide a range without upper bound (e.g., 5.. )
+            if to > 0 && to as isize == isize::MAX {
+                cutoff = Some(cutoff.map_or(from, |cutoff| cutoff.min(from)));
+            } else if matches!(row_range.inclusion, RangeInclusion::Inclusive) {
+                rows.extend(from..=to);
+            } else {
+                rows.extend(from..to);
             }
-            .into_pipeline_data(engine_state.ctrlc.clone()))
         }
-    }
-}
-
-fn extract_int_or_range(
-    engine_state: &EngineState,
-    stack: &mut Stack,
-    call: &Call,
-) -> Result<Either<i64, Range>, ShellError> {
-    let value = call.req::<Value>(engine_state, stack, 0)?;
-
-    let int_opt = value.as_integer().map(Either::Left).ok();
-    let range_opt: Result<nu_protocol::Range, ShellError> = FromValue::from_value(&value);
 
-    let range_opt = range_opt.map(Either::Right).ok();
+        rows.sort_unstable();
+        rows.dedup();
+        if let Some(cutoff) = cutoff {
+            rows.retain(|row| *row < cutoff);
+        }
 
-    int_opt
-        .or(range_opt)
-        .ok_or_else(|| ShellError::TypeMismatch {
-            err_message: "int or range".into(),
-            span: value.span().unwrap_or_else(|_| Span::new(0, 0)),
-        })
+        Ok(DropNthIterator {
+            input: input.into_iter(),
+            rows,
+            cutoff,
+            current: 0,
+        }
+        .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
 }
 
 struct DropNthIterator {
     input: PipelineIterator,
     rows: Vec<usize>,
+    cutoff: Option<usize>,
     current: usize,
 }
 
@@ -211,6 +214,10 @@ impl Iterator for DropNthIterator {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
+            // stop without pulling anything else from upstream
+            if self.cutoff.map_or(false, |cutoff| self.current >= cutoff) {
+                return None;
+            }
             if let Some(row) = self.rows.first() {
                 if self.current == *row {
                     self.rows.remove(0);
@@ -17,6 +17,10 @@ impl Command for Enumerate {
         "Enumerate the elements in a stream."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The standard way to get at indexes: pipe through `enumerate` and work with `{index, item}` records instead of reaching for per-command numbering flags. The input keeps streaming."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["itemize"]
     }
@@ -28,30 +32,44 @@ impl Command for Enumerate {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Add an index to each element of a list",
-            example: r#"[a, b, c] | enumerate "#,
-            result: Some(Value::List {
-                vals: vec![
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(0), Value::test_string("a")],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(1), Value::test_string("b")],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(2), Value::test_string("c")],
-                        span: Span::test_data(),
-                    },
-                ],
-                span: Span::test_data(),
-            }),
-        }]
+        vec![
+            Example {
+                description: "Add an index to each element of a list",
+                example: r#"[a, b, c] | enumerate "#,
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(0), Value::test_string("a")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(1), Value::test_string("b")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(2), Value::test_string("c")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Work with the index and the element together",
+                example: r#"[a, b, c] | enumerate | each {|e| $"($e.index): ($e.item)" }"#,
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("0: a"),
+                        Value::test_string("1: b"),
+                        Value::test_string("2: c"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 
     fn run(
@@ -30,6 +30,18 @@ impl Command for Flatten {
                 "optionally flatten data by column",
             )
             .switch("all", "flatten inner table one level out", Some('a'))
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "flatten nested records this many levels deep into joined column names (default: all levels)",
+                Some('d'),
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the string joining parent and child column names (default '.')",
+                Some('s'),
+            )
             .category(Category::Filters)
     }
 
@@ -37,6 +49,12 @@ impl Command for Flatten {
         "Flatten the table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"With --depth or --separator, nested records are flattened into joined
+column names like `person.name` in a single pass, so deep structures do
+not need repeated invocations with ambiguous name collisions."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -107,6 +125,34 @@ impl Command for Flatten {
                     ],
                     span: Span::test_data(),
                 }),
+            },
+            Example {
+                description: "Flatten nested records into dotted column names",
+                example: "[{user: {name: nu, job: {title: shell}}}] | flatten --separator '.'",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["user.name".to_string(), "user.job.title".to_string()],
+                        vals: vec![Value::test_string("nu"), Value::test_string("shell")],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Limit how many record levels are flattened",
+                example: "[{a: {b: {c: 1}}}] | flatten --depth 1 --separator '.'",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["a.b".to_string()],
+                        vals: vec![Value::Record {
+                            cols: vec!["c".to_string()],
+                            vals: vec![Value::test_int(1)],
+                            span: Span::test_data(),
+                        }],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
             }
         ]
     }
@@ -122,6 +168,29 @@ fn flatten(
     let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
     let metadata = input.metadata();
     let flatten_all = call.has_flag("all");
+    let depth: Option<i64> = call.get_flag(engine_state, stack, "depth")?;
+    let separator: Option<String> = call.get_flag(engine_state, stack, "separator")?;
+
+    if depth.is_some() || separator.is_some() {
+        let depth = match depth {
+            Some(d) if d < 1 => {
+                return Err(ShellError::IncorrectValue {
+                    msg: "depth must be at least 1".into(),
+                    span: call.head,
+                })
+            }
+            Some(d) => d as usize,
+            None => usize::MAX,
+        };
+        let separator = separator.unwrap_or_else(|| ".".into());
+
+        return input
+            .map(
+                move |item| flat_value_deep(&columns, item, &separator, depth, tag),
+                engine_state.ctrlc.clone(),
+            )
+            .map(|x| x.set_metadata(metadata));
+    }
 
     input
         .flat_map(
@@ -131,6 +200,63 @@ fn flatten(
         .map(|x| x.set_metadata(metadata))
 }
 
+fn flat_value_deep(
+    columns: &[CellPath],
+    item: Value,
+    separator: &str,
+    depth: usize,
+    _name_tag: Span,
+) -> Value {
+    let (cols, vals, span) = match item {
+        Value::Record { cols, vals, span } => (cols, vals, span),
+        // rows that are not records (including errors) pass through unchanged
+        other => return other,
+    };
+
+    let mut out = IndexMap::<String, Value>::new();
+    for (column, value) in cols.iter().zip(vals.iter()) {
+        let need_flatten = columns.is_empty() || columns.iter().any(|c| c.into_string() == *column);
+
+        match value {
+            Value::Record { cols, vals, .. } if need_flatten => {
+                flatten_record_deep(&mut out, column, cols, vals, separator, depth - 1)
+            }
+            _ => {
+                out.insert(column.clone(), value.clone());
+            }
+        }
+    }
+
+    Value::Record {
+        cols: out.keys().cloned().collect(),
+        vals: out.values().cloned().collect(),
+        span,
+    }
+}
+
+// walk a nested record, inserting leaves under joined names like `a.b.c`;
+// `depth` counts the record levels still allowed to open up
+fn flatten_record_deep(
+    out: &mut IndexMap<String, Value>,
+    prefix: &str,
+    cols: &[String],
+    vals: &[Value],
+    separator: &str,
+    depth: usize,
+) {
+    for (col, val) in cols.iter().zip(vals.iter()) {
+        let name = format!("{prefix}{separator}{col}");
+        match val {
+            Value::Record { cols, vals, .. } if depth > 0 => {
+                flatten_record_deep(out, &name, cols, vals, separator, depth - 1)
+            }
+            _ => {
+                out.insert(name, val.clone());
+            }
+        }
+    }
+}
+
 enum TableInside<'a> {
     // handle for a column which contains a single list(but not list of records)
     // it contains (column, span, values in the column, column index).
@@ -279,7 +405,7 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                         if !columns.is_empty() {
                             let cell_path =
                                 column_requested.and_then(|x| match x.members.first() {
-                                    Some(PathMember::String { val, span: _ }) => Some(val),
+                                    Some(PathMember::String { val, .. }) => Some(val),
                                     _ => None,
                                 });
 
@@ -21,7 +21,11 @@ impl Command for Get {
     fn extra_usage(&self) -> &str {
         r#"This is equivalent to using the cell path access syntax: `$env.OS` is the same as `$env | get OS`.
 
-If multiple cell paths are given, this will produce a list of values."#
+If multiple cell paths are given, this will produce a list of values.
+
+A member followed by `?` is optional: a missed lookup gives null instead of an
+error. `*` matches every column of a record, and a negative index counts back
+from the end of a list, so `get -1` is the last item."#
     }
 
     fn signature(&self) -> nu_protocol::Signature {
@@ -112,6 +116,24 @@ If multiple cell paths are given, this will produce a list of values."#
                 example: "[{A: A0}] | get 0.A",
                 result: Some(Value::test_string("A0")),
             },
+            Example {
+                description: "Get the last item of a list with a negative index",
+                example: "[0 1 2] | get -1",
+                result: Some(Value::test_int(2)),
+            },
+            Example {
+                description: "A trailing `?` makes a missing member null instead of an error",
+                example: "{a: 1} | get b?",
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "`*` gets every column of a record as a list",
+                example: "{a: 1, b: 2} | get *",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description:
                     "Extract the name of the 3rd record in a list (same as `ls | $in.name`)",
@@ -1,6 +1,6 @@
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
     Type, Value,
@@ -22,17 +22,32 @@ impl Command for GroupBy {
             // example. Perhaps Table should be a subtype of List, in which case
             // the current signature would suffice even when a Table example
             // exists.
-            .input_output_types(vec![(
-                Type::List(Box::new(Type::Any)),
-                Type::Record(vec![]),
-            )])
-            .optional("grouper", SyntaxShape::Any, "the grouper value to use")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Record(vec![])),
+                (Type::List(Box::new(Type::Any)), Type::Table(vec![])),
+            ])
+            .switch(
+                "to-table",
+                "return a table of {group, items} rows instead of a record",
+                None,
+            )
+            .rest(
+                "grouper",
+                SyntaxShape::Any,
+                "the column names or closures to group on; several nest the groups",
+            )
     }
 
     fn usage(&self) -> &str {
         "Splits a list or table into groups, and returns a record containing those groups."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"A grouper is a column name or a closure run against each row. Passing
+more than one grouper groups within groups: the value under each first-level
+key is itself a record keyed by the second grouper, and so on."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -50,6 +65,55 @@ impl Command for GroupBy {
                 example: r#"ls | group-by type"#,
                 result: None,
             },
+            Example {
+                description: "Group items by the \"foo\" column, then by \"bar\" within each group",
+                example: r#"[[foo bar]; [a x] [a y] [b x]] | group-by foo bar"#,
+                result: None,
+            },
+            Example {
+                description: "Group items by a closure over each row",
+                example: r#"ls | group-by { get name | path parse | get extension }"#,
+                result: None,
+            },
+            Example {
+                description: "Group using a table of {group, items} rows as the output",
+                example: "[1 3 1 2] | group-by --to-table",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["group", "items"],
+                            vec![
+                                Value::test_string("1"),
+                                Value::List {
+                                    vals: vec![Value::test_int(1), Value::test_int(1)],
+                                    span: Span::test_data(),
+                                },
+                            ],
+                        ),
+                        Value::test_record(
+                            vec!["group", "items"],
+                            vec![
+                                Value::test_string("3"),
+                                Value::List {
+                                    vals: vec![Value::test_int(3)],
+                                    span: Span::test_data(),
+                                },
+                            ],
+                        ),
+                        Value::test_record(
+                            vec!["group", "items"],
+                            vec![
+                                Value::test_string("2"),
+                                Value::List {
+                                    vals: vec![Value::test_int(2)],
+                                    span: Span::test_data(),
+                                },
+                            ],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "You can also group by raw values by leaving out the argument",
                 example: "['1' '3' '1' '3' '2' '1' '1'] | group-by",
@@ -94,12 +158,33 @@ pub fn group_by(
 ) -> Result<PipelineData, ShellError> {
     let name = call.head;
 
-    let grouper: Option<Value> = call.opt(engine_state, stack, 0)?;
-    let values: Vec<Value> = input.into_iter().collect();
-    let mut keys: Vec<Result<String, ShellError>> = vec![];
-    let mut group_strategy = Grouper::ByColumn(None);
+    let groupers: Vec<Value> = call.rest(engine_state, stack, 0)?;
+    let to_table = call.has_flag("to-table");
+    let ctrlc = engine_state.ctrlc.clone();
 
-    if values.is_empty() {
+    // Stream the input into the first level of buckets: only the grouped
+    // values are buffered and a long stream can still be interrupted.
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+    let mut span = name;
+    let mut seen_any = false;
+    for value in input.into_iter() {
+        if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+            break;
+        }
+        if !seen_any {
+            if let Ok(first_span) = value.span() {
+                span = first_span;
+            }
+            seen_any = true;
+        }
+        let group_key = match groupers.first() {
+            Some(grouper) => group_key(engine_state, stack, call, grouper, &value, span)?,
+            None => value.as_string()?,
+        };
+        groups.entry(group_key).or_default().push(value);
+    }
+
+    if !seen_any {
         return Err(ShellError::GenericError(
             "expected table from pipeline".into(),
             "requires a table input".into(),
@@ -109,92 +194,145 @@ pub fn group_by(
         ));
     }
 
-    let first = values[0].clone();
+    let grouped = nest_groups(engine_state, stack, call, groups, &groupers, span)?;
+    let output = if to_table {
+        groups_to_table(grouped, span)
+    } else {
+        grouped
+    };
+
+    Ok(PipelineData::Value(output, None))
+}
 
-    let value_list = Value::List {
-        vals: values.clone(),
-        span: name,
+/// Turn buckets grouped by the first grouper into a record, grouping each
+/// bucket by the remaining groupers
+fn nest_groups(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    groups: IndexMap<String, Vec<Value>>,
+    groupers: &[Value],
+    span: Span,
+) -> Result<Value, ShellError> {
+    let rest = if groupers.len() > 1 {
+        &groupers[1..]
+    } else {
+        &[]
     };
 
-    match grouper {
-        Some(Value::Block { .. }) | Some(Value::Closure { .. }) => {
-            let block: Option<Closure> = call.opt(engine_state, stack, 0)?;
-            let error_key = "error";
-
-            for value in values {
-                if let Some(capture_block) = &block {
-                    let mut stack = stack.captures_to_stack(&capture_block.captures);
-                    let block = engine_state.get_block(capture_block.block_id);
-                    let pipeline = eval_block(
-                        engine_state,
-                        &mut stack,
-                        block,
-                        value.into_pipeline_data(),
-                        call.redirect_stdout,
-                        call.redirect_stderr,
-                    );
-
-                    match pipeline {
-                        Ok(s) => {
-                            let collection: Vec<Value> = s.into_iter().collect();
-
-                            if collection.len() > 1 {
-                                return Err(ShellError::GenericError(
-                                    "expected one value from the block".into(),
-                                    "requires a table with one value for grouping".into(),
-                                    Some(name),
-                                    None,
-                                    Vec::new(),
-                                ));
-                            }
-
-                            let value = match collection.get(0) {
-                                Some(Value::Error { .. }) | None => Value::string(error_key, name),
-                                Some(return_value) => return_value.clone(),
-                            };
-
-                            keys.push(value.as_string());
-                        }
-                        Err(_) => {
-                            keys.push(Ok(error_key.into()));
-                        }
-                    }
-                }
-            }
+    let mut cols = vec![];
+    let mut vals = vec![];
 
-            group_strategy = Grouper::ByBlock;
-        }
-        Some(other) => {
-            group_strategy = Grouper::ByColumn(Some(Spanned {
-                item: other.as_string()?,
-                span: name,
-            }));
+    for (key, group) in groups {
+        cols.push(key);
+        if rest.is_empty() {
+            vals.push(Value::List { vals: group, span });
+        } else {
+            let mut buckets: IndexMap<String, Vec<Value>> = IndexMap::new();
+            for value in group {
+                let group_key = group_key(engine_state, stack, call, &rest[0], &value, span)?;
+                buckets.entry(group_key).or_default().push(value);
+            }
+            vals.push(nest_groups(engine_state, stack, call, buckets, rest, span)?);
         }
-        _ => {}
     }
 
-    let name = if let Ok(span) = first.span() {
-        span
-    } else {
-        name
-    };
+    Ok(Value::Record { cols, vals, span })
+}
 
-    let group_value = match group_strategy {
-        Grouper::ByBlock => {
-            let map = keys;
+fn group_key(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    grouper: &Value,
+    value: &Value,
+    span: Span,
+) -> Result<String, ShellError> {
+    let error_key = "error";
 
-            let block = Box::new(move |idx: usize, row: &Value| match map.get(idx) {
-                Some(Ok(key)) => Ok(key.clone()),
-                Some(Err(reason)) => Err(reason.clone()),
-                None => row.as_string(),
-            });
+    match grouper {
+        Value::Block { val, .. } => {
+            let closure = Value::Closure {
+                val: *val,
+                captures: std::collections::HashMap::new(),
+                span,
+            };
+            group_key(engine_state, stack, call, &closure, value, span)
+        }
+        Value::Closure { val, captures, .. } => {
+            let mut stack = stack.captures_to_stack(captures);
+            let block = engine_state.get_block(*val);
+            let pipeline = eval_block(
+                engine_state,
+                &mut stack,
+                block,
+                value.clone().into_pipeline_data(),
+                call.redirect_stdout,
+                call.redirect_stderr,
+            );
+
+            match pipeline {
+                Ok(s) => {
+                    let collection: Vec<Value> = s.into_iter().collect();
+
+                    if collection.len() > 1 {
+                        return Err(ShellError::GenericError(
+                            "expected one value from the closure".into(),
+                            "requires a table with one value for grouping".into(),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        ));
+                    }
 
-            data_group(&value_list, &Some(block), name)
+                    match collection.get(0) {
+                        Some(Value::Error { .. }) | None => Ok(error_key.into()),
+                        Some(return_value) => return_value.as_string(),
+                    }
+                }
+                Err(_) => Ok(error_key.into()),
+            }
         }
-        Grouper::ByColumn(column_name) => group(&column_name, &value_list, name),
+        other => {
+            let column_name = other.as_string()?;
+            if let Value::Error { error } = value {
+                return Err(error.clone());
+            };
+            match value.get_data_by_key(&column_name) {
+                Some(group_key) => group_key.as_string(),
+                None => Err(ShellError::CantFindColumn {
+                    col_name: column_name,
+                    span: other.expect_span(),
+                    src_span: value.expect_span(),
+                }),
+            }
+        }
+    }
+}
+
+/// Turn a (possibly nested) record of groups into a table of {group, items} rows
+fn groups_to_table(grouped: Value, span: Span) -> Value {
+    let rows = match grouped {
+        Value::Record { cols, vals, .. } => cols
+            .into_iter()
+            .zip(vals)
+            .map(|(group, items)| {
+                // a record here is a nested level of grouping; leaf items are lists
+                let items = match items {
+                    nested @ Value::Record { .. } => groups_to_table(nested, span),
+                    leaf => leaf,
+                };
+                Value::Record {
+                    cols: vec!["group".into(), "items".into()],
+                    vals: vec![Value::String { val: group, span }, items],
+                    span,
+                }
+            })
+            .collect(),
+        other => vec![other],
     };
 
-    Ok(PipelineData::Value(group_value?, None))
+    Value::List { vals: rows, span }
 }
 
 #[allow(clippy::type_complexity)]
@@ -37,6 +37,10 @@ impl Command for Insert {
         "Insert a new column, using an expression or closure to create each row's values."
     }
 
+    fn extra_usage(&self) -> &str {
+        "When the replacement is a closure, it runs once for every row. The closure can take up to three parameters: the row itself (also available as $in), the value currently at the cell path (null when inserting a new column), and the row's index."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["add"]
     }
@@ -102,6 +106,30 @@ impl Command for Insert {
                 }],
                 span: Span::test_data(),
             }),
+        },
+        Example {
+            description: "Insert a computed column using the row and its index",
+            example: "[[foo]; [7] [8] [9]] | insert bar {|row, old, index| $row.foo + $index }",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::Record {
+                        cols: vec!["foo".into(), "bar".into()],
+                        vals: vec![Value::test_int(7), Value::test_int(7)],
+                        span: Span::test_data(),
+                    },
+                    Value::Record {
+                        cols: vec!["foo".into(), "bar".into()],
+                        vals: vec![Value::test_int(8), Value::test_int(9)],
+                        span: Span::test_data(),
+                    },
+                    Value::Record {
+                        cols: vec!["foo".into(), "bar".into()],
+                        vals: vec![Value::test_int(9), Value::test_int(11)],
+                        span: Span::test_data(),
+                    },
+                ],
+                span: Span::test_data(),
+            }),
         }]
     }
 }
@@ -132,6 +160,7 @@ fn insert(
         let orig_env_vars = stack.env_vars.clone();
         let orig_env_hidden = stack.env_hidden.clone();
 
+        let mut idx: i64 = 0;
         input.map(
             move |mut input| {
                 // with_env() is used here to ensure that each iteration uses
@@ -146,6 +175,26 @@ fn insert(
                     }
                 }
 
+                // Optional second closure argument: the value currently at the
+                // cell path
+                if let Some(var) = block.signature.get_positional(1) {
+                    if let Some(var_id) = &var.var_id {
+                        let current = input
+                            .clone()
+                            .follow_cell_path(&cell_path.members, false, true)
+                            .unwrap_or_else(|_| Value::nothing(span));
+                        stack.add_var(*var_id, current)
+                    }
+                }
+
+                // Optional third closure argument: the row index
+                if let Some(var) = block.signature.get_positional(2) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, Value::int(idx, span))
+                    }
+                }
+                idx += 1;
+
                 let output = eval_block(
                     &engine_state,
                     &mut stack,
@@ -174,6 +223,15 @@ fn insert(
             ctrlc,
         )
     } else {
+        // A leading negative index counts from the end of the input, so it needs
+        // the full length before it can be resolved; collect the stream first
+        if let Some(PathMember::String { val, .. }) = cell_path.members.get(0) {
+            if val.parse::<i64>().map_or(false, i64::is_negative) {
+                let mut value = input.into_value(span);
+                value.insert_data_at_cell_path(&cell_path.members, replacement, span)?;
+                return Ok(value.into_pipeline_data());
+            }
+        }
         if let Some(PathMember::Int { val, .. }) = cell_path.members.get(0) {
             let mut input = input.into_iter();
             let mut pre_elems = vec![];
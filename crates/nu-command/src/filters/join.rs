@@ -0,0 +1,341 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct Join;
+
+impl Command for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn usage(&self) -> &str {
+        "Join two tables on one or more key columns."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"An inner join is performed unless one of --left, --right or --outer is
+given. Rows are matched with a hash lookup, so large tables don't need
+nested loops. Non-key columns that exist on both sides keep the left
+table's value; the right table's value is kept under the column name with
+the suffix appended."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["relational", "merge", "inner", "outer", "sql"]
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("join")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                // outer joins mix row shapes, which types as a plain list
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required("right", SyntaxShape::Table, "the table to join with")
+            .required(
+                "on",
+                SyntaxShape::Any,
+                "the key column name, or list of column names, to join on",
+            )
+            .switch("inner", "keep only matching rows (the default)", Some('i'))
+            .switch("left", "keep every row of the input table", Some('l'))
+            .switch("right", "keep every row of the right table", Some('r'))
+            .switch("outer", "keep every row of both tables", Some('o'))
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "the suffix for right-table columns whose names collide (default '_')",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[{a: 1, b: left}] | join [{a: 1, c: right}] a",
+                description: "Inner join two tables on column 'a'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_record(
+                        vec!["a", "b", "c"],
+                        vec![
+                            Value::test_int(1),
+                            Value::test_string("left"),
+                            Value::test_string("right"),
+                        ],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[{a: 1, b: left}] | join [{a: 2, c: right}] a --outer",
+                description: "Outer join, keeping unmatched rows of both tables",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["a", "b", "c"],
+                            vec![
+                                Value::test_int(1),
+                                Value::test_string("left"),
+                                Value::nothing(Span::test_data()),
+                            ],
+                        ),
+                        Value::test_record(
+                            vec!["a", "b", "c"],
+                            vec![
+                                Value::test_int(2),
+                                Value::nothing(Span::test_data()),
+                                Value::test_string("right"),
+                            ],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[{k: 1, v: old}] | join [{k: 1, v: new}] k --suffix _right",
+                description: "Suffix the right table's column when names collide",
+                result: Some(Value::List {
+                    vals: vec![Value::test_record(
+                        vec!["k", "v", "v_right"],
+                        vec![
+                            Value::test_int(1),
+                            Value::test_string("old"),
+                            Value::test_string("new"),
+                        ],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let right: Value = call.req(engine_state, stack, 0)?;
+        let on: Value = call.req(engine_state, stack, 1)?;
+        let suffix: Option<String> = call.get_flag(engine_state, stack, "suffix")?;
+        let suffix = suffix.unwrap_or_else(|| "_".into());
+        let mode = join_mode(call, head)?;
+        let config = engine_state.get_config();
+
+        let keys = match &on {
+            Value::String { val, .. } => vec![val.clone()],
+            Value::List { vals, .. } => vals
+                .iter()
+                .map(|v| v.as_string())
+                .collect::<Result<Vec<_>, _>>()?,
+            other => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "expected a column name or a list of column names".into(),
+                    span: other.expect_span(),
+                })
+            }
+        };
+
+        let left_rows = match input.into_value(head) {
+            Value::List { vals, .. } => vals,
+            _ => {
+                return Err(ShellError::PipelineMismatch {
+                    exp_input_type: "table".into(),
+                    dst_span: head,
+                    src_span: Span::new(head.start, head.start),
+                })
+            }
+        };
+        let right_rows = match right {
+            Value::List { vals, .. } => vals,
+            other => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "expected the argument to be a table".into(),
+                    span: other.expect_span(),
+                })
+            }
+        };
+
+        let left_cols = template_columns(&left_rows);
+        let right_cols = template_columns(&right_rows);
+
+        // index the right table once, then probe it per left row
+        let mut right_index: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+        for (i, row) in right_rows.iter().enumerate() {
+            if let Some(key) = key_of(row, &keys, config) {
+                right_index.entry(key).or_default().push(i);
+            }
+        }
+
+        let mut matched_right = vec![false; right_rows.len()];
+        let mut output = vec![];
+
+        for left_row in &left_rows {
+            let matches = key_of(left_row, &keys, config)
+                .and_then(|key| right_index.get(&key))
+                .cloned()
+                .unwrap_or_default();
+
+            if matches.is_empty() {
+                if matches!(mode, JoinMode::Left | JoinMode::Outer) {
+                    output.push(joined_row(
+                        Some(left_row),
+                        None,
+                        (&left_cols, &right_cols),
+                        (&keys, &suffix),
+                        head,
+                    ));
+                }
+            } else {
+                for i in matches {
+                    matched_right[i] = true;
+                    output.push(joined_row(
+                        Some(left_row),
+                        Some(&right_rows[i]),
+                        (&left_cols, &right_cols),
+                        (&keys, &suffix),
+                        head,
+                    ));
+                }
+            }
+        }
+
+        if matches!(mode, JoinMode::Right | JoinMode::Outer) {
+            for (i, right_row) in right_rows.iter().enumerate() {
+                if !matched_right[i] {
+                    output.push(joined_row(
+                        None,
+                        Some(right_row),
+                        (&left_cols, &right_cols),
+                        (&keys, &suffix),
+                        head,
+                    ));
+                }
+            }
+        }
+
+        Ok(Value::List {
+            vals: output,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum JoinMode {
+    Inner,
+    Left,
+    Right,
+    Outer,
+}
+
+fn join_mode(call: &Call, head: Span) -> Result<JoinMode, ShellError> {
+    let flags = [
+        (call.has_flag("inner"), JoinMode::Inner),
+        (call.has_flag("left"), JoinMode::Left),
+        (call.has_flag("right"), JoinMode::Right),
+        (call.has_flag("outer"), JoinMode::Outer),
+    ];
+    let mut chosen = flags.iter().filter(|(set, _)| *set).map(|(_, mode)| *mode);
+    let mode = chosen.next().unwrap_or(JoinMode::Inner);
+    if chosen.next().is_some() {
+        return Err(ShellError::GenericError(
+            "Conflicting join types".into(),
+            "only one of --inner, --left, --right and --outer may be used".into(),
+            Some(head),
+            None,
+            vec![],
+        ));
+    }
+    Ok(mode)
+}
+
+/// The union of the column names of every row, in first-seen order
+fn template_columns(rows: &[Value]) -> Vec<String> {
+    let mut columns: Vec<String> = vec![];
+    for row in rows {
+        if let Value::Record { cols, .. } = row {
+            for col in cols {
+                if !columns.contains(col) {
+                    columns.push(col.clone());
+                }
+            }
+        }
+    }
+    columns
+}
+
+/// The row's join key, rendered to strings; `None` if a key column is missing
+fn key_of(row: &Value, keys: &[String], config: &Config) -> Option<Vec<String>> {
+    keys.iter()
+        .map(|key| {
+            row.get_data_by_key(key)
+                .map(|v| v.into_string(", ", config))
+        })
+        .collect()
+}
+
+fn cell(row: Option<&Value>, col: &str, span: Span) -> Value {
+    row.and_then(|row| row.get_data_by_key(col))
+        .unwrap_or_else(|| Value::nothing(span))
+}
+
+fn joined_row(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    (left_cols, right_cols): (&[String], &[String]),
+    (keys, suffix): (&[String], &str),
+    span: Span,
+) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for col in left_cols {
+        cols.push(col.clone());
+        // an unmatched right row still carries the key values
+        if left.is_none() && keys.contains(col) {
+            vals.push(cell(right, col, span));
+        } else {
+            vals.push(cell(left, col, span));
+        }
+    }
+    for col in right_cols {
+        if keys.contains(col) {
+            continue;
+        }
+        if left_cols.contains(col) {
+            cols.push(format!("{col}{suffix}"));
+        } else {
+            cols.push(col.clone());
+        }
+        vals.push(cell(right, col, span));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Join {})
+    }
+}
@@ -0,0 +1,272 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct MergeDeep;
+
+impl Command for MergeDeep {
+    fn name(&self) -> &str {
+        "merge deep"
+    }
+
+    fn usage(&self) -> &str {
+        "Merge the input with a record or table, recursing into nested records."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Unlike `merge`, keys whose values are records on both sides are merged
+key by key at every depth. The strategy decides what happens when both
+sides define the same non-record key:
+  overwrite  take the value from the argument (the default)
+  append     concatenate lists; other values are overwritten
+  error      fail, naming the conflicting key"#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["recursive", "nested", "combine", "config"]
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("merge deep")
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Record(vec![])),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "the record or table to merge with",
+            )
+            .named(
+                "strategy",
+                SyntaxShape::String,
+                "how to resolve conflicting keys: overwrite (default), append or error",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "{a: {x: 1}, b: 2} | merge deep {a: {y: 3}}",
+                description: "Merge nested records without losing sibling keys",
+                result: Some(Value::Record {
+                    cols: vec!["a".into(), "b".into()],
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["x".into(), "y".into()],
+                            vals: vec![Value::test_int(1), Value::test_int(3)],
+                            span: Span::test_data(),
+                        },
+                        Value::test_int(2),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "{tags: [a b]} | merge deep --strategy append {tags: [c]}",
+                description: "Concatenate lists instead of replacing them",
+                result: Some(Value::Record {
+                    cols: vec!["tags".into()],
+                    vals: vec![Value::List {
+                        vals: vec![
+                            Value::test_string("a"),
+                            Value::test_string("b"),
+                            Value::test_string("c"),
+                        ],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[{opts: {color: red}}] | merge deep [{opts: {size: 2}}]",
+                description: "Merge two tables row by row, recursing into each cell",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["opts".into()],
+                        vals: vec![Value::Record {
+                            cols: vec!["color".into(), "size".into()],
+                            vals: vec![Value::test_string("red"), Value::test_int(2)],
+                            span: Span::test_data(),
+                        }],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let merge_value: Value = call.req(engine_state, stack, 0)?;
+        let strategy: Option<Spanned<String>> = call.get_flag(engine_state, stack, "strategy")?;
+        let strategy = match strategy {
+            Some(name) => Strategy::from_name(&name)?,
+            None => Strategy::Overwrite,
+        };
+
+        let input = input.into_value(head);
+        match (input, merge_value) {
+            (record @ Value::Record { .. }, to_merge @ Value::Record { .. }) => {
+                let mut path = vec![];
+                Ok(merge_values(record, to_merge, strategy, &mut path, head)?.into_pipeline_data())
+            }
+            (Value::List { vals, span }, Value::List { vals: to_merge, .. }) => {
+                let mut rows = vec![];
+                let mut to_merge = to_merge.into_iter();
+                for row in vals {
+                    rows.push(match to_merge.next() {
+                        Some(other) => {
+                            let mut path = vec![];
+                            merge_values(row, other, strategy, &mut path, head)?
+                        }
+                        None => row,
+                    });
+                }
+                Ok(Value::List { vals: rows, span }.into_pipeline_data())
+            }
+            _ => Err(ShellError::PipelineMismatch {
+                exp_input_type: "input, and argument, to be both record or both table".to_string(),
+                dst_span: head,
+                src_span: Span::new(head.start, head.start),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Strategy {
+    Overwrite,
+    Append,
+    Error,
+}
+
+impl Strategy {
+    fn from_name(name: &Spanned<String>) -> Result<Self, ShellError> {
+        match name.item.as_str() {
+            "overwrite" => Ok(Strategy::Overwrite),
+            "append" => Ok(Strategy::Append),
+            "error" => Ok(Strategy::Error),
+            other => Err(ShellError::UnsupportedInput(
+                format!("'{other}' is not a valid merge strategy"),
+                "expected overwrite, append or error".into(),
+                name.span,
+                name.span,
+            )),
+        }
+    }
+}
+
+fn merge_values(
+    left: Value,
+    right: Value,
+    strategy: Strategy,
+    path: &mut Vec<String>,
+    head: Span,
+) -> Result<Value, ShellError> {
+    match (left, right) {
+        (
+            Value::Record { cols, vals, span },
+            Value::Record {
+                cols: to_merge_cols,
+                vals: to_merge_vals,
+                ..
+            },
+        ) => {
+            let mut cols = cols;
+            let mut vals = vals;
+            for (col, val) in to_merge_cols.into_iter().zip(to_merge_vals) {
+                match cols.iter().position(|c| c == &col) {
+                    Some(index) => {
+                        path.push(col);
+                        let existing = std::mem::replace(&mut vals[index], Value::nothing(head));
+                        vals[index] = merge_values(existing, val, strategy, path, head)?;
+                        path.pop();
+                    }
+                    None => {
+                        cols.push(col);
+                        vals.push(val);
+                    }
+                }
+            }
+            Ok(Value::Record { cols, vals, span })
+        }
+        (Value::List { vals, span }, Value::List { vals: to_merge, .. })
+            if matches!(strategy, Strategy::Append) =>
+        {
+            let mut vals = vals;
+            vals.extend(to_merge);
+            Ok(Value::List { vals, span })
+        }
+        (_, right) => match strategy {
+            Strategy::Error => Err(ShellError::GenericError(
+                "Merge conflict".into(),
+                format!("the key '{}' is present in both sides", path.join(".")),
+                Some(head),
+                Some("use --strategy overwrite or append to resolve conflicts".into()),
+                vec![],
+            )),
+            _ => Ok(right),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(entries: &[(&str, Value)]) -> Value {
+        Value::Record {
+            cols: entries.iter().map(|(k, _)| k.to_string()).collect(),
+            vals: entries.iter().map(|(_, v)| v.clone()).collect(),
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn error_strategy_names_the_nested_key() {
+        let left = record(&[("a", record(&[("b", Value::test_int(1))]))]);
+        let right = record(&[("a", record(&[("b", Value::test_int(2))]))]);
+
+        let error =
+            merge_values(left, right, Strategy::Error, &mut vec![], Span::test_data()).unwrap_err();
+        assert!(error.to_string().contains("Merge conflict"));
+    }
+
+    #[test]
+    fn append_strategy_still_overwrites_scalars() {
+        let left = record(&[("a", Value::test_int(1))]);
+        let right = record(&[("a", Value::test_int(2))]);
+
+        let merged = merge_values(
+            left,
+            right,
+            Strategy::Append,
+            &mut vec![],
+            Span::test_data(),
+        )
+        .unwrap();
+        assert_eq!(merged, record(&[("a", Value::test_int(2))]));
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(MergeDeep {})
+    }
+}
@@ -1,6 +1,7 @@
 mod all;
 mod any;
 mod append;
+mod chunk_by;
 mod collect;
 mod columns;
 mod compact;
@@ -20,13 +21,16 @@ mod group;
 mod group_by;
 mod headers;
 mod insert;
+mod join;
 mod last;
 mod length;
 mod lines;
 mod merge;
+mod merge_deep;
 mod move_;
 mod par_each;
 mod prepend;
+mod query;
 mod range;
 mod reduce;
 mod reject;
@@ -34,14 +38,20 @@ mod rename;
 mod reverse;
 mod roll;
 mod rotate;
+mod sample;
+mod scan;
 mod select;
 mod shuffle;
 mod skip;
 mod sort;
 mod sort_by;
 mod split_by;
+mod summarize_footer;
 mod take;
+mod tee;
+mod top;
 mod transpose;
+mod unflatten;
 mod uniq;
 mod uniq_by;
 mod update;
@@ -57,6 +67,7 @@ mod zip;
 pub use all::All;
 pub use any::Any;
 pub use append::Append;
+pub use chunk_by::ChunkBy;
 pub use collect::Collect;
 pub use columns::Columns;
 pub use compact::Compact;
@@ -76,13 +87,16 @@ pub use group::Group;
 pub use group_by::GroupBy;
 pub use headers::Headers;
 pub use insert::Insert;
+pub use join::Join;
 pub use last::Last;
 pub use length::Length;
 pub use lines::Lines;
 pub use merge::Merge;
+pub use merge_deep::MergeDeep;
 pub use move_::Move;
 pub use par_each::ParEach;
 pub use prepend::Prepend;
+pub use query::{Query, QueryJson};
 pub use range::Range;
 pub use reduce::Reduce;
 pub use reject::Reject;
@@ -90,14 +104,20 @@ pub use rename::Rename;
 pub use reverse::Reverse;
 pub use roll::*;
 pub use rotate::Rotate;
+pub use sample::Sample;
+pub use scan::Scan;
 pub use select::Select;
 pub use shuffle::Shuffle;
 pub use skip::*;
 pub use sort::Sort;
 pub use sort_by::SortBy;
 pub use split_by::SplitBy;
+pub use summarize_footer::{append_summary_footer, SummarizeFooter};
 pub use take::*;
+pub use tee::Tee;
+pub use top::{Bottom, Top};
 pub use transpose::Transpose;
+pub use unflatten::Unflatten;
 pub use uniq::*;
 pub use uniq_by::UniqBy;
 pub use update::Update;
@@ -30,6 +30,17 @@ impl Command for ParEach {
                 ),
                 (Type::Table(vec![]), Type::List(Box::new(Type::Any))),
             ])
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of threads to use",
+                Some('t'),
+            )
+            .switch(
+                "keep-order",
+                "keep the order of the output the same as the order of the input",
+                Some('k'),
+            )
             .required(
                 "closure",
                 SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
@@ -46,6 +57,23 @@ impl Command for ParEach {
                     "Multiplies each number. Note that the list will become arbitrarily disordered.",
                 result: None,
             },
+            Example {
+                example: "[1 2 3] | par-each --threads 2 { 2 * $in }",
+                description: "Multiplies each number, using a maximum of two worker threads",
+                result: None,
+            },
+            Example {
+                example: r#"[foo bar baz] | par-each --keep-order {|e| $e + '!' }"#,
+                description: "Output is in the same order as the input",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("foo!"),
+                        Value::test_string("bar!"),
+                        Value::test_string("baz!"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 example: r#"[foo bar baz] | par-each {|e| $e + '!' } | sort"#,
                 description: "Output can still be sorted afterward",
@@ -86,6 +114,9 @@ impl Command for ParEach {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let threads: Option<usize> = call.get_flag(engine_state, stack, "threads")?;
+        let max_threads = threads.unwrap_or(0);
+        let keep_order = call.has_flag("keep-order");
 
         let metadata = input.metadata();
         let ctrlc = engine_state.ctrlc.clone();
@@ -93,171 +124,232 @@ impl Command for ParEach {
         let mut stack = stack.captures_to_stack(&capture_block.captures);
         let redirect_stdout = call.redirect_stdout;
         let redirect_stderr = call.redirect_stderr;
+        let span = call.head;
 
-        match input {
-            PipelineData::Empty => Ok(PipelineData::Empty),
-            PipelineData::Value(Value::Range { val, .. }, ..) => Ok(val
-                .into_range_iter(ctrlc.clone())?
-                .par_bridge()
-                .map(move |x| {
-                    let block = engine_state.get_block(block_id);
+        // the work runs inside the pool so every `par_bridge` below respects
+        // the requested thread count
+        create_pool(max_threads, span)?
+            .install(|| -> Result<PipelineData, ShellError> {
+                match input {
+                    PipelineData::Empty => Ok(PipelineData::Empty),
+                    PipelineData::Value(Value::Range { val, .. }, ..) => {
+                        let vals = val
+                            .into_range_iter(ctrlc.clone())?
+                            .enumerate()
+                            .par_bridge()
+                            .map(move |(index, x)| {
+                                let block = engine_state.get_block(block_id);
 
-                    let mut stack = stack.clone();
+                                let mut stack = stack.clone();
 
-                    if let Some(var) = block.signature.get_positional(0) {
-                        if let Some(var_id) = &var.var_id {
-                            stack.add_var(*var_id, x.clone());
-                        }
-                    }
+                                if let Some(var) = block.signature.get_positional(0) {
+                                    if let Some(var_id) = &var.var_id {
+                                        stack.add_var(*var_id, x.clone());
+                                    }
+                                }
 
-                    let val_span = x.span();
-                    match eval_block_with_early_return(
-                        engine_state,
-                        &mut stack,
-                        block,
-                        x.into_pipeline_data(),
-                        redirect_stdout,
-                        redirect_stderr,
-                    ) {
-                        Ok(v) => v,
-                        Err(error) => Value::Error {
-                            error: chain_error_with_input(error, val_span),
-                        }
-                        .into_pipeline_data(),
-                    }
-                })
-                .collect::<Vec<_>>()
-                .into_iter()
-                .flatten()
-                .into_pipeline_data(ctrlc)),
-            PipelineData::Value(Value::List { vals: val, .. }, ..) => Ok(val
-                .into_iter()
-                .par_bridge()
-                .map(move |x| {
-                    let block = engine_state.get_block(block_id);
-
-                    let mut stack = stack.clone();
-
-                    if let Some(var) = block.signature.get_positional(0) {
-                        if let Some(var_id) = &var.var_id {
-                            stack.add_var(*var_id, x.clone());
-                        }
+                                let val_span = x.span();
+                                let result = match eval_block_with_early_return(
+                                    engine_state,
+                                    &mut stack,
+                                    block,
+                                    x.into_pipeline_data(),
+                                    redirect_stdout,
+                                    redirect_stderr,
+                                ) {
+                                    Ok(v) => v,
+                                    Err(error) => Value::Error {
+                                        error: chain_error_with_input(error, val_span),
+                                    }
+                                    .into_pipeline_data(),
+                                };
+
+                                (index, result)
+                            })
+                            .collect::<Vec<_>>();
+
+                        Ok(apply_order(vals, keep_order)
+                            .flatten()
+                            .into_pipeline_data(ctrlc))
                     }
+                    PipelineData::Value(Value::List { vals: val, .. }, ..) => {
+                        let vals = val
+                            .into_iter()
+                            .enumerate()
+                            .par_bridge()
+                            .map(move |(index, x)| {
+                                let block = engine_state.get_block(block_id);
 
-                    let val_span = x.span();
-                    match eval_block_with_early_return(
-                        engine_state,
-                        &mut stack,
-                        block,
-                        x.into_pipeline_data(),
-                        redirect_stdout,
-                        redirect_stderr,
-                    ) {
-                        Ok(v) => v,
-                        Err(error) => Value::Error {
-                            error: chain_error_with_input(error, val_span),
-                        }
-                        .into_pipeline_data(),
+                                let mut stack = stack.clone();
+
+                                if let Some(var) = block.signature.get_positional(0) {
+                                    if let Some(var_id) = &var.var_id {
+                                        stack.add_var(*var_id, x.clone());
+                                    }
+                                }
+
+                                let val_span = x.span();
+                                let result = match eval_block_with_early_return(
+                                    engine_state,
+                                    &mut stack,
+                                    block,
+                                    x.into_pipeline_data(),
+                                    redirect_stdout,
+                                    redirect_stderr,
+                                ) {
+                                    Ok(v) => v,
+                                    Err(error) => Value::Error {
+                                        error: chain_error_with_input(error, val_span),
+                                    }
+                                    .into_pipeline_data(),
+                                };
+
+                                (index, result)
+                            })
+                            .collect::<Vec<_>>();
+
+                        Ok(apply_order(vals, keep_order)
+                            .flatten()
+                            .into_pipeline_data(ctrlc))
                     }
-                })
-                .collect::<Vec<_>>()
-                .into_iter()
-                .flatten()
-                .into_pipeline_data(ctrlc)),
-            PipelineData::ListStream(stream, ..) => Ok(stream
-                .par_bridge()
-                .map(move |x| {
-                    let block = engine_state.get_block(block_id);
-
-                    let mut stack = stack.clone();
-
-                    if let Some(var) = block.signature.get_positional(0) {
-                        if let Some(var_id) = &var.var_id {
-                            stack.add_var(*var_id, x.clone());
-                        }
+                    PipelineData::ListStream(stream, ..) => {
+                        let vals = stream
+                            .enumerate()
+                            .par_bridge()
+                            .map(move |(index, x)| {
+                                let block = engine_state.get_block(block_id);
+
+                                let mut stack = stack.clone();
+
+                                if let Some(var) = block.signature.get_positional(0) {
+                                    if let Some(var_id) = &var.var_id {
+                                        stack.add_var(*var_id, x.clone());
+                                    }
+                                }
+
+                                let val_span = x.span();
+                                let result = match eval_block_with_early_return(
+                                    engine_state,
+                                    &mut stack,
+                                    block,
+                                    x.into_pipeline_data(),
+                                    redirect_stdout,
+                                    redirect_stderr,
+                                ) {
+                                    Ok(v) => v,
+                                    Err(error) => Value::Error {
+                                        error: chain_error_with_input(error, val_span),
+                                    }
+                                    .into_pipeline_data(),
+                                };
+
+                                (index, result)
+                            })
+                            .collect::<Vec<_>>();
+
+                        Ok(apply_order(vals, keep_order)
+                            .flatten()
+                            .into_pipeline_data(ctrlc))
                     }
+                    PipelineData::ExternalStream { stdout: None, .. } => Ok(PipelineData::empty()),
+                    PipelineData::ExternalStream {
+                        stdout: Some(stream),
+                        ..
+                    } => {
+                        let vals = stream
+                            .enumerate()
+                            .par_bridge()
+                            .map(move |(index, x)| {
+                                let result = match x {
+                                    Err(err) => Value::Error { error: err }.into_pipeline_data(),
+                                    Ok(x) => {
+                                        let block = engine_state.get_block(block_id);
 
-                    let val_span = x.span();
-                    match eval_block_with_early_return(
-                        engine_state,
-                        &mut stack,
-                        block,
-                        x.into_pipeline_data(),
-                        redirect_stdout,
-                        redirect_stderr,
-                    ) {
-                        Ok(v) => v,
-                        Err(error) => Value::Error {
-                            error: chain_error_with_input(error, val_span),
-                        }
-                        .into_pipeline_data(),
+                                        let mut stack = stack.clone();
+
+                                        if let Some(var) = block.signature.get_positional(0) {
+                                            if let Some(var_id) = &var.var_id {
+                                                stack.add_var(*var_id, x.clone());
+                                            }
+                                        }
+
+                                        match eval_block_with_early_return(
+                                            engine_state,
+                                            &mut stack,
+                                            block,
+                                            x.into_pipeline_data(),
+                                            redirect_stdout,
+                                            redirect_stderr,
+                                        ) {
+                                            Ok(v) => v,
+                                            Err(error) => {
+                                                Value::Error { error }.into_pipeline_data()
+                                            }
+                                        }
+                                    }
+                                };
+
+                                (index, result)
+                            })
+                            .collect::<Vec<_>>();
+
+                        Ok(apply_order(vals, keep_order)
+                            .flatten()
+                            .into_pipeline_data(ctrlc))
                     }
-                })
-                .collect::<Vec<_>>()
-                .into_iter()
-                .flatten()
-                .into_pipeline_data(ctrlc)),
-            PipelineData::ExternalStream { stdout: None, .. } => Ok(PipelineData::empty()),
-            PipelineData::ExternalStream {
-                stdout: Some(stream),
-                ..
-            } => Ok(stream
-                .par_bridge()
-                .map(move |x| {
-                    let x = match x {
-                        Ok(x) => x,
-                        Err(err) => return Value::Error { error: err }.into_pipeline_data(),
-                    };
-
-                    let block = engine_state.get_block(block_id);
-
-                    let mut stack = stack.clone();
-
-                    if let Some(var) = block.signature.get_positional(0) {
-                        if let Some(var_id) = &var.var_id {
-                            stack.add_var(*var_id, x.clone());
+                    // This match allows non-iterables to be accepted,
+                    // which is currently considered undesirable (Nov 2022).
+                    PipelineData::Value(x, ..) => {
+                        let block = engine_state.get_block(block_id);
+
+                        if let Some(var) = block.signature.get_positional(0) {
+                            if let Some(var_id) = &var.var_id {
+                                stack.add_var(*var_id, x.clone());
+                            }
                         }
-                    }
 
-                    match eval_block_with_early_return(
-                        engine_state,
-                        &mut stack,
-                        block,
-                        x.into_pipeline_data(),
-                        redirect_stdout,
-                        redirect_stderr,
-                    ) {
-                        Ok(v) => v,
-                        Err(error) => Value::Error { error }.into_pipeline_data(),
-                    }
-                })
-                .collect::<Vec<_>>()
-                .into_iter()
-                .flatten()
-                .into_pipeline_data(ctrlc)),
-            // This match allows non-iterables to be accepted,
-            // which is currently considered undesirable (Nov 2022).
-            PipelineData::Value(x, ..) => {
-                let block = engine_state.get_block(block_id);
-
-                if let Some(var) = block.signature.get_positional(0) {
-                    if let Some(var_id) = &var.var_id {
-                        stack.add_var(*var_id, x.clone());
+                        eval_block_with_early_return(
+                            engine_state,
+                            &mut stack,
+                            block,
+                            x.into_pipeline_data(),
+                            redirect_stdout,
+                            redirect_stderr,
+                        )
                     }
                 }
+            })
+            .map(|res| res.set_metadata(metadata))
+    }
+}
 
-                eval_block_with_early_return(
-                    engine_state,
-                    &mut stack,
-                    block,
-                    x.into_pipeline_data(),
-                    redirect_stdout,
-                    redirect_stderr,
-                )
-            }
-        }
-        .map(|res| res.set_metadata(metadata))
+/// A pool limited to `max_threads` workers, or rayon's default when zero
+fn create_pool(max_threads: usize, span: Span) -> Result<rayon::ThreadPool, ShellError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(max_threads)
+        .build()
+        .map_err(|err| {
+            ShellError::GenericError(
+                "Failed to create the thread pool".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                vec![],
+            )
+        })
+}
+
+/// The closures finish in whatever order the pool schedules them; restore the
+/// input order afterwards when `--keep-order` asks for it
+fn apply_order(
+    mut vals: Vec<(usize, PipelineData)>,
+    keep_order: bool,
+) -> impl Iterator<Item = PipelineData> {
+    if keep_order {
+        vals.sort_by_key(|(index, _)| *index);
     }
+
+    vals.into_iter().map(|(_, val)| val)
 }
 
 #[cfg(test)]
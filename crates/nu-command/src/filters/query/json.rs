@@ -0,0 +1,608 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct QueryJson;
+
+impl Command for QueryJson {
+    fn name(&self) -> &str {
+        "query json"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query json")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "query",
+                SyntaxShape::String,
+                "the jq-style path expression to evaluate",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Query structured data with a jq-style path expression."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The expression is a dot-separated path with an optional leading `$`. Beyond
+plain member names it supports recursive descent (`..name` finds the column at
+any depth), indices and slices (`[0]`, `[-1]`, `[1:3]`), wildcards (`.*` or
+`[*]`), and filters over the current element (`[?(@.age > 25)]`). Members that
+do not match produce nothing rather than an error.
+
+A query that only uses plain members and indices returns the single matching
+value; one that uses recursive descent, wildcards, slices or filters returns a
+list of every match."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["jq", "jsonpath", "filter", "extract"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let query: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let steps = parse_query(&query.item, query.span)?;
+        let value = input.into_value(call.head);
+
+        Ok(eval_query(value, &steps, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Drill into nested records",
+                example: "{a: {b: 1}} | query json 'a.b'",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Filter rows like jq, then project a column",
+                example: "{users: [{name: alice, age: 30} {name: bob, age: 20}]} | query json 'users[?(@.age > 25)].name'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("alice")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Collect a column from any depth with recursive descent",
+                example: "{a: {c: 1}, b: {d: {c: 2}}} | query json '..c'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Take a slice of a list",
+                example: "[0 1 2 3 4] | query json '[1:3]'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+/// One segment of a parsed query expression
+enum Step {
+    Member(String),
+    RecursiveMember(String),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>),
+    Wildcard,
+    Filter(FilterExpr),
+}
+
+/// A `[?(@.path op literal)]` predicate; without a comparison it tests that
+/// the path exists
+struct FilterExpr {
+    path: Vec<String>,
+    comparison: Option<(CmpOp, Literal)>,
+}
+
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+enum Literal {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    Null,
+}
+
+fn parse_query(query: &str, span: Span) -> Result<Vec<Step>, ShellError> {
+    let mut chars = query.chars().peekable();
+    let mut steps = vec![];
+
+    if chars.peek() == Some(&'$') {
+        chars.next();
+    }
+
+    loop {
+        match chars.peek().copied() {
+            None => break,
+            Some('.') => {
+                chars.next();
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    let name = read_name(&mut chars);
+                    if name.is_empty() {
+                        return Err(query_error("`..` must be followed by a member name", span));
+                    }
+                    steps.push(Step::RecursiveMember(name));
+                } else if chars.peek() == Some(&'*') {
+                    chars.next();
+                    steps.push(Step::Wildcard);
+                } else {
+                    let name = read_name(&mut chars);
+                    if name.is_empty() {
+                        return Err(query_error("`.` must be followed by a member name", span));
+                    }
+                    steps.push(Step::Member(name));
+                }
+            }
+            Some('[') => {
+                chars.next();
+                steps.push(parse_bracket(&mut chars, span)?);
+            }
+            Some(c) => {
+                // a bare name is allowed at the start, and after `]`
+                let name = read_name(&mut chars);
+                if name.is_empty() {
+                    return Err(query_error(
+                        &format!("unexpected character '{c}' in query"),
+                        span,
+                    ));
+                }
+                steps.push(Step::Member(name));
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+fn parse_bracket(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    span: Span,
+) -> Result<Step, ShellError> {
+    skip_spaces(chars);
+    let step = match chars.peek().copied() {
+        Some('*') => {
+            chars.next();
+            Step::Wildcard
+        }
+        Some(quote) if quote == '\'' || quote == '"' => {
+            chars.next();
+            Step::Member(read_quoted(chars, quote, span)?)
+        }
+        Some('?') => {
+            chars.next();
+            if chars.next() != Some('(') {
+                return Err(query_error("`[?` must be followed by `(`", span));
+            }
+            let filter = parse_filter(chars, span)?;
+            if chars.next() != Some(')') {
+                return Err(query_error("unclosed `(` in filter", span));
+            }
+            Step::Filter(filter)
+        }
+        _ => {
+            let mut body = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ']' {
+                    break;
+                }
+                body.push(c);
+                chars.next();
+            }
+            let body = body.trim();
+            if let Some((from, to)) = body.split_once(':') {
+                Step::Slice(parse_bound(from, span)?, parse_bound(to, span)?)
+            } else {
+                match body.parse::<i64>() {
+                    Ok(idx) => Step::Index(idx),
+                    Err(_) => {
+                        return Err(query_error(
+                            &format!(
+                                "expected an index, slice, wildcard or filter, found '{body}'"
+                            ),
+                            span,
+                        ))
+                    }
+                }
+            }
+        }
+    };
+    skip_spaces(chars);
+    if chars.next() != Some(']') {
+        return Err(query_error("unclosed `[` in query", span));
+    }
+    Ok(step)
+}
+
+fn parse_filter(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    span: Span,
+) -> Result<FilterExpr, ShellError> {
+    skip_spaces(chars);
+    if chars.next() != Some('@') {
+        return Err(query_error("a filter must start with `@`", span));
+    }
+    let mut path = vec![];
+    while chars.peek() == Some(&'.') {
+        chars.next();
+        let name = read_name(chars);
+        if name.is_empty() {
+            return Err(query_error("`.` must be followed by a member name", span));
+        }
+        path.push(name);
+    }
+    skip_spaces(chars);
+    if chars.peek() == Some(&')') {
+        return Ok(FilterExpr {
+            path,
+            comparison: None,
+        });
+    }
+
+    let mut op = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == '=' || c == '!' || c == '<' || c == '>' {
+            op.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    let op = match op.as_str() {
+        "==" => CmpOp::Eq,
+        "!=" => CmpOp::Ne,
+        "<" => CmpOp::Lt,
+        "<=" => CmpOp::Le,
+        ">" => CmpOp::Gt,
+        ">=" => CmpOp::Ge,
+        other => {
+            return Err(query_error(
+                &format!("unknown comparison operator '{other}'"),
+                span,
+            ))
+        }
+    };
+
+    skip_spaces(chars);
+    let literal = match chars.peek().copied() {
+        Some(quote) if quote == '\'' || quote == '"' => {
+            chars.next();
+            Literal::String(read_quoted(chars, quote, span)?)
+        }
+        _ => {
+            let mut body = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ')' || c == ' ' {
+                    break;
+                }
+                body.push(c);
+                chars.next();
+            }
+            match body.as_str() {
+                "true" => Literal::Bool(true),
+                "false" => Literal::Bool(false),
+                "null" => Literal::Null,
+                body => {
+                    if let Ok(int) = body.parse::<i64>() {
+                        Literal::Int(int)
+                    } else if let Ok(float) = body.parse::<f64>() {
+                        Literal::Float(float)
+                    } else {
+                        return Err(query_error(
+                            &format!("expected a literal to compare against, found '{body}'"),
+                            span,
+                        ));
+                    }
+                }
+            }
+        }
+    };
+    skip_spaces(chars);
+
+    Ok(FilterExpr {
+        path,
+        comparison: Some((op, literal)),
+    })
+}
+
+fn read_name(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_alphanumeric() || c == '_' || c == '-' {
+            name.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+fn read_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    quote: char,
+    span: Span,
+) -> Result<String, ShellError> {
+    let mut out = String::new();
+    for c in chars.by_ref() {
+        if c == quote {
+            return Ok(out);
+        }
+        out.push(c);
+    }
+    Err(query_error("unclosed quote in query", span))
+}
+
+fn parse_bound(bound: &str, span: Span) -> Result<Option<i64>, ShellError> {
+    let bound = bound.trim();
+    if bound.is_empty() {
+        return Ok(None);
+    }
+    match bound.parse::<i64>() {
+        Ok(bound) => Ok(Some(bound)),
+        Err(_) => Err(query_error(
+            &format!("slice bounds must be integers, found '{bound}'"),
+            span,
+        )),
+    }
+}
+
+fn skip_spaces(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.peek() == Some(&' ') {
+        chars.next();
+    }
+}
+
+fn query_error(msg: &str, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Invalid query expression".into(),
+        msg.into(),
+        Some(span),
+        None,
+        vec![],
+    )
+}
+
+fn eval_query(value: Value, steps: &[Step], head: Span) -> Value {
+    let mut current = vec![value];
+    let mut multi = false;
+
+    for step in steps {
+        let mut next = vec![];
+        for value in current {
+            apply_step(value, step, &mut next);
+        }
+        multi = multi
+            || matches!(
+                step,
+                Step::RecursiveMember(_) | Step::Slice(..) | Step::Wildcard | Step::Filter(_)
+            );
+        current = next;
+    }
+
+    if multi {
+        Value::List {
+            vals: current,
+            span: head,
+        }
+    } else {
+        current
+            .into_iter()
+            .next()
+            .unwrap_or(Value::Nothing { span: head })
+    }
+}
+
+fn apply_step(value: Value, step: &Step, out: &mut Vec<Value>) {
+    match step {
+        Step::Member(name) => {
+            if let Value::Record { cols, vals, .. } = value {
+                if let Some(found) = cols.iter().position(|col| col == name) {
+                    out.push(vals[found].clone());
+                }
+            }
+        }
+        Step::RecursiveMember(name) => collect_recursive(&value, name, out),
+        Step::Index(idx) => {
+            if let Value::List { vals, .. } = value {
+                let idx = if *idx < 0 {
+                    vals.len() as i64 + idx
+                } else {
+                    *idx
+                };
+                if (0..vals.len() as i64).contains(&idx) {
+                    out.push(vals[idx as usize].clone());
+                }
+            }
+        }
+        Step::Slice(from, to) => {
+            if let Value::List { vals, .. } = value {
+                let len = vals.len() as i64;
+                let resolve = |bound: i64| -> i64 {
+                    if bound < 0 {
+                        (len + bound).max(0)
+                    } else {
+                        bound.min(len)
+                    }
+                };
+                let from = resolve(from.unwrap_or(0));
+                let to = resolve(to.unwrap_or(len));
+                for idx in from..to {
+                    out.push(vals[idx as usize].clone());
+                }
+            }
+        }
+        Step::Wildcard => match value {
+            Value::List { vals, .. } => out.extend(vals),
+            Value::Record { vals, .. } => out.extend(vals),
+            _ => {}
+        },
+        Step::Filter(filter) => {
+            if let Value::List { vals, .. } = value {
+                for val in vals {
+                    if filter_matches(&val, filter) {
+                        out.push(val);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Depth-first over records and lists, collecting every record field whose
+/// name matches
+fn collect_recursive(value: &Value, name: &str, out: &mut Vec<Value>) {
+    match value {
+        Value::Record { cols, vals, .. } => {
+            for (col, val) in cols.iter().zip(vals) {
+                if col == name {
+                    out.push(val.clone());
+                }
+                collect_recursive(val, name, out);
+            }
+        }
+        Value::List { vals, .. } => {
+            for val in vals {
+                collect_recursive(val, name, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn filter_matches(value: &Value, filter: &FilterExpr) -> bool {
+    let mut current = value.clone();
+    for name in &filter.path {
+        match current {
+            Value::Record { cols, vals, .. } => match cols.iter().position(|col| col == name) {
+                Some(found) => current = vals[found].clone(),
+                None => return false,
+            },
+            _ => return false,
+        }
+    }
+
+    match &filter.comparison {
+        None => !matches!(current, Value::Nothing { .. }),
+        Some((op, literal)) => {
+            let ordering = match (&current, literal) {
+                (Value::Nothing { .. }, Literal::Null) => Some(Ordering::Equal),
+                (_, Literal::Null) => None,
+                (Value::Bool { val, .. }, Literal::Bool(rhs)) => val.partial_cmp(rhs),
+                (Value::String { val, .. }, Literal::String(rhs)) => val.partial_cmp(rhs),
+                (Value::Int { val, .. }, Literal::Int(rhs)) => val.partial_cmp(rhs),
+                (Value::Int { val, .. }, Literal::Float(rhs)) => (*val as f64).partial_cmp(rhs),
+                (Value::Float { val, .. }, Literal::Int(rhs)) => val.partial_cmp(&(*rhs as f64)),
+                (Value::Float { val, .. }, Literal::Float(rhs)) => val.partial_cmp(rhs),
+                _ => None,
+            };
+            match ordering {
+                None => matches!(op, CmpOp::Ne),
+                Some(ordering) => match op {
+                    CmpOp::Eq => ordering == Ordering::Equal,
+                    CmpOp::Ne => ordering != Ordering::Equal,
+                    CmpOp::Lt => ordering == Ordering::Less,
+                    CmpOp::Le => ordering != Ordering::Greater,
+                    CmpOp::Gt => ordering == Ordering::Greater,
+                    CmpOp::Ge => ordering != Ordering::Less,
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn query(input: Value, expr: &str) -> Value {
+        let steps = parse_query(expr, Span::test_data()).expect("query should parse");
+        eval_query(input, &steps, Span::test_data())
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryJson {})
+    }
+
+    #[test]
+    fn negative_index_counts_from_the_end() {
+        let input = Value::List {
+            vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+            span: Span::test_data(),
+        };
+        assert_eq!(query(input, "[-1]"), Value::test_int(3));
+    }
+
+    #[test]
+    fn missing_member_yields_nothing() {
+        let input = Value::Record {
+            cols: vec!["a".into()],
+            vals: vec![Value::test_int(1)],
+            span: Span::test_data(),
+        };
+        assert!(matches!(query(input, "b.c"), Value::Nothing { .. }));
+    }
+
+    #[test]
+    fn filter_without_comparison_tests_existence() {
+        let rows = vec![
+            Value::Record {
+                cols: vec!["a".into()],
+                vals: vec![Value::test_int(1)],
+                span: Span::test_data(),
+            },
+            Value::Record {
+                cols: vec!["b".into()],
+                vals: vec![Value::test_int(2)],
+                span: Span::test_data(),
+            },
+        ];
+        let input = Value::List {
+            vals: rows.clone(),
+            span: Span::test_data(),
+        };
+        assert_eq!(
+            query(input, "[?(@.a)]"),
+            Value::List {
+                vals: vec![rows[0].clone()],
+                span: Span::test_data(),
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_query_is_rejected() {
+        assert!(parse_query("a..", Span::test_data()).is_err());
+        assert!(parse_query("a[1", Span::test_data()).is_err());
+        assert!(parse_query("a[?(@.x ~ 1)]", Span::test_data()).is_err());
+    }
+}
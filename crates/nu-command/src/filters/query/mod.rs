@@ -0,0 +1,7 @@
+mod json;
+mod query_;
+mod xml;
+
+pub use json::QueryJson;
+pub use query_::Query;
+pub use xml::QueryXml;
@@ -0,0 +1,279 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct QueryXml;
+
+impl Command for QueryXml {
+    fn name(&self) -> &str {
+        "query xml"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query xml")
+            .input_output_types(vec![
+                (Type::String, Type::List(Box::new(Type::Any))),
+                (Type::Record(vec![]), Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "path",
+                SyntaxShape::String,
+                "the XPath-like path to extract",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Extract nodes from XML with an XPath-like path."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The input is either XML text or the node records `from xml` produces. The
+path is a `/`-separated list of steps: a tag name (or `*`) selects child
+elements, `//` before a step searches at any depth, `@name` selects an
+attribute value, and `text()` selects the text of an element. The result is
+always a list of every match."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["xpath", "extract", "filter"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let head = call.head;
+        let steps = parse_path(&path.item, path.span)?;
+
+        let value = input.into_value(head);
+        let root = match value {
+            Value::String { val, .. } => crate::formats::from_xml_string_to_value(val, head)
+                .map_err(|err| {
+                    ShellError::UnsupportedInput(
+                        format!("Could not parse string as XML: {err}"),
+                        "value originates from here".into(),
+                        head,
+                        head,
+                    )
+                })?,
+            value => value,
+        };
+
+        // start at a virtual document node, so `/root`-style paths can name
+        // the root element itself
+        let document = Value::Record {
+            cols: vec!["tag".into(), "attributes".into(), "content".into()],
+            vals: vec![
+                Value::string("", head),
+                Value::nothing(head),
+                Value::List {
+                    vals: vec![root],
+                    span: head,
+                },
+            ],
+            span: head,
+        };
+
+        let mut current = vec![document];
+        for step in &steps {
+            let mut next = vec![];
+            for value in &current {
+                apply_step(value, step, &mut next);
+            }
+            current = next;
+        }
+
+        Ok(Value::List {
+            vals: current,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Extract the text of every matching element, at any depth",
+                example: "'<a><b>hi</b><c><b>yo</b></c></a>' | query xml '//b/text()'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("hi"), Value::test_string("yo")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Extract an attribute value",
+                example: "'<a id=\"7\"><b/></a>' | query xml '/a/@id'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("7")],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+struct XmlStep {
+    /// `true` for steps introduced by `//`: search at any depth
+    descendant: bool,
+    test: NodeTest,
+}
+
+enum NodeTest {
+    Element(String),
+    Attribute(String),
+    Text,
+}
+
+fn parse_path(path: &str, span: Span) -> Result<Vec<XmlStep>, ShellError> {
+    let mut steps = vec![];
+    let mut descendant = false;
+
+    for (i, segment) in path.split('/').enumerate() {
+        if segment.is_empty() {
+            if i > 0 {
+                descendant = true;
+            }
+            continue;
+        }
+        let test = if let Some(name) = segment.strip_prefix('@') {
+            NodeTest::Attribute(name.to_string())
+        } else if segment == "text()" {
+            NodeTest::Text
+        } else {
+            NodeTest::Element(segment.to_string())
+        };
+        steps.push(XmlStep { descendant, test });
+        descendant = false;
+    }
+
+    if steps.is_empty() || descendant {
+        return Err(ShellError::GenericError(
+            "Invalid XML path".into(),
+            "the path must end with a tag name, `@attribute` or `text()`".into(),
+            Some(span),
+            None,
+            vec![],
+        ));
+    }
+    Ok(steps)
+}
+
+/// The element's tag if the value is an element node record
+fn element_tag(value: &Value) -> Option<&str> {
+    if let Value::Record { cols, vals, .. } = value {
+        if let Some(found) = cols.iter().position(|col| col == "tag") {
+            if let Value::String { val, .. } = &vals[found] {
+                if val != "!" && !val.starts_with('?') {
+                    return Some(val);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn column(value: &Value, name: &str) -> Option<Value> {
+    if let Value::Record { cols, vals, .. } = value {
+        cols.iter()
+            .position(|col| col == name)
+            .map(|found| vals[found].clone())
+    } else {
+        None
+    }
+}
+
+fn apply_step(value: &Value, step: &XmlStep, out: &mut Vec<Value>) {
+    if step.descendant {
+        // `//x` is shorthand for descendant-or-self, then `x`
+        let mut elements = vec![];
+        descendants_or_self(value, &mut elements);
+        for element in elements {
+            apply_test(element, &step.test, out);
+        }
+    } else {
+        apply_test(value, &step.test, out);
+    }
+}
+
+fn descendants_or_self<'a>(value: &'a Value, out: &mut Vec<&'a Value>) {
+    if element_tag(value).is_none() {
+        return;
+    }
+    out.push(value);
+    if let Value::Record { cols, vals, .. } = value {
+        if let Some(found) = cols.iter().position(|col| col == "content") {
+            if let Value::List { vals: children, .. } = &vals[found] {
+                for child in children {
+                    descendants_or_self(child, out);
+                }
+            }
+        }
+    }
+}
+
+fn apply_test(value: &Value, test: &NodeTest, out: &mut Vec<Value>) {
+    if element_tag(value).is_none() {
+        return;
+    }
+    match test {
+        NodeTest::Element(name) => {
+            if let Some(Value::List { vals, .. }) = column(value, "content") {
+                for child in vals {
+                    if element_tag(&child).map_or(false, |tag| name == "*" || tag == name) {
+                        out.push(child);
+                    }
+                }
+            }
+        }
+        NodeTest::Attribute(name) => {
+            if let Some(attributes) = column(value, "attributes") {
+                if let Some(attribute) = column(&attributes, name) {
+                    out.push(attribute);
+                }
+            }
+        }
+        NodeTest::Text => {
+            if let Some(Value::List { vals, .. }) = column(value, "content") {
+                for child in vals {
+                    if let Value::Record { cols, vals, .. } = &child {
+                        let tag = cols.iter().position(|col| col == "tag");
+                        let content = cols.iter().position(|col| col == "content");
+                        if let (Some(tag), Some(content)) = (tag, content) {
+                            if matches!(vals[tag], Value::Nothing { .. }) {
+                                out.push(vals[content].clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryXml {})
+    }
+
+    #[test]
+    fn rejects_a_trailing_slash() {
+        assert!(parse_path("a/b/", Span::test_data()).is_err());
+        assert!(parse_path("", Span::test_data()).is_err());
+    }
+}
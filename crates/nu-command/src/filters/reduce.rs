@@ -26,6 +26,12 @@ impl Command for Reduce {
                 "reduce with initial value",
                 Some('f'),
             )
+            .named(
+                "until",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "a predicate on the accumulator; reducing stops early once it returns true",
+                Some('u'),
+            )
             .required(
                 "closure",
                 SyntaxShape::Closure(Some(vec![
@@ -58,6 +64,12 @@ impl Command for Reduce {
                 description: "Sum values of a list, plus their indexes",
                 result: Some(Value::test_int(24)),
             },
+            Example {
+                example:
+                    "[ 1 2 3 4 5 ] | reduce --until {|acc| $acc >= 6 } {|it, acc| $acc + $it }",
+                description: "Sum values until the total reaches six, ignoring the rest",
+                result: Some(Value::test_int(6)),
+            },
             Example {
                 example: "[ 1 2 3 4 ] | reduce -f 10 {|it, acc| $acc + $it }",
                 description: "Sum values with a starting value (fold)",
@@ -87,7 +99,14 @@ impl Command for Reduce {
         let span = call.head;
 
         let fold: Option<Value> = call.get_flag(engine_state, stack, "fold")?;
+        let until: Option<Closure> = call.get_flag(engine_state, stack, "until")?;
         let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let mut until = until.map(|closure| {
+            (
+                engine_state.get_block(closure.block_id).clone(),
+                stack.captures_to_stack(&closure.captures),
+            )
+        });
         let mut stack = stack.captures_to_stack(&capture_block.captures);
         let block = engine_state.get_block(capture_block.block_id);
         let ctrlc = engine_state.ctrlc.clone();
@@ -151,6 +170,30 @@ impl Command for Reduce {
             )?
             .into_value(span);
 
+            // stop folding as soon as the accumulator satisfies the predicate
+            if let Some((until_block, until_stack)) = &mut until {
+                if let Some(var) = until_block.signature.get_positional(0) {
+                    if let Some(var_id) = &var.var_id {
+                        until_stack.add_var(*var_id, acc.clone());
+                    }
+                }
+
+                let stop = eval_block_with_early_return(
+                    engine_state,
+                    until_stack,
+                    until_block,
+                    acc.clone().into_pipeline_data(),
+                    true,
+                    redirect_stderr,
+                )?
+                .into_value(span)
+                .is_true();
+
+                if stop {
+                    break;
+                }
+            }
+
             if nu_utils::ctrl_c::was_pressed(&ctrlc) {
                 break;
             }
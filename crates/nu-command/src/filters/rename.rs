@@ -1,8 +1,9 @@
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, FromValue, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -25,7 +26,16 @@ impl Command for Rename {
                 "column name to be changed",
                 Some('c'),
             )
-            .rest("rest", SyntaxShape::String, "the new names for the columns")
+            .rest(
+                "rest",
+                SyntaxShape::Any,
+                "the new names for the columns, or a single record mapping old names to new ones, or a closure run on every column name",
+            )
+            .switch(
+                "strict",
+                "error when a column in a rename mapping is missing from the input",
+                Some('s'),
+            )
             .category(Category::Filters)
     }
 
@@ -90,6 +100,31 @@ impl Command for Rename {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Rename columns by a record mapping old names to new ones",
+                example: "[[a, b]; [1, 2]] | rename {a: ham}",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["ham".to_string(), "b".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Run a closure on every column name",
+                example:
+                    "[[ColA, ColB]; [1, 2]] | rename {|col| $col | str replace 'Col' 'field_' }",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["field_A".to_string(), "field_B".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -124,7 +159,36 @@ fn rename(
         }
     }
 
-    let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+    let args: Vec<Value> = call.rest(engine_state, stack, 0)?;
+    let strict = call.has_flag("strict");
+
+    // a single closure argument is run on every column name, and a single
+    // record argument maps old names to new ones; anything else is the
+    // positional new names
+    if specified_column.is_none() {
+        if let [arg] = args.as_slice() {
+            if arg.as_block().is_ok() {
+                return rename_with_closure(engine_state, stack, call, input, arg.clone());
+            }
+        }
+    }
+    let mapping: Option<Vec<(String, String)>> = match args.as_slice() {
+        [Value::Record { cols, vals, .. }] => {
+            let mut mapping = Vec::with_capacity(cols.len());
+            for (old, new) in cols.iter().zip(vals) {
+                mapping.push((old.clone(), new.as_string()?));
+            }
+            Some(mapping)
+        }
+        _ => None,
+    };
+    let columns: Vec<String> = if mapping.is_none() {
+        args.iter()
+            .map(|arg| arg.as_string())
+            .collect::<Result<_, _>>()?
+    } else {
+        Vec::new()
+    };
     let metadata = input.metadata();
 
     let head_span = call.head;
@@ -161,6 +225,24 @@ fn rename(
                                 }
                             }
                         }
+                        None if mapping.is_some() => {
+                            for (old, new) in mapping.as_deref().unwrap_or_default() {
+                                if let Some(idx) = cols.iter().position(|col| col == old) {
+                                    cols[idx] = new.clone();
+                                } else if strict {
+                                    return Value::Error {
+                                        error: ShellError::UnsupportedInput(
+                                            format!(
+                                                "The column '{old}' does not exist in the input"
+                                            ),
+                                            "value originated from here".into(),
+                                            head_span,
+                                            span,
+                                        ),
+                                    };
+                                }
+                            }
+                        }
                         None => {
                             for (idx, val) in columns.iter().enumerate() {
                                 if idx >= cols.len() {
@@ -190,6 +272,71 @@ fn rename(
         .map(|x| x.set_metadata(metadata))
 }
 
+fn rename_with_closure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    closure: Value,
+) -> Result<PipelineData, ShellError> {
+    let capture_block: Closure = FromValue::from_value(&closure)?;
+    let block = engine_state.get_block(capture_block.block_id).clone();
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+    let metadata = input.metadata();
+    let ctrlc = engine_state.ctrlc.clone();
+    let engine_state = engine_state.clone();
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+    let head_span = call.head;
+
+    input
+        .map(
+            move |item| match item {
+                Value::Record { cols, vals, span } => {
+                    let mut new_cols = Vec::with_capacity(cols.len());
+                    for col in cols {
+                        let col_value = Value::string(col, span);
+                        if let Some(var_id) = var_id {
+                            stack.add_var(var_id, col_value.clone());
+                        }
+                        match eval_block(
+                            &engine_state,
+                            &mut stack,
+                            &block,
+                            col_value.into_pipeline_data(),
+                            redirect_stdout,
+                            redirect_stderr,
+                        )
+                        .and_then(|data| data.into_value(head_span).as_string())
+                        {
+                            Ok(new_name) => new_cols.push(new_name),
+                            Err(error) => return Value::Error { error },
+                        }
+                    }
+                    Value::Record {
+                        cols: new_cols,
+                        vals,
+                        span,
+                    }
+                }
+                // Propagate errors by explicitly matching them before the final case.
+                Value::Error { .. } => item,
+                other => Value::Error {
+                    error: ShellError::OnlySupportsThisInputType {
+                        exp_input_type: "record".into(),
+                        wrong_type: other.get_type().to_string(),
+                        dst_span: head_span,
+                        src_span: other.expect_span(),
+                    },
+                },
+            },
+            ctrlc,
+        )
+        .map(|x| x.set_metadata(metadata))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -36,6 +36,27 @@ fn vertical_rotate_value(
                 span,
             })
         }
+        // cycling a record's values vertically moves each cell to the next
+        // key while the keys stay put
+        Value::Record {
+            cols,
+            mut vals,
+            span,
+        } => {
+            let rotations = by.map(|n| n % vals.len()).unwrap_or(1);
+            let values = vals.as_mut_slice();
+
+            match direction {
+                VerticalDirection::Up => values.rotate_left(rotations),
+                VerticalDirection::Down => values.rotate_right(rotations),
+            }
+
+            Ok(Value::Record {
+                cols,
+                vals: values.to_owned(),
+                span,
+            })
+        }
         _ => Err(ShellError::TypeMismatch {
             err_message: "list".to_string(),
             span: value.span()?,
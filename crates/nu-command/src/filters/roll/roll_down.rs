@@ -23,7 +23,10 @@ impl Command for RollDown {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             // TODO: It also operates on List
-            .input_output_types(vec![(Type::Table(vec![]), Type::Table(vec![]))])
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
             .named("by", SyntaxShape::Int, "Number of rows to roll", Some('b'))
             .category(Category::Filters)
     }
@@ -2,8 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
 };
 
 use super::{vertical_rotate_value, VerticalDirection};
@@ -23,7 +23,10 @@ impl Command for RollUp {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             // TODO: It also operates on List
-            .input_output_types(vec![(Type::Table(vec![]), Type::Table(vec![]))])
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
             .named("by", SyntaxShape::Int, "Number of rows to roll", Some('b'))
             .category(Category::Filters)
     }
@@ -32,32 +35,47 @@ impl Command for RollUp {
         "Roll table rows up."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Streaming input is rolled without collecting it: only the first `--by` rows are held back. For a record, the values cycle up while the keys stay in place."
+    }
+
     fn examples(&self) -> Vec<Example> {
         let columns = vec!["a".to_string(), "b".to_string()];
-        vec![Example {
-            description: "Rolls rows up",
-            example: "[[a b]; [1 2] [3 4] [5 6]] | roll up",
-            result: Some(Value::List {
-                vals: vec![
-                    Value::Record {
-                        cols: columns.clone(),
-                        vals: vec![Value::test_int(3), Value::test_int(4)],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: columns.clone(),
-                        vals: vec![Value::test_int(5), Value::test_int(6)],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: columns,
-                        vals: vec![Value::test_int(1), Value::test_int(2)],
-                        span: Span::test_data(),
-                    },
-                ],
-                span: Span::test_data(),
-            }),
-        }]
+        vec![
+            Example {
+                description: "Cycle the values of a record upwards",
+                example: "{a: 1, b: 2, c: 3} | roll up",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                    vals: vec![Value::test_int(2), Value::test_int(3), Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Rolls rows up",
+                example: "[[a b]; [1 2] [3 4] [5 6]] | roll up",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: columns.clone(),
+                            vals: vec![Value::test_int(3), Value::test_int(4)],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: columns.clone(),
+                            vals: vec![Value::test_int(5), Value::test_int(6)],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: columns,
+                            vals: vec![Value::test_int(1), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 
     fn run(
@@ -70,6 +88,35 @@ impl Command for RollUp {
         let by: Option<usize> = call.get_flag(engine_state, stack, "by")?;
         let metadata = input.metadata();
 
+        // A stream can be rolled up without collecting it: hold back the
+        // first `by` rows and emit them again after the rest.
+        if let PipelineData::ListStream(stream, ..) = input {
+            let by = by.unwrap_or(1);
+            let ctrlc = stream.ctrlc.clone();
+            let mut stream = stream.into_iter();
+            let held_back: Vec<Value> = stream.by_ref().take(by).collect();
+
+            let mut rest = stream.peekable();
+            if rest.peek().is_none() {
+                // the whole input fit in the buffer, so roll it in place to
+                // keep the `by > length` wrapping behavior
+                let rotated_value = vertical_rotate_value(
+                    Value::List {
+                        vals: held_back,
+                        span: call.head,
+                    },
+                    Some(by),
+                    VerticalDirection::Up,
+                )?;
+                return Ok(rotated_value.into_pipeline_data().set_metadata(metadata));
+            }
+
+            return Ok(rest
+                .chain(held_back)
+                .into_pipeline_data(ctrlc)
+                .set_metadata(metadata));
+        }
+
         let value = input.into_value(call.head);
         let rotated_value = vertical_rotate_value(value, by, VerticalDirection::Up)?;
 
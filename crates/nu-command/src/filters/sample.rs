@@ -0,0 +1,132 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape, Type,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Clone)]
+pub struct Sample;
+
+impl Command for Sample {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sample")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .allow_variants_without_examples(true)
+            .optional("n", SyntaxShape::Int, "how many rows to keep")
+            .named(
+                "fraction",
+                SyntaxShape::Number,
+                "keep each row with this probability instead of keeping a fixed count",
+                Some('f'),
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the random number generator for a reproducible sample",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Keep a random sample of the input rows."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "`sample n` uses reservoir sampling, so only n rows are ever held in memory no matter how large the input is. `sample --fraction p` streams, keeping each row independently with probability p."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["random", "reservoir", "subset", "spot-check"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let n: Option<usize> = call.opt(engine_state, stack, 0)?;
+        let fraction: Option<f64> = call.get_flag(engine_state, stack, "fraction")?;
+        let seed: Option<i64> = call.get_flag(engine_state, stack, "seed")?;
+        let span = call.head;
+        let metadata = input.metadata();
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed as u64),
+            None => StdRng::from_entropy(),
+        };
+
+        match (n, fraction) {
+            (Some(_), Some(_)) => Err(ShellError::IncompatibleParametersSingle {
+                msg: "supply either a count or --fraction, not both".into(),
+                span,
+            }),
+            (None, None) => Err(ShellError::MissingParameter {
+                param_name: "n or --fraction".into(),
+                span,
+            }),
+            (Some(n), None) => {
+                // reservoir sampling: every row ends up kept with equal probability
+                let mut kept = Vec::with_capacity(n);
+                for (i, value) in input.into_iter_strict(span)?.enumerate() {
+                    if kept.len() < n {
+                        kept.push(value);
+                    } else {
+                        let j = rng.gen_range(0..=i);
+                        if j < n {
+                            kept[j] = value;
+                        }
+                    }
+                }
+
+                Ok(kept
+                    .into_iter()
+                    .into_pipeline_data(ctrlc)
+                    .set_metadata(metadata))
+            }
+            (None, Some(fraction)) => {
+                if !(0.0..=1.0).contains(&fraction) {
+                    return Err(ShellError::IncorrectValue {
+                        msg: "--fraction must be between 0 and 1".into(),
+                        span,
+                    });
+                }
+
+                Ok(input
+                    .into_iter_strict(span)?
+                    .filter(move |_| rng.gen_bool(fraction))
+                    .into_pipeline_data(ctrlc)
+                    .set_metadata(metadata))
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Spot-check three random files",
+                example: "ls | sample 3",
+                result: None,
+            },
+            Example {
+                description: "Keep roughly a tenth of a large stream, reproducibly",
+                example: "open big.csv | sample --fraction 0.1 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,165 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::{Block, Call};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Scan;
+
+impl Command for Scan {
+    fn name(&self) -> &str {
+        "scan"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scan")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .required("init", SyntaxShape::Any, "the initial accumulator value")
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "the reducing function",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Like reduce, but emits every intermediate accumulator value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Values are emitted lazily as the input is consumed, so running totals over an unbounded stream work."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["reduce", "fold", "cumulative", "running"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let init: Value = call.req(engine_state, stack, 0)?;
+        let capture_block: Closure = call.req(engine_state, stack, 1)?;
+
+        let engine_state = engine_state.clone();
+        let stack = stack.captures_to_stack(&capture_block.captures);
+        let block: Block = engine_state.get_block(capture_block.block_id).clone();
+
+        let metadata = input.metadata();
+        let ctrlc = engine_state.ctrlc.clone();
+
+        Ok(ScanIterator {
+            input: Box::new(input.into_iter()),
+            acc: init,
+            engine_state,
+            stack,
+            block,
+            redirect_stdout: call.redirect_stdout,
+            redirect_stderr: call.redirect_stderr,
+            span: call.head,
+        }
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Running total of a list",
+                example: "[1 2 3 4] | scan 0 {|it, acc| $acc + $it }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::test_int(3),
+                        Value::test_int(6),
+                        Value::test_int(10),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Cumulative maximum",
+                example: "[3 1 4 1 5] | scan 0 {|it, acc| if $it > $acc { $it } else { $acc } }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(3),
+                        Value::test_int(3),
+                        Value::test_int(4),
+                        Value::test_int(4),
+                        Value::test_int(5),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+struct ScanIterator {
+    input: Box<dyn Iterator<Item = Value> + Send>,
+    acc: Value,
+    engine_state: EngineState,
+    stack: Stack,
+    block: Block,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+    span: Span,
+}
+
+impl Iterator for ScanIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.input.next()?;
+
+        // Element argument
+        if let Some(var) = self.block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                self.stack.add_var(*var_id, value);
+            }
+        }
+
+        // Accumulator argument
+        if let Some(var) = self.block.signature.get_positional(1) {
+            if let Some(var_id) = &var.var_id {
+                self.stack.add_var(*var_id, self.acc.clone());
+            }
+        }
+
+        self.acc = match eval_block_with_early_return(
+            &self.engine_state,
+            &mut self.stack,
+            &self.block,
+            PipelineData::empty(),
+            self.redirect_stdout,
+            self.redirect_stderr,
+        ) {
+            Ok(data) => data.into_value(self.span),
+            Err(error) => Value::Error { error },
+        };
+
+        Some(self.acc.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Scan {})
+    }
+}
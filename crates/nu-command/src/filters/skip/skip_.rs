@@ -1,11 +1,11 @@
 use std::convert::TryInto;
 
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
     ast::Call,
-    engine::{Command, EngineState, Stack},
-    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
-    Signature, Span, SyntaxShape, Type, Value,
+    engine::{Closure, Command, EngineState, Stack},
+    Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
+    ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -25,7 +25,11 @@ impl Command for Skip {
                     Type::List(Box::new(Type::Any)),
                 ),
             ])
-            .optional("n", SyntaxShape::Int, "the number of elements to skip")
+            .optional(
+                "n",
+                SyntaxShape::Any,
+                "the number of elements to skip, or a predicate to keep skipping elements while it holds",
+            )
             .category(Category::Filters)
     }
 
@@ -51,6 +55,14 @@ impl Command for Skip {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Skip elements while a predicate holds",
+                example: "[-2 0 2 -1] | skip {|x| $x < 0 }",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(2), Value::test_int(-1)],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Skip two rows of a table",
                 example: "[[editions]; [2015] [2018] [2021]] | skip 2",
@@ -77,6 +89,12 @@ impl Command for Skip {
         let span = call.head;
         let metadata = input.metadata();
 
+        if let Some(predicate) = &n {
+            if predicate.as_block().is_ok() {
+                return skip_with_predicate(engine_state, stack, call, input, predicate.clone());
+            }
+        }
+
         let n: usize = match n {
             Some(Value::Int { val, span }) => {
                 val.try_into().map_err(|err| ShellError::TypeMismatch {
@@ -86,7 +104,7 @@ impl Command for Skip {
             }
             Some(_) => {
                 return Err(ShellError::TypeMismatch {
-                    err_message: "expected integer".into(),
+                    err_message: "expected an integer or a closure".into(),
                     span,
                 })
             }
@@ -154,6 +172,50 @@ impl Command for Skip {
     }
 }
 
+// A predicate argument makes `skip` behave like `skip while`: elements are
+// dropped until the predicate first fails, and the rest stream through.
+fn skip_with_predicate(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    predicate: Value,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let capture_block: Closure = FromValue::from_value(&predicate)?;
+    let block = engine_state.get_block(capture_block.block_id).clone();
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+    let metadata = input.metadata();
+    let ctrlc = engine_state.ctrlc.clone();
+    let engine_state = engine_state.clone();
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+
+    Ok(input
+        .into_iter_strict(span)?
+        .skip_while(move |value| {
+            if let Some(var_id) = var_id {
+                stack.add_var(var_id, value.clone());
+            }
+
+            eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            )
+            .map_or(false, |pipeline_data| {
+                pipeline_data.into_value(span).is_true()
+            })
+        })
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Skip;
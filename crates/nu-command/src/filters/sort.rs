@@ -288,109 +288,15 @@ pub fn sort(
             ..
         } => {
             let columns = cols.clone();
-            vec.sort_by(|a, b| process(a, b, &columns, span, insensitive, natural));
-        }
-        _ => {
             vec.sort_by(|a, b| {
-                if insensitive {
-                    let lowercase_left = match a {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => a.clone(),
-                    };
-
-                    let lowercase_right = match b {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => b.clone(),
-                    };
-
-                    if natural {
-                        match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                            (Ok(left), Ok(right)) => compare_str(left, right),
-                            _ => Ordering::Equal,
-                        }
-                    } else {
-                        lowercase_left
-                            .partial_cmp(&lowercase_right)
-                            .unwrap_or(Ordering::Equal)
-                    }
-                } else if natural {
-                    match (a.as_string(), b.as_string()) {
-                        (Ok(left), Ok(right)) => compare_str(left, right),
-                        _ => Ordering::Equal,
-                    }
-                } else {
-                    a.partial_cmp(b).unwrap_or(Ordering::Equal)
-                }
+                crate::sort_utils::compare(a, b, &columns, span, insensitive, natural)
             });
         }
-    }
-    Ok(())
-}
-
-pub fn process(
-    left: &Value,
-    right: &Value,
-    columns: &[String],
-    span: Span,
-    insensitive: bool,
-    natural: bool,
-) -> Ordering {
-    for column in columns {
-        let left_value = left.get_data_by_key(column);
-
-        let left_res = match left_value {
-            Some(left_res) => left_res,
-            None => Value::Nothing { span },
-        };
-
-        let right_value = right.get_data_by_key(column);
-
-        let right_res = match right_value {
-            Some(right_res) => right_res,
-            None => Value::Nothing { span },
-        };
-
-        let result = if insensitive {
-            let lowercase_left = match left_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => left_res,
-            };
-
-            let lowercase_right = match right_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => right_res,
-            };
-            if natural {
-                match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                    (Ok(left), Ok(right)) => compare_str(left, right),
-                    _ => Ordering::Equal,
-                }
-            } else {
-                lowercase_left
-                    .partial_cmp(&lowercase_right)
-                    .unwrap_or(Ordering::Equal)
-            }
-        } else {
-            left_res.partial_cmp(&right_res).unwrap_or(Ordering::Equal)
-        };
-        if result != Ordering::Equal {
-            return result;
+        _ => {
+            vec.sort_by(|a, b| crate::sort_utils::compare_values(a, b, insensitive, natural));
         }
     }
-
-    Ordering::Equal
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1,10 +1,11 @@
-use nu_engine::CallExt;
+use nu_engine::{eval_block_with_early_return, CallExt};
 use nu_protocol::{
     ast::Call,
-    engine::{Command, EngineState, Stack},
+    engine::{Closure, Command, EngineState, Stack},
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     SyntaxShape, Type, Value,
 };
+use std::cmp::Ordering;
 
 #[derive(Clone)]
 pub struct SortBy;
@@ -16,8 +17,18 @@ impl Command for SortBy {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("sort-by")
-            .input_output_types(vec![(Type::Table(vec![]), Type::Table(vec![]))])
-            .rest("columns", SyntaxShape::Any, "the column(s) to sort by")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .rest(
+                "columns",
+                SyntaxShape::Any,
+                "the column(s) to sort by: a name, or a {column, reverse} record for a per-column direction",
+            )
             .switch("reverse", "Sort in reverse order", Some('r'))
             .switch(
                 "ignore-case",
@@ -29,6 +40,12 @@ impl Command for SortBy {
                 "Sort alphanumeric string-based columns naturally (1, 9, 10, 99, 100, ...)",
                 Some('n'),
             )
+            .named(
+                "custom",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "a comparator closure: given two values, return whether the first sorts before the second",
+                Some('c'),
+            )
             .category(Category::Filters)
     }
 
@@ -69,6 +86,35 @@ impl Command for SortBy {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Sort a table with one column reversed",
+                example: "[[fruit count]; [apple 9] [pear 3] [orange 7]] | sort-by {column: count, reverse: true}",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("apple"), Value::test_int(9)],
+                        ),
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("orange"), Value::test_int(7)],
+                        ),
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("pear"), Value::test_int(3)],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Sort with a custom comparator",
+                example: "[5 1 4] | sort-by --custom {|a, b| $a < $b }",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(4), Value::test_int(5)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -79,21 +125,27 @@ impl Command for SortBy {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+        let custom: Option<Closure> = call.get_flag(engine_state, stack, "custom")?;
         let reverse = call.has_flag("reverse");
         let insensitive = call.has_flag("ignore-case");
         let natural = call.has_flag("natural");
         let metadata = &input.metadata();
         let mut vec: Vec<_> = input.into_iter_strict(call.head)?.collect();
 
-        if columns.is_empty() {
-            return Err(ShellError::MissingParameter {
-                param_name: "columns".into(),
-                span: call.head,
-            });
-        }
+        if let Some(closure) = custom {
+            sort_by_closure(&mut vec, closure, engine_state, stack, call)?;
+        } else {
+            if columns.is_empty() {
+                return Err(ShellError::MissingParameter {
+                    param_name: "columns".into(),
+                    span: call.head,
+                });
+            }
 
-        crate::sort(&mut vec, columns, call.head, insensitive, natural)?;
+            let keys = sort_keys(columns)?;
+            crate::sort_by_keys(&mut vec, keys, call.head, insensitive, natural)?;
+        }
 
         if reverse {
             vec.reverse()
@@ -109,6 +161,91 @@ impl Command for SortBy {
     }
 }
 
+/// Each rest argument becomes a column name plus whether it sorts in reverse
+fn sort_keys(columns: Vec<Value>) -> Result<Vec<(String, bool)>, ShellError> {
+    columns
+        .into_iter()
+        .map(|column| match &column {
+            Value::String { val, .. } => Ok((val.clone(), false)),
+            Value::Record { span, .. } => {
+                let name = column
+                    .get_data_by_key("column")
+                    .and_then(|value| value.as_string().ok())
+                    .ok_or_else(|| ShellError::TypeMismatch {
+                        err_message: "expected a record with a 'column' field".into(),
+                        span: *span,
+                    })?;
+                let reverse = column
+                    .get_data_by_key("reverse")
+                    .map(|value| value.is_true())
+                    .unwrap_or(false);
+                Ok((name, reverse))
+            }
+            _ => Err(ShellError::TypeMismatch {
+                err_message: "expected a column name or a {column, reverse} record".into(),
+                span: column.span()?,
+            }),
+        })
+        .collect()
+}
+
+/// Sort with the `--custom` comparator, which returns whether its first
+/// argument sorts before its second
+fn sort_by_closure(
+    vec: &mut [Value],
+    closure: Closure,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<(), ShellError> {
+    let block = engine_state.get_block(closure.block_id).clone();
+    let mut stack = stack.captures_to_stack(&closure.captures);
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+    let span = call.head;
+
+    let mut compare_err = None;
+    vec.sort_by(|a, b| {
+        if let Some(var) = block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, a.clone());
+            }
+        }
+        if let Some(var) = block.signature.get_positional(1) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, b.clone());
+            }
+        }
+
+        match eval_block_with_early_return(
+            engine_state,
+            &mut stack,
+            &block,
+            PipelineData::empty(),
+            redirect_stdout,
+            redirect_stderr,
+        ) {
+            Ok(data) => {
+                if data.into_value(span).is_true() {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            Err(err) => {
+                // sort_by cannot bail out mid-sort, so keep the first error
+                compare_err.get_or_insert(err);
+                Ordering::Equal
+            }
+        }
+    });
+
+    match compare_err {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
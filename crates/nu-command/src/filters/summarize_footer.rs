@@ -0,0 +1,226 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SummarizeFooter;
+
+impl Command for SummarizeFooter {
+    fn name(&self) -> &str {
+        "summarize-footer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("summarize-footer")
+            .input_output_types(vec![(Type::Table(vec![]), Type::Table(vec![]))])
+            .required(
+                "calc",
+                SyntaxShape::Record,
+                "a record mapping columns to sum, avg, min, max, or count",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Append a summary row with per-column aggregates to the table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Columns not named in the calc record are left empty in the footer row.
+`table --footer-calc` applies the same summary while rendering, without
+changing the data that flows on through the pipeline."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["total", "aggregate", "sum", "footer", "report"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let calc: Value = call.req(engine_state, stack, 0)?;
+        let metadata = input.metadata();
+        let span = call.head;
+
+        let rows: Vec<Value> = input.into_iter().collect();
+        let vals = append_summary_footer(rows, &calc, span)?;
+
+        Ok(Value::List { vals, span }
+            .into_pipeline_data()
+            .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Total a price column",
+                example: "[[item price]; [apple 2] [pear 3]] | summarize-footer {price: sum}",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["item".to_string(), "price".to_string()],
+                            vals: vec![Value::test_string("apple"), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["item".to_string(), "price".to_string()],
+                            vals: vec![Value::test_string("pear"), Value::test_int(3)],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["item".to_string(), "price".to_string()],
+                            vals: vec![Value::nothing(Span::test_data()), Value::test_int(5)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Combine several aggregates in one footer",
+                example: "ls | summarize-footer {name: count, size: sum}",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Append one record to `rows` holding the aggregates the `calc` record asks
+/// for; every other column is filled with nothing so the row lines up.
+pub fn append_summary_footer(
+    mut rows: Vec<Value>,
+    calc: &Value,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let (calc_cols, calc_vals) = match calc {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        other => {
+            return Err(ShellError::TypeMismatch {
+                err_message: format!(
+                    "expected a record of column names to calculations, got {}",
+                    other.get_type()
+                ),
+                span: other.span().unwrap_or(span),
+            })
+        }
+    };
+
+    // the footer follows the column layout of the first row, so untouched
+    // columns stay in place
+    let columns = match rows.first() {
+        Some(Value::Record { cols, .. }) => cols.clone(),
+        _ => calc_cols.clone(),
+    };
+
+    let mut footer_cols = vec![];
+    let mut footer_vals = vec![];
+    for column in columns {
+        let val = match calc_cols.iter().position(|c| c == &column) {
+            Some(idx) => {
+                let op = calc_vals[idx].as_string()?;
+                let column_vals: Vec<&Value> = rows
+                    .iter()
+                    .filter_map(|row| match row {
+                        Value::Record { cols, vals, .. } => {
+                            cols.iter().position(|c| c == &column).map(|idx| &vals[idx])
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                aggregate(&op, &column_vals, span)?
+            }
+            None => Value::nothing(span),
+        };
+
+        footer_cols.push(column);
+        footer_vals.push(val);
+    }
+
+    rows.push(Value::Record {
+        cols: footer_cols,
+        vals: footer_vals,
+        span,
+    });
+
+    Ok(rows)
+}
+
+fn aggregate(op: &str, vals: &[&Value], span: Span) -> Result<Value, ShellError> {
+    match op {
+        "count" => Ok(Value::Int {
+            val: vals.len() as i64,
+            span,
+        }),
+        "sum" => sum(vals, span),
+        "avg" | "mean" => {
+            if vals.is_empty() {
+                return Ok(Value::nothing(span));
+            }
+            let count = Value::Int {
+                val: vals.len() as i64,
+                span,
+            };
+            sum(vals, span)?.div(span, &count, span)
+        }
+        "min" | "max" => {
+            let mut best: Option<&Value> = None;
+            for val in vals {
+                best = match best {
+                    Some(b) => {
+                        let replace = match val.partial_cmp(b) {
+                            Some(ordering) => {
+                                if op == "min" {
+                                    ordering.is_lt()
+                                } else {
+                                    ordering.is_gt()
+                                }
+                            }
+                            None => false,
+                        };
+                        Some(if replace { val } else { b })
+                    }
+                    None => Some(val),
+                };
+            }
+            Ok(best.cloned().unwrap_or_else(|| Value::nothing(span)))
+        }
+        _ => Err(ShellError::IncorrectValue {
+            msg: format!(
+                "unknown footer calculation '{op}'; expected sum, avg, min, max, or count"
+            ),
+            span,
+        }),
+    }
+}
+
+fn sum(vals: &[&Value], span: Span) -> Result<Value, ShellError> {
+    let mut iter = vals.iter();
+    // start from the first value so filesizes and durations keep their type
+    let mut total = match iter.next() {
+        Some(val) => (*val).clone(),
+        None => return Ok(Value::Int { val: 0, span }),
+    };
+    for val in iter {
+        total = total.add(span, val, span)?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SummarizeFooter {})
+    }
+}
@@ -1,9 +1,9 @@
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    SyntaxShape, Type, Value,
+    Category, Example, FromValue, IntoInterruptiblePipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -27,8 +27,8 @@ impl Command for Take {
             ])
             .required(
                 "n",
-                SyntaxShape::Int,
-                "starting from the front, the number of elements to return",
+                SyntaxShape::Any,
+                "starting from the front, the number of elements to return, or a predicate to keep taking elements while it holds",
             )
             .category(Category::Filters)
     }
@@ -38,7 +38,7 @@ impl Command for Take {
     }
 
     fn search_terms(&self) -> Vec<&str> {
-        vec!["first", "slice", "head"]
+        vec!["first", "slice", "head", "while"]
     }
 
     fn run(
@@ -48,7 +48,19 @@ impl Command for Take {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let rows_desired: usize = call.req(engine_state, stack, 0)?;
+        let arg: Value = call.req(engine_state, stack, 0)?;
+        if arg.as_block().is_ok() {
+            return take_with_predicate(engine_state, stack, call, input, arg);
+        }
+        let rows_desired: usize = match arg.as_integer() {
+            Ok(n) if !n.is_negative() => n as usize,
+            _ => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "expected a non-negative integer or a closure".into(),
+                    span: arg.span()?,
+                })
+            }
+        };
 
         let ctrlc = engine_state.ctrlc.clone();
         let metadata = input.metadata();
@@ -147,10 +159,62 @@ impl Command for Take {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Take elements while a predicate holds",
+                example: "[1 2 3 2] | take {|x| $x < 3 }",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
 
+// A predicate argument makes `take` behave like `take while`, stopping the
+// upstream as soon as the predicate fails.
+fn take_with_predicate(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    predicate: Value,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let capture_block: Closure = FromValue::from_value(&predicate)?;
+    let block = engine_state.get_block(capture_block.block_id).clone();
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+    let metadata = input.metadata();
+    let ctrlc = engine_state.ctrlc.clone();
+    let engine_state = engine_state.clone();
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+
+    Ok(input
+        .into_iter_strict(span)?
+        .take_while(move |value| {
+            if let Some(var_id) = var_id {
+                stack.add_var(var_id, value.clone());
+            }
+
+            eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            )
+            .map_or(false, |pipeline_data| {
+                pipeline_data.into_value(span).is_true()
+            })
+        })
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
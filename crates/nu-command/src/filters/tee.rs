@@ -0,0 +1,165 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape, Type, Value,
+};
+use std::sync::mpsc::{self, SyncSender};
+use std::thread::JoinHandle;
+
+/// How far the main stream may run ahead of the side closure before it blocks
+const TEE_BUFFER_SIZE: usize = 64;
+
+#[derive(Clone)]
+pub struct Tee;
+
+impl Command for Tee {
+    fn name(&self) -> &str {
+        "tee"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tee")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "the closure to run with a copy of the stream as its input",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Copy a stream to a closure in the middle of a pipeline without consuming it."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The closure runs on its own thread and sees the same values as the rest of
+the pipeline. The copy goes through a bounded buffer, so when the closure
+falls behind the main stream slows down instead of collecting everything.
+An error from the closure surfaces once the stream is exhausted."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["spy", "side", "channel", "inspect"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let block = engine_state.get_block(capture_block.block_id).clone();
+        let mut closure_stack = stack.captures_to_stack(&capture_block.captures);
+
+        let metadata = input.metadata();
+        let ctrlc = engine_state.ctrlc.clone();
+        let closure_ctrlc = ctrlc.clone();
+        let closure_engine_state = engine_state.clone();
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+        let span = call.head;
+
+        let (sender, receiver) = mpsc::sync_channel(TEE_BUFFER_SIZE);
+
+        let handle = std::thread::Builder::new()
+            .name("tee".into())
+            .spawn(move || -> Result<(), ShellError> {
+                let data = receiver.into_iter().into_pipeline_data(closure_ctrlc);
+                let output = eval_block(
+                    &closure_engine_state,
+                    &mut closure_stack,
+                    &block,
+                    data,
+                    redirect_stdout,
+                    redirect_stderr,
+                )?;
+                // drain whatever the closure produced so `save` and friends
+                // run to completion
+                output.into_iter().for_each(drop);
+                Ok(())
+            })
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+
+        Ok(TeeIterator {
+            input: Box::new(input.into_iter()),
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+        .into_pipeline_data(ctrlc)
+        .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save a copy of the stream while it keeps flowing downstream",
+                example: "ls | tee { save files.json } | where size > 1mb",
+                result: None,
+            },
+            Example {
+                description: "The main stream is passed through unchanged",
+                example: "[1 2 3] | tee { length | ignore } | math sum",
+                result: Some(Value::test_int(6)),
+            },
+        ]
+    }
+}
+
+struct TeeIterator {
+    input: Box<dyn Iterator<Item = Value> + Send>,
+    sender: Option<SyncSender<Value>>,
+    handle: Option<JoinHandle<Result<(), ShellError>>>,
+}
+
+impl Iterator for TeeIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.input.next() {
+            Some(value) => {
+                if let Some(sender) = &self.sender {
+                    // the closure stopped reading early; keep the main stream
+                    // going without it
+                    if sender.send(value.clone()).is_err() {
+                        self.sender = None;
+                    }
+                }
+                Some(value)
+            }
+            None => {
+                // closing the channel lets the closure finish
+                self.sender = None;
+                match self.handle.take()?.join() {
+                    Ok(Ok(())) => None,
+                    Ok(Err(error)) => Some(Value::Error { error }),
+                    Err(_) => Some(Value::Error {
+                        error: ShellError::GenericError(
+                            "The tee closure panicked".into(),
+                            "the copy of the stream could not be processed".into(),
+                            None,
+                            None,
+                            vec![],
+                        ),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Tee {})
+    }
+}
@@ -0,0 +1,256 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct Top;
+
+impl Command for Top {
+    fn name(&self) -> &str {
+        "top"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("top")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required("n", SyntaxShape::Int, "how many rows to keep")
+            .named(
+                "by",
+                SyntaxShape::String,
+                "the column to rank rows by",
+                Some('b'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Keep only the n largest values, without sorting the whole input."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only the kept rows are held in memory, so this works over inputs far too large to `sort-by | last n`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["largest", "max", "rank", "biggest"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_bounded(engine_state, stack, call, input, true)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the three largest values",
+                example: "[3 1 4 1 5 9 2 6] | top 3",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(9), Value::test_int(6), Value::test_int(5)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Keep the two largest rows by a column",
+                example: "[[name size]; [a 10] [b 30] [c 20]] | top 2 --by size",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["name", "size"],
+                            vec![Value::test_string("b"), Value::test_int(30)],
+                        ),
+                        Value::test_record(
+                            vec!["name", "size"],
+                            vec![Value::test_string("c"), Value::test_int(20)],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+pub struct Bottom;
+
+impl Command for Bottom {
+    fn name(&self) -> &str {
+        "bottom"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bottom")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .required("n", SyntaxShape::Int, "how many rows to keep")
+            .named(
+                "by",
+                SyntaxShape::String,
+                "the column to rank rows by",
+                Some('b'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Keep only the n smallest values, without sorting the whole input."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only the kept rows are held in memory, so this works over inputs far too large to `sort-by | first n`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["smallest", "min", "rank"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_bounded(engine_state, stack, call, input, false)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the two smallest values",
+                example: "[3 1 4 1 5] | bottom 2",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Keep the smallest row by a column",
+                example: "[[name size]; [a 10] [b 30] [c 20]] | bottom 1 --by size",
+                result: Some(Value::List {
+                    vals: vec![Value::test_record(
+                        vec!["name", "size"],
+                        vec![Value::test_string("a"), Value::test_int(10)],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn run_bounded(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    largest: bool,
+) -> Result<PipelineData, ShellError> {
+    let n: usize = call.req(engine_state, stack, 0)?;
+    let by: Option<String> = call.get_flag(engine_state, stack, "by")?;
+    let span = call.head;
+    let metadata = input.metadata();
+
+    let kept = keep_extremes(input.into_iter_strict(span)?, n, by, largest, span);
+
+    Ok(kept
+        .into_iter()
+        .into_pipeline_data(engine_state.ctrlc.clone())
+        .set_metadata(metadata))
+}
+
+/// Stream the input, keeping only the `n` best values ranked by `compare_rank`.
+///
+/// `kept` stays sorted best-first, so each incoming value only has to beat the
+/// current worst entry to be inserted.
+fn keep_extremes(
+    input: impl Iterator<Item = Value>,
+    n: usize,
+    by: Option<String>,
+    largest: bool,
+    span: Span,
+) -> Vec<Value> {
+    let mut kept: Vec<Value> = Vec::with_capacity(n + 1);
+    if n == 0 {
+        return kept;
+    }
+
+    for value in input {
+        let pos = kept
+            .binary_search_by(|probe| compare_rank(probe, &value, &by, largest, span))
+            .unwrap_or_else(|pos| pos);
+        if pos < n {
+            kept.insert(pos, value);
+            kept.truncate(n);
+        }
+    }
+
+    kept
+}
+
+fn compare_rank(
+    left: &Value,
+    right: &Value,
+    by: &Option<String>,
+    largest: bool,
+    span: Span,
+) -> Ordering {
+    let (left, right) = match by {
+        Some(column) => (
+            left.get_data_by_key(column)
+                .unwrap_or(Value::Nothing { span }),
+            right
+                .get_data_by_key(column)
+                .unwrap_or(Value::Nothing { span }),
+        ),
+        None => (left.clone(), right.clone()),
+    };
+
+    let result = crate::compare_values(&left, &right, false, false);
+    if largest {
+        result.reverse()
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Top {})
+    }
+
+    #[test]
+    fn test_bottom_examples() {
+        use crate::test_examples;
+
+        test_examples(Bottom {})
+    }
+}
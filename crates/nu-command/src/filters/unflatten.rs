@@ -0,0 +1,176 @@
+use indexmap::IndexMap;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Unflatten;
+
+impl Command for Unflatten {
+    fn name(&self) -> &str {
+        "unflatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unflatten")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the string splitting column names into nesting levels (default '.')",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Turn delimited column names like `a.b.c` back into nested records."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The inverse of `flatten --separator`: rows whose column names share a
+prefix are rebuilt into nested records, so flat data like CSV round-trips
+to nested JSON. When a name is both a leaf and a prefix, the nested
+columns win."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["nest", "flatten", "expand", "unfold"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let separator: Option<String> = call.get_flag(engine_state, stack, "separator")?;
+        let separator = separator.unwrap_or_else(|| ".".into());
+        let metadata = input.metadata();
+
+        input
+            .map(
+                move |item| unflatten_value(item, &separator),
+                engine_state.ctrlc.clone(),
+            )
+            .map(|x| x.set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rebuild a nested record from dotted column names",
+                example: "{ 'a.b': 1, 'a.c': 2, d: 3 } | unflatten",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string(), "d".to_string()],
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["b".to_string(), "c".to_string()],
+                            vals: vec![Value::test_int(1), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::test_int(3),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Unflatten every row of a table",
+                example: "[{ 'a.b': 1 }, { 'a.b': 2 }] | unflatten | get a.b",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Use a custom separator",
+                example: "{ a_b: 1 } | unflatten --separator '_'",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string()],
+                    vals: vec![Value::Record {
+                        cols: vec!["b".to_string()],
+                        vals: vec![Value::test_int(1)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+// intermediate shape for rebuilding the nesting: a column is either a plain
+// value or a branch still collecting its children
+enum Node {
+    Leaf(Value),
+    Branch(IndexMap<String, Node>),
+}
+
+fn unflatten_value(item: Value, separator: &str) -> Value {
+    let (cols, vals, span) = match item {
+        Value::Record { cols, vals, span } => (cols, vals, span),
+        // rows that are not records (including errors) pass through unchanged
+        other => return other,
+    };
+
+    let mut tree = IndexMap::<String, Node>::new();
+    for (column, value) in cols.into_iter().zip(vals) {
+        let parts: Vec<&str> = column.split(separator).collect();
+        insert_node(&mut tree, &parts, value);
+    }
+
+    tree_to_value(tree, span)
+}
+
+fn insert_node(tree: &mut IndexMap<String, Node>, parts: &[&str], value: Value) {
+    if let [leaf] = parts {
+        tree.insert((*leaf).into(), Node::Leaf(value));
+        return;
+    }
+
+    let entry = tree
+        .entry(parts[0].into())
+        .or_insert_with(|| Node::Branch(IndexMap::new()));
+    if let Node::Leaf(_) = entry {
+        // the name was already a plain column; the nested columns win
+        *entry = Node::Branch(IndexMap::new());
+    }
+    if let Node::Branch(inner) = entry {
+        insert_node(inner, &parts[1..], value);
+    }
+}
+
+fn tree_to_value(tree: IndexMap<String, Node>, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for (col, node) in tree {
+        cols.push(col);
+        vals.push(match node {
+            Node::Leaf(value) => value,
+            Node::Branch(inner) => tree_to_value(inner, span),
+        });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Unflatten {})
+    }
+}
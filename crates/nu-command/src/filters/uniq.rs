@@ -50,6 +50,11 @@ impl Command for Uniq {
                 "Return the input values that occur once only",
                 Some('u'),
             )
+            .switch(
+                "natural",
+                "Compare string values in natural order, so numbers inside them match numerically ('file7' and 'file07' count as the same value)",
+                Some('n'),
+            )
             .category(Category::Filters)
     }
 
@@ -69,7 +74,7 @@ impl Command for Uniq {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let mapper = Box::new(move |ms: ItemMapperState| -> ValueCounter {
-            item_mapper(ms.item, ms.flag_ignore_case, ms.index)
+            item_mapper(ms.item, ms.flag_ignore_case, ms.flag_natural, ms.index)
         });
 
         let metadata = input.metadata();
@@ -77,7 +82,7 @@ impl Command for Uniq {
             engine_state,
             stack,
             call,
-            input.into_iter().collect(),
+            input.into_iter(),
             mapper,
             metadata,
         )
@@ -117,6 +122,14 @@ impl Command for Uniq {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Treat numbers inside strings numerically when comparing",
+                example: "['file7' 'file07' 'file8'] | uniq --natural",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("file7"), Value::test_string("file8")],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Return a table containing the distinct input values together with their counts",
                 example: "[1 2 2] | uniq -c",
@@ -143,11 +156,17 @@ impl Command for Uniq {
 pub struct ItemMapperState {
     pub item: Value,
     pub flag_ignore_case: bool,
+    pub flag_natural: bool,
     pub index: usize,
 }
 
-fn item_mapper(item: Value, flag_ignore_case: bool, index: usize) -> ValueCounter {
-    ValueCounter::new(item, flag_ignore_case, index)
+fn item_mapper(
+    item: Value,
+    flag_ignore_case: bool,
+    flag_natural: bool,
+    index: usize,
+) -> ValueCounter {
+    ValueCounter::new(item, flag_ignore_case, flag_natural, index)
 }
 
 pub struct ValueCounter {
@@ -164,22 +183,26 @@ impl PartialEq<Self> for ValueCounter {
 }
 
 impl ValueCounter {
-    fn new(val: Value, flag_ignore_case: bool, index: usize) -> Self {
-        Self::new_vals_to_compare(val.clone(), flag_ignore_case, val, index)
+    fn new(val: Value, flag_ignore_case: bool, flag_natural: bool, index: usize) -> Self {
+        Self::new_vals_to_compare(val.clone(), flag_ignore_case, flag_natural, val, index)
     }
     pub fn new_vals_to_compare(
         val: Value,
         flag_ignore_case: bool,
+        flag_natural: bool,
         vals_to_compare: Value,
         index: usize,
     ) -> Self {
+        let mut val_to_compare = vals_to_compare.with_span(Span::unknown());
+        if flag_ignore_case {
+            val_to_compare = clone_to_lowercase(&val_to_compare);
+        }
+        if flag_natural {
+            val_to_compare = clone_to_natural(&val_to_compare);
+        }
         ValueCounter {
             val,
-            val_to_compare: if flag_ignore_case {
-                clone_to_lowercase(&vals_to_compare.with_span(Span::unknown()))
-            } else {
-                vals_to_compare.with_span(Span::unknown())
-            },
+            val_to_compare,
             count: 1,
             index,
         }
@@ -213,6 +236,47 @@ fn clone_to_lowercase(value: &Value) -> Value {
     }
 }
 
+/// Rewrite every digit run in string values to a canonical form without
+/// leading zeros, so values that compare equal in natural order ("file7",
+/// "file07") generate the same key
+fn clone_to_natural(value: &Value) -> Value {
+    match value {
+        Value::String { val: s, span } => Value::String {
+            val: normalize_digit_runs(s),
+            span: *span,
+        },
+        Value::List { vals: vec, span } => Value::List {
+            vals: vec.iter().map(clone_to_natural).collect(),
+            span: *span,
+        },
+        Value::Record { cols, vals, span } => Value::Record {
+            cols: cols.clone(),
+            vals: vals.iter().map(clone_to_natural).collect(),
+            span: *span,
+        },
+        other => other.clone(),
+    }
+}
+
+fn normalize_digit_runs(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            let mut run = String::from(c);
+            while let Some(d) = chars.peek().copied().filter(char::is_ascii_digit) {
+                run.push(d);
+                chars.next();
+            }
+            let trimmed = run.trim_start_matches('0');
+            out.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 fn sort_attributes(val: Value) -> Value {
     match val {
         Value::Record { cols, vals, span } => {
@@ -258,11 +322,13 @@ fn generate_results_with_count(head: Span, uniq_values: Vec<ValueCounter>) -> Ve
         .collect()
 }
 
+/// The input is consumed item by item, so only the distinct values are ever
+/// buffered and an interrupted stream still returns the values seen so far.
 pub fn uniq(
     engine_state: &EngineState,
     _stack: &mut Stack,
     call: &Call,
-    input: Vec<Value>,
+    input: impl Iterator<Item = Value>,
     item_mapper: Box<dyn Fn(ItemMapperState) -> ValueCounter>,
     metadata: Option<Box<PipelineMetadata>>,
 ) -> Result<PipelineData, ShellError> {
@@ -272,9 +338,9 @@ pub fn uniq(
     let flag_show_repeated = call.has_flag("repeated");
     let flag_ignore_case = call.has_flag("ignore-case");
     let flag_only_uniques = call.has_flag("unique");
+    let flag_natural = call.has_flag("natural");
 
     let uniq_values = input
-        .into_iter()
         .enumerate()
         .map_while(|(index, item)| {
             if nu_utils::ctrl_c::was_pressed(&ctrlc) {
@@ -283,6 +349,7 @@ pub fn uniq(
             Some(item_mapper(ItemMapperState {
                 item,
                 flag_ignore_case,
+                flag_natural,
                 index,
             }))
         })
@@ -39,6 +39,11 @@ impl Command for UniqBy {
                 "Return the input values that occur once only",
                 Some('u'),
             )
+            .switch(
+                "natural",
+                "Compare string values in natural order, so numbers inside them match numerically ('file7' and 'file07' count as the same value)",
+                Some('n'),
+            )
             .category(Category::Filters)
     }
 
@@ -68,17 +73,25 @@ impl Command for UniqBy {
 
         let metadata = input.metadata();
 
-        let vec: Vec<_> = input.into_iter().collect();
-        match validate(vec.clone(), &columns, call.head) {
-            Ok(_) => {}
-            Err(err) => {
-                return Err(err);
+        // peek at the first row to validate the columns; the rest of the
+        // stream is handed to `uniq` untouched
+        let mut input = input.into_iter().peekable();
+        match input.peek() {
+            Some(first) => validate(first, &columns, call.head)?,
+            None => {
+                return Err(ShellError::GenericError(
+                    "no values to work with".to_string(),
+                    "".to_string(),
+                    None,
+                    Some("no values to work with".to_string()),
+                    Vec::new(),
+                ))
             }
         }
 
         let mapper = Box::new(item_mapper_by_col(columns));
 
-        uniq(engine_state, stack, call, vec, mapper, metadata)
+        uniq(engine_state, stack, call, input, mapper, metadata)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -106,22 +119,12 @@ impl Command for UniqBy {
     }
 }
 
-fn validate(vec: Vec<Value>, columns: &Vec<String>, span: Span) -> Result<(), ShellError> {
-    if vec.is_empty() {
-        return Err(ShellError::GenericError(
-            "no values to work with".to_string(),
-            "".to_string(),
-            None,
-            Some("no values to work with".to_string()),
-            Vec::new(),
-        ));
-    }
-
+fn validate(first: &Value, columns: &Vec<String>, span: Span) -> Result<(), ShellError> {
     if let Value::Record {
         cols,
         vals: _input_vals,
         span: val_span,
-    } = &vec[0]
+    } = first
     {
         if columns.is_empty() {
             // This uses the same format as the 'requires a column name' error in split_by.rs
@@ -164,7 +167,13 @@ fn item_mapper_by_col(cols: Vec<String>) -> impl Fn(crate::ItemMapperState) -> c
             span: Span::unknown(),
         };
 
-        crate::ValueCounter::new_vals_to_compare(ms.item, ms.flag_ignore_case, col_vals, ms.index)
+        crate::ValueCounter::new_vals_to_compare(
+            ms.item,
+            ms.flag_ignore_case,
+            ms.flag_natural,
+            col_vals,
+            ms.index,
+        )
     })
 }
 
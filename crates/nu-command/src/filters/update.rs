@@ -37,6 +37,10 @@ impl Command for Update {
         "Update an existing column to have a new value."
     }
 
+    fn extra_usage(&self) -> &str {
+        "When the replacement is a closure, it runs once for every row. The closure can take up to three parameters: the row itself (also available as $in), the old value of the cell, and the row's index."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -70,6 +74,25 @@ impl Command for Update {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Update a column from its old value and the row index",
+                example: "[[count]; [5] [5]] | update count {|row, old, index| $old + $index }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["count".into()],
+                            vals: vec![Value::test_int(5)],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["count".into()],
+                            vals: vec![Value::test_int(6)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Alter each value in the 'authors' column to use a single string instead of a list",
                 example: "[[project, authors]; ['nu', ['Andrés', 'JT', 'Yehuda']]] | update authors {|row| $row.authors | str join ','}",
@@ -105,6 +128,7 @@ fn update(
         let orig_env_vars = stack.env_vars.clone();
         let orig_env_hidden = stack.env_hidden.clone();
 
+        let mut idx: i64 = 0;
         input.map(
             move |mut input| {
                 // with_env() is used here to ensure that each iteration uses
@@ -118,6 +142,26 @@ fn update(
                     }
                 }
 
+                // Optional second closure argument: the value currently at the
+                // cell path
+                if let Some(var) = block.signature.get_positional(1) {
+                    if let Some(var_id) = &var.var_id {
+                        let current = input
+                            .clone()
+                            .follow_cell_path(&cell_path.members, false, true)
+                            .unwrap_or_else(|_| Value::nothing(span));
+                        stack.add_var(*var_id, current)
+                    }
+                }
+
+                // Optional third closure argument: the row index
+                if let Some(var) = block.signature.get_positional(2) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, Value::int(idx, span))
+                    }
+                }
+                idx += 1;
+
                 let output = eval_block(
                     &engine_state,
                     &mut stack,
@@ -143,7 +187,16 @@ fn update(
             ctrlc,
         )
     } else {
-        if let Some(PathMember::Int { val, span }) = cell_path.members.get(0) {
+        // A leading negative index counts from the end of the input, so it needs
+        // the full length before it can be resolved; collect the stream first
+        if let Some(PathMember::String { val, .. }) = cell_path.members.get(0) {
+            if val.parse::<i64>().map_or(false, i64::is_negative) {
+                let mut value = input.into_value(span);
+                value.update_data_at_cell_path(&cell_path.members, replacement)?;
+                return Ok(value.into_pipeline_data());
+            }
+        }
+        if let Some(PathMember::Int { val, span, .. }) = cell_path.members.get(0) {
             let mut input = input.into_iter();
             let mut pre_elems = vec![];
 
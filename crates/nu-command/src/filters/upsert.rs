@@ -37,6 +37,10 @@ impl Command for Upsert {
         "Update an existing column to have a new value, or insert a new column."
     }
 
+    fn extra_usage(&self) -> &str {
+        "When the replacement is a closure, it runs once for every row. The closure can take up to three parameters: the row itself (also available as $in), the current value of the cell (null when the column does not exist yet), and the row's index."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["add"]
     }
@@ -76,6 +80,25 @@ impl Command for Upsert {
                 Value::Record { cols: vec!["count".into(), "fruit".into()], vals: vec![Value::test_int(5), Value::test_string("apple")], span: Span::test_data()}],
                 span: Span::test_data()}),
         },
+        Example {
+            description: "Number rows with a column that did not exist before",
+            example: "[[name]; [amy] [bob]] | upsert id {|row, old, index| $index + 1 }",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::Record {
+                        cols: vec!["name".into(), "id".into()],
+                        vals: vec![Value::test_string("amy"), Value::test_int(1)],
+                        span: Span::test_data(),
+                    },
+                    Value::Record {
+                        cols: vec!["name".into(), "id".into()],
+                        vals: vec![Value::test_string("bob"), Value::test_int(2)],
+                        span: Span::test_data(),
+                    },
+                ],
+                span: Span::test_data(),
+            }),
+        },
         Example {
             description: "Upsert an int into a list, updating an existing value based on the index",
             example: "[1 2 3] | upsert 0 2",
@@ -127,6 +150,7 @@ fn upsert(
         let orig_env_vars = stack.env_vars.clone();
         let orig_env_hidden = stack.env_hidden.clone();
 
+        let mut idx: i64 = 0;
         input.map(
             move |mut input| {
                 // with_env() is used here to ensure that each iteration uses
@@ -140,6 +164,26 @@ fn upsert(
                     }
                 }
 
+                // Optional second closure argument: the value currently at the
+                // cell path
+                if let Some(var) = block.signature.get_positional(1) {
+                    if let Some(var_id) = &var.var_id {
+                        let current = input
+                            .clone()
+                            .follow_cell_path(&cell_path.members, false, true)
+                            .unwrap_or_else(|_| Value::nothing(span));
+                        stack.add_var(*var_id, current)
+                    }
+                }
+
+                // Optional third closure argument: the row index
+                if let Some(var) = block.signature.get_positional(2) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, Value::int(idx, span))
+                    }
+                }
+                idx += 1;
+
                 let output = eval_block(
                     &engine_state,
                     &mut stack,
@@ -165,7 +209,16 @@ fn upsert(
             ctrlc,
         )
     } else {
-        if let Some(PathMember::Int { val, span }) = cell_path.members.get(0) {
+        // A leading negative index counts from the end of the input, so it needs
+        // the full length before it can be resolved; collect the stream first
+        if let Some(PathMember::String { val, .. }) = cell_path.members.get(0) {
+            if val.parse::<i64>().map_or(false, i64::is_negative) {
+                let mut value = input.into_value(span);
+                value.upsert_data_at_cell_path(&cell_path.members, replacement)?;
+                return Ok(value.into_pipeline_data());
+            }
+        }
+        if let Some(PathMember::Int { val, span, .. }) = cell_path.members.get(0) {
             let mut input = input.into_iter();
             let mut pre_elems = vec![];
 
@@ -1,5 +1,5 @@
 use nu_engine::{eval_block, CallExt};
-use nu_protocol::ast::Call;
+use nu_protocol::ast::{Block, Call, Expr, PipelineElement};
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
@@ -20,8 +20,8 @@ impl Command for Where {
 
     fn extra_usage(&self) -> &str {
         r#"This command works similar to 'filter' but allows extra shorthands for working with
-tables, known as "row conditions". On the other hand, reading the condition from a variable is
-not supported."#
+tables, known as "row conditions". A closure stored in a variable can be used as the condition,
+and a trailing `?` in a cell path keeps rows without the column from erroring."#
     }
 
     fn signature(&self) -> nu_protocol::Signature {
@@ -52,7 +52,25 @@ not supported."#
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let mut closure: Closure = call.req(engine_state, stack, 0)?;
+
+        // `where $predicate` parses into a row-condition block that merely
+        // returns the stored closure; unwrap it so the closure itself is run
+        // against each row
+        if let Some(var_id) = condition_is_a_lone_variable(engine_state.get_block(closure.block_id))
+        {
+            let stored = closure
+                .captures
+                .get(&var_id)
+                .cloned()
+                .or_else(|| stack.get_var(var_id, call.head).ok());
+            if let Some(Value::Closure { val, captures, .. }) = stored {
+                closure = Closure {
+                    block_id: val,
+                    captures,
+                };
+            }
+        }
 
         let span = call.head;
 
@@ -126,6 +144,26 @@ not supported."#
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Filter with a closure stored in a variable",
+                example: "let cond = {|x| $x > 1}; [1 2] | where $cond",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Keep rows even when the column is missing, using optional access",
+                example: "[{a: 1} {b: 2}] | where a? == 1",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["a".to_string()],
+                        vals: vec![Value::test_int(1)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "List all files in the current directory with sizes greater than 2kb",
                 example: "ls | where size > 2kb",
@@ -155,6 +193,21 @@ not supported."#
     }
 }
 
+// Recognize a row condition that is nothing but a variable reference, like
+// `where $predicate`, so a stored closure can be unwrapped from it.
+fn condition_is_a_lone_variable(block: &Block) -> Option<nu_protocol::VarId> {
+    if let [pipeline] = block.pipelines.as_slice() {
+        if let [PipelineElement::Expression(_, expr)] = pipeline.elements.as_slice() {
+            let expr = match &expr.expr {
+                Expr::FullCellPath(path) if path.tail.is_empty() => &path.head,
+                _ => expr,
+            };
+            return expr.as_var();
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -39,6 +39,10 @@ impl Command for Window {
         "Creates a sliding window of `window_size` that slide by n rows/elements across input."
     }
 
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["moving", "sliding", "rolling", "pairwise"]
+    }
+
     fn examples(&self) -> Vec<Example> {
         let stream_test_1 = vec![
             Value::List {
@@ -90,6 +94,11 @@ impl Command for Window {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: "[1 2 3 4 5] | window 2 | each {|pair| ($pair.0 + $pair.1) / 2}",
+                description: "Compute a moving average over pairs of values",
+                result: None,
+            },
             Example {
                 example: "[1, 2, 3, 4, 5, 6, 7, 8] | window 2 --stride 3",
                 description: "A sliding window of two elements, with a stride of 3",
@@ -104,6 +104,20 @@ impl Command for FromCsv {
     }
 }
 
+// Entry point for the fuzz targets: parse untrusted CSV text directly,
+// with headers, type inference and no trimming.
+pub fn from_csv_string_to_value(input: String, span: Span) -> Result<Value, ShellError> {
+    Ok(from_delimited_data(
+        false,
+        false,
+        ',',
+        csv::Trim::None,
+        PipelineData::Value(Value::string(input, span), None),
+        span,
+    )?
+    .into_value(span))
+}
+
 fn from_csv(
     engine_state: &EngineState,
     stack: &mut Stack,
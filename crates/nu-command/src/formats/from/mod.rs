@@ -25,3 +25,12 @@ pub use xlsx::FromXlsx;
 pub use xml::FromXml;
 pub use yaml::FromYaml;
 pub use yaml::FromYml;
+
+// Entry points for the fuzz targets in `fuzz/`: each takes untrusted text and
+// must return an error rather than panic.
+pub use self::csv::from_csv_string_to_value;
+pub use self::toml::convert_string_to_value as from_toml_string_to_value;
+pub use json::convert_string_to_value as from_json_string_to_value;
+pub use nuon::from_nuon_string_to_value;
+pub use xml::from_xml_string_to_value;
+pub use yaml::from_yaml_string_to_value;
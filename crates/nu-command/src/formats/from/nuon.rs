@@ -53,54 +53,86 @@ impl Command for FromNuon {
 
     fn run(
         &self,
-        engine_state: &EngineState,
+        _engine_state: &EngineState,
         _stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let (string_input, _span, metadata) = input.collect_string_strict(head)?;
+        let result = from_nuon_string_to_value(&string_input, head)?;
+        Ok(result.into_pipeline_data_with_metadata(metadata))
+    }
+}
 
-        let engine_state = engine_state.clone();
-
-        let mut working_set = StateWorkingSet::new(&engine_state);
-        let mut error = None;
-        let (mut block, err) =
-            nu_parser::parse(&mut working_set, None, string_input.as_bytes(), false, &[]);
-        error = error.or(err);
-
-        if let Some(pipeline) = block.pipelines.get(1) {
-            if let Some(element) = pipeline.elements.get(0) {
-                return Err(ShellError::GenericError(
-                    "error when loading nuon text".into(),
-                    "could not load nuon text".into(),
-                    Some(head),
-                    None,
-                    vec![ShellError::OutsideSpannedLabeledError(
-                        string_input,
-                        "error when loading".into(),
-                        "excess values when loading".into(),
-                        element.span(),
-                    )],
-                ));
-            } else {
-                return Err(ShellError::GenericError(
-                    "error when loading nuon text".into(),
-                    "could not load nuon text".into(),
+/// Parse nuon text into a `Value` without needing an engine; this is also the
+/// entry point the fuzz targets use, so untrusted input must produce an error
+/// rather than a panic.
+pub fn from_nuon_string_to_value(string_input: &str, head: Span) -> Result<Value, ShellError> {
+    // nuon can't refer to declarations, so parsing against an empty engine
+    // state is enough
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+    let (mut block, error) =
+        nu_parser::parse(&mut working_set, None, string_input.as_bytes(), false, &[]);
+
+    if let Some(pipeline) = block.pipelines.get(1) {
+        if let Some(element) = pipeline.elements.get(0) {
+            return Err(ShellError::GenericError(
+                "error when loading nuon text".into(),
+                "could not load nuon text".into(),
+                Some(head),
+                None,
+                vec![ShellError::OutsideSpannedLabeledError(
+                    string_input.to_string(),
+                    "error when loading".into(),
+                    "excess values when loading".into(),
+                    element.span(),
+                )],
+            ));
+        } else {
+            return Err(ShellError::GenericError(
+                "error when loading nuon text".into(),
+                "could not load nuon text".into(),
+                Some(head),
+                None,
+                vec![ShellError::GenericError(
+                    "error when loading".into(),
+                    "excess values when loading".into(),
                     Some(head),
                     None,
-                    vec![ShellError::GenericError(
-                        "error when loading".into(),
-                        "excess values when loading".into(),
-                        Some(head),
-                        None,
-                        Vec::new(),
-                    )],
-                ));
-            }
+                    Vec::new(),
+                )],
+            ));
+        }
+    }
+
+    let expr = if block.pipelines.is_empty() {
+        Expression {
+            expr: Expr::Nothing,
+            span: head,
+            custom_completion: None,
+            ty: Type::Nothing,
         }
+    } else {
+        let mut pipeline = block.pipelines.remove(0);
 
-        let expr = if block.pipelines.is_empty() {
+        if let Some(expr) = pipeline.elements.get(1) {
+            return Err(ShellError::GenericError(
+                "error when loading nuon text".into(),
+                "could not load nuon text".into(),
+                Some(head),
+                None,
+                vec![ShellError::OutsideSpannedLabeledError(
+                    string_input.to_string(),
+                    "error when loading".into(),
+                    "detected a pipeline in nuon file".into(),
+                    expr.span(),
+                )],
+            ));
+        }
+
+        if pipeline.elements.is_empty() {
             Expression {
                 expr: Expr::Nothing,
                 span: head,
@@ -108,71 +140,45 @@ impl Command for FromNuon {
                 ty: Type::Nothing,
             }
         } else {
-            let mut pipeline = block.pipelines.remove(0);
-
-            if let Some(expr) = pipeline.elements.get(1) {
-                return Err(ShellError::GenericError(
-                    "error when loading nuon text".into(),
-                    "could not load nuon text".into(),
-                    Some(head),
-                    None,
-                    vec![ShellError::OutsideSpannedLabeledError(
-                        string_input,
-                        "error when loading".into(),
-                        "detected a pipeline in nuon file".into(),
-                        expr.span(),
-                    )],
-                ));
-            }
-
-            if pipeline.elements.is_empty() {
-                Expression {
-                    expr: Expr::Nothing,
-                    span: head,
-                    custom_completion: None,
-                    ty: Type::Nothing,
-                }
-            } else {
-                match pipeline.elements.remove(0) {
-                    PipelineElement::Expression(_, expression)
-                    | PipelineElement::Redirection(_, _, expression)
-                    | PipelineElement::And(_, expression)
-                    | PipelineElement::Or(_, expression)
-                    | PipelineElement::SeparateRedirection {
-                        out: (_, expression),
-                        ..
-                    } => expression,
-                }
+            match pipeline.elements.remove(0) {
+                PipelineElement::Expression(_, expression)
+                | PipelineElement::Redirection(_, _, expression)
+                | PipelineElement::And(_, expression)
+                | PipelineElement::Or(_, expression)
+                | PipelineElement::SeparateRedirection {
+                    out: (_, expression),
+                    ..
+                } => expression,
             }
-        };
-
-        if let Some(err) = error {
-            return Err(ShellError::GenericError(
-                "error when parsing nuon text".into(),
-                "could not parse nuon text".into(),
-                Some(head),
-                None,
-                vec![ShellError::OutsideSpannedLabeledError(
-                    string_input,
-                    "error when parsing".into(),
-                    err.to_string(),
-                    err.span(),
-                )],
-            ));
         }
+    };
+
+    if let Some(err) = error {
+        return Err(ShellError::GenericError(
+            "error when parsing nuon text".into(),
+            "could not parse nuon text".into(),
+            Some(head),
+            None,
+            vec![ShellError::OutsideSpannedLabeledError(
+                string_input.to_string(),
+                "error when parsing".into(),
+                err.to_string(),
+                err.span(),
+            )],
+        ));
+    }
 
-        let result = convert_to_value(expr, head, &string_input);
+    let result = convert_to_value(expr, head, string_input);
 
-        match result {
-            Ok(result) => Ok(result.into_pipeline_data_with_metadata(metadata)),
-            Err(err) => Err(ShellError::GenericError(
-                "error when loading nuon text".into(),
-                "could not load nuon text".into(),
-                Some(head),
-                None,
-                vec![err],
-            )),
-        }
+    match result {
+        Ok(result) => Ok(result),
+        Err(err) => Err(ShellError::GenericError(
+            "error when loading nuon text".into(),
+            "could not load nuon text".into(),
+            Some(head),
+            None,
+            vec![err],
+        )),
     }
 }
 
@@ -32,6 +32,12 @@ impl Command for FromSsv {
                 "the minimum spaces to separate columns",
                 Some('m'),
             )
+            .named(
+                "columns",
+                SyntaxShape::Table,
+                "a fixed-width column spec with 'name', 'start' and 'width' columns (and an optional 'type' of int, number, bool or string), parsed instead of inferring the layout",
+                Some('c'),
+            )
             .category(Category::Formats)
     }
 
@@ -51,6 +57,25 @@ impl Command for FromSsv {
             description: "Converts ssv formatted string to table but not treating the first row as column names",
             result: Some(
                 Value::List { vals: vec![Value::Record { cols: vec!["column1".to_string(), "column2".to_string()], vals: vec![Value::test_string("FOO"), Value::test_string("BAR")], span: Span::test_data() }, Value::Record { cols: vec!["column1".to_string(), "column2".to_string()], vals: vec![Value::test_string("1"), Value::test_string("2")], span: Span::test_data() }], span: Span::test_data() }),
+        }, Example {
+            example: r#"'8199 nu    0.2
+ 321 bash  1.5' | from ssv -n --columns [[name, start, width, type]; [pid, 0, 4, int], [cmd, 5, 5, string], [cpu, 11, 3, number]]"#,
+            description: "Parses fixed-width columns from an explicit spec, with type hints",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::Record {
+                        cols: vec!["pid".to_string(), "cmd".to_string(), "cpu".to_string()],
+                        vals: vec![Value::test_int(8199), Value::test_string("nu"), Value::test_float(0.2)],
+                        span: Span::test_data(),
+                    },
+                    Value::Record {
+                        cols: vec!["pid".to_string(), "cmd".to_string(), "cpu".to_string()],
+                        vals: vec![Value::test_int(321), Value::test_string("bash"), Value::test_float(1.5)],
+                        span: Span::test_data(),
+                    },
+                ],
+                span: Span::test_data(),
+            }),
         }]
     }
 
@@ -262,6 +287,150 @@ fn from_ssv_string_to_value(
     Value::List { vals: rows, span }
 }
 
+/// One entry of the `--columns` spec
+struct ColumnSpec {
+    name: String,
+    start: usize,
+    width: usize,
+    column_type: ColumnType,
+}
+
+enum ColumnType {
+    String,
+    Int,
+    Number,
+    Bool,
+}
+
+fn parse_column_specs(specs: &Value, head: Span) -> Result<Vec<ColumnSpec>, ShellError> {
+    let spec_error = |msg: &str, span: Span| {
+        ShellError::UnsupportedInput(
+            format!("the column spec {msg}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+    let rows = match specs {
+        Value::List { vals, .. } => vals,
+        other => return Err(spec_error("must be a table", other.span().unwrap_or(head))),
+    };
+
+    let mut columns = vec![];
+    for row in rows {
+        let row_span = row.span().unwrap_or(head);
+        let name = match row.get_data_by_key("name") {
+            Some(Value::String { val, .. }) => val,
+            _ => return Err(spec_error("needs a 'name' string in every row", row_span)),
+        };
+        let start = match row.get_data_by_key("start") {
+            Some(Value::Int { val, .. }) if val >= 0 => val as usize,
+            _ => {
+                return Err(spec_error(
+                    "needs a non-negative 'start' int in every row",
+                    row_span,
+                ))
+            }
+        };
+        let width = match row.get_data_by_key("width") {
+            Some(Value::Int { val, .. }) if val > 0 => val as usize,
+            _ => {
+                return Err(spec_error(
+                    "needs a positive 'width' int in every row",
+                    row_span,
+                ))
+            }
+        };
+        let column_type = match row.get_data_by_key("type") {
+            None => ColumnType::String,
+            Some(Value::String { val, span }) => match val.as_str() {
+                "string" => ColumnType::String,
+                "int" => ColumnType::Int,
+                "number" => ColumnType::Number,
+                "bool" => ColumnType::Bool,
+                _ => {
+                    return Err(spec_error(
+                        "'type' must be 'string', 'int', 'number' or 'bool'",
+                        span,
+                    ))
+                }
+            },
+            Some(other) => {
+                return Err(spec_error(
+                    "'type' must be 'string', 'int', 'number' or 'bool'",
+                    other.span().unwrap_or(head),
+                ))
+            }
+        };
+        columns.push(ColumnSpec {
+            name,
+            start,
+            width,
+            column_type,
+        });
+    }
+    Ok(columns)
+}
+
+fn convert_cell(cell: &str, column_type: &ColumnType, span: Span) -> Result<Value, ShellError> {
+    let conversion_error = |to_type: &str| {
+        ShellError::UnsupportedInput(
+            format!("'{cell}' is not {to_type}"),
+            "value originates from here".into(),
+            span,
+            span,
+        )
+    };
+    if cell.is_empty() {
+        return Ok(Value::nothing(span));
+    }
+    match column_type {
+        ColumnType::String => Ok(Value::string(cell, span)),
+        ColumnType::Int => cell
+            .parse::<i64>()
+            .map(|val| Value::int(val, span))
+            .map_err(|_| conversion_error("an int")),
+        ColumnType::Number => cell
+            .parse::<f64>()
+            .map(|val| Value::float(val, span))
+            .map_err(|_| conversion_error("a number")),
+        ColumnType::Bool => match cell {
+            "true" => Ok(Value::boolean(true, span)),
+            "false" => Ok(Value::boolean(false, span)),
+            _ => Err(conversion_error("a bool")),
+        },
+    }
+}
+
+fn from_fixed_width_spec_to_value(
+    s: &str,
+    specs: &[ColumnSpec],
+    noheaders: bool,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let lines = s
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        // without -n the first line is a header, and the spec replaces it
+        .skip(usize::from(!noheaders));
+
+    let mut rows = vec![];
+    for line in lines {
+        let chars: Vec<char> = line.chars().collect();
+        let mut cols = vec![];
+        let mut vals = vec![];
+        for spec in specs {
+            let end = (spec.start + spec.width).min(chars.len());
+            let start = spec.start.min(end);
+            let cell: String = chars[start..end].iter().collect();
+            cols.push(spec.name.clone());
+            vals.push(convert_cell(cell.trim(), &spec.column_type, span)?);
+        }
+        rows.push(Value::Record { cols, vals, span });
+    }
+    Ok(Value::List { vals: rows, span })
+}
+
 fn from_ssv(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -275,12 +444,22 @@ fn from_ssv(
     let minimum_spaces: Option<Spanned<usize>> =
         call.get_flag(engine_state, stack, "minimum-spaces")?;
 
+    let column_specs: Option<Value> = call.get_flag(engine_state, stack, "columns")?;
+
     let (concat_string, _span, metadata) = input.collect_string_strict(name)?;
     let split_at = match minimum_spaces {
         Some(number) => number.item,
         None => DEFAULT_MINIMUM_SPACES,
     };
 
+    if let Some(specs) = column_specs {
+        let specs = parse_column_specs(&specs, name)?;
+        return Ok(
+            from_fixed_width_spec_to_value(&concat_string, &specs, noheaders, name)?
+                .into_pipeline_data_with_metadata(metadata),
+        );
+    }
+
     Ok(
         from_ssv_string_to_value(&concat_string, noheaders, aligned_columns, split_at, name)
             .into_pipeline_data_with_metadata(metadata),
@@ -491,6 +670,77 @@ mod tests {
         assert_eq!(aligned_columns_with_headers, separator_with_headers);
     }
 
+    fn spec(rows: &[(&str, i64, i64, &str)]) -> Value {
+        let vals = rows
+            .iter()
+            .map(|(name, start, width, column_type)| Value::Record {
+                cols: vec!["name".into(), "start".into(), "width".into(), "type".into()],
+                vals: vec![
+                    Value::test_string(*name),
+                    Value::test_int(*start),
+                    Value::test_int(*width),
+                    Value::test_string(*column_type),
+                ],
+                span: Span::test_data(),
+            })
+            .collect();
+        Value::List {
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn fixed_width_spec_parses_types_and_skips_the_header() {
+        let input = "PID CPU\n 42 0.5";
+        let specs = spec(&[("pid", 0, 3, "int"), ("cpu", 4, 3, "number")]);
+        let specs = parse_column_specs(&specs, Span::test_data()).unwrap();
+
+        let result = from_fixed_width_spec_to_value(input, &specs, false, Span::test_data());
+
+        assert_eq!(
+            result.unwrap(),
+            Value::List {
+                vals: vec![Value::Record {
+                    cols: vec!["pid".into(), "cpu".into()],
+                    vals: vec![Value::test_int(42), Value::test_float(0.5)],
+                    span: Span::test_data(),
+                }],
+                span: Span::test_data(),
+            }
+        );
+    }
+
+    #[test]
+    fn fixed_width_spec_turns_missing_cells_into_null() {
+        let input = "ab";
+        let specs = spec(&[("a", 0, 2, "string"), ("b", 3, 2, "string")]);
+        let specs = parse_column_specs(&specs, Span::test_data()).unwrap();
+
+        let result =
+            from_fixed_width_spec_to_value(input, &specs, true, Span::test_data()).unwrap();
+
+        assert_eq!(
+            result,
+            Value::List {
+                vals: vec![Value::Record {
+                    cols: vec!["a".into(), "b".into()],
+                    vals: vec![Value::test_string("ab"), Value::nothing(Span::test_data())],
+                    span: Span::test_data(),
+                }],
+                span: Span::test_data(),
+            }
+        );
+    }
+
+    #[test]
+    fn fixed_width_spec_rejects_values_that_do_not_match_the_type_hint() {
+        let specs = spec(&[("pid", 0, 3, "int")]);
+        let specs = parse_column_specs(&specs, Span::test_data()).unwrap();
+
+        assert!(from_fixed_width_spec_to_value("abc", &specs, true, Span::test_data()).is_err());
+    }
+
     #[test]
     fn test_examples() {
         use crate::test_examples;
@@ -1,10 +1,10 @@
-use indexmap::map::IndexMap;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
-    Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
 
 #[derive(Clone)]
 pub struct FromXml;
@@ -24,6 +24,19 @@ impl Command for FromXml {
         "Parse text as .xml and create record."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Every XML node becomes a record with three columns:
+  tag        the element name; `null` for text nodes, `!` for comments, and
+             `?target` for processing instructions
+  attributes a record of the element's attributes, exactly as written
+             (namespace declarations included); `null` for other nodes
+  content    a list of child nodes for elements; the text itself for text
+             nodes, comments and processing instructions
+
+This shape is stable and round-trips through `to xml` faithfully. The
+document is read event by event rather than through an intermediate DOM."#
+    }
+
     fn run(
         &self,
         _engine_state: &EngineState,
@@ -43,138 +56,155 @@ impl Command for FromXml {
 </note>' | from xml"#,
             description: "Converts xml formatted string to record",
             result: Some(Value::Record {
-                cols: vec!["note".to_string()],
-                vals: vec![Value::Record {
-                    cols: vec!["children".to_string(), "attributes".to_string()],
-                    vals: vec![
-                        Value::List {
-                            vals: vec![Value::Record {
-                                cols: vec!["remember".to_string()],
-                                vals: vec![Value::Record {
-                                    cols: vec!["children".to_string(), "attributes".to_string()],
-                                    vals: vec![
-                                        Value::List {
-                                            vals: vec![Value::test_string("Event")],
-                                            span: Span::test_data(),
-                                        },
-                                        Value::Record {
-                                            cols: vec![],
-                                            vals: vec![],
-                                            span: Span::test_data(),
-                                        },
-                                    ],
+                cols: vec!["tag".into(), "attributes".into(), "content".into()],
+                vals: vec![
+                    Value::test_string("note"),
+                    Value::Record {
+                        cols: vec![],
+                        vals: vec![],
+                        span: Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::Record {
+                            cols: vec!["tag".into(), "attributes".into(), "content".into()],
+                            vals: vec![
+                                Value::test_string("remember"),
+                                Value::Record {
+                                    cols: vec![],
+                                    vals: vec![],
                                     span: Span::test_data(),
-                                }],
-                                span: Span::test_data(),
-                            }],
-                            span: Span::test_data(),
-                        },
-                        Value::Record {
-                            cols: vec![],
-                            vals: vec![],
+                                },
+                                Value::List {
+                                    vals: vec![Value::Record {
+                                        cols: vec![
+                                            "tag".into(),
+                                            "attributes".into(),
+                                            "content".into(),
+                                        ],
+                                        vals: vec![
+                                            Value::nothing(Span::test_data()),
+                                            Value::nothing(Span::test_data()),
+                                            Value::test_string("Event"),
+                                        ],
+                                        span: Span::test_data(),
+                                    }],
+                                    span: Span::test_data(),
+                                },
+                            ],
                             span: Span::test_data(),
-                        },
-                    ],
-                    span: Span::test_data(),
-                }],
+                        }],
+                        span: Span::test_data(),
+                    },
+                ],
                 span: Span::test_data(),
             }),
         }]
     }
 }
 
-fn from_attributes_to_value(attributes: &[roxmltree::Attribute], span: Span) -> Value {
-    let mut collected = IndexMap::new();
-    for a in attributes {
-        collected.insert(String::from(a.name()), Value::string(a.value(), span));
+fn element_value(tag: String, attributes: Value, content: Vec<Value>, span: Span) -> Value {
+    Value::Record {
+        cols: vec!["tag".into(), "attributes".into(), "content".into()],
+        vals: vec![
+            Value::string(tag, span),
+            attributes,
+            Value::List {
+                vals: content,
+                span,
+            },
+        ],
+        span,
     }
-
-    let (cols, vals) = collected
-        .into_iter()
-        .fold((vec![], vec![]), |mut acc, (k, v)| {
-            acc.0.push(k);
-            acc.1.push(v);
-            acc
-        });
-
-    Value::Record { cols, vals, span }
 }
 
-fn from_node_to_value(n: &roxmltree::Node, span: Span) -> Value {
-    if n.is_element() {
-        let name = n.tag_name().name().trim().to_string();
+/// A text node, comment (`tag` of `!`) or processing instruction (`tag` of
+/// `?target`)
+fn special_value(tag: Value, content: String, span: Span) -> Value {
+    Value::Record {
+        cols: vec!["tag".into(), "attributes".into(), "content".into()],
+        vals: vec![tag, Value::nothing(span), Value::string(content, span)],
+        span,
+    }
+}
 
-        let mut children_values = vec![];
-        for c in n.children() {
-            children_values.push(from_node_to_value(&c, span));
-        }
+fn attributes_value(start: &BytesStart, span: Span) -> Result<Value, String> {
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|err| format!("malformed attribute: {err}"))?;
+        let value = attribute
+            .unescape_value()
+            .map_err(|err| format!("malformed attribute value: {err}"))?;
+        cols.push(String::from_utf8_lossy(attribute.key.as_ref()).to_string());
+        vals.push(Value::string(value, span));
+    }
+    Ok(Value::Record { cols, vals, span })
+}
 
-        let children_values: Vec<Value> = children_values
-            .into_iter()
-            .filter(|x| match x {
-                Value::String { val: f, .. } => {
-                    !f.trim().is_empty() // non-whitespace characters?
+/// Read the children of the element opened by `start` until its closing tag,
+/// building the node record as events arrive
+fn parse_element(
+    reader: &mut Reader<&[u8]>,
+    start: &BytesStart,
+    span: Span,
+) -> Result<Value, String> {
+    let tag = String::from_utf8_lossy(start.name().as_ref()).to_string();
+    let attributes = attributes_value(start, span)?;
+    let mut content = vec![];
+
+    loop {
+        match reader.read_event().map_err(|err| err.to_string())? {
+            Event::Start(child) => content.push(parse_element(reader, &child, span)?),
+            Event::End(_) => break,
+            Event::Text(text) => {
+                let text = text.unescape().map_err(|err| err.to_string())?;
+                if !text.trim().is_empty() {
+                    content.push(special_value(Value::nothing(span), text.to_string(), span));
                 }
-                _ => true,
-            })
-            .collect();
-
-        let mut collected = IndexMap::new();
-
-        let attribute_value: Value =
-            from_attributes_to_value(&n.attributes().collect::<Vec<_>>(), span);
-
-        let mut row = IndexMap::new();
-        row.insert(
-            String::from("children"),
-            Value::List {
-                vals: children_values,
-                span,
-            },
-        );
-        row.insert(String::from("attributes"), attribute_value);
-        collected.insert(name, Value::from(Spanned { item: row, span }));
-
-        Value::from(Spanned {
-            item: collected,
-            span,
-        })
-    } else if n.is_comment() {
-        Value::String {
-            val: "<comment>".to_string(),
-            span,
-        }
-    } else if n.is_pi() {
-        Value::String {
-            val: "<processing_instruction>".to_string(),
-            span,
-        }
-    } else if n.is_text() {
-        match n.text() {
-            Some(text) => Value::String {
-                val: text.to_string(),
+            }
+            Event::CData(data) => content.push(special_value(
+                Value::nothing(span),
+                String::from_utf8_lossy(&data.into_inner()).to_string(),
                 span,
-            },
-            None => Value::String {
-                val: "<error>".to_string(),
+            )),
+            Event::Comment(comment) => content.push(special_value(
+                Value::string("!", span),
+                String::from_utf8_lossy(comment.as_ref()).to_string(),
                 span,
-            },
-        }
-    } else {
-        Value::String {
-            val: "<unknown>".to_string(),
-            span,
+            )),
+            Event::PI(instruction) => {
+                let instruction = String::from_utf8_lossy(instruction.as_ref()).to_string();
+                let (target, rest) = match instruction.split_once(char::is_whitespace) {
+                    Some((target, rest)) => (target.to_string(), rest.to_string()),
+                    None => (instruction, String::new()),
+                };
+                content.push(special_value(
+                    Value::string(format!("?{target}"), span),
+                    rest,
+                    span,
+                ));
+            }
+            Event::Eof => return Err("unexpected end of document".into()),
+            _ => {}
         }
     }
-}
 
-fn from_document_to_value(d: &roxmltree::Document, span: Span) -> Value {
-    from_node_to_value(&d.root_element(), span)
+    Ok(element_value(tag, attributes, content, span))
 }
 
-pub fn from_xml_string_to_value(s: String, span: Span) -> Result<Value, roxmltree::Error> {
-    let parsed = roxmltree::Document::parse(&s)?;
-    Ok(from_document_to_value(&parsed, span))
+pub fn from_xml_string_to_value(s: String, span: Span) -> Result<Value, String> {
+    let mut reader = Reader::from_str(&s);
+    reader.expand_empty_elements(true);
+
+    loop {
+        match reader.read_event().map_err(|err| err.to_string())? {
+            Event::Start(start) => return parse_element(&mut reader, &start, span),
+            Event::Eof => return Err("document has no root element".into()),
+            // the prolog: declaration, doctype, and anything else before the
+            // root element
+            _ => {}
+        }
+    }
 }
 
 fn from_xml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
@@ -182,8 +212,8 @@ fn from_xml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
 
     match from_xml_string_to_value(concat_string, head) {
         Ok(x) => Ok(x.into_pipeline_data_with_metadata(metadata)),
-        _ => Err(ShellError::UnsupportedInput(
-            "Could not parse string as XML".to_string(),
+        Err(err) => Err(ShellError::UnsupportedInput(
+            format!("Could not parse string as XML: {err}"),
             "value originates from here".into(),
             head,
             span,
@@ -195,68 +225,62 @@ fn from_xml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
 mod tests {
     use super::*;
 
-    use indexmap::indexmap;
-    use indexmap::IndexMap;
-    use nu_protocol::{Spanned, Value};
-
     fn string(input: impl Into<String>) -> Value {
         Value::test_string(input)
     }
 
-    fn row(entries: IndexMap<String, Value>) -> Value {
-        Value::from(Spanned {
-            item: entries,
+    fn attributes(entries: &[(&str, &str)]) -> Value {
+        Value::Record {
+            cols: entries.iter().map(|(k, _)| k.to_string()).collect(),
+            vals: entries.iter().map(|(_, v)| string(*v)).collect(),
             span: Span::test_data(),
-        })
+        }
     }
 
-    fn table(list: &[Value]) -> Value {
-        Value::List {
-            vals: list.to_vec(),
-            span: Span::test_data(),
-        }
+    fn element(tag: &str, attrs: &[(&str, &str)], content: &[Value]) -> Value {
+        element_value(
+            tag.into(),
+            attributes(attrs),
+            content.to_vec(),
+            Span::test_data(),
+        )
+    }
+
+    fn text(content: &str) -> Value {
+        special_value(
+            Value::nothing(Span::test_data()),
+            content.into(),
+            Span::test_data(),
+        )
     }
 
-    fn parse(xml: &str) -> Result<Value, roxmltree::Error> {
+    fn parse(xml: &str) -> Result<Value, String> {
         from_xml_string_to_value(xml.to_string(), Span::test_data())
     }
 
     #[test]
-    fn parses_empty_element() -> Result<(), roxmltree::Error> {
+    fn parses_empty_element() -> Result<(), String> {
         let source = "<nu></nu>";
 
-        assert_eq!(
-            parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[]),
-                    "attributes".into() => row(indexmap! {})
-                })
-            })
-        );
+        assert_eq!(parse(source)?, element("nu", &[], &[]));
 
         Ok(())
     }
 
     #[test]
-    fn parses_element_with_text() -> Result<(), roxmltree::Error> {
+    fn parses_element_with_text() -> Result<(), String> {
         let source = "<nu>La era de los tres caballeros</nu>";
 
         assert_eq!(
             parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[string("La era de los tres caballeros")]),
-                    "attributes".into() => row(indexmap! {})
-                })
-            })
+            element("nu", &[], &[text("La era de los tres caballeros")])
         );
 
         Ok(())
     }
 
     #[test]
-    fn parses_element_with_elements() -> Result<(), roxmltree::Error> {
+    fn parses_element_with_elements() -> Result<(), String> {
         let source = "\
 <nu>
     <dev>Andrés</dev>
@@ -266,59 +290,33 @@ mod tests {
 
         assert_eq!(
             parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[
-                        row(indexmap! {
-                            "dev".into() => row(indexmap! {
-                                "children".into() => table(&[string("Andrés")]),
-                                "attributes".into() => row(indexmap! {})
-                            })
-                        }),
-                        row(indexmap! {
-                            "dev".into() => row(indexmap! {
-                                "children".into() => table(&[string("Jonathan")]),
-                                "attributes".into() => row(indexmap! {})
-                            })
-                        }),
-                        row(indexmap! {
-                            "dev".into() => row(indexmap! {
-                                "children".into() => table(&[string("Yehuda")]),
-                                "attributes".into() => row(indexmap! {})
-                            })
-                        })
-                    ]),
-                    "attributes".into() => row(indexmap! {})
-                })
-            })
+            element(
+                "nu",
+                &[],
+                &[
+                    element("dev", &[], &[text("Andrés")]),
+                    element("dev", &[], &[text("Jonathan")]),
+                    element("dev", &[], &[text("Yehuda")]),
+                ]
+            )
         );
 
         Ok(())
     }
 
     #[test]
-    fn parses_element_with_attribute() -> Result<(), roxmltree::Error> {
+    fn parses_element_with_attribute() -> Result<(), String> {
         let source = "\
 <nu version=\"2.0\">
 </nu>";
 
-        assert_eq!(
-            parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[]),
-                    "attributes".into() => row(indexmap! {
-                        "version".into() => string("2.0")
-                    })
-                })
-            })
-        );
+        assert_eq!(parse(source)?, element("nu", &[("version", "2.0")], &[]));
 
         Ok(())
     }
 
     #[test]
-    fn parses_element_with_attribute_and_element() -> Result<(), roxmltree::Error> {
+    fn parses_element_with_attribute_and_element() -> Result<(), String> {
         let source = "\
 <nu version=\"2.0\">
     <version>2.0</version>
@@ -326,43 +324,60 @@ mod tests {
 
         assert_eq!(
             parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[
-                           row(indexmap! {
-                                "version".into() => row(indexmap! {
-                                    "children".into() => table(&[string("2.0")]),
-                                    "attributes".into() => row(indexmap! {})
-                                })
-                          })
-                    ]),
-                    "attributes".into() => row(indexmap! {
-                        "version".into() => string("2.0")
-                    })
-                })
-            })
+            element(
+                "nu",
+                &[("version", "2.0")],
+                &[element("version", &[], &[text("2.0")])]
+            )
         );
 
         Ok(())
     }
 
     #[test]
-    fn parses_element_with_multiple_attributes() -> Result<(), roxmltree::Error> {
+    fn parses_element_with_multiple_attributes() -> Result<(), String> {
         let source = "\
 <nu version=\"2.0\" age=\"25\">
 </nu>";
 
         assert_eq!(
             parse(source)?,
-            row(indexmap! {
-                "nu".into() => row(indexmap! {
-                    "children".into() => table(&[]),
-                    "attributes".into() => row(indexmap! {
-                        "version".into() => string("2.0"),
-                        "age".into() => string("25")
-                    })
-                })
-            })
+            element("nu", &[("version", "2.0"), ("age", "25")], &[])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_comments_and_processing_instructions() -> Result<(), String> {
+        let source = "<nu><!-- greeting --><?robot beep?></nu>";
+
+        assert_eq!(
+            parse(source)?,
+            element(
+                "nu",
+                &[],
+                &[
+                    special_value(string("!"), " greeting ".into(), Span::test_data()),
+                    special_value(string("?robot"), "beep".into(), Span::test_data()),
+                ]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_namespace_declarations_and_prefixes() -> Result<(), String> {
+        let source = r#"<root xmlns:a="urn:x"><a:item>1</a:item></root>"#;
+
+        assert_eq!(
+            parse(source)?,
+            element(
+                "root",
+                &[("xmlns:a", "urn:x")],
+                &[element("a:item", &[], &[text("1")])]
+            )
         );
 
         Ok(())
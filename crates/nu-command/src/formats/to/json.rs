@@ -105,7 +105,26 @@ impl Command for ToJson {
     }
 }
 
+/// How deeply nested values may be before conversion is aborted with an error
+/// instead of risking a stack overflow. This matches the limit that nu-json
+/// enforces when parsing.
+const MAX_DEPTH: i64 = 500;
+
 pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
+    value_to_json_value_impl(v, 0)
+}
+
+fn value_to_json_value_impl(v: &Value, depth: i64) -> Result<nu_json::Value, ShellError> {
+    if depth > MAX_DEPTH {
+        return Err(ShellError::GenericError(
+            "Value is too deeply nested to convert to JSON".into(),
+            format!("exceeded depth limit of {MAX_DEPTH}"),
+            v.span().ok(),
+            None,
+            Vec::new(),
+        ));
+    }
+
     Ok(match v {
         Value::Bool { val, .. } => nu_json::Value::Bool(*val),
         Value::Filesize { val, .. } => nu_json::Value::I64(*val),
@@ -125,7 +144,7 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
                 .collect::<Result<Vec<nu_json::Value>, ShellError>>()?,
         ),
 
-        Value::List { vals, .. } => nu_json::Value::Array(json_list(vals)?),
+        Value::List { vals, .. } => nu_json::Value::Array(json_list(vals, depth)?),
         Value::Error { error } => return Err(error.clone()),
         Value::Closure { .. } | Value::Block { .. } | Value::Range { .. } => nu_json::Value::Null,
         Value::Binary { val, .. } => {
@@ -134,26 +153,26 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
         Value::Record { cols, vals, .. } => {
             let mut m = nu_json::Map::new();
             for (k, v) in cols.iter().zip(vals) {
-                m.insert(k.clone(), value_to_json_value(v)?);
+                m.insert(k.clone(), value_to_json_value_impl(v, depth + 1)?);
             }
             nu_json::Value::Object(m)
         }
         Value::LazyRecord { val, .. } => {
             let collected = val.collect()?;
-            value_to_json_value(&collected)?
+            value_to_json_value_impl(&collected, depth + 1)?
         }
         Value::CustomValue { val, span } => {
             let collected = val.to_base_value(*span)?;
-            value_to_json_value(&collected)?
+            value_to_json_value_impl(&collected, depth + 1)?
         }
     })
 }
 
-fn json_list(input: &[Value]) -> Result<Vec<nu_json::Value>, ShellError> {
+fn json_list(input: &[Value], depth: i64) -> Result<Vec<nu_json::Value>, ShellError> {
     let mut out = vec![];
 
     for value in input {
-        out.push(value_to_json_value(value)?);
+        out.push(value_to_json_value_impl(value, depth + 1)?);
     }
 
     Ok(out)
@@ -5,6 +5,7 @@ mod html;
 mod json;
 mod md;
 mod nuon;
+mod sql;
 mod text;
 mod toml;
 mod tsv;
@@ -19,6 +20,7 @@ pub use json::ToJson;
 pub use md::ToMd;
 pub use nuon::value_to_string;
 pub use nuon::ToNuon;
+pub use sql::ToSql;
 pub use text::ToText;
 pub use tsv::ToTsv;
 pub use xml::ToXml;
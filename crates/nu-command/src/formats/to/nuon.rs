@@ -5,7 +5,8 @@ use nu_parser::escape_quote_string;
 use nu_protocol::ast::{Call, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
 };
 use once_cell::sync::Lazy;
 
@@ -20,6 +21,23 @@ impl Command for ToNuon {
     fn signature(&self) -> Signature {
         Signature::build("to nuon")
             .input_output_types(vec![(Type::Any, Type::String)])
+            .switch(
+                "raw",
+                "remove all of the whitespace (default behavior and overwrites -i and -t)",
+                Some('r'),
+            )
+            .named(
+                "indent",
+                SyntaxShape::Number,
+                "specify indentation width",
+                Some('i'),
+            )
+            .named(
+                "tabs",
+                SyntaxShape::Number,
+                "specify indentation tab quantity",
+                Some('t'),
+            )
             .category(Category::Experimental)
     }
 
@@ -29,28 +47,111 @@ impl Command for ToNuon {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        let raw = call.has_flag("raw");
+        let use_tabs = call.get_flag(engine_state, stack, "tabs")?;
+        let use_indent = call.get_flag(engine_state, stack, "indent")?;
+
+        let style = if raw {
+            ToStyle::Raw
+        } else if let Some(tabs) = use_tabs {
+            ToStyle::Tabs(tabs)
+        } else if let Some(indent) = use_indent {
+            ToStyle::Spaces(indent)
+        } else {
+            ToStyle::Default
+        };
+
         Ok(Value::String {
-            val: to_nuon(call, input)?,
+            val: to_nuon(call, input, style)?,
             span: call.head,
         }
         .into_pipeline_data())
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs a nuon string representing the contents of this list",
-            example: "[1 2 3] | to nuon",
-            result: Some(Value::test_string("[1, 2, 3]")),
-        }]
+        vec![
+            Example {
+                description: "Outputs a nuon string representing the contents of this list",
+                example: "[1 2 3] | to nuon",
+                result: Some(Value::test_string("[1, 2, 3]")),
+            },
+            Example {
+                description: "Outputs a nuon string representing the contents of this list, without any whitespace",
+                example: "[1 2 3] | to nuon --raw",
+                result: Some(Value::test_string("[1,2,3]")),
+            },
+            Example {
+                description: "Outputs a nuon string representing the contents of this table, with pretty indentation",
+                example: "[1 2 3] | to nuon --indent 2",
+                result: Some(Value::test_string("[\n  1,\n  2,\n  3\n]")),
+            },
+        ]
+    }
+}
+
+/// How `to nuon` lays out its output: the default single line with spaces,
+/// fully compact, or pretty-printed with the given indentation.
+pub enum ToStyle {
+    Default,
+    Raw,
+    Spaces(usize),
+    Tabs(usize),
+}
+
+impl ToStyle {
+    fn indent_unit(&self) -> Option<String> {
+        match self {
+            ToStyle::Default | ToStyle::Raw => None,
+            ToStyle::Spaces(n) => Some(" ".repeat(*n)),
+            ToStyle::Tabs(n) => Some("\t".repeat(*n)),
+        }
+    }
+
+    fn item_separator(&self) -> &str {
+        match self {
+            ToStyle::Default => ", ",
+            _ => ",",
+        }
     }
 }
 
 pub fn value_to_string(v: &Value, span: Span) -> Result<String, ShellError> {
+    value_to_string_styled(v, span, 0, &ToStyle::Default)
+}
+
+// Wraps already-converted items in the given open/close delimiters, laying
+// them out on one line or one item per indented line depending on the style.
+fn wrap_collection(
+    items: Vec<String>,
+    open: char,
+    close: char,
+    depth: usize,
+    style: &ToStyle,
+) -> String {
+    match style.indent_unit() {
+        Some(unit) if !items.is_empty() => {
+            let outer = unit.repeat(depth);
+            let inner = unit.repeat(depth + 1);
+            format!(
+                "{open}\n{inner}{}\n{outer}{close}",
+                items.join(&format!(",\n{inner}"))
+            )
+        }
+        _ => format!("{open}{}{close}", items.join(style.item_separator())),
+    }
+}
+
+fn value_to_string_styled(
+    v: &Value,
+    span: Span,
+    depth: usize,
+    style: &ToStyle,
+) -> Result<String, ShellError> {
     match v {
         Value::Binary { val, .. } => {
             let mut s = String::with_capacity(2 * val.len());
@@ -131,7 +232,7 @@ pub fn value_to_string(v: &Value, span: Span) -> Result<String, ShellError> {
                         }
                     })
                     .collect();
-                let headers_output = headers.join(", ");
+                let headers_output = headers.join(style.item_separator());
 
                 let mut table_output = vec![];
                 for val in vals {
@@ -139,24 +240,45 @@ pub fn value_to_string(v: &Value, span: Span) -> Result<String, ShellError> {
 
                     if let Value::Record { vals, .. } = val {
                         for val in vals {
-                            row.push(value_to_string_without_quotes(val, span)?);
+                            row.push(value_to_string_without_quotes(val, span, depth + 2, style)?);
                         }
                     }
 
-                    table_output.push(row.join(", "));
+                    table_output.push(format!("[{}]", row.join(style.item_separator())));
                 }
 
-                Ok(format!(
-                    "[[{}]; [{}]]",
-                    headers_output,
-                    table_output.join("], [")
-                ))
+                let mut rows = vec![format!("[{headers_output}];")];
+                rows.extend(table_output);
+                match style.indent_unit() {
+                    Some(unit) => {
+                        let outer = unit.repeat(depth);
+                        let inner = unit.repeat(depth + 1);
+                        // the header row is followed by `;` rather than `,`
+                        let (first, rest) = rows.split_first().expect("header row is always present");
+                        let mut out = format!("[\n{inner}{first}");
+                        for (i, row) in rest.iter().enumerate() {
+                            if i > 0 {
+                                out.push(',');
+                            }
+                            out.push_str(&format!("\n{inner}{row}"));
+                        }
+                        out.push_str(&format!("\n{outer}]"));
+                        Ok(out)
+                    }
+                    None => {
+                        let (first, rest) = rows.split_first().expect("header row is always present");
+                        Ok(format!(
+                            "[{first} {}]",
+                            rest.join(style.item_separator())
+                        ))
+                    }
+                }
             } else {
                 let mut collection = vec![];
                 for val in vals {
-                    collection.push(value_to_string_without_quotes(val, span)?);
+                    collection.push(value_to_string_without_quotes(val, span, depth + 1, style)?);
                 }
-                Ok(format!("[{}]", collection.join(", ")))
+                Ok(wrap_collection(collection, '[', ']', depth, style))
             }
         }
         Value::Nothing { .. } => Ok("null".to_string()),
@@ -177,17 +299,21 @@ pub fn value_to_string(v: &Value, span: Span) -> Result<String, ShellError> {
                     format!(
                         "\"{}\": {}",
                         col,
-                        value_to_string_without_quotes(val, span)?
+                        value_to_string_without_quotes(val, span, depth + 1, style)?
                     )
                 } else {
-                    format!("{}: {}", col, value_to_string_without_quotes(val, span)?)
+                    format!(
+                        "{}: {}",
+                        col,
+                        value_to_string_without_quotes(val, span, depth + 1, style)?
+                    )
                 });
             }
-            Ok(format!("{{{}}}", collection.join(", ")))
+            Ok(wrap_collection(collection, '{', '}', depth, style))
         }
         Value::LazyRecord { val, .. } => {
             let collected = val.collect()?;
-            value_to_string(&collected, span)
+            value_to_string_styled(&collected, span, depth, style)
         }
         // All strings outside data structures are quoted because they are in 'command position'
         // (could be mistaken for commands by the Nu parser)
@@ -195,7 +321,12 @@ pub fn value_to_string(v: &Value, span: Span) -> Result<String, ShellError> {
     }
 }
 
-fn value_to_string_without_quotes(v: &Value, span: Span) -> Result<String, ShellError> {
+fn value_to_string_without_quotes(
+    v: &Value,
+    span: Span,
+    depth: usize,
+    style: &ToStyle,
+) -> Result<String, ShellError> {
     match v {
         Value::String { val, .. } => Ok({
             if needs_quotes(val) {
@@ -204,14 +335,14 @@ fn value_to_string_without_quotes(v: &Value, span: Span) -> Result<String, Shell
                 val.clone()
             }
         }),
-        _ => value_to_string(v, span),
+        _ => value_to_string_styled(v, span, depth, style),
     }
 }
 
-fn to_nuon(call: &Call, input: PipelineData) -> Result<String, ShellError> {
+fn to_nuon(call: &Call, input: PipelineData, style: ToStyle) -> Result<String, ShellError> {
     let v = input.into_value(call.head);
 
-    value_to_string(&v, call.head)
+    value_to_string_styled(&v, call.head, 0, &style)
 }
 
 // This hits, in order:
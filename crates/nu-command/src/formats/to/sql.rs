@@ -0,0 +1,284 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToSql;
+
+impl Command for ToSql {
+    fn name(&self) -> &str {
+        "to sql"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to sql")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::String),
+                (Type::Record(vec![]), Type::String),
+            ])
+            .required_named(
+                "table",
+                SyntaxShape::String,
+                "the table to insert into",
+                Some('t'),
+            )
+            .named(
+                "dialect",
+                SyntaxShape::String,
+                "the SQL dialect to quote for: sqlite (default), postgres or mysql",
+                Some('d'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into SQL INSERT statements."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every row becomes one set of values in a single multi-row INSERT, so the
+output can be piped straight into a database client. Identifiers and string
+literals are quoted for the chosen dialect; null, booleans, numbers, dates
+and binary values become the matching SQL literals."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["database", "insert", "sqlite", "postgres", "mysql"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let table: Spanned<String> = call
+            .get_flag(engine_state, stack, "table")?
+            .expect("required named flag");
+        let dialect: Option<Spanned<String>> = call.get_flag(engine_state, stack, "dialect")?;
+        let dialect = match dialect {
+            Some(name) => Dialect::from_name(&name)?,
+            None => Dialect::Sqlite,
+        };
+
+        let value = input.into_value(head);
+        let rows = match value {
+            Value::List { vals, .. } => vals,
+            record @ Value::Record { .. } => vec![record],
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "expected a table or record".into(),
+                    format!("input type: {}", other.get_type()),
+                    head,
+                    other.expect_span(),
+                ))
+            }
+        };
+
+        let sql = rows_to_sql(&rows, &table.item, dialect, head)?;
+        Ok(Value::string(sql, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render a table as a multi-row INSERT statement",
+                example: "[[name, age]; [Alice, 30] [Bob, null]] | to sql --table users",
+                result: Some(Value::test_string(
+                    "INSERT INTO \"users\" (\"name\", \"age\") VALUES ('Alice', 30), ('Bob', NULL);",
+                )),
+            },
+            Example {
+                description: "Quote identifiers and booleans for MySQL",
+                example: "[[user, admin]; [amy, true]] | to sql --table accounts --dialect mysql",
+                result: Some(Value::test_string(
+                    "INSERT INTO `accounts` (`user`, `admin`) VALUES ('amy', TRUE);",
+                )),
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Dialect {
+    Sqlite,
+    Postgres,
+    Mysql,
+}
+
+impl Dialect {
+    fn from_name(name: &Spanned<String>) -> Result<Self, ShellError> {
+        match name.item.as_str() {
+            "sqlite" => Ok(Dialect::Sqlite),
+            "postgres" => Ok(Dialect::Postgres),
+            "mysql" => Ok(Dialect::Mysql),
+            other => Err(ShellError::UnsupportedInput(
+                format!("'{other}' is not a supported SQL dialect"),
+                "expected sqlite, postgres or mysql".into(),
+                name.span,
+                name.span,
+            )),
+        }
+    }
+
+    fn quote_identifier(&self, name: &str) -> String {
+        match self {
+            Dialect::Mysql => format!("`{}`", name.replace('`', "``")),
+            _ => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    fn quote_string(&self, text: &str) -> String {
+        let escaped = match self {
+            // MySQL treats backslashes in string literals as escapes
+            Dialect::Mysql => text.replace('\\', "\\\\").replace('\'', "''"),
+            _ => text.replace('\'', "''"),
+        };
+        format!("'{escaped}'")
+    }
+
+    fn binary_literal(&self, bytes: &[u8]) -> String {
+        let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+        match self {
+            Dialect::Postgres => format!("'\\x{hex}'"),
+            _ => format!("X'{hex}'"),
+        }
+    }
+}
+
+fn value_to_sql_literal(value: &Value, dialect: Dialect, head: Span) -> Result<String, ShellError> {
+    Ok(match value {
+        Value::Nothing { .. } => "NULL".into(),
+        Value::Bool { val, .. } => match dialect {
+            // SQLite has no boolean literals older clients accept reliably
+            Dialect::Sqlite => if *val { "1" } else { "0" }.into(),
+            _ => if *val { "TRUE" } else { "FALSE" }.into(),
+        },
+        Value::Int { val, .. } => val.to_string(),
+        Value::Float { val, .. } => val.to_string(),
+        Value::Filesize { val, .. } => val.to_string(),
+        Value::String { val, .. } => dialect.quote_string(val),
+        Value::Date { val, .. } => dialect.quote_string(&val.to_rfc3339()),
+        Value::Binary { val, .. } => dialect.binary_literal(val),
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                format!(
+                    "{} values cannot be written as SQL literals",
+                    other.get_type()
+                ),
+                "value originates from here".into(),
+                head,
+                other.expect_span(),
+            ))
+        }
+    })
+}
+
+fn rows_to_sql(
+    rows: &[Value],
+    table: &str,
+    dialect: Dialect,
+    head: Span,
+) -> Result<String, ShellError> {
+    let columns = match rows.first() {
+        Some(Value::Record { cols, .. }) => cols.clone(),
+        Some(other) => {
+            return Err(ShellError::UnsupportedInput(
+                "expected a table of records".into(),
+                format!("row type: {}", other.get_type()),
+                head,
+                other.expect_span(),
+            ))
+        }
+        None => {
+            return Err(ShellError::UnsupportedInput(
+                "cannot write INSERT statements for an empty table".into(),
+                "value originates from here".into(),
+                head,
+                head,
+            ))
+        }
+    };
+
+    let mut tuples = vec![];
+    for row in rows {
+        let mut literals = vec![];
+        for column in &columns {
+            let cell = row
+                .get_data_by_key(column)
+                .unwrap_or_else(|| Value::nothing(head));
+            literals.push(value_to_sql_literal(&cell, dialect, head)?);
+        }
+        tuples.push(format!("({})", literals.join(", ")));
+    }
+
+    let columns: Vec<String> = columns
+        .iter()
+        .map(|column| dialect.quote_identifier(column))
+        .collect();
+    Ok(format!(
+        "INSERT INTO {} ({}) VALUES {};",
+        dialect.quote_identifier(table),
+        columns.join(", "),
+        tuples.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn row(entries: &[(&str, Value)]) -> Value {
+        Value::Record {
+            cols: entries.iter().map(|(k, _)| k.to_string()).collect(),
+            vals: entries.iter().map(|(_, v)| v.clone()).collect(),
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn escapes_single_quotes_in_strings() {
+        let rows = vec![row(&[("name", Value::test_string("O'Brien"))])];
+
+        assert_eq!(
+            rows_to_sql(&rows, "users", Dialect::Sqlite, Span::test_data()).unwrap(),
+            "INSERT INTO \"users\" (\"name\") VALUES ('O''Brien');"
+        );
+    }
+
+    #[test]
+    fn mysql_escapes_backslashes_too() {
+        let rows = vec![row(&[("path", Value::test_string(r"C:\tmp"))])];
+
+        assert_eq!(
+            rows_to_sql(&rows, "files", Dialect::Mysql, Span::test_data()).unwrap(),
+            r"INSERT INTO `files` (`path`) VALUES ('C:\\tmp');"
+        );
+    }
+
+    #[test]
+    fn missing_cells_become_null() {
+        let rows = vec![
+            row(&[("a", Value::test_int(1)), ("b", Value::test_int(2))]),
+            row(&[("a", Value::test_int(3))]),
+        ];
+
+        assert_eq!(
+            rows_to_sql(&rows, "t", Dialect::Postgres, Span::test_data()).unwrap(),
+            "INSERT INTO \"t\" (\"a\", \"b\") VALUES (1, 2), (3, NULL);"
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToSql {})
+    }
+}
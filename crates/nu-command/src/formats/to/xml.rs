@@ -1,4 +1,3 @@
-use indexmap::IndexMap;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -35,14 +34,14 @@ impl Command for ToXml {
         vec![
             Example {
                 description: "Outputs an XML string representing the contents of this table",
-                example: r#"{ "note": { "children": [{ "remember": {"attributes" : {}, "children": [Event]}}], "attributes": {} } } | to xml"#,
+                example: r#"{tag: note, attributes: {}, content: [{tag: remember, attributes: {}, content: [{tag: null, attributes: null, content: 'Event'}]}]} | to xml"#,
                 result: Some(Value::test_string(
                     "<note><remember>Event</remember></note>",
                 )),
             },
             Example {
                 description: "Optionally, formats the text with a custom indentation setting",
-                example: r#"{ "note": { "children": [{ "remember": {"attributes" : {}, "children": [Event]}}], "attributes": {} } } | to xml -p 3"#,
+                example: r#"{tag: note, attributes: {}, content: [{tag: remember, attributes: {}, content: [{tag: null, attributes: null, content: 'Event'}]}]} | to xml -p 3"#,
                 result: Some(Value::test_string(
                     "<note>\n   <remember>Event</remember>\n</note>",
                 )),
@@ -51,7 +50,11 @@ impl Command for ToXml {
     }
 
     fn usage(&self) -> &str {
-        "Convert table into .xml text."
+        "Convert a record in the `from xml` shape into .xml text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Expects the `tag`/`attributes`/`content` node records produced by `from xml`, and writes attributes, text, comments, processing instructions and namespace declarations back out as they were read."
     }
 
     fn run(
@@ -69,45 +72,58 @@ impl Command for ToXml {
     }
 }
 
-pub fn add_attributes<'a>(
-    element: &mut quick_xml::events::BytesStart<'a>,
-    attributes: &'a IndexMap<String, String>,
-) {
-    for (k, v) in attributes {
-        element.push_attribute((k.as_str(), v.as_str()));
+pub fn is_xml_row(row: &Value) -> bool {
+    if let Value::Record { cols, .. } = &row {
+        let keys: HashSet<&str> = cols.iter().map(String::as_str).collect();
+        return keys.len() == 3
+            && keys.contains("tag")
+            && keys.contains("attributes")
+            && keys.contains("content");
     }
+    false
 }
 
-pub fn get_attributes(row: &Value, config: &Config) -> Option<IndexMap<String, String>> {
-    if let Value::Record { .. } = row {
-        if let Some(Value::Record { cols, vals, .. }) = row.get_data_by_key("attributes") {
-            let mut h = IndexMap::new();
-            for (k, v) in cols.iter().zip(vals.iter()) {
-                h.insert(k.clone(), v.clone().into_abbreviated_string(config));
-            }
-            return Some(h);
+fn write_xml_element<W: Write>(
+    tag: &str,
+    attributes: &Value,
+    content: &Value,
+    writer: &mut quick_xml::Writer<W>,
+    config: &Config,
+    span: Span,
+) -> Result<(), ShellError> {
+    let mut element = BytesStart::new(tag);
+    if let Value::Record { cols, vals, .. } = attributes {
+        for (k, v) in cols.iter().zip(vals.iter()) {
+            element.push_attribute((
+                k.as_str(),
+                v.clone().into_abbreviated_string(config).as_str(),
+            ));
         }
     }
-    None
-}
-
-pub fn get_children(row: &Value) -> Option<Vec<Value>> {
-    if let Value::Record { .. } = row {
-        if let Some(Value::List { vals, .. }) = row.get_data_by_key("children") {
-            return Some(vals);
+    writer
+        .write_event(Event::Start(element))
+        .expect("Couldn't open XML node");
+    if let Value::List { vals, .. } = content {
+        for child in vals {
+            write_xml_events(child.clone(), writer, config)?;
         }
+    } else if !matches!(content, Value::Nothing { .. }) {
+        return Err(invalid_node_error(span));
     }
-    None
+    writer
+        .write_event(Event::End(BytesEnd::new(tag)))
+        .expect("Couldn't close XML node");
+    Ok(())
 }
 
-pub fn is_xml_row(row: &Value) -> bool {
-    if let Value::Record { cols, .. } = &row {
-        let keys: HashSet<&String> = cols.iter().collect();
-        let children: String = "children".to_string();
-        let attributes: String = "attributes".to_string();
-        return keys.contains(&children) && keys.contains(&attributes) && keys.len() == 2;
-    }
-    false
+fn invalid_node_error(span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Expected a node record with 'tag', 'attributes' and 'content' columns".to_string(),
+        "this is not in the shape `from xml` produces".to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
 }
 
 pub fn write_xml_events<W: Write>(
@@ -116,34 +132,49 @@ pub fn write_xml_events<W: Write>(
     config: &Config,
 ) -> Result<(), ShellError> {
     match current {
-        Value::Record { cols, vals, span } => {
-            for (k, v) in cols.iter().zip(vals.iter()) {
-                let mut e = BytesStart::new(k);
-                if !is_xml_row(v) {
-                    return Err(ShellError::GenericError(
-                        "Expected a row with 'children' and 'attributes' columns".to_string(),
-                        "missing 'children' and 'attributes' columns ".to_string(),
-                        Some(span),
-                        None,
-                        Vec::new(),
-                    ));
+        Value::Record { span, .. } => {
+            if !is_xml_row(&current) {
+                return Err(invalid_node_error(span));
+            }
+            let tag = current
+                .get_data_by_key("tag")
+                .unwrap_or_else(|| Value::nothing(span));
+            let attributes = current
+                .get_data_by_key("attributes")
+                .unwrap_or_else(|| Value::nothing(span));
+            let content = current
+                .get_data_by_key("content")
+                .unwrap_or_else(|| Value::nothing(span));
+            match &tag {
+                // a text node carries its text in `content`
+                Value::Nothing { .. } => {
+                    let text = content.into_abbreviated_string(config);
+                    writer
+                        .write_event(Event::Text(BytesText::new(&text)))
+                        .expect("Couldn't write XML text");
+                }
+                Value::String { val, .. } if val == "!" => {
+                    let text = content.into_abbreviated_string(config);
+                    writer
+                        .write_event(Event::Comment(BytesText::from_escaped(text.as_str())))
+                        .expect("Couldn't write XML comment");
                 }
-                let a = get_attributes(v, config);
-                if let Some(ref a) = a {
-                    add_attributes(&mut e, a);
+                Value::String { val, .. } if val.starts_with('?') => {
+                    let target = &val[1..];
+                    let rest = content.into_abbreviated_string(config);
+                    let instruction = if rest.is_empty() {
+                        target.to_string()
+                    } else {
+                        format!("{target} {rest}")
+                    };
+                    writer
+                        .write_event(Event::PI(BytesText::from_escaped(instruction.as_str())))
+                        .expect("Couldn't write XML processing instruction");
                 }
-                writer
-                    .write_event(Event::Start(e))
-                    .expect("Couldn't open XML node");
-                let c = get_children(v);
-                if let Some(c) = c {
-                    for v in c {
-                        write_xml_events(v, writer, config)?;
-                    }
+                Value::String { val, .. } => {
+                    write_xml_element(val, &attributes, &content, writer, config, span)?;
                 }
-                writer
-                    .write_event(Event::End(BytesEnd::new(k)))
-                    .expect("Couldn't close XML node");
+                _ => return Err(invalid_node_error(span)),
             }
         }
         Value::List { vals, .. } => {
@@ -154,7 +185,7 @@ pub fn write_xml_events<W: Write>(
         _ => {
             let s = current.into_abbreviated_string(config);
             writer
-                .write_event(Event::Text(BytesText::from_escaped(s.as_str())))
+                .write_event(Event::Text(BytesText::new(s.as_str())))
                 .expect("Couldn't write XML text");
         }
     }
@@ -0,0 +1,270 @@
+use digest::Digest;
+use nu_engine::{current_dir, CallExt};
+use nu_glob::Pattern;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone)]
+pub struct HashDir;
+
+impl Command for HashDir {
+    fn name(&self) -> &str {
+        "hash dir"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("hash dir")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .optional(
+                "path",
+                SyntaxShape::Directory,
+                "the directory to hash; defaults to the current directory",
+            )
+            .named(
+                "include",
+                SyntaxShape::String,
+                "only hash files whose relative path matches this glob",
+                Some('i'),
+            )
+            .named(
+                "exclude",
+                SyntaxShape::String,
+                "skip files whose relative path matches this glob",
+                Some('e'),
+            )
+            .named(
+                "verify",
+                SyntaxShape::Filepath,
+                "compare against a saved manifest and report changed, missing and new files",
+                Some('v'),
+            )
+            .category(Category::Hash)
+    }
+
+    fn usage(&self) -> &str {
+        "Recursively hash a directory into a manifest of path, size and SHA-256 hash."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Files are listed by their relative path, in sorted order, so the manifest
+can be saved with `to nuon` and compared later. With `--verify`, the saved
+manifest is read back and the output is instead a table of every file whose
+hash changed, that went missing, or that is new since the manifest was
+written; an empty table means the tree is intact."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["checksum", "manifest", "integrity", "sha256"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let path: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let include: Option<Spanned<String>> = call.get_flag(engine_state, stack, "include")?;
+        let exclude: Option<Spanned<String>> = call.get_flag(engine_state, stack, "exclude")?;
+        let verify: Option<Spanned<String>> = call.get_flag(engine_state, stack, "verify")?;
+
+        let cwd = current_dir(engine_state, stack)?;
+        let (root, root_span) = match &path {
+            Some(path) => (cwd.join(&path.item), path.span),
+            None => (cwd.clone(), head),
+        };
+        if !root.is_dir() {
+            return Err(ShellError::DirectoryNotFound(root_span, None));
+        }
+
+        let include = include.map(parse_pattern).transpose()?;
+        let exclude = exclude.map(parse_pattern).transpose()?;
+
+        let mut files = vec![];
+        collect_files(&root, &root, &mut files, &engine_state.ctrlc)?;
+        files.sort();
+
+        let mut manifest = vec![];
+        for (relative, absolute) in &files {
+            if let Some(include) = &include {
+                if !include.matches(relative) {
+                    continue;
+                }
+            }
+            if let Some(exclude) = &exclude {
+                if exclude.matches(relative) {
+                    continue;
+                }
+            }
+            let contents = std::fs::read(absolute)
+                .map_err(|err| ShellError::IOError(format!("could not read {relative}: {err}")))?;
+            manifest.push(manifest_row(relative, &contents, head));
+        }
+
+        let output = match verify {
+            Some(manifest_path) => {
+                let saved = read_manifest(&cwd.join(&manifest_path.item), manifest_path.span)?;
+                verify_manifest(&saved, &manifest, head)
+            }
+            None => manifest,
+        };
+
+        Ok(Value::List {
+            vals: output,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Hash every file under the current directory into a manifest",
+                example: "hash dir",
+                result: None,
+            },
+            Example {
+                description: "Save a manifest of the sources, skipping the build directory",
+                example: "hash dir --exclude 'target/**' | to nuon | save manifest.nuon",
+                result: None,
+            },
+            Example {
+                description: "Report what changed since the manifest was saved",
+                example: "hash dir --exclude 'target/**' --verify manifest.nuon",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn parse_pattern(glob: Spanned<String>) -> Result<Pattern, ShellError> {
+    Pattern::new(&glob.item).map_err(|err| {
+        ShellError::UnsupportedInput(
+            format!("invalid glob pattern: {err}"),
+            "value originates from here".into(),
+            glob.span,
+            glob.span,
+        )
+    })
+}
+
+/// Walk `dir`, collecting `(relative path, absolute path)` for every file
+fn collect_files(
+    dir: &Path,
+    root: &Path,
+    out: &mut Vec<(String, PathBuf)>,
+    ctrlc: &Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<(), ShellError> {
+    for entry in std::fs::read_dir(dir)
+        .map_err(|err| ShellError::IOError(format!("could not read {}: {err}", dir.display())))?
+    {
+        if nu_utils::ctrl_c::was_pressed(ctrlc) {
+            return Ok(());
+        }
+        let path = entry
+            .map_err(|err| ShellError::IOError(err.to_string()))?
+            .path();
+        if path.is_dir() {
+            collect_files(&path, root, out, ctrlc)?;
+        } else {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            out.push((relative, path));
+        }
+    }
+    Ok(())
+}
+
+fn manifest_row(path: &str, contents: &[u8], span: Span) -> Value {
+    let digest = Sha256::digest(contents);
+    Value::Record {
+        cols: vec!["path".into(), "size".into(), "hash".into()],
+        vals: vec![
+            Value::string(path, span),
+            Value::Filesize {
+                val: contents.len() as i64,
+                span,
+            },
+            Value::string(format!("{digest:x}"), span),
+        ],
+        span,
+    }
+}
+
+/// Read a saved manifest back into `path -> hash` pairs
+fn read_manifest(path: &Path, span: Span) -> Result<HashMap<String, String>, ShellError> {
+    let text = std::fs::read_to_string(path).map_err(|err| {
+        ShellError::IOErrorSpanned(format!("could not read manifest: {err}"), span)
+    })?;
+    let rows = crate::formats::from_nuon_string_to_value(&text, span)?;
+
+    let mut saved = HashMap::new();
+    if let Value::List { vals, .. } = &rows {
+        for row in vals {
+            let path = row.get_data_by_key("path");
+            let hash = row.get_data_by_key("hash");
+            if let (Some(Value::String { val: path, .. }), Some(Value::String { val: hash, .. })) =
+                (path, hash)
+            {
+                saved.insert(path, hash);
+                continue;
+            }
+            break;
+        }
+        if saved.len() == vals.len() {
+            return Ok(saved);
+        }
+    }
+    Err(ShellError::UnsupportedInput(
+        "the manifest is not a table of path and hash columns".into(),
+        "expected the output of `hash dir | to nuon`".into(),
+        span,
+        span,
+    ))
+}
+
+fn verify_manifest(saved: &HashMap<String, String>, current: &[Value], span: Span) -> Vec<Value> {
+    let status_row = |path: &str, status: &str| Value::Record {
+        cols: vec!["path".into(), "status".into()],
+        vals: vec![Value::string(path, span), Value::string(status, span)],
+        span,
+    };
+
+    let mut report = vec![];
+    let mut seen = vec![];
+    for row in current {
+        let (path, hash) = match (row.get_data_by_key("path"), row.get_data_by_key("hash")) {
+            (Some(Value::String { val: path, .. }), Some(Value::String { val: hash, .. })) => {
+                (path, hash)
+            }
+            _ => continue,
+        };
+        match saved.get(&path) {
+            Some(expected) if *expected == hash => {}
+            Some(_) => report.push(status_row(&path, "changed")),
+            None => report.push(status_row(&path, "new")),
+        }
+        seen.push(path);
+    }
+
+    let mut missing: Vec<&String> = saved.keys().filter(|path| !seen.contains(*path)).collect();
+    missing.sort();
+    for path in missing {
+        report.push(status_row(path, "missing"));
+    }
+    report
+}
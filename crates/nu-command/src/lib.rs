@@ -18,12 +18,15 @@ mod input_handler;
 mod math;
 mod misc;
 mod network;
+mod package;
 mod path;
 mod platform;
 mod progress_bar;
 mod random;
+mod session;
 mod shells;
 mod sort_utils;
+mod state;
 mod strings;
 mod system;
 mod viewers;
@@ -48,11 +51,14 @@ pub use hash::*;
 pub use math::*;
 pub use misc::*;
 pub use network::*;
+pub use package::*;
 pub use path::*;
 pub use platform::*;
 pub use random::*;
+pub use session::*;
 pub use shells::*;
 pub use sort_utils::*;
+pub use state::*;
 pub use strings::*;
 pub use system::*;
 pub use viewers::*;
@@ -0,0 +1,240 @@
+use chrono::Timelike;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, HistoryFileFormat, IntoPipelineData, PipelineData, ShellError, Signature,
+    Span, SyntaxShape, Type, Value,
+};
+use reedline::{
+    FileBackedHistory, History as ReedlineHistory, HistoryItem, SearchDirection, SearchQuery,
+    SqliteBackedHistory,
+};
+
+#[derive(Clone)]
+pub struct HistoryStats;
+
+impl Command for HistoryStats {
+    fn name(&self) -> &str {
+        "history stats"
+    }
+
+    fn usage(&self) -> &str {
+        "Compute usage statistics from the command history."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Everything is computed locally from the history file; nothing is sent anywhere. Durations, failure rates and hours are only available with the sqlite history format."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["frequency", "usage", "most-used", "profile"]
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history stats")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .named(
+                "top",
+                SyntaxShape::Int,
+                "how many commands to list, ordered by use count (defaults to 10)",
+                Some('t'),
+            )
+            .category(Category::Misc)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let top: Option<i64> = call.get_flag(engine_state, stack, "top")?;
+        let top = top.unwrap_or(10).max(0) as usize;
+
+        let Some(mut history_path) = nu_path::config_dir() else {
+            return Err(ShellError::FileNotFound(head));
+        };
+        history_path.push("nushell");
+        match engine_state.config.history_file_format {
+            HistoryFileFormat::Sqlite => history_path.push("history.sqlite3"),
+            HistoryFileFormat::PlainText => history_path.push("history.txt"),
+        }
+
+        let history_reader: Option<Box<dyn ReedlineHistory>> =
+            match engine_state.config.history_file_format {
+                HistoryFileFormat::Sqlite => SqliteBackedHistory::with_file(history_path)
+                    .map(|inner| {
+                        let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                        boxed
+                    })
+                    .ok(),
+                HistoryFileFormat::PlainText => FileBackedHistory::with_file(
+                    engine_state.config.max_history_size as usize,
+                    history_path,
+                )
+                .map(|inner| {
+                    let boxed: Box<dyn ReedlineHistory> = Box::new(inner);
+                    boxed
+                })
+                .ok(),
+            };
+
+        let entries = history_reader
+            .and_then(|h| {
+                h.search(SearchQuery::everything(SearchDirection::Forward))
+                    .ok()
+            })
+            .ok_or(ShellError::FileNotFound(head))?;
+
+        Ok(build_stats(&entries, top, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "history stats",
+                description: "Show the ten most-used commands and the busiest hours",
+                result: None,
+            },
+            Example {
+                example: "history stats --top 3 | get commands",
+                description: "Show the three most-used commands",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct CommandStats {
+    count: usize,
+    duration_nanos: i64,
+    timed_runs: usize,
+    failures: usize,
+    runs_with_status: usize,
+}
+
+fn build_stats(entries: &[HistoryItem], top: usize, head: Span) -> Value {
+    let mut per_command: Vec<(String, CommandStats)> = Vec::new();
+    let mut per_hour = [0usize; 24];
+
+    for entry in entries {
+        // the statistics are per command name, so only the first word counts
+        let Some(name) = entry.command_line.split_whitespace().next() else {
+            continue;
+        };
+
+        let stats = match per_command.iter_mut().find(|(n, _)| n == name) {
+            Some((_, stats)) => stats,
+            None => {
+                per_command.push((
+                    name.to_string(),
+                    CommandStats {
+                        count: 0,
+                        duration_nanos: 0,
+                        timed_runs: 0,
+                        failures: 0,
+                        runs_with_status: 0,
+                    },
+                ));
+                &mut per_command.last_mut().expect("just pushed").1
+            }
+        };
+
+        stats.count += 1;
+        if let Some(duration) = entry.duration {
+            stats.duration_nanos += duration.as_nanos().try_into().unwrap_or(0);
+            stats.timed_runs += 1;
+        }
+        if let Some(exit_status) = entry.exit_status {
+            stats.runs_with_status += 1;
+            if exit_status != 0 {
+                stats.failures += 1;
+            }
+        }
+        if let Some(time) = entry.start_timestamp {
+            per_hour[time.hour() as usize % 24] += 1;
+        }
+    }
+
+    per_command.sort_by(|a, b| b.1.count.cmp(&a.1.count).then(a.0.cmp(&b.0)));
+
+    let commands = per_command
+        .iter()
+        .take(top)
+        .map(|(name, stats)| {
+            let avg_duration = if stats.timed_runs > 0 {
+                stats.duration_nanos / stats.timed_runs as i64
+            } else {
+                0
+            };
+            let failure_rate = if stats.runs_with_status > 0 {
+                stats.failures as f64 / stats.runs_with_status as f64
+            } else {
+                0.0
+            };
+
+            Value::Record {
+                cols: vec![
+                    "command".into(),
+                    "count".into(),
+                    "avg_duration".into(),
+                    "failure_rate".into(),
+                ],
+                vals: vec![
+                    Value::string(name, head),
+                    Value::int(stats.count as i64, head),
+                    Value::Duration {
+                        val: avg_duration,
+                        span: head,
+                    },
+                    Value::float(failure_rate, head),
+                ],
+                span: head,
+            }
+        })
+        .collect();
+
+    let mut hours: Vec<(usize, usize)> = per_hour
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    hours.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let busiest_hours = hours
+        .into_iter()
+        .map(|(hour, count)| Value::Record {
+            cols: vec!["hour".into(), "count".into()],
+            vals: vec![
+                Value::int(hour as i64, head),
+                Value::int(count as i64, head),
+            ],
+            span: head,
+        })
+        .collect();
+
+    Value::Record {
+        cols: vec![
+            "total_entries".into(),
+            "commands".into(),
+            "busiest_hours".into(),
+        ],
+        vals: vec![
+            Value::int(entries.len() as i64, head),
+            Value::List {
+                vals: commands,
+                span: head,
+            },
+            Value::List {
+                vals: busiest_hours,
+                span: head,
+            },
+        ],
+        span: head,
+    }
+}
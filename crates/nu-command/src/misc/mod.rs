@@ -1,7 +1,9 @@
 mod history;
 mod history_session;
+mod history_stats;
 mod tutor;
 
 pub use history::History;
 pub use history_session::HistorySession;
+pub use history_stats::HistoryStats;
 pub use tutor::Tutor;
@@ -0,0 +1,6 @@
+mod dns_;
+mod query;
+mod resolver;
+
+pub use dns_::Dns;
+pub use query::SubCommand as DnsQuery;
@@ -0,0 +1,142 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+use super::resolver::{dns_lookup, record_type_code, record_type_name, system_nameserver};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "dns query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dns query")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("name", SyntaxShape::String, "the domain name to look up")
+            .named(
+                "type",
+                SyntaxShape::String,
+                "the record type to query for (A, AAAA, CNAME, MX, NS, PTR, SOA, SRV, TXT); defaults to A",
+                Some('t'),
+            )
+            .named(
+                "server",
+                SyntaxShape::String,
+                "the DNS server to query instead of the system resolver",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Look up DNS records for a domain name."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Queries the system resolver (or the server given with --server) over UDP and returns the answer section as a table."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "dig", "nslookup", "resolve", "lookup"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let rtype: Option<String> = call.get_flag(engine_state, stack, "type")?;
+        let server: Option<String> = call.get_flag(engine_state, stack, "server")?;
+
+        let rtype = rtype.unwrap_or_else(|| "A".into());
+        let qtype = record_type_code(&rtype).ok_or_else(|| {
+            ShellError::UnsupportedInput(
+                format!("'{rtype}' is not a supported record type"),
+                "supported types are A, AAAA, CNAME, MX, NS, PTR, SOA, SRV, and TXT".into(),
+                span,
+                span,
+            )
+        })?;
+
+        let server = server.or_else(system_nameserver).ok_or_else(|| {
+            ShellError::NetworkFailure(
+                "No DNS server found; specify one with --server".into(),
+                span,
+            )
+        })?;
+
+        let records = dns_lookup(&name, qtype, &server, span)?;
+
+        let vals = records
+            .into_iter()
+            .map(|record| Value::Record {
+                cols: vec![
+                    "name".into(),
+                    "type".into(),
+                    "ttl".into(),
+                    "value".into(),
+                ],
+                vals: vec![
+                    Value::String {
+                        val: record.name,
+                        span,
+                    },
+                    Value::String {
+                        val: record_type_name(record.rtype),
+                        span,
+                    },
+                    Value::Int {
+                        val: record.ttl as i64,
+                        span,
+                    },
+                    Value::String {
+                        val: record.value,
+                        span,
+                    },
+                ],
+                span,
+            })
+            .collect();
+
+        Ok(Value::List { vals, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Look up the A records for a domain",
+                example: "dns query nushell.sh",
+                result: None,
+            },
+            Example {
+                description: "Look up the MX records for a domain using a specific server",
+                example: "dns query --type MX --server 1.1.1.1 nushell.sh",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
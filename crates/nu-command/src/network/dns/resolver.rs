@@ -0,0 +1,256 @@
+use nu_protocol::{ShellError, Span};
+use std::net::{Ipv4Addr, Ipv6Addr, UdpSocket};
+use std::time::Duration;
+
+/// A single resource record from a DNS answer, with the record data already
+/// rendered into a displayable string.
+pub struct DnsRecord {
+    pub name: String,
+    pub rtype: u16,
+    pub ttl: u32,
+    pub value: String,
+}
+
+pub fn record_type_code(name: &str) -> Option<u16> {
+    match name.to_ascii_uppercase().as_str() {
+        "A" => Some(1),
+        "NS" => Some(2),
+        "CNAME" => Some(5),
+        "SOA" => Some(6),
+        "PTR" => Some(12),
+        "MX" => Some(15),
+        "TXT" => Some(16),
+        "AAAA" => Some(28),
+        "SRV" => Some(33),
+        _ => None,
+    }
+}
+
+pub fn record_type_name(code: u16) -> String {
+    match code {
+        1 => "A".into(),
+        2 => "NS".into(),
+        5 => "CNAME".into(),
+        6 => "SOA".into(),
+        12 => "PTR".into(),
+        15 => "MX".into(),
+        16 => "TXT".into(),
+        28 => "AAAA".into(),
+        33 => "SRV".into(),
+        other => format!("TYPE{other}"),
+    }
+}
+
+/// Returns the first nameserver from /etc/resolv.conf, if one can be found.
+pub fn system_nameserver() -> Option<String> {
+    let contents = std::fs::read_to_string("/etc/resolv.conf").ok()?;
+    contents.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some("nameserver"), Some(addr)) => Some(addr.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Sends a single DNS question over UDP and parses the answer section.
+pub fn dns_lookup(
+    name: &str,
+    qtype: u16,
+    server: &str,
+    span: Span,
+) -> Result<Vec<DnsRecord>, ShellError> {
+    let server = if server.contains(':') {
+        server.to_string()
+    } else {
+        format!("{server}:53")
+    };
+
+    let socket = UdpSocket::bind("0.0.0.0:0")
+        .map_err(|e| ShellError::NetworkFailure(format!("Unable to open a socket: {e}"), span))?;
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .map_err(|e| ShellError::NetworkFailure(e.to_string(), span))?;
+    socket.connect(&server).map_err(|e| {
+        ShellError::NetworkFailure(format!("Unable to reach DNS server {server}: {e}"), span)
+    })?;
+
+    let query = build_query(name, qtype, span)?;
+    socket
+        .send(&query)
+        .map_err(|e| ShellError::NetworkFailure(format!("Unable to send query: {e}"), span))?;
+
+    let mut buf = [0u8; 4096];
+    let len = socket.recv(&mut buf).map_err(|e| {
+        ShellError::NetworkFailure(format!("No answer from DNS server {server}: {e}"), span)
+    })?;
+
+    parse_response(&buf[..len])
+        .map_err(|e| ShellError::NetworkFailure(format!("Invalid DNS response: {e}"), span))
+}
+
+fn build_query(name: &str, qtype: u16, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut msg: Vec<u8> = Vec::with_capacity(name.len() + 18);
+    // header: arbitrary id, recursion desired, one question
+    msg.extend_from_slice(&[0x1e, 0xaf, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(ShellError::NetworkFailure(
+                format!("'{name}' is not a valid domain name"),
+                span,
+            ));
+        }
+        msg.push(label.len() as u8);
+        msg.extend_from_slice(label.as_bytes());
+    }
+    msg.push(0);
+    msg.extend_from_slice(&qtype.to_be_bytes());
+    msg.extend_from_slice(&1u16.to_be_bytes()); // class IN
+
+    Ok(msg)
+}
+
+fn parse_response(buf: &[u8]) -> Result<Vec<DnsRecord>, String> {
+    if buf.len() < 12 {
+        return Err("response is too short".into());
+    }
+
+    let rcode = buf[3] & 0x0f;
+    if rcode != 0 {
+        return Err(match rcode {
+            3 => "the domain name does not exist (NXDOMAIN)".into(),
+            2 => "the server failed to complete the request (SERVFAIL)".into(),
+            5 => "the server refused the query (REFUSED)".into(),
+            other => format!("the server returned error code {other}"),
+        });
+    }
+
+    let qdcount = u16::from_be_bytes([buf[4], buf[5]]) as usize;
+    let ancount = u16::from_be_bytes([buf[6], buf[7]]) as usize;
+    let mut pos = 12;
+
+    // skip over the echoed question section
+    for _ in 0..qdcount {
+        let (_, next) = read_name(buf, pos)?;
+        pos = next + 4;
+    }
+
+    let mut records = Vec::with_capacity(ancount);
+    for _ in 0..ancount {
+        let (name, next) = read_name(buf, pos)?;
+        pos = next;
+        if pos + 10 > buf.len() {
+            return Err("truncated resource record".into());
+        }
+        let rtype = u16::from_be_bytes([buf[pos], buf[pos + 1]]);
+        let ttl = u32::from_be_bytes([buf[pos + 4], buf[pos + 5], buf[pos + 6], buf[pos + 7]]);
+        let rdlength = u16::from_be_bytes([buf[pos + 8], buf[pos + 9]]) as usize;
+        pos += 10;
+        if pos + rdlength > buf.len() {
+            return Err("truncated resource record data".into());
+        }
+        let value = read_rdata(buf, pos, rdlength, rtype)?;
+        pos += rdlength;
+
+        records.push(DnsRecord {
+            name,
+            rtype,
+            ttl,
+            value,
+        });
+    }
+
+    Ok(records)
+}
+
+fn read_rdata(buf: &[u8], pos: usize, len: usize, rtype: u16) -> Result<String, String> {
+    let data = &buf[pos..pos + len];
+    match rtype {
+        // A
+        1 if len == 4 => Ok(Ipv4Addr::new(data[0], data[1], data[2], data[3]).to_string()),
+        // AAAA
+        28 if len == 16 => {
+            let mut octets = [0u8; 16];
+            octets.copy_from_slice(data);
+            Ok(Ipv6Addr::from(octets).to_string())
+        }
+        // NS, CNAME, PTR: a single (possibly compressed) domain name
+        2 | 5 | 12 => Ok(read_name(buf, pos)?.0),
+        // SOA: render just the primary nameserver and admin mailbox
+        6 => {
+            let (mname, next) = read_name(buf, pos)?;
+            let (rname, _) = read_name(buf, next)?;
+            Ok(format!("{mname} {rname}"))
+        }
+        // MX: preference followed by the exchange name
+        15 if len > 2 => {
+            let preference = u16::from_be_bytes([data[0], data[1]]);
+            let (exchange, _) = read_name(buf, pos + 2)?;
+            Ok(format!("{preference} {exchange}"))
+        }
+        // TXT: one or more length-prefixed character strings
+        16 => {
+            let mut out = String::new();
+            let mut i = 0;
+            while i < len {
+                let slen = data[i] as usize;
+                i += 1;
+                if i + slen > len {
+                    return Err("truncated TXT record".into());
+                }
+                out.push_str(&String::from_utf8_lossy(&data[i..i + slen]));
+                i += slen;
+            }
+            Ok(out)
+        }
+        // SRV: priority, weight, port, then the target name
+        33 if len > 6 => {
+            let priority = u16::from_be_bytes([data[0], data[1]]);
+            let weight = u16::from_be_bytes([data[2], data[3]]);
+            let port = u16::from_be_bytes([data[4], data[5]]);
+            let (target, _) = read_name(buf, pos + 6)?;
+            Ok(format!("{priority} {weight} {port} {target}"))
+        }
+        // anything else: render the raw bytes as hex
+        _ => Ok(data.iter().map(|b| format!("{b:02x}")).collect()),
+    }
+}
+
+/// Reads a domain name starting at `pos`, following compression pointers, and
+/// returns it along with the position just past the name in the record.
+fn read_name(buf: &[u8], mut pos: usize) -> Result<(String, usize), String> {
+    let mut name = String::new();
+    let mut end = None;
+    let mut jumps = 0;
+
+    loop {
+        let len = *buf.get(pos).ok_or("truncated domain name")? as usize;
+        if len == 0 {
+            pos += 1;
+            break;
+        } else if len & 0xc0 == 0xc0 {
+            // compression pointer; remember where the record continues
+            let low = *buf.get(pos + 1).ok_or("truncated compression pointer")? as usize;
+            if end.is_none() {
+                end = Some(pos + 2);
+            }
+            pos = ((len & 0x3f) << 8) | low;
+            jumps += 1;
+            if jumps > 64 {
+                return Err("compression pointer loop".into());
+            }
+        } else {
+            let label = buf
+                .get(pos + 1..pos + 1 + len)
+                .ok_or("truncated domain name")?;
+            if !name.is_empty() {
+                name.push('.');
+            }
+            name.push_str(&String::from_utf8_lossy(label));
+            pos += 1 + len;
+        }
+    }
+
+    Ok((name, end.unwrap_or(pos)))
+}
@@ -0,0 +1,171 @@
+use crate::network::http::client::extension_from_content_type;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use ureq::Response;
+
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metadata stored next to each cached response body, used to decide whether
+/// a cached entry is still fresh and to revalidate stale entries via ETag.
+#[derive(Serialize, Deserialize)]
+pub struct CacheMeta {
+    pub url: String,
+    pub etag: Option<String>,
+    pub content_type: Option<String>,
+    pub stored_at: u64,
+}
+
+pub struct CacheEntry {
+    pub meta: CacheMeta,
+    pub body: Vec<u8>,
+}
+
+// Cached responses live under the nushell data dir, one body file and one
+// metadata file per URL+headers combination.
+fn cache_dir() -> Option<PathBuf> {
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("http-cache");
+    Some(path)
+}
+
+/// The cache key is a hash over the URL and any custom headers, so the same
+/// URL requested with different headers (e.g. Accept) is cached separately.
+pub fn cache_key(url: &str, headers: &Option<Value>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(url.as_bytes());
+    if let Some(headers) = headers {
+        if let Ok(text) = headers.as_string() {
+            hasher.update(text.as_bytes());
+        } else if let Ok(vals) = headers.as_list() {
+            for val in vals {
+                if let Ok(text) = val.as_string() {
+                    hasher.update(text.as_bytes());
+                }
+            }
+        }
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+pub fn cache_lookup(key: &str) -> Option<CacheEntry> {
+    let dir = cache_dir()?;
+    let meta_text = fs::read_to_string(dir.join(format!("{key}.meta"))).ok()?;
+    let meta: CacheMeta = nu_json::from_str(&meta_text).ok()?;
+    let body = fs::read(dir.join(format!("{key}.body"))).ok()?;
+    Some(CacheEntry { meta, body })
+}
+
+pub fn cache_store(key: &str, meta: &CacheMeta, body: &[u8], span: Span) -> Result<(), ShellError> {
+    let dir = cache_dir().ok_or_else(|| {
+        ShellError::GenericError(
+            "Cannot find the nushell data dir to store the response cache".into(),
+            "".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+    fs::create_dir_all(&dir).map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+
+    let meta_text = nu_json::to_string(meta)
+        .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+    fs::write(dir.join(format!("{key}.meta")), meta_text)
+        .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+    fs::write(dir.join(format!("{key}.body")), body)
+        .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+
+    Ok(())
+}
+
+/// Updates only the freshness timestamp of an entry, used after a 304 Not
+/// Modified revalidation told us the cached body is still current.
+pub fn cache_touch(key: &str, meta: &CacheMeta, span: Span) -> Result<(), ShellError> {
+    let refreshed = CacheMeta {
+        url: meta.url.clone(),
+        etag: meta.etag.clone(),
+        content_type: meta.content_type.clone(),
+        stored_at: now_epoch_secs(),
+    };
+    if let Some(dir) = cache_dir() {
+        let meta_text = nu_json::to_string(&refreshed)
+            .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+        fs::write(dir.join(format!("{key}.meta")), meta_text)
+            .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+    }
+    Ok(())
+}
+
+pub fn is_fresh(meta: &CacheMeta, max_age_nanos: i64) -> bool {
+    let age_secs = now_epoch_secs().saturating_sub(meta.stored_at);
+    // durations are stored in nanoseconds
+    (age_secs as i128) * 1_000_000_000 <= max_age_nanos as i128
+}
+
+pub fn now_epoch_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Turns a cached (or freshly fetched and cached) body into command output,
+/// running the same `from ...` conversion that a streamed response would get.
+pub fn body_to_output(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+    requested_url: &str,
+    raw: bool,
+    content_type: &Option<String>,
+    body: &[u8],
+) -> Result<PipelineData, ShellError> {
+    let value = match String::from_utf8(body.to_vec()) {
+        Ok(s) => Value::string(s, span),
+        Err(_) => Value::Binary {
+            val: body.to_vec(),
+            span,
+        },
+    };
+    let output = value.into_pipeline_data();
+
+    if raw {
+        return Ok(output);
+    }
+
+    let ext = match content_type {
+        Some(content_type) => extension_from_content_type(requested_url, content_type)?,
+        None => None,
+    };
+
+    if let Some(ext) = ext {
+        match engine_state.find_decl(format!("from {ext}").as_bytes(), &[]) {
+            Some(converter_id) => {
+                engine_state
+                    .get_decl(converter_id)
+                    .run(engine_state, stack, &Call::new(span), output)
+            }
+            None => Ok(output),
+        }
+    } else {
+        Ok(output)
+    }
+}
+
+/// Reads a response body fully into memory so it can be written to the cache
+/// as well as returned to the caller.
+pub fn response_to_bytes(response: Response, span: Span) -> Result<(Option<String>, Vec<u8>), ShellError> {
+    let content_type = response.header("content-type").map(|it| it.to_string());
+    let mut buf = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut buf)
+        .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))?;
+    Ok((content_type, buf))
+}
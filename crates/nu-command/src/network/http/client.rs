@@ -5,15 +5,19 @@ use base64::{alphabet, Engine};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{
-    BufferedReader, IntoPipelineData, PipelineData, RawStream, ShellError, Span, Value,
+    BufferedReader, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, RawStream,
+    ShellError, Span, Value,
 };
 use ureq::{Error, ErrorKind, Request, Response};
 
-use std::collections::HashMap;
-use std::io::BufReader;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use url::Url;
 
 #[derive(PartialEq, Eq)]
@@ -132,12 +136,23 @@ pub fn request_add_authorization_header(
     request
 }
 
+pub fn request_add_bearer_token_header(token: Option<String>, mut request: Request) -> Request {
+    if let Some(token) = token {
+        request = request.set("Authorization", &format!("Bearer {token}"));
+    }
+
+    request
+}
+
 pub fn send_request(
     request: Request,
     span: Span,
     body: Option<Value>,
     content_type: Option<String>,
 ) -> Result<Response, ShellError> {
+    // Advertise the compressed encodings we can transparently decompress, so
+    // servers may negotiate a smaller response.
+    let request = request.set("Accept-Encoding", "gzip, br");
     let request_url = request.url().to_string();
     if body.is_none() {
         return request
@@ -203,6 +218,310 @@ pub fn send_request(
     }
 }
 
+/// Gzips a request body before it is sent, serializing structured bodies the
+/// same way `send_request` would have. Returns the adjusted request along with
+/// the compressed body; the original content type moves into a header since
+/// the body is handed to ureq as plain bytes afterwards.
+pub fn request_compress_body(
+    mut request: Request,
+    body: Value,
+    content_type: Option<String>,
+    span: Span,
+) -> Result<(Request, Value), ShellError> {
+    use std::io::Write;
+
+    let body_type = match &content_type {
+        Some(it) if it == "application/json" => BodyType::Json,
+        Some(it) if it == "application/x-www-form-urlencoded" => BodyType::Form,
+        _ => BodyType::Unknown,
+    };
+
+    let bytes = match &body {
+        Value::Binary { val, .. } => val.clone(),
+        Value::String { val, .. } => val.as_bytes().to_vec(),
+        Value::Record { .. } if body_type == BodyType::Json => {
+            let data = value_to_json_value(&body)?;
+            nu_json::to_string_raw(&data)
+                .map_err(|e| ShellError::IOError(e.to_string()))?
+                .into_bytes()
+        }
+        Value::Record { cols, vals, .. } if body_type == BodyType::Form => {
+            let mut data: Vec<(String, String)> = Vec::with_capacity(cols.len());
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                data.push((col.clone(), val.as_string()?))
+            }
+            serde_urlencoded::to_string(data)
+                .map_err(|e| ShellError::IOError(e.to_string()))?
+                .into_bytes()
+        }
+        _ => return Err(ShellError::IOError("unsupported body input".into())),
+    };
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&bytes)
+        .and_then(|_| encoder.finish())
+        .map(|compressed| {
+            request = request.set("Content-Encoding", "gzip");
+            if let Some(content_type) = &content_type {
+                request = request.set("Content-Type", content_type);
+            }
+            (request, Value::Binary {
+                val: compressed,
+                span,
+            })
+        })
+        .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), span))
+}
+
+/// Streams a raw byte stream (e.g. an opened file or external output) directly
+/// into the request body without collecting it into memory first.
+pub fn send_request_streaming(
+    mut request: Request,
+    span: Span,
+    stream: RawStream,
+    content_type: Option<String>,
+) -> Result<Response, ShellError> {
+    if let Some(content_type) = content_type {
+        request = request.set("Content-Type", &content_type);
+    }
+    let request = request.set("Accept-Encoding", "gzip, br");
+    let request_url = request.url().to_string();
+
+    request
+        .send(RawStreamReader::new(stream))
+        .map_err(|err| handle_response_error(span, &request_url, err))
+}
+
+// Adapts a RawStream of byte chunks into a std::io::Read for ureq.
+struct RawStreamReader {
+    stream: Box<dyn Iterator<Item = Result<Vec<u8>, ShellError>> + Send + 'static>,
+    buffer: Vec<u8>,
+    offset: usize,
+}
+
+impl RawStreamReader {
+    fn new(stream: RawStream) -> Self {
+        Self {
+            buffer: stream.leftover,
+            offset: 0,
+            stream: stream.stream,
+        }
+    }
+}
+
+impl std::io::Read for RawStreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.offset >= self.buffer.len() {
+            match self.stream.next() {
+                Some(Ok(chunk)) => {
+                    self.buffer = chunk;
+                    self.offset = 0;
+                }
+                Some(Err(err)) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        err.to_string(),
+                    ))
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let n = buf.len().min(self.buffer.len() - self.offset);
+        buf[..n].copy_from_slice(&self.buffer[self.offset..self.offset + n]);
+        self.offset += n;
+        Ok(n)
+    }
+}
+
+/// Turns a `text/event-stream` response into a stream of `{event, data, id}`
+/// records, one per Server-Sent Event.
+pub fn response_to_sse_stream(
+    response: Response,
+    span: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> PipelineData {
+    let reader = BufReader::new(response.into_reader());
+
+    SseIterator {
+        lines: Box::new(reader.lines()),
+        event: None,
+        data: Vec::new(),
+        id: None,
+        span,
+    }
+    .into_pipeline_data(ctrlc)
+}
+
+struct SseIterator {
+    lines: Box<dyn Iterator<Item = std::io::Result<String>> + Send>,
+    event: Option<String>,
+    data: Vec<String>,
+    id: Option<String>,
+    span: Span,
+}
+
+impl SseIterator {
+    fn emit(&mut self) -> Value {
+        let span = self.span;
+        let event = self.event.take().unwrap_or_else(|| "message".to_string());
+        let data = self.data.join("\n");
+        self.data.clear();
+
+        Value::Record {
+            cols: vec!["event".into(), "data".into(), "id".into()],
+            vals: vec![
+                Value::String { val: event, span },
+                Value::String { val: data, span },
+                match self.id.clone() {
+                    Some(id) => Value::String { val: id, span },
+                    None => Value::Nothing { span },
+                },
+            ],
+            span,
+        }
+    }
+}
+
+impl Iterator for SseIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        loop {
+            match self.lines.next() {
+                Some(Ok(line)) => {
+                    if line.is_empty() {
+                        // a blank line terminates the event
+                        if !self.data.is_empty() || self.event.is_some() {
+                            return Some(self.emit());
+                        }
+                        continue;
+                    }
+
+                    // comment lines keep the connection alive and carry no data
+                    if line.starts_with(':') {
+                        continue;
+                    }
+
+                    let (field, value) = match line.split_once(':') {
+                        Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                        None => (line.as_str(), ""),
+                    };
+
+                    match field {
+                        "event" => self.event = Some(value.to_string()),
+                        "data" => self.data.push(value.to_string()),
+                        "id" => self.id = Some(value.to_string()),
+                        // `retry` and unknown fields are ignored
+                        _ => {}
+                    }
+                }
+                // the server closed the connection; flush any unterminated event
+                _ => {
+                    if !self.data.is_empty() || self.event.is_some() {
+                        return Some(self.emit());
+                    }
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+// Requests made through `--rate-limit` are tracked here so that the limit
+// holds across separate command invocations in the same pipeline or loop.
+static RATE_LIMITER: Lazy<Mutex<HashMap<String, VecDeque<Instant>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Parses a `<n>/<duration>` rate limit spec like `10/1min` or `2/500ms`.
+fn parse_rate_limit(spec: &str, span: Span) -> Result<(usize, Duration), ShellError> {
+    let invalid = || {
+        ShellError::TypeMismatch {
+            err_message: format!(
+                "'{spec}' is not a valid rate limit; expected <n>/<duration>, e.g. 10/1min"
+            ),
+            span,
+        }
+    };
+
+    let (count, window) = spec.split_once('/').ok_or_else(invalid)?;
+    let count: usize = count.trim().parse().map_err(|_| invalid())?;
+    if count == 0 {
+        return Err(invalid());
+    }
+
+    let window = window.trim();
+    let digits = window
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    let amount: u64 = digits.parse().map_err(|_| invalid())?;
+    let window = match window[digits.len()..].trim() {
+        "ns" => Duration::from_nanos(amount),
+        "us" => Duration::from_micros(amount),
+        "ms" => Duration::from_millis(amount),
+        "sec" => Duration::from_secs(amount),
+        "min" => Duration::from_secs(amount * 60),
+        "hr" => Duration::from_secs(amount * 60 * 60),
+        _ => return Err(invalid()),
+    };
+
+    Ok((count, window))
+}
+
+/// Blocks until another request is allowed under the given `<n>/<duration>`
+/// rate limit. Limits with the same spec share one counter, so repeated calls
+/// in a loop are throttled together.
+pub fn request_apply_rate_limit(
+    spec: Option<String>,
+    span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
+) -> Result<(), ShellError> {
+    let spec = match spec {
+        Some(spec) => spec,
+        None => return Ok(()),
+    };
+    let (count, window) = parse_rate_limit(&spec, span)?;
+
+    loop {
+        let wait = {
+            let mut limiter = RATE_LIMITER
+                .lock()
+                .expect("internal error: rate limiter mutex poisoned");
+            let timestamps = limiter.entry(spec.clone()).or_default();
+
+            let now = Instant::now();
+            while let Some(first) = timestamps.front() {
+                if now.duration_since(*first) >= window {
+                    timestamps.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            if timestamps.len() < count {
+                timestamps.push_back(now);
+                return Ok(());
+            }
+
+            window - now.duration_since(*timestamps.front().expect("queue is non-empty"))
+        };
+
+        // sleep in short slices so ctrl-c can interrupt the wait
+        let slice = wait.min(Duration::from_millis(100));
+        std::thread::sleep(slice);
+        if let Some(ctrlc) = &ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                return Err(ShellError::IOInterrupted(
+                    "Rate limit wait was interrupted".into(),
+                    span,
+                ));
+            }
+        }
+    }
+}
+
 pub fn request_set_timeout(
     timeout: Option<Value>,
     mut request: Request,
@@ -317,6 +636,49 @@ fn handle_response_error(span: Span, requested_url: &str, response_err: Error) -
     }
 }
 
+// Maps a response's content type to the extension used to look up a `from ...`
+// converter. text/plain falls back to the extension of the requested path, if any.
+pub fn extension_from_content_type(
+    requested_url: &str,
+    content_type: &str,
+) -> Result<Option<String>, ShellError> {
+    let content_type = mime::Mime::from_str(content_type).map_err(|_| {
+        ShellError::GenericError(
+            format!("MIME type unknown: {content_type}"),
+            "".to_string(),
+            None,
+            Some("given unknown MIME type".to_string()),
+            Vec::new(),
+        )
+    })?;
+    let ext = match (content_type.type_(), content_type.subtype()) {
+        (mime::TEXT, mime::PLAIN) => {
+            let path_extension = url::Url::parse(requested_url)
+                .map_err(|_| {
+                    ShellError::GenericError(
+                        format!("Cannot parse URL: {requested_url}"),
+                        "".to_string(),
+                        None,
+                        Some("cannot parse".to_string()),
+                        Vec::new(),
+                    )
+                })?
+                .path_segments()
+                .and_then(|segments| segments.last())
+                .and_then(|name| if name.is_empty() { None } else { Some(name) })
+                .and_then(|name| {
+                    PathBuf::from(name)
+                        .extension()
+                        .map(|name| name.to_string_lossy().to_string())
+                });
+            path_extension
+        }
+        _ => Some(content_type.subtype().to_string()),
+    };
+
+    Ok(ext)
+}
+
 pub fn request_handle_response(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -328,39 +690,7 @@ pub fn request_handle_response(
     match response {
         Ok(resp) => match resp.header("content-type") {
             Some(content_type) => {
-                let content_type = mime::Mime::from_str(content_type).map_err(|_| {
-                    ShellError::GenericError(
-                        format!("MIME type unknown: {content_type}"),
-                        "".to_string(),
-                        None,
-                        Some("given unknown MIME type".to_string()),
-                        Vec::new(),
-                    )
-                })?;
-                let ext = match (content_type.type_(), content_type.subtype()) {
-                    (mime::TEXT, mime::PLAIN) => {
-                        let path_extension = url::Url::parse(requested_url)
-                            .map_err(|_| {
-                                ShellError::GenericError(
-                                    format!("Cannot parse URL: {requested_url}"),
-                                    "".to_string(),
-                                    None,
-                                    Some("cannot parse".to_string()),
-                                    Vec::new(),
-                                )
-                            })?
-                            .path_segments()
-                            .and_then(|segments| segments.last())
-                            .and_then(|name| if name.is_empty() { None } else { Some(name) })
-                            .and_then(|name| {
-                                PathBuf::from(name)
-                                    .extension()
-                                    .map(|name| name.to_string_lossy().to_string())
-                            });
-                        path_extension
-                    }
-                    _ => Some(content_type.subtype().to_string()),
-                };
+                let ext = extension_from_content_type(requested_url, content_type)?;
 
                 let output = response_to_buffer(resp, engine_state, span);
 
@@ -5,9 +5,14 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
 
+use crate::network::http::cache::{
+    body_to_output, cache_key, cache_lookup, cache_store, cache_touch, is_fresh, now_epoch_secs,
+    response_to_bytes, CacheEntry, CacheMeta,
+};
 use crate::network::http::client::{
-    http_client, http_parse_url, request_add_authorization_header, request_add_custom_headers,
-    request_handle_response, request_set_timeout, send_request,
+    http_client, http_parse_url, request_add_authorization_header, request_add_bearer_token_header,
+    request_add_custom_headers, request_apply_rate_limit, request_handle_response,
+    request_set_timeout, response_to_sse_stream, send_request,
 };
 
 #[derive(Clone)]
@@ -39,18 +44,41 @@ impl Command for SubCommand {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "set the Authorization header to a Bearer token",
+                None,
+            )
             .named(
                 "max-time",
                 SyntaxShape::Int,
                 "timeout period in seconds",
                 Some('m'),
             )
+            .named(
+                "rate-limit",
+                SyntaxShape::String,
+                "limit request frequency to <n>/<duration>, e.g. 10/1min; shared across calls",
+                None,
+            )
             .named(
                 "headers",
                 SyntaxShape::Any,
                 "custom headers you want to add ",
                 Some('H'),
             )
+            .named(
+                "cache",
+                SyntaxShape::Duration,
+                "serve a cached response if one newer than the given duration exists, revalidating stale entries via ETag",
+                None,
+            )
+            .switch(
+                "stream-events",
+                "parse the response as a text/event-stream and emit each event as a record",
+                None,
+            )
             .switch(
                 "raw",
                 "fetch contents as text rather than a table",
@@ -106,6 +134,16 @@ impl Command for SubCommand {
                 example: "http get -H [my-header-key my-header-value] https://www.example.com",
                 result: None,
             },
+            Example {
+                description: "Consume a Server-Sent Events API, one record per event",
+                example: "http get --stream-events https://www.example.com/events | each {|e| $e.data }",
+                result: None,
+            },
+            Example {
+                description: "Get content from example.com, reusing a cached response up to 5 minutes old",
+                example: "http get --cache 5min https://www.example.com",
+                result: None,
+            },
         ]
     }
 }
@@ -117,7 +155,11 @@ struct Arguments {
     insecure: bool,
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     timeout: Option<Value>,
+    rate_limit: Option<String>,
+    cache: Option<Value>,
+    stream_events: bool,
 }
 
 fn run_get(
@@ -133,7 +175,11 @@ fn run_get(
         insecure: call.has_flag("insecure"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
         timeout: call.get_flag(engine_state, stack, "max-time")?,
+        rate_limit: call.get_flag(engine_state, stack, "rate-limit")?,
+        cache: call.get_flag(engine_state, stack, "cache")?,
+        stream_events: call.has_flag("stream-events"),
     };
     helper(engine_state, stack, call, args)
 }
@@ -147,23 +193,133 @@ fn helper(
     args: Arguments,
 ) -> Result<PipelineData, ShellError> {
     let span = args.url.span()?;
-    let (requested_url, _) = http_parse_url(call, span, args.url)?;
+    let (requested_url, _) = http_parse_url(call, span, args.url.clone())?;
+
+    let max_age = match &args.cache {
+        Some(Value::Duration { val, .. }) => Some(*val),
+        Some(other) => {
+            return Err(ShellError::TypeMismatch {
+                err_message: "Cache max age must be a duration".to_string(),
+                span: other.expect_span(),
+            })
+        }
+        None => None,
+    };
+
+    let cached = match max_age {
+        Some(max_age) => {
+            let key = cache_key(&requested_url, &args.headers);
+            match cache_lookup(&key) {
+                // fresh enough: skip the network round trip entirely
+                Some(entry) if is_fresh(&entry.meta, max_age) => {
+                    return body_to_output(
+                        engine_state,
+                        stack,
+                        span,
+                        &requested_url,
+                        args.raw,
+                        &entry.meta.content_type,
+                        &entry.body,
+                    );
+                }
+                entry => entry,
+            }
+        }
+        None => None,
+    };
 
     let client = http_client(args.insecure);
     let mut request = client.get(&requested_url);
 
+    request_apply_rate_limit(args.rate_limit, span, engine_state.ctrlc.clone())?;
+
     request = request_set_timeout(args.timeout, request)?;
     request = request_add_authorization_header(args.user, args.password, request);
-    request = request_add_custom_headers(args.headers, request)?;
+    request = request_add_bearer_token_header(args.bearer, request);
+    request = request_add_custom_headers(args.headers.clone(), request)?;
+
+    // server-sent events are never cached; hand the open connection straight
+    // to the SSE parser
+    if args.stream_events {
+        let response = send_request(request, span, None, None)?;
+        return Ok(response_to_sse_stream(
+            response,
+            span,
+            engine_state.ctrlc.clone(),
+        ));
+    }
+
+    if max_age.is_none() {
+        let response = send_request(request, span, None, None);
+        return request_handle_response(
+            engine_state,
+            stack,
+            span,
+            &requested_url,
+            args.raw,
+            response,
+        );
+    }
+
+    // a stale cached entry with an ETag can be revalidated instead of refetched
+    if let Some(CacheEntry {
+        meta: meta @ CacheMeta { etag: Some(_), .. },
+        body,
+    }) = &cached
+    {
+        if let Some(etag) = &meta.etag {
+            request = request.set("If-None-Match", etag);
+        }
+        let response = send_request(request, span, None, None)?;
+        let key = cache_key(&requested_url, &args.headers);
+        if response.status() == 304 {
+            cache_touch(&key, meta, span)?;
+            return body_to_output(
+                engine_state,
+                stack,
+                span,
+                &requested_url,
+                args.raw,
+                &meta.content_type,
+                body,
+            );
+        }
+        return handle_fresh_response(engine_state, stack, span, &requested_url, &args, response);
+    }
+
+    let response = send_request(request, span, None, None)?;
+    handle_fresh_response(engine_state, stack, span, &requested_url, &args, response)
+}
+
+// Stores a just-fetched response in the cache and converts it to output.
+fn handle_fresh_response(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: nu_protocol::Span,
+    requested_url: &str,
+    args: &Arguments,
+    response: ureq::Response,
+) -> Result<PipelineData, ShellError> {
+    let etag = response.header("etag").map(|it| it.to_string());
+    let (content_type, body) = response_to_bytes(response, span)?;
+
+    let meta = CacheMeta {
+        url: requested_url.to_string(),
+        etag,
+        content_type: content_type.clone(),
+        stored_at: now_epoch_secs(),
+    };
+    let key = cache_key(requested_url, &args.headers);
+    cache_store(&key, &meta, &body, span)?;
 
-    let response = send_request(request, span, None, None);
-    request_handle_response(
+    body_to_output(
         engine_state,
         stack,
         span,
-        &requested_url,
+        requested_url,
         args.raw,
-        response,
+        &content_type,
+        &body,
     )
 }
 
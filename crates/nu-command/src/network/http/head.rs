@@ -6,8 +6,9 @@ use nu_protocol::{
 };
 
 use crate::network::http::client::{
-    http_client, http_parse_url, request_add_authorization_header, request_add_custom_headers,
-    request_handle_response_headers, request_set_timeout, send_request,
+    http_client, http_parse_url, request_add_authorization_header, request_add_bearer_token_header,
+    request_add_custom_headers, request_apply_rate_limit, request_handle_response_headers,
+    request_set_timeout, send_request,
 };
 
 #[derive(Clone)]
@@ -39,12 +40,24 @@ impl Command for SubCommand {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "set the Authorization header to a Bearer token",
+                None,
+            )
             .named(
                 "max-time",
                 SyntaxShape::Int,
                 "timeout period in seconds",
                 Some('m'),
             )
+            .named(
+                "rate-limit",
+                SyntaxShape::String,
+                "limit request frequency to <n>/<duration>, e.g. 10/1min; shared across calls",
+                None,
+            )
             .named(
                 "headers",
                 SyntaxShape::Any,
@@ -109,7 +122,9 @@ struct Arguments {
     insecure: bool,
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     timeout: Option<Value>,
+    rate_limit: Option<String>,
 }
 
 fn run_head(
@@ -124,23 +139,33 @@ fn run_head(
         insecure: call.has_flag("insecure"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
         timeout: call.get_flag(engine_state, stack, "max-time")?,
+        rate_limit: call.get_flag(engine_state, stack, "rate-limit")?,
     };
 
-    helper(call, args)
+    helper(engine_state, stack, call, args)
 }
 
 // Helper function that actually goes to retrieve the resource from the url given
 // The Option<String> return a possible file extension which can be used in AutoConvert commands
-fn helper(call: &Call, args: Arguments) -> Result<PipelineData, ShellError> {
+fn helper(
+    engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+    args: Arguments,
+) -> Result<PipelineData, ShellError> {
     let span = args.url.span()?;
     let (requested_url, _) = http_parse_url(call, span, args.url)?;
 
     let client = http_client(args.insecure);
     let mut request = client.head(&requested_url);
 
+    request_apply_rate_limit(args.rate_limit, span, engine_state.ctrlc.clone())?;
+
     request = request_set_timeout(args.timeout, request)?;
     request = request_add_authorization_header(args.user, args.password, request);
+    request = request_add_bearer_token_header(args.bearer, request);
     request = request_add_custom_headers(args.headers, request)?;
 
     let response = send_request(request, span, None, None);
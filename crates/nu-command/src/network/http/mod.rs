@@ -1,3 +1,4 @@
+mod cache;
 mod client;
 mod delete;
 mod get;
@@ -6,6 +7,8 @@ mod http_;
 mod patch;
 mod post;
 mod put;
+mod request;
+mod token;
 
 pub use delete::SubCommand as HttpDelete;
 pub use get::SubCommand as HttpGet;
@@ -14,3 +17,5 @@ pub use http_::Http;
 pub use patch::SubCommand as HttpPatch;
 pub use post::SubCommand as HttpPost;
 pub use put::SubCommand as HttpPut;
+pub use request::SubCommand as HttpRequest;
+pub use token::SubCommand as HttpToken;
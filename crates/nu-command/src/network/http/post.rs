@@ -6,8 +6,9 @@ use nu_protocol::{
 };
 
 use crate::network::http::client::{
-    http_client, http_parse_url, request_add_authorization_header, request_add_custom_headers,
-    request_handle_response, request_set_timeout, send_request,
+    http_client, http_parse_url, request_add_authorization_header, request_add_bearer_token_header,
+    request_add_custom_headers, request_apply_rate_limit, request_compress_body, request_handle_response,
+    request_set_timeout, send_request, send_request_streaming,
 };
 
 #[derive(Clone)]
@@ -20,10 +21,14 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("http post")
-            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .input_output_types(vec![(Type::Nothing, Type::Any), (Type::Any, Type::Any)])
             .allow_variants_without_examples(true)
             .required("URL", SyntaxShape::String, "the URL to post to")
-            .required("data", SyntaxShape::Any, "the contents of the post body")
+            .optional(
+                "data",
+                SyntaxShape::Any,
+                "the contents of the post body; if omitted, the pipeline input is used",
+            )
             .named(
                 "user",
                 SyntaxShape::Any,
@@ -36,6 +41,12 @@ impl Command for SubCommand {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "set the Authorization header to a Bearer token",
+                None,
+            )
             .named(
                 "content-type",
                 SyntaxShape::Any,
@@ -48,6 +59,12 @@ impl Command for SubCommand {
                 "timeout period in seconds",
                 Some('m'),
             )
+            .named(
+                "rate-limit",
+                SyntaxShape::String,
+                "limit request frequency to <n>/<duration>, e.g. 10/1min; shared across calls",
+                None,
+            )
             .named(
                 "headers",
                 SyntaxShape::Any,
@@ -64,6 +81,11 @@ impl Command for SubCommand {
                 "allow insecure server connections when using SSL",
                 Some('k'),
             )
+            .switch(
+                "compress",
+                "gzip the request body and set the Content-Encoding header",
+                None,
+            )
             .filter()
             .category(Category::Network)
     }
@@ -112,6 +134,11 @@ impl Command for SubCommand {
                 example: "http post -t application/json https://www.example.com { field: value }",
                 result: None,
             },
+            Example {
+                description: "Post the contents of a file as the body, streamed from the pipeline",
+                example: "open --raw big.json | http post -t application/json https://www.example.com",
+                result: None,
+            },
         ]
     }
 }
@@ -119,34 +146,40 @@ impl Command for SubCommand {
 struct Arguments {
     url: Value,
     headers: Option<Value>,
-    data: Value,
+    data: Option<Value>,
     content_type: Option<String>,
     raw: bool,
     insecure: bool,
+    compress: bool,
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     timeout: Option<Value>,
+    rate_limit: Option<String>,
 }
 
 fn run_post(
     engine_state: &EngineState,
     stack: &mut Stack,
     call: &Call,
-    _input: PipelineData,
+    input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let args = Arguments {
         url: call.req(engine_state, stack, 0)?,
         headers: call.get_flag(engine_state, stack, "headers")?,
-        data: call.req(engine_state, stack, 1)?,
+        data: call.opt(engine_state, stack, 1)?,
         content_type: call.get_flag(engine_state, stack, "content-type")?,
         raw: call.has_flag("raw"),
         insecure: call.has_flag("insecure"),
+        compress: call.has_flag("compress"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
         timeout: call.get_flag(engine_state, stack, "max-time")?,
+        rate_limit: call.get_flag(engine_state, stack, "rate-limit")?,
     };
 
-    helper(engine_state, stack, call, args)
+    helper(engine_state, stack, call, args, input)
 }
 
 // Helper function that actually goes to retrieve the resource from the url given
@@ -156,6 +189,7 @@ fn helper(
     stack: &mut Stack,
     call: &Call,
     args: Arguments,
+    input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let span = args.url.span()?;
     let (requested_url, _) = http_parse_url(call, span, args.url)?;
@@ -163,11 +197,52 @@ fn helper(
     let client = http_client(args.insecure);
     let mut request = client.post(&requested_url);
 
+    request_apply_rate_limit(args.rate_limit, span, engine_state.ctrlc.clone())?;
+
     request = request_set_timeout(args.timeout, request)?;
     request = request_add_authorization_header(args.user, args.password, request);
+    request = request_add_bearer_token_header(args.bearer, request);
     request = request_add_custom_headers(args.headers, request)?;
 
-    let response = send_request(request, span, Some(args.data), args.content_type);
+    // The body either comes from the positional `data` argument or from the
+    // pipeline input; byte streams are forwarded without collecting them.
+    let data = match args.data {
+        Some(data) => data,
+        None => match input {
+            PipelineData::Value(data @ Value::Nothing { .. }, ..) => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "data".to_string(),
+                    span: data.span().unwrap_or(call.head),
+                })
+            }
+            PipelineData::Value(data, ..) => data,
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                ..
+            } if !args.compress => {
+                let response = send_request_streaming(request, span, stream, args.content_type);
+                return request_handle_response(
+                    engine_state,
+                    stack,
+                    span,
+                    &requested_url,
+                    args.raw,
+                    response,
+                );
+            }
+            data => data.into_value(span),
+        },
+    };
+
+    let (request, data, content_type) = if args.compress {
+        // the content type has moved into a header; the body is plain bytes now
+        let (request, data) = request_compress_body(request, data, args.content_type, span)?;
+        (request, data, None)
+    } else {
+        (request, data, args.content_type)
+    };
+
+    let response = send_request(request, span, Some(data), content_type);
     request_handle_response(
         engine_state,
         stack,
@@ -6,7 +6,8 @@ use nu_protocol::{
 };
 
 use crate::network::http::client::{
-    http_client, http_parse_url, request_add_authorization_header, request_add_custom_headers,
+    http_client, http_parse_url, request_add_authorization_header, request_add_bearer_token_header,
+    request_add_custom_headers, request_apply_rate_limit,
     request_handle_response, request_set_timeout, send_request,
 };
 
@@ -36,6 +37,12 @@ impl Command for SubCommand {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "set the Authorization header to a Bearer token",
+                None,
+            )
             .named(
                 "content-type",
                 SyntaxShape::Any,
@@ -48,6 +55,12 @@ impl Command for SubCommand {
                 "timeout period in seconds",
                 Some('m'),
             )
+            .named(
+                "rate-limit",
+                SyntaxShape::String,
+                "limit request frequency to <n>/<duration>, e.g. 10/1min; shared across calls",
+                None,
+            )
             .named(
                 "headers",
                 SyntaxShape::Any,
@@ -125,7 +138,9 @@ struct Arguments {
     insecure: bool,
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     timeout: Option<Value>,
+    rate_limit: Option<String>,
 }
 
 fn run_put(
@@ -143,7 +158,9 @@ fn run_put(
         insecure: call.has_flag("insecure"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
         timeout: call.get_flag(engine_state, stack, "max-time")?,
+        rate_limit: call.get_flag(engine_state, stack, "rate-limit")?,
     };
 
     helper(engine_state, stack, call, args)
@@ -163,8 +180,11 @@ fn helper(
     let client = http_client(args.insecure);
     let mut request = client.put(&requested_url);
 
+    request_apply_rate_limit(args.rate_limit, span, engine_state.ctrlc.clone())?;
+
     request = request_set_timeout(args.timeout, request)?;
     request = request_add_authorization_header(args.user, args.password, request);
+    request = request_add_bearer_token_header(args.bearer, request);
     request = request_add_custom_headers(args.headers, request)?;
 
     let response = send_request(request, span, Some(args.data), args.content_type);
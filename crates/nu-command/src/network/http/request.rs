@@ -0,0 +1,303 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::network::http::client::{
+    http_client, http_parse_url, request_add_authorization_header, request_add_bearer_token_header,
+    request_add_custom_headers, request_apply_rate_limit, request_handle_response,
+    request_set_timeout, send_request,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "http request"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http request")
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::Any),
+                (Type::Table(vec![]), Type::Any),
+            ])
+            .allow_variants_without_examples(true)
+            .optional(
+                "request",
+                SyntaxShape::Record,
+                "the request record; if omitted, the pipeline input is used",
+            )
+            .named(
+                "method",
+                SyntaxShape::String,
+                "the HTTP method to use, overriding the record's method field",
+                Some('X'),
+            )
+            .named(
+                "user",
+                SyntaxShape::Any,
+                "the username when authenticating",
+                Some('u'),
+            )
+            .named(
+                "password",
+                SyntaxShape::Any,
+                "the password when authenticating",
+                Some('p'),
+            )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "set the Authorization header to a Bearer token",
+                None,
+            )
+            .named(
+                "max-time",
+                SyntaxShape::Int,
+                "timeout period in seconds",
+                Some('m'),
+            )
+            .named(
+                "rate-limit",
+                SyntaxShape::String,
+                "limit request frequency to <n>/<duration>, e.g. 10/1min; shared across calls",
+                None,
+            )
+            .switch(
+                "raw",
+                "return values as a string instead of a table",
+                Some('r'),
+            )
+            .switch(
+                "insecure",
+                "allow insecure server connections when using SSL",
+                Some('k'),
+            )
+            .filter()
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Send an HTTP request described by a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The request record may contain url (required), method, headers, body and query fields, so request definitions can be kept in tables or files and replayed. A table as input sends one request per row."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "fetch", "send", "curl", "replay", "collection"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_request(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Send a request described by a record",
+                example: "{url: https://www.example.com, method: get} | http request",
+                result: None,
+            },
+            Example {
+                description: "Send a POST request with a JSON body and query parameters",
+                example: "{url: https://www.example.com, method: post, query: {page: '2'}, body: {name: foo}} | http request",
+                result: None,
+            },
+            Example {
+                description: "Replay every request stored in a file",
+                example: "open requests.json | http request",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct Arguments {
+    method: Option<String>,
+    raw: bool,
+    insecure: bool,
+    user: Option<String>,
+    password: Option<String>,
+    bearer: Option<String>,
+    timeout: Option<Value>,
+    rate_limit: Option<String>,
+}
+
+fn run_request(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let args = Arguments {
+        method: call.get_flag(engine_state, stack, "method")?,
+        raw: call.has_flag("raw"),
+        insecure: call.has_flag("insecure"),
+        user: call.get_flag(engine_state, stack, "user")?,
+        password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
+        timeout: call.get_flag(engine_state, stack, "max-time")?,
+        rate_limit: call.get_flag(engine_state, stack, "rate-limit")?,
+    };
+
+    let record: Option<Value> = call.opt(engine_state, stack, 0)?;
+    let record = match record {
+        Some(record) => record,
+        None => input.into_value(call.head),
+    };
+
+    match record {
+        record @ Value::Record { .. } => send_one(engine_state, stack, call, &args, record),
+        Value::List { vals, span } => {
+            let mut results = Vec::with_capacity(vals.len());
+            for record in vals {
+                results
+                    .push(send_one(engine_state, stack, call, &args, record)?.into_value(span));
+            }
+            Ok(PipelineData::Value(
+                Value::List {
+                    vals: results,
+                    span,
+                },
+                None,
+            ))
+        }
+        other => Err(ShellError::UnsupportedInput(
+            "expected a record or table describing the request".into(),
+            format!("input is {}", other.get_type()),
+            call.head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn send_one(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    args: &Arguments,
+    record: Value,
+) -> Result<PipelineData, ShellError> {
+    let span = record.span().unwrap_or(call.head);
+
+    let url = record_field(&record, "url")?.ok_or_else(|| ShellError::MissingParameter {
+        param_name: "url".to_string(),
+        span,
+    })?;
+    let method = match &args.method {
+        Some(method) => method.clone(),
+        None => match record_field(&record, "method")? {
+            Some(method) => method.as_string()?,
+            None => "get".to_string(),
+        },
+    };
+    let headers = record_field(&record, "headers")?;
+    let body = record_field(&record, "body")?;
+    let query = record_field(&record, "query")?;
+
+    let (requested_url, _) = http_parse_url(call, span, url)?;
+    let requested_url = append_query_string(&requested_url, query, span)?;
+
+    let client = http_client(args.insecure);
+    let mut request = client.request(&method.to_uppercase(), &requested_url);
+
+    request_apply_rate_limit(args.rate_limit.clone(), span, engine_state.ctrlc.clone())?;
+
+    request = request_set_timeout(args.timeout.clone(), request)?;
+    request = request_add_authorization_header(args.user.clone(), args.password.clone(), request);
+    request = request_add_bearer_token_header(args.bearer.clone(), request);
+    request = request_add_custom_headers(headers.clone(), request)?;
+
+    // a structured body with no explicit content type is sent as JSON
+    let content_type = header_content_type(&headers)?.or(match &body {
+        Some(Value::Record { .. } | Value::List { .. }) => Some("application/json".to_string()),
+        _ => None,
+    });
+
+    let response = send_request(request, span, body, content_type);
+    request_handle_response(engine_state, stack, span, &requested_url, args.raw, response)
+}
+
+fn record_field(record: &Value, field: &str) -> Result<Option<Value>, ShellError> {
+    match record {
+        Value::Record { cols, vals, .. } => Ok(cols
+            .iter()
+            .position(|col| col == field)
+            .map(|idx| vals[idx].clone())),
+        _ => Ok(None),
+    }
+}
+
+fn header_content_type(headers: &Option<Value>) -> Result<Option<String>, ShellError> {
+    match headers {
+        Some(headers @ Value::Record { .. }) => {
+            for field in ["content-type", "Content-Type"] {
+                if let Some(value) = record_field(headers, field)? {
+                    return Ok(Some(value.as_string()?));
+                }
+            }
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn append_query_string(
+    url: &str,
+    query: Option<Value>,
+    span: Span,
+) -> Result<String, ShellError> {
+    let query = match query {
+        Some(Value::Record { cols, vals, .. }) => {
+            let mut pairs: Vec<(String, String)> = Vec::with_capacity(cols.len());
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                pairs.push((col.clone(), val.as_string()?));
+            }
+            serde_urlencoded::to_string(pairs).map_err(|_| ShellError::CantConvert {
+                to_type: "query string".into(),
+                from_type: "record".into(),
+                span,
+                help: None,
+            })?
+        }
+        Some(other) => {
+            return Err(ShellError::TypeMismatch {
+                err_message: "query must be a record".to_string(),
+                span: other.expect_span(),
+            })
+        }
+        None => return Ok(url.to_string()),
+    };
+
+    if query.is_empty() {
+        Ok(url.to_string())
+    } else if url.contains('?') {
+        Ok(format!("{url}&{query}"))
+    } else {
+        Ok(format!("{url}?{query}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,307 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+use crate::network::http::client::{http_client, http_parse_url, request_set_timeout};
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "http token"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http token")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "URL",
+                SyntaxShape::String,
+                "the URL of the OAuth2 token endpoint",
+            )
+            .required("client-id", SyntaxShape::String, "the OAuth2 client id")
+            .named(
+                "client-secret",
+                SyntaxShape::String,
+                "the OAuth2 client secret, for the client-credentials grant",
+                Some('s'),
+            )
+            .named(
+                "scope",
+                SyntaxShape::String,
+                "the scope(s) to request, space separated",
+                None,
+            )
+            .named(
+                "device-url",
+                SyntaxShape::String,
+                "the device authorization endpoint; when given, use the device-code grant instead of client-credentials",
+                Some('d'),
+            )
+            .named(
+                "max-time",
+                SyntaxShape::Int,
+                "timeout period in seconds",
+                Some('m'),
+            )
+            .switch(
+                "insecure",
+                "allow insecure server connections when using SSL",
+                Some('k'),
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Fetch an OAuth2 access token from a token endpoint."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Implements the client-credentials grant by default, or the device-code grant when --device-url is given.
+The resulting access_token can be passed to other http commands via --bearer."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "oauth", "oauth2", "bearer", "authorization"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let args = Arguments {
+            url: call.req(engine_state, stack, 0)?,
+            client_id: call.req(engine_state, stack, 1)?,
+            client_secret: call.get_flag(engine_state, stack, "client-secret")?,
+            scope: call.get_flag(engine_state, stack, "scope")?,
+            device_url: call.get_flag(engine_state, stack, "device-url")?,
+            insecure: call.has_flag("insecure"),
+            timeout: call.get_flag(engine_state, stack, "max-time")?,
+        };
+        helper(engine_state, call, args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fetch a token using the client-credentials grant",
+                example: "http token https://login.example.com/oauth2/token my-client -s my-secret --scope read",
+                result: None,
+            },
+            Example {
+                description: "Fetch a token interactively using the device-code grant",
+                example: "http token https://login.example.com/oauth2/token my-client -d https://login.example.com/oauth2/device",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct Arguments {
+    url: Value,
+    client_id: String,
+    client_secret: Option<String>,
+    scope: Option<String>,
+    device_url: Option<String>,
+    insecure: bool,
+    timeout: Option<Value>,
+}
+
+fn helper(
+    engine_state: &EngineState,
+    call: &Call,
+    args: Arguments,
+) -> Result<PipelineData, ShellError> {
+    let span = args.url.span()?;
+    let (token_url, _) = http_parse_url(call, span, args.url)?;
+
+    match args.device_url {
+        Some(device_url) => device_code_flow(engine_state, span, &token_url, &device_url, &args),
+        None => client_credentials_flow(span, &token_url, &args),
+    }
+    .map(|v| v.into_pipeline_data())
+}
+
+fn client_credentials_flow(
+    span: Span,
+    token_url: &str,
+    args: &Arguments,
+) -> Result<Value, ShellError> {
+    let mut form: Vec<(&str, &str)> = vec![
+        ("grant_type", "client_credentials"),
+        ("client_id", &args.client_id),
+    ];
+    if let Some(secret) = &args.client_secret {
+        form.push(("client_secret", secret));
+    }
+    if let Some(scope) = &args.scope {
+        form.push(("scope", scope));
+    }
+
+    token_endpoint_request(span, token_url, &form, args)
+}
+
+fn device_code_flow(
+    engine_state: &EngineState,
+    span: Span,
+    token_url: &str,
+    device_url: &str,
+    args: &Arguments,
+) -> Result<Value, ShellError> {
+    let mut form: Vec<(&str, &str)> = vec![("client_id", &args.client_id)];
+    if let Some(scope) = &args.scope {
+        form.push(("scope", scope));
+    }
+
+    let authorization = token_endpoint_request(span, device_url, &form, args)?;
+
+    let device_code = record_string_field(&authorization, "device_code", span)?;
+    let user_code = record_string_field(&authorization, "user_code", span)?;
+    let verification_uri = record_string_field(&authorization, "verification_uri", span)
+        .or_else(|_| record_string_field(&authorization, "verification_url", span))?;
+    let mut interval = record_int_field(&authorization, "interval").unwrap_or(5);
+
+    eprintln!("To authorize, open {verification_uri} and enter the code: {user_code}");
+
+    let form: Vec<(&str, &str)> = vec![
+        ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+        ("device_code", &device_code),
+        ("client_id", &args.client_id),
+    ];
+
+    loop {
+        if let Some(ctrlc) = &engine_state.ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                return Err(ShellError::IOInterrupted(
+                    "Device authorization was interrupted".into(),
+                    span,
+                ));
+            }
+        }
+        std::thread::sleep(Duration::from_secs(interval.max(1) as u64));
+
+        let response = token_endpoint_request(span, token_url, &form, args)?;
+        match record_string_field(&response, "error", span) {
+            Ok(error) if error == "authorization_pending" => continue,
+            Ok(error) if error == "slow_down" => {
+                interval += 5;
+                continue;
+            }
+            Ok(error) => {
+                return Err(ShellError::NetworkFailure(
+                    format!("Token endpoint refused the device authorization: {error}"),
+                    span,
+                ))
+            }
+            Err(_) => return Ok(response),
+        }
+    }
+}
+
+// Sends a form-encoded POST to an OAuth2 endpoint and parses the JSON response
+// into a record. OAuth2 error responses come back with a 4xx status but still
+// carry a JSON body, so those are parsed rather than treated as failures.
+fn token_endpoint_request(
+    span: Span,
+    url: &str,
+    form: &[(&str, &str)],
+    args: &Arguments,
+) -> Result<Value, ShellError> {
+    let client = http_client(args.insecure);
+    let mut request = client.post(url);
+    request = request_set_timeout(args.timeout.clone(), request)?;
+
+    let body = match request.send_form(form) {
+        Ok(resp) => resp.into_string(),
+        Err(ureq::Error::Status(_, resp)) => resp.into_string(),
+        Err(e) => {
+            return Err(ShellError::NetworkFailure(
+                format!("Cannot make request to {url:?}. Error is {e:?}"),
+                span,
+            ))
+        }
+    }
+    .map_err(|e| ShellError::NetworkFailure(e.to_string(), span))?;
+
+    let json = nu_json::from_str(&body).map_err(|_| {
+        ShellError::NetworkFailure(
+            format!("Token endpoint {url:?} did not return a JSON response"),
+            span,
+        )
+    })?;
+
+    Ok(json_to_value(&json, span))
+}
+
+fn json_to_value(json: &nu_json::Value, span: Span) -> Value {
+    match json {
+        nu_json::Value::Null => Value::nothing(span),
+        nu_json::Value::Bool(b) => Value::boolean(*b, span),
+        nu_json::Value::I64(i) => Value::int(*i, span),
+        nu_json::Value::U64(u) => Value::int(*u as i64, span),
+        nu_json::Value::F64(f) => Value::float(*f, span),
+        nu_json::Value::String(s) => Value::string(s, span),
+        nu_json::Value::Array(vals) => Value::List {
+            vals: vals.iter().map(|v| json_to_value(v, span)).collect(),
+            span,
+        },
+        nu_json::Value::Object(map) => Value::Record {
+            cols: map.keys().cloned().collect(),
+            vals: map.values().map(|v| json_to_value(v, span)).collect(),
+            span,
+        },
+    }
+}
+
+fn record_string_field(record: &Value, field: &str, span: Span) -> Result<String, ShellError> {
+    match record {
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .position(|c| c == field)
+            .and_then(|idx| vals[idx].as_string().ok())
+            .ok_or_else(|| {
+                ShellError::NetworkFailure(
+                    format!("Token endpoint response is missing the '{field}' field"),
+                    span,
+                )
+            }),
+        _ => Err(ShellError::NetworkFailure(
+            format!("Token endpoint response is missing the '{field}' field"),
+            span,
+        )),
+    }
+}
+
+fn record_int_field(record: &Value, field: &str) -> Option<i64> {
+    match record {
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .position(|c| c == field)
+            .and_then(|idx| vals[idx].as_integer().ok()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,8 +1,17 @@
+mod dns;
 mod http;
+mod net;
 mod port;
+mod port_scan;
+mod version_check;
 mod url;
 
+pub use self::dns::*;
 pub use self::http::*;
+pub use self::net::*;
 pub use self::url::*;
 
 pub use port::SubCommand as Port;
+pub use port_scan::SubCommand as PortScan;
+pub(crate) use version_check::{extract_tag_name, is_newer};
+pub use version_check::SubCommand as VersionCheck;
@@ -0,0 +1,80 @@
+//! The wire format `net listen` and `net send` speak: each value is one
+//! frame, a little-endian u64 byte length followed by that many bytes of
+//! NUON text. NUON round-trips structured data, so nothing is lost to
+//! text formatting on the way through.
+
+use std::io::{Read, Write};
+
+/// The largest frame `read_frame` will accept. The declared length comes
+/// straight off the wire, so it must never be trusted as an allocation size.
+const MAX_FRAME_LEN: u64 = 64 * 1024 * 1024;
+
+pub fn write_frame(writer: &mut impl Write, text: &str) -> std::io::Result<()> {
+    writer.write_all(&(text.len() as u64).to_le_bytes())?;
+    writer.write_all(text.as_bytes())
+}
+
+/// Read one frame, or `None` on a clean end of stream
+pub fn read_frame(reader: &mut impl Read) -> std::io::Result<Option<String>> {
+    let mut len = [0u8; 8];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let len = u64::from_le_bytes(len);
+    if len > MAX_FRAME_LEN {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("frame of {len} bytes exceeds the {MAX_FRAME_LEN} byte limit"),
+        ));
+    }
+
+    let mut buf = vec![0u8; len as usize];
+    reader.read_exact(&mut buf)?;
+
+    String::from_utf8(buf)
+        .map(Some)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_roundtrip() {
+        let mut buf = vec![];
+        write_frame(&mut buf, "[1, 2, 3]").unwrap();
+        write_frame(&mut buf, "\"hello\"").unwrap();
+
+        let mut reader = std::io::Cursor::new(buf);
+        assert_eq!(
+            read_frame(&mut reader).unwrap().as_deref(),
+            Some("[1, 2, 3]")
+        );
+        assert_eq!(
+            read_frame(&mut reader).unwrap().as_deref(),
+            Some("\"hello\"")
+        );
+        assert_eq!(read_frame(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn truncated_frame_is_an_error() {
+        let mut buf = vec![];
+        write_frame(&mut buf, "[1, 2, 3]").unwrap();
+        buf.truncate(buf.len() - 2);
+
+        let mut reader = std::io::Cursor::new(buf);
+        assert!(read_frame(&mut reader).is_err());
+    }
+
+    #[test]
+    fn oversized_frame_is_rejected_before_allocating() {
+        let mut reader = std::io::Cursor::new(u64::MAX.to_le_bytes());
+        let err = read_frame(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,113 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+
+use super::frame::read_frame;
+
+#[derive(Clone)]
+pub struct NetListen;
+
+impl Command for NetListen {
+    fn name(&self) -> &str {
+        "net listen"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("net listen")
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::Any)))])
+            .allow_variants_without_examples(true)
+            .required(
+                "address",
+                SyntaxShape::String,
+                "the address to listen on, like 127.0.0.1:9001",
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Accept one connection and stream the values another nushell sends."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Values arrive as a stream, so they can be piped onwards while the sender is still running. The other side is `net send`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["tcp", "receive", "serve", "pair"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let address: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let span = call.head;
+
+        let listener = TcpListener::bind(&address.item).map_err(|err| {
+            ShellError::GenericError(
+                format!("Failed to listen on {}", address.item),
+                err.to_string(),
+                Some(address.span),
+                None,
+                vec![],
+            )
+        })?;
+        let (stream, _) = listener
+            .accept()
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+
+        Ok(ListenIterator {
+            reader: BufReader::new(stream),
+            span,
+            done: false,
+        }
+        .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Receive values from another nushell and sum them",
+            example: "net listen 127.0.0.1:9001 | math sum",
+            result: None,
+        }]
+    }
+}
+
+struct ListenIterator {
+    reader: BufReader<TcpStream>,
+    span: Span,
+    done: bool,
+}
+
+impl Iterator for ListenIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match read_frame(&mut self.reader) {
+            Ok(Some(text)) => match crate::formats::from_nuon_string_to_value(&text, self.span) {
+                Ok(value) => Some(value),
+                Err(error) => Some(Value::Error { error }),
+            },
+            Ok(None) => None,
+            Err(err) => {
+                self.done = true;
+                Some(Value::Error {
+                    error: ShellError::IOErrorSpanned(err.to_string(), self.span),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod frame;
+mod listen;
+mod net_;
+mod send;
+
+pub use listen::NetListen;
+pub use net_::Net;
+pub use send::NetSend;
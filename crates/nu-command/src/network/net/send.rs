@@ -0,0 +1,84 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+use std::io::{BufWriter, Write};
+use std::net::TcpStream;
+
+use super::frame::write_frame;
+
+#[derive(Clone)]
+pub struct NetSend;
+
+impl Command for NetSend {
+    fn name(&self) -> &str {
+        "net send"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("net send")
+            .input_output_types(vec![(Type::Any, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "address",
+                SyntaxShape::String,
+                "the address a `net listen` is waiting on, like 127.0.0.1:9001",
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Send the pipeline's values to a listening nushell instance."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each value is framed and serialized as NUON, so records, tables and the rest arrive typed instead of as flattened text."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["tcp", "transmit", "pair"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let address: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let span = call.head;
+
+        let stream = TcpStream::connect(&address.item).map_err(|err| {
+            ShellError::GenericError(
+                format!("Failed to connect to {}", address.item),
+                err.to_string(),
+                Some(address.span),
+                None,
+                vec![],
+            )
+        })?;
+        let mut writer = BufWriter::new(stream);
+
+        for value in input.into_iter() {
+            let text = crate::formats::value_to_string(&value, span)?;
+            write_frame(&mut writer, &text)
+                .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+        }
+        writer
+            .flush()
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+
+        Ok(PipelineData::new_with_metadata(None, span))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Send a table to a listening nushell",
+            example: "ls | net send 127.0.0.1:9001",
+            result: None,
+        }]
+    }
+}
@@ -2,7 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::IntoPipelineData;
-use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
 
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
@@ -18,18 +19,31 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("port")
-            .input_output_types(vec![(Type::Nothing, Type::Int)])
+            .input_output_types(vec![
+                (Type::Nothing, Type::Int),
+                (Type::Nothing, Type::Bool),
+            ])
             .optional(
                 "start",
+                SyntaxShape::Any,
+                "The start port to scan (inclusive), or a host to test a connection to",
+            )
+            .optional(
+                "end",
                 SyntaxShape::Int,
-                "The start port to scan (inclusive)",
+                "The end port to scan (inclusive), or the port to connect to on the host",
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "timeout for testing a connection to a host (default 5sec)",
+                Some('t'),
             )
-            .optional("end", SyntaxShape::Int, "The end port to scan (inclusive)")
             .category(Category::Network)
     }
 
     fn usage(&self) -> &str {
-        "Get a free port from system."
+        "Get a free port from system, or test whether a host accepts TCP connections on a port."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -43,7 +57,12 @@ impl Command for SubCommand {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        get_free_port(engine_state, stack, call)
+        // `port <host> <port>` tests a connection instead of finding a free port
+        if let Some(host @ Value::String { .. }) = call.opt(engine_state, stack, 0)? {
+            test_connection(engine_state, stack, call, host)
+        } else {
+            get_free_port(engine_state, stack, call)
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -58,10 +77,47 @@ impl Command for SubCommand {
                 example: "port",
                 result: None,
             },
+            Example {
+                description: "test whether example.com accepts connections on port 443",
+                example: "port example.com 443",
+                result: None,
+            },
         ]
     }
 }
 
+fn test_connection(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    host: Value,
+) -> Result<PipelineData, ShellError> {
+    let host = host.as_string()?;
+    let port: Option<i64> = call.opt(engine_state, stack, 1)?;
+    let port = port.ok_or_else(|| ShellError::MissingParameter {
+        param_name: "port".to_string(),
+        span: call.head,
+    })?;
+    let timeout: Option<Value> = call.get_flag(engine_state, stack, "timeout")?;
+    let timeout = match timeout {
+        Some(Value::Duration { val, .. }) => Duration::from_nanos(val.max(0) as u64),
+        _ => Duration::from_secs(5),
+    };
+
+    let addrs = format!("{host}:{port}")
+        .to_socket_addrs()
+        .map_err(|e| {
+            ShellError::NetworkFailure(format!("Unable to resolve '{host}': {e}"), call.head)
+        })?
+        .collect::<Vec<_>>();
+
+    let open = addrs
+        .iter()
+        .any(|addr| TcpStream::connect_timeout(addr, timeout).is_ok());
+
+    Ok(Value::boolean(open, call.head).into_pipeline_data())
+}
+
 fn get_free_port(
     engine_state: &EngineState,
     stack: &mut Stack,
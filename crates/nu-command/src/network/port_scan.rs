@@ -0,0 +1,159 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, Range, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+use rayon::prelude::*;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "port scan"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("port scan")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("host", SyntaxShape::String, "the host to scan")
+            .required(
+                "range",
+                SyntaxShape::Range,
+                "the range of ports to scan, e.g. 8000..8100",
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "timeout for each connection attempt (default 1sec)",
+                Some('t'),
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Scan a range of TCP ports on a host and list the open ones."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Attempts a TCP connection to every port in the range and returns a table with a row for each port that accepted one."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "tcp", "open", "connection"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        scan(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Scan the privileged ports on localhost",
+                example: "port scan 127.0.0.1 1..1024",
+                result: None,
+            },
+            Example {
+                description: "Scan a range of ports with a shorter timeout",
+                example: "port scan example.com 8000..8100 --timeout 250ms",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn scan(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let host: String = call.req(engine_state, stack, 0)?;
+    let range: Range = call.req(engine_state, stack, 1)?;
+    let timeout: Option<Value> = call.get_flag(engine_state, stack, "timeout")?;
+    let timeout = match timeout {
+        Some(Value::Duration { val, .. }) => Duration::from_nanos(val.max(0) as u64),
+        _ => Duration::from_secs(1),
+    };
+
+    let start = range.from.as_integer()?;
+    let end = if range.is_end_inclusive() {
+        range.to.as_integer()?
+    } else {
+        range.to.as_integer()? - 1
+    };
+
+    if start < 1 || end > u16::MAX as i64 || start > end {
+        return Err(ShellError::InvalidRange {
+            left_flank: start.to_string(),
+            right_flank: end.to_string(),
+            span,
+        });
+    }
+
+    // resolve the host once rather than for every port
+    let addr = format!("{host}:{start}")
+        .to_socket_addrs()
+        .map_err(|e| ShellError::NetworkFailure(format!("Unable to resolve '{host}': {e}"), span))?
+        .next()
+        .ok_or_else(|| {
+            ShellError::NetworkFailure(format!("Unable to resolve '{host}'"), span)
+        })?;
+
+    let mut open_ports: Vec<u16> = (start..=end)
+        .collect::<Vec<i64>>()
+        .into_par_iter()
+        .filter_map(|port| {
+            let mut addr = addr;
+            addr.set_port(port as u16);
+            TcpStream::connect_timeout(&addr, timeout)
+                .is_ok()
+                .then_some(port as u16)
+        })
+        .collect();
+    open_ports.sort_unstable();
+
+    let vals = open_ports
+        .into_iter()
+        .map(|port| Value::Record {
+            cols: vec!["host".into(), "port".into()],
+            vals: vec![
+                Value::String {
+                    val: host.clone(),
+                    span,
+                },
+                Value::Int {
+                    val: port as i64,
+                    span,
+                },
+            ],
+            span,
+        })
+        .collect();
+
+    Ok(Value::List { vals, span }.into_pipeline_data())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,160 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+use std::time::Duration;
+
+const RELEASE_FEED_URL: &str = "https://api.github.com/repos/nushell/nushell/releases/latest";
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "version check"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("version check")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Check if a newer version of Nu is available."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Queries the GitHub release feed for the latest release. The check can be disabled with the allow_version_check config setting, and the HTTPS_PROXY/HTTP_PROXY environment variables are honored."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["update", "release", "latest", "upgrade"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        if !engine_state.get_config().allow_version_check {
+            return Err(ShellError::GenericError(
+                "Version checking is disabled".into(),
+                "checking was disabled by the allow_version_check config setting".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let proxy = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+            .iter()
+            .find_map(|var| stack.get_env_var(engine_state, var))
+            .and_then(|v| v.as_string().ok());
+
+        let tls = native_tls::TlsConnector::builder()
+            .build()
+            .expect("Failed to build network tls");
+
+        let mut builder = ureq::builder()
+            .user_agent("nushell")
+            .tls_connector(std::sync::Arc::new(tls));
+
+        if let Some(proxy) = proxy {
+            let proxy = ureq::Proxy::new(&proxy).map_err(|e| {
+                ShellError::NetworkFailure(format!("Invalid proxy '{proxy}': {e}"), span)
+            })?;
+            builder = builder.proxy(proxy);
+        }
+
+        let body = builder
+            .build()
+            .get(RELEASE_FEED_URL)
+            .timeout(Duration::from_secs(10))
+            .call()
+            .map_err(|e| {
+                ShellError::NetworkFailure(format!("Unable to query the release feed: {e}"), span)
+            })?
+            .into_string()
+            .map_err(|e| {
+                ShellError::NetworkFailure(format!("Unable to read the release feed: {e}"), span)
+            })?;
+
+        let latest = extract_tag_name(&body).ok_or_else(|| {
+            ShellError::NetworkFailure(
+                "The release feed did not contain a version tag".into(),
+                span,
+            )
+        })?;
+        let current = env!("CARGO_PKG_VERSION");
+
+        Ok(Value::Record {
+            cols: vec![
+                "current".into(),
+                "latest".into(),
+                "update_available".into(),
+            ],
+            vals: vec![
+                Value::string(current, span),
+                Value::string(&latest, span),
+                Value::boolean(is_newer(&latest, current), span),
+            ],
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check if a newer version of Nu has been released",
+            example: "version check",
+            result: None,
+        }]
+    }
+}
+
+// Pulls the "tag_name" field out of the release feed response without needing
+// a full JSON parse.
+pub(crate) fn extract_tag_name(body: &str) -> Option<String> {
+    let rest = &body[body.find("\"tag_name\"")? + "\"tag_name\"".len()..];
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    let end = rest.find('"')?;
+    Some(rest[..end].trim_start_matches('v').to_string())
+}
+
+pub(crate) fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split('.')
+            .map(|part| part.parse::<u64>().unwrap_or(0))
+            .collect()
+    };
+
+    parse(latest) > parse(current)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extracts_the_tag_name() {
+        let body = r#"{"url": "https://example.com", "tag_name": "0.77.0", "draft": false}"#;
+        assert_eq!(extract_tag_name(body), Some("0.77.0".to_string()));
+    }
+
+    #[test]
+    fn compares_versions_numerically() {
+        assert!(is_newer("0.77.0", "0.76.1"));
+        assert!(is_newer("0.76.10", "0.76.9"));
+        assert!(!is_newer("0.76.1", "0.76.1"));
+        assert!(!is_newer("0.75.0", "0.76.1"));
+    }
+}
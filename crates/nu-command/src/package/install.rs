@@ -0,0 +1,118 @@
+use super::pkg;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct PackageInstall;
+
+impl Command for PackageInstall {
+    fn name(&self) -> &str {
+        "package install"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("package install")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required("name", SyntaxShape::String, "the package to install")
+            .named(
+                "version",
+                SyntaxShape::String,
+                "the exact version to install (defaults to the latest)",
+                Some('v'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Install a module package and its dependencies from the registry."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Modules land in the managed package directory, which is part of NU_LIB_DIRS, so an installed package is available to `use` on the next line. Every install is recorded in the lockfile."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["nupm", "module", "registry", "add"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let version: Option<String> = call.get_flag(engine_state, stack, "version")?;
+
+        let registry = pkg::load_registry(engine_state, stack, span)?;
+        let entry = match pkg::find_entry(&registry, &name.item, version.as_deref()) {
+            Some(entry) => entry,
+            None => {
+                return Err(ShellError::GenericError(
+                    format!("Package '{}' not found in the registry", name.item),
+                    match version {
+                        Some(version) => format!("no entry with version {version}"),
+                        None => "no entry with that name".into(),
+                    },
+                    Some(name.span),
+                    None,
+                    vec![],
+                ))
+            }
+        };
+
+        let packages_dir = pkg::packages_dir(engine_state, stack, span)?;
+        let mut lock = pkg::read_lock(&packages_dir);
+        let mut report = vec![];
+
+        for entry in pkg::resolve_deps(&registry, entry, span)? {
+            let status = if lock
+                .iter()
+                .any(|(name, version, _)| name == &entry.name && version == &entry.version)
+            {
+                "up to date"
+            } else {
+                let hash = pkg::install_entry(&registry, entry, &packages_dir, span)?;
+                pkg::set_locked(&mut lock, &entry.name, &entry.version, hash);
+                "installed"
+            };
+
+            report.push(Value::Record {
+                cols: vec!["name".into(), "version".into(), "status".into()],
+                vals: vec![
+                    Value::string(&entry.name, span),
+                    Value::string(&entry.version, span),
+                    Value::string(status, span),
+                ],
+                span,
+            });
+        }
+
+        pkg::write_lock(&packages_dir, &lock, span)?;
+
+        Ok(Value::List { vals: report, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Install the latest version of a package",
+                example: "package install my-utils",
+                result: None,
+            },
+            Example {
+                description: "Install a specific version",
+                example: "package install my-utils --version 0.2.0",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,64 @@
+use super::pkg;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct PackageList;
+
+impl Command for PackageList {
+    fn name(&self) -> &str {
+        "package list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("package list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "List the packages recorded in the lockfile."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["nupm", "module", "installed"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let packages_dir = pkg::packages_dir(engine_state, stack, span)?;
+
+        let vals = pkg::read_lock(&packages_dir)
+            .into_iter()
+            .map(|(name, version, hash)| Value::Record {
+                cols: vec!["name".into(), "version".into(), "hash".into()],
+                vals: vec![
+                    Value::string(name, span),
+                    Value::string(version, span),
+                    Value::string(hash, span),
+                ],
+                span,
+            })
+            .collect();
+
+        Ok(Value::List { vals, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the installed packages",
+            example: "package list",
+            result: None,
+        }]
+    }
+}
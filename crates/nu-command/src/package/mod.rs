@@ -0,0 +1,10 @@
+mod install;
+mod list;
+mod package_;
+pub mod pkg;
+mod update;
+
+pub use install::PackageInstall;
+pub use list::PackageList;
+pub use package_::Package;
+pub use update::PackageUpdate;
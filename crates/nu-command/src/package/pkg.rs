@@ -0,0 +1,354 @@
+//! Shared pieces of the package management subsystem.
+//!
+//! Packages are single-file nushell modules published through a registry: a
+//! NUON table of `{name, version, url, sha256, deps}` rows, reachable through
+//! `$env.NU_PKG_REGISTRY` as either a URL or a file path. `package install`
+//! copies a module (and its dependencies) into the managed directory, which
+//! the default environment adds to `NU_LIB_DIRS`, and records every install
+//! in a lockfile kept next to the packages.
+
+use crate::env::nu_env::content_hash;
+use nu_engine::current_dir;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{ShellError, Span, Value};
+use std::path::PathBuf;
+
+/// Where the registry index lives: a URL or a file path
+pub const REGISTRY_ENV: &str = "NU_PKG_REGISTRY";
+/// Overrides the managed directory packages are installed into
+pub const PACKAGES_DIR_ENV: &str = "NU_PKG_DIR";
+/// One `<name> <version> <hash>` line per installed package
+pub const LOCK_FILE_NAME: &str = "package.lock";
+
+pub struct PkgEntry {
+    pub name: String,
+    pub version: String,
+    pub url: String,
+    pub sha256: Option<String>,
+    pub deps: Vec<String>,
+}
+
+pub struct Registry {
+    pub entries: Vec<PkgEntry>,
+    /// What relative package urls are resolved against
+    base: String,
+    base_is_url: bool,
+}
+
+fn is_url(name: &str) -> bool {
+    name.starts_with("https://") || name.starts_with("http://")
+}
+
+fn fetch(url: &str, span: Span) -> Result<String, ShellError> {
+    ureq::get(url)
+        .call()
+        .map_err(|err| {
+            ShellError::GenericError(
+                format!("Failed to fetch {url}"),
+                err.to_string(),
+                Some(span),
+                None,
+                vec![],
+            )
+        })?
+        .into_string()
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))
+}
+
+fn registry_error(msg: String, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Invalid package registry".into(),
+        msg,
+        Some(span),
+        None,
+        vec![],
+    )
+}
+
+/// The managed directory modules are installed into
+pub fn packages_dir(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Span,
+) -> Result<PathBuf, ShellError> {
+    if let Some(dir) = stack
+        .get_env_var(engine_state, PACKAGES_DIR_ENV)
+        .and_then(|dir| dir.as_string().ok())
+    {
+        let cwd = current_dir(engine_state, stack)?;
+        return Ok(nu_path::expand_path_with(dir, cwd));
+    }
+
+    match nu_path::config_dir() {
+        Some(mut dir) => {
+            dir.push("nushell");
+            dir.push("packages");
+            Ok(dir)
+        }
+        None => Err(ShellError::GenericError(
+            "Could not find the config directory".into(),
+            "the managed package directory could not be located".into(),
+            Some(span),
+            None,
+            vec![],
+        )),
+    }
+}
+
+/// Read and parse the registry `$env.NU_PKG_REGISTRY` points at
+pub fn load_registry(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Span,
+) -> Result<Registry, ShellError> {
+    let registry = match stack
+        .get_env_var(engine_state, REGISTRY_ENV)
+        .and_then(|value| value.as_string().ok())
+    {
+        Some(registry) => registry,
+        None => {
+            return Err(ShellError::GenericError(
+                "No package registry configured".into(),
+                format!("set $env.{REGISTRY_ENV} to the URL or file path of a registry index"),
+                Some(span),
+                None,
+                vec![],
+            ))
+        }
+    };
+
+    let (contents, base, base_is_url) = if is_url(&registry) {
+        let base = match registry.rsplit_once('/') {
+            Some((base, _)) => base.to_string(),
+            None => registry.clone(),
+        };
+        (fetch(&registry, span)?, base, true)
+    } else {
+        let cwd = current_dir(engine_state, stack)?;
+        let path = nu_path::expand_path_with(&registry, cwd);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+        let base = path
+            .parent()
+            .map(|parent| parent.to_string_lossy().to_string())
+            .unwrap_or_default();
+        (contents, base, false)
+    };
+
+    let value = crate::formats::from_nuon_string_to_value(&contents, span)?;
+    let rows = match value {
+        Value::List { vals, .. } => vals,
+        other => {
+            return Err(registry_error(
+                format!("expected a table of packages, found {}", other.get_type()),
+                span,
+            ))
+        }
+    };
+
+    let mut entries = vec![];
+    for row in rows {
+        entries.push(entry_from_row(&row, span)?);
+    }
+
+    Ok(Registry {
+        entries,
+        base,
+        base_is_url,
+    })
+}
+
+fn entry_from_row(row: &Value, span: Span) -> Result<PkgEntry, ShellError> {
+    let field = |name: &str| -> Result<String, ShellError> {
+        row.get_data_by_key(name)
+            .and_then(|value| value.as_string().ok())
+            .ok_or_else(|| registry_error(format!("a package row is missing '{name}'"), span))
+    };
+
+    let deps = match row.get_data_by_key("deps") {
+        Some(Value::List { vals, .. }) => vals
+            .iter()
+            .filter_map(|value| value.as_string().ok())
+            .collect(),
+        _ => vec![],
+    };
+
+    Ok(PkgEntry {
+        name: field("name")?,
+        version: field("version")?,
+        url: field("url")?,
+        sha256: row
+            .get_data_by_key("sha256")
+            .and_then(|value| value.as_string().ok()),
+        deps,
+    })
+}
+
+/// A sortable form of a dotted version string
+pub fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| {
+            part.chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// The newest registry entry for `name`, or the pinned version if given
+pub fn find_entry<'a>(
+    registry: &'a Registry,
+    name: &str,
+    version: Option<&str>,
+) -> Option<&'a PkgEntry> {
+    let mut candidates = registry.entries.iter().filter(|entry| entry.name == name);
+
+    match version {
+        Some(version) => candidates.find(|entry| entry.version == version),
+        None => candidates.max_by_key(|entry| version_key(&entry.version)),
+    }
+}
+
+/// `entry` and everything it depends on, dependencies first
+pub fn resolve_deps<'a>(
+    registry: &'a Registry,
+    entry: &'a PkgEntry,
+    span: Span,
+) -> Result<Vec<&'a PkgEntry>, ShellError> {
+    let mut ordered: Vec<&PkgEntry> = vec![];
+    let mut visited: Vec<&str> = vec![];
+    resolve_into(registry, entry, &mut ordered, &mut visited, span)?;
+    Ok(ordered)
+}
+
+fn resolve_into<'a>(
+    registry: &'a Registry,
+    entry: &'a PkgEntry,
+    ordered: &mut Vec<&'a PkgEntry>,
+    visited: &mut Vec<&'a str>,
+    span: Span,
+) -> Result<(), ShellError> {
+    if visited.contains(&entry.name.as_str()) {
+        return Ok(());
+    }
+    visited.push(&entry.name);
+
+    for dep in &entry.deps {
+        let dep_entry = find_entry(registry, dep, None).ok_or_else(|| {
+            registry_error(
+                format!(
+                    "'{}' depends on '{dep}', which is not in the registry",
+                    entry.name
+                ),
+                span,
+            )
+        })?;
+        resolve_into(registry, dep_entry, ordered, visited, span)?;
+    }
+
+    ordered.push(entry);
+    Ok(())
+}
+
+/// Download (or copy) a package module into the managed directory
+///
+/// Returns the content hash recorded in the lockfile.
+pub fn install_entry(
+    registry: &Registry,
+    entry: &PkgEntry,
+    packages_dir: &std::path::Path,
+    span: Span,
+) -> Result<String, ShellError> {
+    let contents = if is_url(&entry.url) {
+        fetch(&entry.url, span)?
+    } else if registry.base_is_url {
+        fetch(&format!("{}/{}", registry.base, entry.url), span)?
+    } else {
+        let path = nu_path::expand_path_with(&entry.url, &registry.base);
+        std::fs::read_to_string(path)
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?
+    };
+
+    let hash = content_hash(&contents);
+    if let Some(pin) = &entry.sha256 {
+        if &hash != pin {
+            return Err(ShellError::GenericError(
+                format!("Package hash mismatch for '{}'", entry.name),
+                format!("the registry pins {pin} but the module hashes to {hash}"),
+                Some(span),
+                None,
+                vec![],
+            ));
+        }
+    }
+
+    let module_dir = packages_dir.join(&entry.name);
+    std::fs::create_dir_all(&module_dir)
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+    std::fs::write(module_dir.join("mod.nu"), &contents)
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+
+    Ok(hash)
+}
+
+pub fn read_lock(packages_dir: &std::path::Path) -> Vec<(String, String, String)> {
+    let Ok(contents) = std::fs::read_to_string(packages_dir.join(LOCK_FILE_NAME)) else {
+        return vec![];
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split(' ');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(name), Some(version), Some(hash)) => {
+                    Some((name.to_string(), version.to_string(), hash.to_string()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+pub fn write_lock(
+    packages_dir: &std::path::Path,
+    entries: &[(String, String, String)],
+    span: Span,
+) -> Result<(), ShellError> {
+    let mut contents = String::new();
+    for (name, version, hash) in entries {
+        contents.push_str(&format!("{name} {version} {hash}\n"));
+    }
+
+    std::fs::create_dir_all(packages_dir)
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+    std::fs::write(packages_dir.join(LOCK_FILE_NAME), contents)
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))
+}
+
+/// Record `name` at `version` in the lock entries, replacing any older line
+pub fn set_locked(
+    lock: &mut Vec<(String, String, String)>,
+    name: &str,
+    version: &str,
+    hash: String,
+) {
+    lock.retain(|(locked, _, _)| locked != name);
+    lock.push((name.to_string(), version.to_string(), hash));
+    lock.sort();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_sort_numerically() {
+        assert!(version_key("0.10.0") > version_key("0.9.1"));
+        assert!(version_key("1.0") > version_key("0.99.99"));
+        assert_eq!(version_key("1.2.3"), vec![1, 2, 3]);
+    }
+}
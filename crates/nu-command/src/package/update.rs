@@ -0,0 +1,109 @@
+use super::pkg;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct PackageUpdate;
+
+impl Command for PackageUpdate {
+    fn name(&self) -> &str {
+        "package update"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("package update")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .rest(
+                "name",
+                SyntaxShape::String,
+                "the packages to update (defaults to all of them)",
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Update installed packages to the newest versions in the registry."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["nupm", "module", "upgrade"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let names: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+
+        let registry = pkg::load_registry(engine_state, stack, span)?;
+        let packages_dir = pkg::packages_dir(engine_state, stack, span)?;
+        let mut lock = pkg::read_lock(&packages_dir);
+        let mut report = vec![];
+
+        for (name, version, _) in pkg::read_lock(&packages_dir) {
+            if !names.is_empty() && !names.iter().any(|wanted| wanted.item == name) {
+                continue;
+            }
+
+            let latest = match pkg::find_entry(&registry, &name, None) {
+                Some(latest) => latest,
+                // a package that left the registry stays as it is
+                None => continue,
+            };
+
+            if pkg::version_key(&latest.version) <= pkg::version_key(&version) {
+                continue;
+            }
+
+            for entry in pkg::resolve_deps(&registry, latest, span)? {
+                if lock
+                    .iter()
+                    .any(|(name, version, _)| name == &entry.name && version == &entry.version)
+                {
+                    continue;
+                }
+                let hash = pkg::install_entry(&registry, entry, &packages_dir, span)?;
+                pkg::set_locked(&mut lock, &entry.name, &entry.version, hash);
+            }
+
+            report.push(Value::Record {
+                cols: vec!["name".into(), "from".into(), "to".into()],
+                vals: vec![
+                    Value::string(&name, span),
+                    Value::string(&version, span),
+                    Value::string(&latest.version, span),
+                ],
+                span,
+            });
+        }
+
+        pkg::write_lock(&packages_dir, &lock, span)?;
+
+        Ok(Value::List { vals: report, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Update everything the lockfile knows about",
+                example: "package update",
+                result: None,
+            },
+            Example {
+                description: "Update a single package",
+                example: "package update my-utils",
+                result: None,
+            },
+        ]
+    }
+}
@@ -2,7 +2,7 @@ use crate::input_handler::{operate, CellPathOnlyArgs};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call, ast::CellPath, engine::Command, engine::EngineState, engine::Stack, Category,
-    Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Example, PipelineData, RawStream, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -15,7 +15,11 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("ansi strip")
-            .input_output_types(vec![(Type::String, Type::String)])
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Record(vec![]), Type::Record(vec![])),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
             .rest(
                 "cell path",
                 SyntaxShape::CellPath,
@@ -28,6 +32,10 @@ impl Command for SubCommand {
         "Strip ANSI escape sequences from a string."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Records and tables are stripped across every string cell. External streams are stripped chunk by chunk as they arrive, without collecting the whole stream first."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -36,24 +44,66 @@ impl Command for SubCommand {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        if let PipelineData::ExternalStream {
+            stdout: Some(stream),
+            stderr,
+            exit_code,
+            span,
+            metadata,
+            trim_end_newline,
+        } = input
+        {
+            // Strip each chunk as it comes in rather than collecting the
+            // stream; an escape sequence can straddle a chunk boundary, so a
+            // partial trailing sequence is carried over to the next chunk
+            let stripped = RawStream::new(
+                Box::new(StripStream {
+                    stream: stream.stream,
+                    carry: Vec::new(),
+                }),
+                stream.ctrlc,
+                stream.span,
+                None,
+            );
+            return Ok(PipelineData::ExternalStream {
+                stdout: Some(stripped),
+                stderr,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+            });
+        }
         let arg = CellPathOnlyArgs::from(cell_paths);
         operate(action, arg, input, call.head, engine_state.ctrlc.clone())
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Strip ANSI escape sequences from a string",
-            example: r#"$'(ansi green)(ansi cursor_on)hello' | ansi strip"#,
-            result: Some(Value::test_string("hello")),
-        }]
+        vec![
+            Example {
+                description: "Strip ANSI escape sequences from a string",
+                example: r#"$'(ansi green)(ansi cursor_on)hello' | ansi strip"#,
+                result: Some(Value::test_string("hello")),
+            },
+            Example {
+                description: "Strip ANSI escape sequences from every string cell of a table",
+                example: r#"[[name]; [$'(ansi green)hello']] | ansi strip"#,
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["name".into()],
+                        vals: vec![Value::test_string("hello")],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 }
 
 fn action(input: &Value, _args: &CellPathOnlyArgs, command_span: Span) -> Value {
     match input {
-        Value::String { val, span } => {
-            Value::string(nu_utils::strip_ansi_likely(val).to_string(), *span)
-        }
+        Value::String { .. } | Value::Record { .. } | Value::List { .. } => strip_value(input),
         other => {
             let got = format!("value is {}, not string", other.get_type());
 
@@ -67,9 +117,82 @@ fn action(input: &Value, _args: &CellPathOnlyArgs, command_span: Span) -> Value
     }
 }
 
+/// Strip every string found in the value, leaving other cells untouched
+fn strip_value(input: &Value) -> Value {
+    match input {
+        Value::String { val, span } => {
+            Value::string(nu_utils::strip_ansi_likely(val).to_string(), *span)
+        }
+        Value::Record { cols, vals, span } => Value::Record {
+            cols: cols.clone(),
+            vals: vals.iter().map(strip_value).collect(),
+            span: *span,
+        },
+        Value::List { vals, span } => Value::List {
+            vals: vals.iter().map(strip_value).collect(),
+            span: *span,
+        },
+        other => other.clone(),
+    }
+}
+
+struct StripStream {
+    stream: Box<dyn Iterator<Item = Result<Vec<u8>, ShellError>> + Send + 'static>,
+    carry: Vec<u8>,
+}
+
+impl Iterator for StripStream {
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.stream.next() {
+            Some(Ok(chunk)) => {
+                let mut bytes = std::mem::take(&mut self.carry);
+                bytes.extend(chunk);
+                if let Some(at) = trailing_partial_escape(&bytes) {
+                    self.carry = bytes.split_off(at);
+                }
+                Some(Ok(nu_utils::strip_ansi_bytes_likely(&bytes).into_owned()))
+            }
+            Some(Err(err)) => Some(Err(err)),
+            None if self.carry.is_empty() => None,
+            None => {
+                // the stream ended inside an escape sequence; strip what can
+                // be stripped and emit the rest as-is
+                let carry = std::mem::take(&mut self.carry);
+                Some(Ok(nu_utils::strip_ansi_bytes_likely(&carry).into_owned()))
+            }
+        }
+    }
+}
+
+/// If the chunk ends inside an unfinished escape sequence, the index where
+/// that sequence starts
+fn trailing_partial_escape(bytes: &[u8]) -> Option<usize> {
+    let esc = bytes.iter().rposition(|b| *b == 0x1b)?;
+    let complete = match bytes.get(esc + 1) {
+        // the chunk ends right at the escape character
+        None => false,
+        // CSI: terminated by a byte in `@`..=`~`
+        Some(b'[') => bytes[esc + 2..].iter().any(|b| (0x40..=0x7e).contains(b)),
+        // OSC: terminated by BEL or ST (ESC `\`)
+        Some(b']') => {
+            bytes[esc + 2..].contains(&0x07)
+                || bytes[esc + 2..].windows(2).any(|pair| pair == b"\x1b\\")
+        }
+        // any other two-byte escape is already complete
+        Some(_) => true,
+    };
+    if complete {
+        None
+    } else {
+        Some(esc)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{action, SubCommand};
+    use super::{action, trailing_partial_escape, SubCommand};
     use nu_protocol::{Span, Value};
 
     #[test]
@@ -88,4 +211,32 @@ mod tests {
         let actual = action(&input_string, &vec![].into(), Span::test_data());
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn strips_every_string_cell_of_a_record() {
+        let input = Value::Record {
+            cols: vec!["name".into(), "count".into()],
+            vals: vec![
+                Value::test_string("\u{1b}[32mhello\u{1b}[0m"),
+                Value::test_int(3),
+            ],
+            span: Span::test_data(),
+        };
+        let expected = Value::Record {
+            cols: vec!["name".into(), "count".into()],
+            vals: vec![Value::test_string("hello"), Value::test_int(3)],
+            span: Span::test_data(),
+        };
+
+        let actual = action(&input, &vec![].into(), Span::test_data());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn detects_partial_trailing_escapes() {
+        assert_eq!(trailing_partial_escape(b"hello \x1b[3"), Some(6));
+        assert_eq!(trailing_partial_escape(b"hello \x1b"), Some(6));
+        assert_eq!(trailing_partial_escape(b"hello \x1b[32m"), None);
+        assert_eq!(trailing_partial_escape(b"plain text"), None);
+    }
 }
@@ -4,6 +4,7 @@ mod dir_info;
 mod du;
 mod input;
 mod kill;
+mod progress;
 mod reedline_commands;
 mod sleep;
 mod term_size;
@@ -14,6 +15,7 @@ pub use dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use du::Du;
 pub use input::Input;
 pub use kill::Kill;
+pub use progress::Progress;
 pub use reedline_commands::{Keybindings, KeybindingsDefault, KeybindingsList, KeybindingsListen};
 pub use sleep::Sleep;
 pub use term_size::TermSize;
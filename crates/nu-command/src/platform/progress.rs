@@ -0,0 +1,215 @@
+use crate::progress_bar::NuProgressBar;
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, RawStream, ShellError,
+    Signature, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Progress;
+
+impl Command for Progress {
+    fn name(&self) -> &str {
+        "progress"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("progress")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .allow_variants_without_examples(true)
+            .optional(
+                "closure",
+                SyntaxShape::Closure(None),
+                "the closure to run; its output is passed through with a progress bar",
+            )
+            .named(
+                "total",
+                SyntaxShape::Int,
+                "the expected total (bytes for byte streams, rows otherwise), used to show an ETA",
+                Some('t'),
+            )
+            .filter()
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Draw a progress bar on stderr while a stream is consumed."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The bar tracks bytes for external streams and rows for everything else. It is only drawn when stderr is a terminal; its style can be changed with $env.config.progress_bar_style."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["bar", "spinner", "eta", "throughput"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let closure: Option<Closure> = call.opt(engine_state, stack, 0)?;
+        let total: Option<i64> = call.get_flag(engine_state, stack, "total")?;
+
+        let data = match closure {
+            Some(closure) => {
+                let block = engine_state.get_block(closure.block_id);
+                let mut stack = stack.captures_to_stack(&closure.captures);
+
+                eval_block_with_early_return(
+                    engine_state,
+                    &mut stack,
+                    block,
+                    input,
+                    call.redirect_stdout,
+                    call.redirect_stderr,
+                )?
+            }
+            None => input,
+        };
+
+        // drawing over a pipe or a file would only mangle the output
+        if !atty::is(atty::Stream::Stderr) {
+            return Ok(data);
+        }
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let style = engine_state.config.progress_bar_style.clone();
+
+        match data {
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                stderr,
+                exit_code,
+                span: stream_span,
+                metadata,
+                trim_end_newline,
+            } => {
+                let total = total.map(|t| t as u64).or(stream.known_size);
+                let bar = NuProgressBar::new_with_style(total, &style);
+
+                let mut wrapped = RawStream::new(
+                    Box::new(ProgressIter::new(stream.stream, bar)),
+                    stream.ctrlc,
+                    stream.span,
+                    stream.known_size,
+                );
+                wrapped.leftover = stream.leftover;
+                wrapped.is_binary = stream.is_binary;
+
+                Ok(PipelineData::ExternalStream {
+                    stdout: Some(wrapped),
+                    stderr,
+                    exit_code,
+                    span: stream_span,
+                    metadata,
+                    trim_end_newline,
+                })
+            }
+            PipelineData::Value(Value::Range { .. }, ..)
+            | PipelineData::Value(Value::List { .. }, ..)
+            | PipelineData::ListStream { .. } => {
+                let total = total.map(|t| t as u64);
+                let bar = NuProgressBar::new_with_style(total, &style);
+
+                Ok(ProgressIter::new(data.into_iter(), bar).into_pipeline_data(ctrlc))
+            }
+            // a single value finishes instantly; nothing worth drawing
+            data => Ok(data),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the progress of a download",
+                example: "progress { http get https://www.example.com/large.bin } | save large.bin",
+                result: None,
+            },
+            Example {
+                description: "Track rows flowing through a pipeline, with an ETA",
+                example: "ls | progress | each {|row| $row.name }",
+                result: None,
+            },
+        ]
+    }
+}
+
+// Counts what flows through an iterator and keeps the bar up to date, finishing
+// it when the stream ends.
+struct ProgressIter<I, T> {
+    iter: I,
+    bar: NuProgressBar,
+    processed: u64,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<I, T> ProgressIter<I, T> {
+    fn new(iter: I, bar: NuProgressBar) -> Self {
+        Self {
+            iter,
+            bar,
+            processed: 0,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+trait ProgressWeight {
+    fn weight(&self) -> u64;
+}
+
+impl ProgressWeight for Value {
+    fn weight(&self) -> u64 {
+        1
+    }
+}
+
+impl ProgressWeight for Result<Vec<u8>, ShellError> {
+    fn weight(&self) -> u64 {
+        match self {
+            Ok(bytes) => bytes.len() as u64,
+            Err(_) => 0,
+        }
+    }
+}
+
+impl<I, T> Iterator for ProgressIter<I, T>
+where
+    I: Iterator<Item = T>,
+    T: ProgressWeight,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.iter.next() {
+            Some(item) => {
+                self.processed += item.weight();
+                self.bar.update_bar(self.processed);
+                Some(item)
+            }
+            None => {
+                self.bar.finished_msg("done".to_string());
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Progress {})
+    }
+}
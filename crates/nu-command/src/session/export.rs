@@ -0,0 +1,189 @@
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, SessionEntry, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+use super::log::read_log;
+
+#[derive(Clone)]
+pub struct SessionExport;
+
+impl Command for SessionExport {
+    fn name(&self) -> &str {
+        "session export"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("session export")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+            .required("file", SyntaxShape::Filepath, "the log to export")
+            .named(
+                "format",
+                SyntaxShape::String,
+                "the output format: 'md' (default) or 'asciicast'",
+                Some('f'),
+            )
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Turn a recorded session into a markdown transcript or an asciicast."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The document is returned as a string; pipe it into `save` to keep it."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["asciinema", "markdown", "transcript"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let file: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
+        let head = call.head;
+
+        let cwd = current_dir(engine_state, stack)?;
+        let path = nu_path::expand_path_with(&file.item, cwd);
+        let entries = read_log(&path, head)?;
+
+        let rendered = match format.as_ref().map(|format| format.item.as_str()) {
+            None | Some("md") | Some("markdown") => render_markdown(&entries),
+            Some("asciicast") | Some("cast") => render_asciicast(&entries),
+            Some(other) => {
+                return Err(ShellError::GenericError(
+                    format!("Unknown export format '{other}'"),
+                    "expected 'md' or 'asciicast'".into(),
+                    Some(format.map(|format| format.span).unwrap_or(head)),
+                    None,
+                    vec![],
+                ))
+            }
+        };
+
+        Ok(Value::String {
+            val: rendered,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render a recorded session as a markdown transcript",
+                example: "session export demo.nuon | save demo.md",
+                result: None,
+            },
+            Example {
+                description: "Render a recorded session as an asciinema cast",
+                example: "session export demo.nuon --format asciicast | save demo.cast",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn render_markdown(entries: &[SessionEntry]) -> String {
+    let mut out = String::from("# Session transcript\n");
+
+    for entry in entries {
+        out.push_str(&format!("\n```nu\n> {}\n", entry.command));
+        if !entry.output.is_empty() {
+            out.push_str(&entry.output);
+            out.push('\n');
+        }
+        out.push_str("```\n");
+    }
+
+    out
+}
+
+/// An asciicast v2 document: a JSON header line, then one JSON event per line
+fn render_asciicast(entries: &[SessionEntry]) -> String {
+    let mut out = String::from("{\"version\": 2, \"width\": 80, \"height\": 24}\n");
+
+    for entry in entries {
+        let offset = entry.offset_ms as f64 / 1000.0;
+        out.push_str(&format!(
+            "[{:.3}, \"o\", {}]\n",
+            offset,
+            json_string(&format!("> {}\r\n", entry.command))
+        ));
+        if !entry.output.is_empty() {
+            let output = format!("{}\r\n", entry.output.replace('\n', "\r\n"));
+            out.push_str(&format!(
+                "[{:.3}, \"o\", {}]\n",
+                offset + entry.duration_ms as f64 / 1000.0,
+                json_string(&output)
+            ));
+        }
+    }
+
+    out
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(command: &str, output: &str) -> SessionEntry {
+        SessionEntry {
+            offset_ms: 1500,
+            duration_ms: 250,
+            command: command.to_string(),
+            output: output.to_string(),
+        }
+    }
+
+    #[test]
+    fn markdown_wraps_commands_in_code_blocks() {
+        let rendered = render_markdown(&[entry("ls", "foo.txt")]);
+
+        assert!(rendered.contains("```nu\n> ls\nfoo.txt\n```"));
+    }
+
+    #[test]
+    fn asciicast_escapes_event_text() {
+        let rendered = render_asciicast(&[entry("echo \"hi\"", "hi")]);
+        let mut lines = rendered.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("{\"version\": 2, \"width\": 80, \"height\": 24}")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("[1.500, \"o\", \"> echo \\\"hi\\\"\\r\\n\"]")
+        );
+        assert_eq!(lines.next(), Some("[1.750, \"o\", \"hi\\r\\n\"]"));
+    }
+}
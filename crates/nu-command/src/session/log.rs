@@ -0,0 +1,83 @@
+//! Reading and writing the NUON logs produced by `session stop`.
+
+use nu_protocol::engine::SessionEntry;
+use nu_protocol::{ShellError, Span, Value};
+
+fn log_error(msg: String, span: Span) -> ShellError {
+    ShellError::GenericError("Invalid session log".into(), msg, Some(span), None, vec![])
+}
+
+/// Read a log written by `session stop` back into entries
+pub fn read_log(path: &std::path::Path, span: Span) -> Result<Vec<SessionEntry>, ShellError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), span))?;
+    let value = crate::formats::from_nuon_string_to_value(&contents, span)?;
+
+    let rows = match value {
+        Value::List { vals, .. } => vals,
+        other => {
+            return Err(log_error(
+                format!("expected a table of entries, found {}", other.get_type()),
+                span,
+            ))
+        }
+    };
+
+    rows.iter().map(|row| entry_from_row(row, span)).collect()
+}
+
+fn entry_from_row(row: &Value, span: Span) -> Result<SessionEntry, ShellError> {
+    let int = |name: &str| -> Result<i64, ShellError> {
+        row.get_data_by_key(name)
+            .and_then(|value| value.as_i64().ok())
+            .ok_or_else(|| log_error(format!("a log row is missing '{name}'"), span))
+    };
+    let string = |name: &str| -> Result<String, ShellError> {
+        row.get_data_by_key(name)
+            .and_then(|value| value.as_string().ok())
+            .ok_or_else(|| log_error(format!("a log row is missing '{name}'"), span))
+    };
+
+    Ok(SessionEntry {
+        offset_ms: int("offset_ms")?,
+        duration_ms: int("duration_ms")?,
+        command: string("command")?,
+        output: string("output")?,
+    })
+}
+
+/// The NUON table `session stop` writes
+pub fn log_to_nuon(entries: &[SessionEntry], span: Span) -> Result<String, ShellError> {
+    let vals = entries
+        .iter()
+        .map(|entry| Value::Record {
+            cols: vec![
+                "offset_ms".into(),
+                "duration_ms".into(),
+                "command".into(),
+                "output".into(),
+            ],
+            vals: vec![
+                Value::Int {
+                    val: entry.offset_ms,
+                    span,
+                },
+                Value::Int {
+                    val: entry.duration_ms,
+                    span,
+                },
+                Value::String {
+                    val: entry.command.clone(),
+                    span,
+                },
+                Value::String {
+                    val: entry.output.clone(),
+                    span,
+                },
+            ],
+            span,
+        })
+        .collect();
+
+    crate::formats::value_to_string(&Value::List { vals, span }, span)
+}
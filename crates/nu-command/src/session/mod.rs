@@ -0,0 +1,12 @@
+mod export;
+mod log;
+mod record;
+mod replay;
+mod session_;
+mod stop;
+
+pub use export::SessionExport;
+pub use record::SessionRecord;
+pub use replay::SessionReplay;
+pub use session_::Session;
+pub use stop::SessionStop;
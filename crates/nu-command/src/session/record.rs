@@ -0,0 +1,81 @@
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, SessionRecording, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct SessionRecord;
+
+impl Command for SessionRecord {
+    fn name(&self) -> &str {
+        "session record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("session record")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("file", SyntaxShape::Filepath, "where to save the log")
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Start recording the commands run in this interactive session."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every executed line is captured with its timing. The output text comes from
+$env.LAST_OUTPUT, so enable keep_last_output in the config to record it.
+
+Stop and save the log with `session stop`, then print it back with
+`session replay` or turn it into a document with `session export`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["asciinema", "transcript", "demo", "capture"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let file: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+        let path = nu_path::expand_path_with(&file.item, cwd);
+
+        let mut recording = engine_state
+            .session_recording
+            .lock()
+            .expect("session recording mutex");
+
+        if let Some(active) = recording.as_ref() {
+            return Err(ShellError::GenericError(
+                "A session recording is already in progress".into(),
+                format!(
+                    "run `session stop` to finish the recording to {}",
+                    active.path.display()
+                ),
+                Some(call.head),
+                None,
+                vec![],
+            ));
+        }
+
+        *recording = Some(SessionRecording::new(path));
+
+        Ok(PipelineData::new_with_metadata(None, call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Start recording this session to a log file",
+            example: "session record demo.nuon",
+            result: None,
+        }]
+    }
+}
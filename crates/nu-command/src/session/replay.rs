@@ -0,0 +1,108 @@
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::log::read_log;
+
+#[derive(Clone)]
+pub struct SessionReplay;
+
+impl Command for SessionReplay {
+    fn name(&self) -> &str {
+        "session replay"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("session replay")
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::String)))])
+            .allow_variants_without_examples(true)
+            .required("file", SyntaxShape::Filepath, "the log to replay")
+            .switch(
+                "timing",
+                "pause between commands to match the original pacing",
+                Some('t'),
+            )
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Print a recorded session back, command by command."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let file: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let timing = call.has_flag("timing");
+        let head = call.head;
+
+        let cwd = current_dir(engine_state, stack)?;
+        let path = nu_path::expand_path_with(&file.item, cwd);
+        let entries = read_log(&path, head)?;
+
+        let mut events = vec![];
+        let mut previous_offset = 0;
+        for entry in entries {
+            let delay = if timing {
+                (entry.offset_ms - previous_offset).max(0) as u64
+            } else {
+                0
+            };
+            previous_offset = entry.offset_ms;
+
+            events.push((
+                delay,
+                Value::String {
+                    val: format!("> {}", entry.command),
+                    span: head,
+                },
+            ));
+            if !entry.output.is_empty() {
+                events.push((
+                    0,
+                    Value::String {
+                        val: entry.output,
+                        span: head,
+                    },
+                ));
+            }
+        }
+
+        Ok(ReplayIterator {
+            events: events.into_iter(),
+        }
+        .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Replay a recorded session with its original pacing",
+            example: "session replay demo.nuon --timing",
+            result: None,
+        }]
+    }
+}
+
+struct ReplayIterator {
+    events: std::vec::IntoIter<(u64, Value)>,
+}
+
+impl Iterator for ReplayIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (delay, value) = self.events.next()?;
+        if delay > 0 {
+            std::thread::sleep(std::time::Duration::from_millis(delay));
+        }
+        Some(value)
+    }
+}
@@ -0,0 +1,76 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::log::log_to_nuon;
+
+#[derive(Clone)]
+pub struct SessionStop;
+
+impl Command for SessionStop {
+    fn name(&self) -> &str {
+        "session stop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("session stop")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+            .category(Category::Misc)
+    }
+
+    fn usage(&self) -> &str {
+        "Stop the current session recording and write the log."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns the path the log was written to."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let recording = engine_state
+            .session_recording
+            .lock()
+            .expect("session recording mutex")
+            .take();
+
+        let recording = match recording {
+            Some(recording) => recording,
+            None => {
+                return Err(ShellError::GenericError(
+                    "No session recording in progress".into(),
+                    "start one with `session record <file>`".into(),
+                    Some(call.head),
+                    None,
+                    vec![],
+                ))
+            }
+        };
+
+        let contents = log_to_nuon(&recording.entries, call.head)?;
+        std::fs::write(&recording.path, contents)
+            .map_err(|err| ShellError::IOErrorSpanned(err.to_string(), call.head))?;
+
+        Ok(Value::String {
+            val: recording.path.to_string_lossy().to_string(),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Finish a recording and save its log",
+            example: "session stop",
+            result: None,
+        }]
+    }
+}
@@ -132,105 +132,140 @@ pub fn sort(
             });
         }
         _ => {
-            vec.sort_by(|a, b| {
-                if insensitive {
-                    let lowercase_left = match a {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => a.clone(),
-                    };
-
-                    let lowercase_right = match b {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => b.clone(),
-                    };
-
-                    if natural {
-                        match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                            (Ok(left), Ok(right)) => compare_str(left, right),
-                            _ => Ordering::Equal,
-                        }
-                    } else {
-                        lowercase_left
-                            .partial_cmp(&lowercase_right)
-                            .unwrap_or(Ordering::Equal)
-                    }
-                } else if natural {
-                    match (a.as_string(), b.as_string()) {
-                        (Ok(left), Ok(right)) => compare_str(left, right),
-                        _ => Ordering::Equal,
-                    }
-                } else {
-                    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+            vec.sort_by(|a, b| compare_values(a, b, insensitive, natural));
+        }
+    }
+    Ok(())
+}
+
+/// Like [`sort`], but each column carries its own direction: a key is a column
+/// name plus whether that column sorts in reverse.
+pub fn sort_by_keys(
+    vec: &mut [Value],
+    keys: Vec<(String, bool)>,
+    span: Span,
+    insensitive: bool,
+    natural: bool,
+) -> Result<(), ShellError> {
+    if vec.is_empty() {
+        return Err(ShellError::GenericError(
+            "no values to work with".to_string(),
+            "".to_string(),
+            None,
+            Some("no values to work with".to_string()),
+            Vec::new(),
+        ));
+    }
+
+    match &vec[0] {
+        Value::Record {
+            cols,
+            vals: _input_vals,
+            span: val_span,
+        } => {
+            if keys.is_empty() {
+                return Err(ShellError::GenericError(
+                    "expected name".into(),
+                    "requires a column name to sort table data".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+
+            let columns: Vec<String> = keys.iter().map(|(column, _)| column.clone()).collect();
+            if let Some(nonexistent) = nonexistent_column(columns.clone(), cols.to_vec()) {
+                return Err(ShellError::CantFindColumn {
+                    col_name: nonexistent,
+                    span,
+                    src_span: *val_span,
+                });
+            }
+
+            let mut vals = vec![];
+            for item in vec.iter() {
+                for col in &columns {
+                    let val = item
+                        .get_data_by_key(col)
+                        .unwrap_or_else(|| Value::nothing(Span::unknown()));
+                    vals.push(val);
                 }
+            }
+
+            let should_sort_case_insensitively = insensitive
+                && vals
+                    .iter()
+                    .all(|x| matches!(x.get_type(), nu_protocol::Type::String));
+
+            let should_sort_case_naturally = natural
+                && vals
+                    .iter()
+                    .all(|x| matches!(x.get_type(), nu_protocol::Type::String));
+
+            vec.sort_by(|a, b| {
+                compare_with_directions(
+                    a,
+                    b,
+                    &keys,
+                    span,
+                    should_sort_case_insensitively,
+                    should_sort_case_naturally,
+                )
             });
         }
+        _ => {
+            vec.sort_by(|a, b| compare_values(a, b, insensitive, natural));
+        }
     }
     Ok(())
 }
 
-pub fn compare(
+/// Like [`compare`], but honoring each key's direction
+fn compare_with_directions(
     left: &Value,
     right: &Value,
-    columns: &[String],
+    keys: &[(String, bool)],
     span: Span,
     insensitive: bool,
     natural: bool,
 ) -> Ordering {
-    for column in columns {
-        let left_value = left.get_data_by_key(column);
-
-        let left_res = match left_value {
-            Some(left_res) => left_res,
-            None => Value::Nothing { span },
-        };
+    for (column, reverse) in keys {
+        let left_res = left
+            .get_data_by_key(column)
+            .unwrap_or(Value::Nothing { span });
+        let right_res = right
+            .get_data_by_key(column)
+            .unwrap_or(Value::Nothing { span });
 
-        let right_value = right.get_data_by_key(column);
+        let mut result = compare_values(&left_res, &right_res, insensitive, natural);
+        if *reverse {
+            result = result.reverse();
+        }
+        if result != Ordering::Equal {
+            return result;
+        }
+    }
 
-        let right_res = match right_value {
-            Some(right_res) => right_res,
-            None => Value::Nothing { span },
-        };
+    Ordering::Equal
+}
 
-        let result = if insensitive {
-            let lowercase_left = match left_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => left_res,
-            };
+pub fn compare(
+    left: &Value,
+    right: &Value,
+    columns: &[String],
+    span: Span,
+    insensitive: bool,
+    natural: bool,
+) -> Ordering {
+    for column in columns {
+        let left_res = left
+            .get_data_by_key(column)
+            .unwrap_or(Value::Nothing { span });
+        let right_res = right
+            .get_data_by_key(column)
+            .unwrap_or(Value::Nothing { span });
 
-            let lowercase_right = match right_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => right_res,
-            };
-            if natural {
-                match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                    (Ok(left), Ok(right)) => compare_str(left, right),
-                    _ => Ordering::Equal,
-                }
-            } else {
-                lowercase_left
-                    .partial_cmp(&lowercase_right)
-                    .unwrap_or(Ordering::Equal)
-            }
-        } else if natural {
-            match (left_res.as_string(), right_res.as_string()) {
-                (Ok(left), Ok(right)) => compare_str(left, right),
-                _ => Ordering::Equal,
-            }
-        } else {
-            left_res.partial_cmp(&right_res).unwrap_or(Ordering::Equal)
-        };
+        let result = compare_values(&left_res, &right_res, insensitive, natural);
         if result != Ordering::Equal {
             return result;
         }
@@ -239,6 +274,37 @@ pub fn compare(
     Ordering::Equal
 }
 
+/// Compare two values the way `sort` and `sort-by` do: optionally
+/// case-insensitively, and optionally in natural order, where runs of digits
+/// inside strings are compared numerically so that `file2` sorts before
+/// `file10`.
+pub fn compare_values(left: &Value, right: &Value, insensitive: bool, natural: bool) -> Ordering {
+    let (left, right) = if insensitive {
+        (lowercase_string(left), lowercase_string(right))
+    } else {
+        (left.clone(), right.clone())
+    };
+
+    if natural {
+        match (left.as_string(), right.as_string()) {
+            (Ok(left), Ok(right)) => compare_str(left, right),
+            _ => Ordering::Equal,
+        }
+    } else {
+        left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+    }
+}
+
+fn lowercase_string(value: &Value) -> Value {
+    match value {
+        Value::String { val, span } => Value::String {
+            val: val.to_ascii_lowercase(),
+            span: *span,
+        },
+        _ => value.clone(),
+    }
+}
+
 #[test]
 fn test_sort_value() {
     let val = Value::List {
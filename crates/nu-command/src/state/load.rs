@@ -0,0 +1,72 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type};
+
+/// Source a saved session snapshot back into the current context.
+#[derive(Clone)]
+pub struct StateLoad;
+
+impl Command for StateLoad {
+    fn name(&self) -> &str {
+        "state load"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("state load")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "filename",
+                SyntaxShape::Filepath,
+                "the session snapshot to restore, as written by `state save`",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Restore variables, custom commands, and aliases from a saved session snapshot."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"This command is a parser keyword. For details, check:
+  https://www.nushell.sh/book/thinking_in_nu.html"#
+    }
+
+    fn is_parser_keyword(&self) -> bool {
+        true
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["session", "snapshot", "restore", "source"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        // Note: this hidden positional is the block_id that corresponded to the 0th position
+        // it is put here by the parser
+        let block_id: i64 = call.req_parser_info(engine_state, stack, 0)?;
+
+        let block = engine_state.get_block(block_id as usize).clone();
+        eval_block_with_early_return(
+            engine_state,
+            stack,
+            &block,
+            input,
+            call.redirect_stdout,
+            call.redirect_stderr,
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Restore session state saved with `state save`",
+            example: r#"state load session.nu"#,
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,7 @@
+mod load;
+mod save;
+mod state_;
+
+pub use load::StateLoad;
+pub use save::StateSave;
+pub use state_::State;
@@ -0,0 +1,207 @@
+use crate::formats::value_to_string;
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack, Visibility};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[derive(Clone)]
+pub struct StateSave;
+
+impl Command for StateSave {
+    fn name(&self) -> &str {
+        "state save"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("state save")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "filename",
+                SyntaxShape::Filepath,
+                "the file to write the session snapshot to",
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Snapshot user-defined variables, custom commands, and aliases to a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The snapshot is a regular script: variables are serialized as NUON and definitions keep their original source. Restore it in a new session with `state load` or `source`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["session", "snapshot", "persist"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let filename: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+
+        let script = build_snapshot(engine_state, stack, call)?;
+        std::fs::write(nu_path::expand_path_with(&filename.item, cwd), script)
+            .map_err(|e| ShellError::IOErrorSpanned(e.to_string(), filename.span))?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Snapshot the current session state to a file",
+            example: "state save session.nu",
+            result: None,
+        }]
+    }
+}
+
+fn build_snapshot(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<String, ShellError> {
+    let span = call.head;
+
+    let mut vars_map = HashMap::new();
+    let mut decls_map = HashMap::new();
+    let mut aliases_map = HashMap::new();
+    let mut visibility = Visibility::new();
+    for overlay_frame in engine_state.active_overlays(&[]) {
+        vars_map.extend(&overlay_frame.vars);
+        decls_map.extend(&overlay_frame.decls);
+        aliases_map.extend(&overlay_frame.aliases);
+        visibility.merge_with(overlay_frame.visibility.clone());
+    }
+
+    let mut script = String::from("# session state saved by `state save`\n");
+
+    // Variables, serialized as NUON. Non-representable values (closures, ...)
+    // are skipped with a note rather than failing the whole snapshot.
+    let mut vars: Vec<_> = vars_map.into_iter().collect();
+    vars.sort_by(|a, b| a.0.cmp(b.0));
+    for (name_bytes, var_id) in vars {
+        let name = String::from_utf8_lossy(name_bytes).to_string();
+        let name = name.strip_prefix('$').unwrap_or(&name).to_string();
+        if matches!(
+            name.as_str(),
+            "nu" | "in" | "env" | "nothing" | "nothing-var"
+        ) {
+            continue;
+        }
+
+        if let Ok(value) = stack.get_var(*var_id, span) {
+            match value_to_string(&value, span) {
+                Ok(nuon) => {
+                    let _ = writeln!(script, "let {name} = {nuon}");
+                }
+                Err(_) => {
+                    let _ = writeln!(script, "# skipped ${name}: value cannot be saved as nuon");
+                }
+            }
+        }
+    }
+
+    // Custom commands, with their body text captured from the original source.
+    let mut decls: Vec<_> = decls_map.into_iter().collect();
+    decls.sort_by(|a, b| a.0 .0.cmp(&b.0 .0));
+    for ((name_bytes, _), decl_id) in decls {
+        if !visibility.is_decl_id_visible(decl_id) {
+            continue;
+        }
+        let decl = engine_state.get_decl(*decl_id);
+        let block_id = match decl.get_block_id() {
+            Some(block_id) => block_id,
+            None => continue,
+        };
+        let block = engine_state.get_block(block_id);
+        let body_span = match block.span {
+            Some(body_span) => body_span,
+            None => continue,
+        };
+
+        let name = String::from_utf8_lossy(name_bytes).to_string();
+        let name = if name.contains(' ') {
+            format!("\"{name}\"")
+        } else {
+            name
+        };
+        let body = String::from_utf8_lossy(engine_state.get_span_contents(&body_span)).to_string();
+        let body = if body.trim_start().starts_with('{') {
+            body
+        } else {
+            format!("{{ {body} }}")
+        };
+
+        let _ = writeln!(
+            script,
+            "def {name} [{}] {body}",
+            render_params(&decl.signature())
+        );
+    }
+
+    // Aliases, reassembled from the spans of their expansion.
+    let mut aliases: Vec<_> = aliases_map.into_iter().collect();
+    aliases.sort_by(|a, b| a.0.cmp(b.0));
+    for (name_bytes, alias_id) in aliases {
+        if !visibility.is_alias_id_visible(alias_id) {
+            continue;
+        }
+        let name = String::from_utf8_lossy(name_bytes).to_string();
+
+        let mut expansion = String::new();
+        for alias_span in engine_state.get_alias(*alias_id) {
+            if !expansion.is_empty() {
+                expansion.push(' ');
+            }
+            expansion.push_str(&String::from_utf8_lossy(
+                engine_state.get_span_contents(alias_span),
+            ));
+        }
+
+        let _ = writeln!(script, "alias {name} = {expansion}");
+    }
+
+    Ok(script)
+}
+
+// Renders the parameter list of a custom command's signature back into the
+// `def` syntax, close enough to re-parse to the same signature.
+fn render_params(sig: &nu_protocol::Signature) -> String {
+    let mut params = vec![];
+
+    for arg in &sig.required_positional {
+        params.push(format!("{}: {}", arg.name, arg.shape));
+    }
+    for arg in &sig.optional_positional {
+        params.push(format!("{}?: {}", arg.name, arg.shape));
+    }
+    if let Some(rest) = &sig.rest_positional {
+        params.push(format!("...{}: {}", rest.name, rest.shape));
+    }
+    for flag in &sig.named {
+        if flag.long == "help" {
+            continue;
+        }
+        let mut rendered = format!("--{}", flag.long);
+        if let Some(short) = flag.short {
+            let _ = write!(rendered, " (-{short})");
+        }
+        if let Some(shape) = &flag.arg {
+            let _ = write!(rendered, ": {shape}");
+        }
+        params.push(rendered);
+    }
+
+    params.join(", ")
+}
@@ -0,0 +1,53 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct State;
+
+impl Command for State {
+    fn name(&self) -> &str {
+        "state"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("state")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Commands for saving and restoring session state."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["session", "snapshot", "persist"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &State.signature(),
+                &State.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -2,7 +2,7 @@ use std::iter::Peekable;
 use std::str::CharIndices;
 
 use nu_engine::CallExt;
-use nu_protocol::ast::Call;
+use nu_protocol::ast::{Call, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
@@ -29,6 +29,17 @@ impl Command for DetectColumns {
             )
             .input_output_types(vec![(Type::String, Type::Table(vec![]))])
             .switch("no-headers", "don't detect headers", Some('n'))
+            .switch(
+                "guess",
+                "detect fixed-width columns from the alignment of all lines, for output like ps or df",
+                Some('g'),
+            )
+            .named(
+                "combine-columns",
+                SyntaxShape::Range,
+                "columns to be combined; listed as a range",
+                Some('c'),
+            )
             .category(Category::Strings)
     }
 
@@ -78,6 +89,25 @@ impl Command for DetectColumns {
                 example: "$'c1 c2 c3(char nl)a b c' | detect columns",
                 result: None,
             },
+            Example {
+                description:
+                    "Detect fixed-width columns, lining up right-aligned values with their header",
+                example:
+                    "$'name   ID(char nl)alice  77(char nl)bob     1' | detect columns --guess",
+                result: None,
+            },
+            Example {
+                description: "Merge overflowed fields back into one column",
+                example: "'a b c' | detect columns -n --combine-columns 1..2",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["column0".to_string(), "column1".to_string()],
+                        vals: vec![Value::test_string("a"), Value::test_string("b c")],
+                        span,
+                    }],
+                    span,
+                }),
+            },
         ]
     }
 }
@@ -91,6 +121,10 @@ fn detect_columns(
     let name_span = call.head;
     let num_rows_to_skip: Option<usize> = call.get_flag(engine_state, stack, "skip")?;
     let noheader = call.has_flag("no-headers");
+    let guess = call.has_flag("guess");
+    let combine: Option<nu_protocol::Range> =
+        call.get_flag(engine_state, stack, "combine-columns")?;
+    let combine = combine.map(|range| CombineRange::from_range(&range));
     let ctrlc = engine_state.ctrlc.clone();
     let config = engine_state.get_config();
     let input = input.collect_string("", config)?;
@@ -102,15 +136,32 @@ fn detect_columns(
         .map(|x| x.to_string())
         .collect();
 
+    // fixed-width boundaries come from the alignment of every line at once
+    let bounds = if guess {
+        Some(guess_boundaries(&input))
+    } else {
+        None
+    };
+    let split = move |line: &str| -> Vec<Spanned<String>> {
+        let cells = match &bounds {
+            Some(bounds) => split_fixed(line, bounds),
+            None => find_columns(line),
+        };
+        match &combine {
+            Some(combine) => combine.merge(cells),
+            None => cells,
+        }
+    };
+
     let mut input = input.into_iter();
     let headers = input.next();
 
     if let Some(orig_headers) = headers {
-        let mut headers = find_columns(&orig_headers);
+        let mut headers = split(&orig_headers);
 
-        if noheader {
-            for header in headers.iter_mut().enumerate() {
-                header.1.item = format!("column{}", header.0);
+        for (i, header) in headers.iter_mut().enumerate() {
+            if noheader || header.item.is_empty() {
+                header.item = format!("column{i}");
             }
         }
 
@@ -120,7 +171,7 @@ fn detect_columns(
             vec![].into_iter().chain(input)
         })
         .map(move |x| {
-            let row = find_columns(&x);
+            let row = split(&x);
 
             let mut cols = vec![];
             let mut vals = vec![];
@@ -205,6 +256,108 @@ pub fn find_columns(input: &str) -> Vec<Spanned<String>> {
     output
 }
 
+/// The column range resolved from `--combine-columns`
+#[derive(Clone, Copy)]
+struct CombineRange {
+    from: Option<i64>,
+    to: Option<i64>,
+    inclusive: bool,
+}
+
+impl CombineRange {
+    fn from_range(range: &nu_protocol::Range) -> Self {
+        let bound = |value: &Value| match value {
+            Value::Int { val, .. } => Some(*val),
+            _ => None,
+        };
+        CombineRange {
+            from: bound(&range.from),
+            to: bound(&range.to),
+            inclusive: range.inclusion == RangeInclusion::Inclusive,
+        }
+    }
+
+    /// Join the cells covered by the range into one, space-separated
+    fn merge(&self, mut cells: Vec<Spanned<String>>) -> Vec<Spanned<String>> {
+        let len = cells.len() as i64;
+        let resolve = |bound: i64| if bound < 0 { len + bound } else { bound };
+        let start = resolve(self.from.unwrap_or(0)).clamp(0, len) as usize;
+        let mut end = resolve(self.to.unwrap_or(len - 1));
+        if self.inclusive {
+            end += 1;
+        }
+        let end = end.clamp(0, len) as usize;
+        if end > start + 1 {
+            let merged = Spanned {
+                item: cells[start..end]
+                    .iter()
+                    .map(|cell| cell.item.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                span: Span::new(cells[start].span.start, cells[end - 1].span.end),
+            };
+            cells.splice(start..end, std::iter::once(merged));
+        }
+        cells
+    }
+}
+
+/// Character ranges that hold non-whitespace in at least one line; the gaps
+/// between them are blank in every line, so they are safe split points even
+/// when cells are right-aligned under their header
+fn guess_boundaries(lines: &[String]) -> Vec<(usize, usize)> {
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let mut occupied = vec![false; width];
+    for line in lines {
+        for (i, c) in line.chars().enumerate() {
+            if !c.is_whitespace() {
+                occupied[i] = true;
+            }
+        }
+    }
+
+    let mut bounds = vec![];
+    let mut start = None;
+    for (i, occupied) in occupied.iter().enumerate() {
+        match (start, occupied) {
+            (None, true) => start = Some(i),
+            (Some(from), false) => {
+                bounds.push((from, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        bounds.push((from, width));
+    }
+    bounds
+}
+
+fn split_fixed(line: &str, bounds: &[(usize, usize)]) -> Vec<Spanned<String>> {
+    let chars: Vec<char> = line.chars().collect();
+    bounds
+        .iter()
+        .map(|&(from, to)| {
+            let to = to.min(chars.len());
+            let from = from.min(to);
+            let cell = &chars[from..to];
+            let leading = cell.iter().take_while(|c| c.is_whitespace()).count();
+            let item: String = cell[leading..]
+                .iter()
+                .collect::<String>()
+                .trim_end()
+                .to_string();
+            let from = from + leading;
+            let width = item.chars().count();
+            Spanned {
+                item,
+                span: Span::new(from, from + width),
+            }
+        })
+        .collect()
+}
+
 #[derive(Clone, Copy)]
 enum BlockKind {
     Paren,
@@ -1,9 +1,10 @@
+use encoding_rs::Decoder;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
-    SyntaxShape, Type, Value,
+    Category, Example, IntoPipelineData, PipelineData, RawStream, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -25,7 +26,12 @@ impl Command for Decode {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("decode")
             .input_output_types(vec![(Type::Binary, Type::String)])
-            .required("encoding", SyntaxShape::String, "the text encoding to use")
+            .optional("encoding", SyntaxShape::String, "the text encoding to use")
+            .switch(
+                "guess",
+                "guess the encoding from the bytes instead of naming it",
+                Some('g'),
+            )
             .category(Category::Strings)
     }
 
@@ -33,6 +39,10 @@ impl Command for Decode {
         r#"Multiple encodings are supported; here are a few:
 big5, euc-jp, euc-kr, gbk, iso-8859-1, utf-16, cp1252, latin5
 
+With --guess the encoding is detected from the bytes instead (BOM, UTF-8
+validity, then common legacy encodings). External streams are transcoded
+chunk by chunk rather than buffered whole.
+
 For a more complete list of encodings please refer to the encoding_rs
 documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
     }
@@ -44,6 +54,11 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
                 example: "^cat myfile.q | decode utf-8",
                 result: None,
             },
+            Example {
+                description: "Guess the encoding instead of naming it",
+                example: "open --raw legacy.txt | decode --guess",
+                result: None,
+            },
             Example {
                 description: "Decode an UTF-16 string into nushell UTF-8 string",
                 example: r#"0x[00 53 00 6F 00 6D 00 65 00 20 00 44 00 61 00 74 00 61] | decode utf-16be"#,
@@ -63,20 +78,77 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        let encoding: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let encoding: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let guess = call.has_flag("guess");
+
+        let encoding = match (encoding, guess) {
+            (Some(_), true) => {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "--guess can't be combined with a named encoding".to_string(),
+                    span: head,
+                })
+            }
+            (None, false) => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "encoding".to_string(),
+                    span: head,
+                })
+            }
+            (encoding, _) => encoding,
+        };
 
         match input {
             PipelineData::ExternalStream { stdout: None, .. } => Ok(PipelineData::empty()),
             PipelineData::ExternalStream {
                 stdout: Some(stream),
                 ..
-            } => {
-                let bytes: Vec<u8> = stream.into_bytes()?.item;
-                super::encoding::decode(head, encoding, &bytes).map(|val| val.into_pipeline_data())
-            }
+            } => match encoding {
+                // guessing needs to see the bytes up front, but with a known
+                // encoding the stream is transcoded chunk by chunk
+                None => {
+                    let bytes: Vec<u8> = stream.into_bytes()?.item;
+                    let encoding = super::encoding::detect_encoding(&bytes);
+                    let (result, ..) = encoding.decode(&bytes);
+                    Ok(Value::String {
+                        val: result.into_owned(),
+                        span: head,
+                    }
+                    .into_pipeline_data())
+                }
+                Some(encoding) => {
+                    let decoder = super::encoding::parse_encoding(encoding.span, &encoding.item)?
+                        .new_decoder();
+                    let ctrlc = engine_state.ctrlc.clone();
+                    let leftover = (!stream.leftover.is_empty()).then_some(Ok(stream.leftover));
+                    let decoded = DecodeStream {
+                        input: leftover.into_iter().chain(stream.stream),
+                        decoder,
+                        done: false,
+                    };
+                    Ok(PipelineData::ExternalStream {
+                        stdout: Some(RawStream::new(Box::new(decoded), ctrlc, head, None)),
+                        stderr: None,
+                        exit_code: None,
+                        span: head,
+                        metadata: None,
+                        trim_end_newline: false,
+                    })
+                }
+            },
             PipelineData::Value(v, ..) => match v {
-                Value::Binary { val: bytes, .. } => super::encoding::decode(head, encoding, &bytes)
-                    .map(|val| val.into_pipeline_data()),
+                Value::Binary { val: bytes, .. } => match encoding {
+                    Some(encoding) => super::encoding::decode(head, encoding, &bytes)
+                        .map(|val| val.into_pipeline_data()),
+                    None => {
+                        let encoding = super::encoding::detect_encoding(&bytes);
+                        let (result, ..) = encoding.decode(&bytes);
+                        Ok(Value::String {
+                            val: result.into_owned(),
+                            span: head,
+                        }
+                        .into_pipeline_data())
+                    }
+                },
                 Value::Error { error } => Err(error),
                 _ => Err(ShellError::OnlySupportsThisInputType {
                     exp_input_type: "binary".into(),
@@ -97,6 +169,49 @@ documentation link at https://docs.rs/encoding_rs/latest/encoding_rs/#statics"#
     }
 }
 
+// Transcodes a byte stream chunk by chunk; the decoder carries partial
+// multi-byte sequences over to the next chunk, and a final empty call flushes
+// whatever is left.
+struct DecodeStream<I> {
+    input: I,
+    decoder: Decoder,
+    done: bool,
+}
+
+impl<I> DecodeStream<I> {
+    fn decode_chunk(&mut self, chunk: &[u8], last: bool) -> Vec<u8> {
+        let capacity = self
+            .decoder
+            .max_utf8_buffer_length(chunk.len())
+            .unwrap_or(chunk.len() * 3 + 4);
+        let mut out = String::with_capacity(capacity);
+        let _ = self.decoder.decode_to_string(chunk, &mut out, last);
+        out.into_bytes()
+    }
+}
+
+impl<I> Iterator for DecodeStream<I>
+where
+    I: Iterator<Item = Result<Vec<u8>, ShellError>>,
+{
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.input.next() {
+            Some(Ok(chunk)) => Some(Ok(self.decode_chunk(&chunk, false))),
+            Some(Err(err)) => Some(Err(err)),
+            None => {
+                self.done = true;
+                let flushed = self.decode_chunk(&[], true);
+                (!flushed.is_empty()).then_some(Ok(flushed))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
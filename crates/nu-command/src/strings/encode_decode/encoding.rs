@@ -1,6 +1,45 @@
-use encoding_rs::Encoding;
+use encoding_rs::{Encoding, BIG5, EUC_JP, EUC_KR, GBK, SHIFT_JIS, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
 use nu_protocol::{ShellError, Span, Spanned, Value};
 
+/// Guess the encoding of a byte buffer: the BOM wins, then valid UTF-8, then a
+/// NUL-byte heuristic for UTF-16, then the first CJK encoding that decodes
+/// cleanly, falling back to windows-1252 (which never fails).
+pub fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _bom_length)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+    // NUL bytes are technically valid UTF-8 but don't appear in text, so
+    // their presence pushes the guess towards UTF-16
+    if !bytes.contains(&0) && std::str::from_utf8(bytes).is_ok() {
+        return UTF_8;
+    }
+
+    // UTF-16 text that is mostly ASCII has a NUL in every other byte
+    let sample = &bytes[..bytes.len().min(4096)];
+    if sample.len() >= 2 {
+        let nul_even = sample.iter().step_by(2).filter(|b| **b == 0).count();
+        let nul_odd = sample.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+        let pairs = sample.len() / 2;
+        if nul_odd * 2 > pairs {
+            return UTF_16LE;
+        }
+        if nul_even * 2 > pairs {
+            return UTF_16BE;
+        }
+    }
+
+    for encoding in [SHIFT_JIS, EUC_JP, GBK, EUC_KR, BIG5] {
+        if encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .is_some()
+        {
+            return encoding;
+        }
+    }
+
+    WINDOWS_1252
+}
+
 pub fn decode(
     head: Span,
     encoding_name: Spanned<String>,
@@ -52,7 +91,7 @@ pub fn encode(
     }
 }
 
-fn parse_encoding(span: Span, label: &str) -> Result<&'static Encoding, ShellError> {
+pub fn parse_encoding(span: Span, label: &str) -> Result<&'static Encoding, ShellError> {
     // Workaround for a bug in the Encodings Specification.
     let label = if label.to_lowercase() == "utf16" {
         "utf-16"
@@ -107,4 +146,27 @@ mod test {
 
         assert_eq!(decoded, expected);
     }
+
+    #[rstest]
+    #[case::utf8("utf-8", "Valid UTF-8 stays UTF-8: あいうえお")]
+    #[case::shift_jis("shift-jis", "何だと？……無駄な努力だ？")]
+    fn detects_the_round_tripped_encoding(#[case] encoding: &str, #[case] text: &str) {
+        let encoding = Encoding::for_label(encoding.as_bytes()).unwrap();
+        let (bytes, ..) = encoding.encode(text);
+        let detected = detect_encoding(&bytes);
+
+        let (decoded, ..) = detected.decode(&bytes);
+        assert_eq!(decoded, text);
+    }
+
+    // encoding_rs never encodes to UTF-16, so build those buffers by hand
+    #[test]
+    fn detects_bomless_utf16() {
+        let text = "mostly ascii text";
+        let little: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let big: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+
+        assert_eq!(detect_encoding(&little), UTF_16LE);
+        assert_eq!(detect_encoding(&big), UTF_16BE);
+    }
 }
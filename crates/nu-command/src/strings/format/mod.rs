@@ -1,5 +1,7 @@
 pub mod command;
 mod filesize;
+mod template;
 
 pub use self::filesize::FileSize;
 pub use command::Format;
+pub use template::FormatTemplate;
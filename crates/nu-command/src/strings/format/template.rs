@@ -0,0 +1,326 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FormatTemplate;
+
+impl Command for FormatTemplate {
+    fn name(&self) -> &str {
+        "format template"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format template")
+            .input_output_types(vec![
+                (Type::Record(vec![]), Type::String),
+                (Type::Table(vec![]), Type::List(Box::new(Type::String))),
+            ])
+            .required(
+                "template",
+                SyntaxShape::String,
+                "the mustache-style template to render the input through",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Render record input through a minimal mustache-style template."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"`{{key}}` interpolates a value (cell paths like `{{a.b}}` work), `{{#key}}...{{/key}}`
+loops over a list or renders once when the value is truthy, `{{^key}}...{{/key}}` renders
+when it is missing or falsy, and `{{.}}` is the current loop element. `{{! ...}}` is a
+comment. Missing keys interpolate as the empty string, so templates stay total."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["mustache", "handlebars", "render", "report", "interpolate"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let template: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let nodes = parse_template(&template.item, template.span)?;
+        let config = engine_state.get_config().clone();
+
+        match input.into_value(head) {
+            Value::List { vals, span } => {
+                let vals = vals
+                    .into_iter()
+                    .map(|context| match render(&nodes, &context, &config) {
+                        Ok(rendered) => Value::string(rendered, span),
+                        Err(error) => Value::Error { error },
+                    })
+                    .collect();
+                Ok(Value::List { vals, span }.into_pipeline_data())
+            }
+            err @ Value::Error { .. } => Ok(err.into_pipeline_data()),
+            context => {
+                Ok(Value::string(render(&nodes, &context, &config)?, head).into_pipeline_data())
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Interpolate record values into a string",
+                example: "{name: nushell, lang: rust} | format template 'The {{name}} shell is written in {{lang}}'",
+                result: Some(Value::test_string("The nushell shell is written in rust")),
+            },
+            Example {
+                description: "Loop over a list with a section",
+                example: "{features: [fast safe]} | format template '{{#features}}[{{.}}]{{/features}}'",
+                result: Some(Value::test_string("[fast][safe]")),
+            },
+            Example {
+                description: "Inverted sections render when the value is missing or false",
+                example: "{name: sam, admin: false} | format template '{{name}}{{^admin}} (read-only){{/admin}}'",
+                result: Some(Value::test_string("sam (read-only)")),
+            },
+        ]
+    }
+}
+
+enum Node {
+    Text(String),
+    Interpolate(String),
+    Section {
+        name: String,
+        inverted: bool,
+        children: Vec<Node>,
+    },
+}
+
+fn template_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Invalid template".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn parse_template(template: &str, span: Span) -> Result<Vec<Node>, ShellError> {
+    // a stack of (section name, inverted, nodes); the bottom entry is the root
+    let mut stack: Vec<(Option<String>, bool, Vec<Node>)> = vec![(None, false, Vec::new())];
+    let mut rest = template;
+
+    while let Some(open) = rest.find("{{") {
+        if !rest[..open].is_empty() {
+            stack
+                .last_mut()
+                .expect("stack never empty")
+                .2
+                .push(Node::Text(rest[..open].to_string()));
+        }
+        let after = &rest[open + 2..];
+        let close = after
+            .find("}}")
+            .ok_or_else(|| template_error("unclosed '{{' tag", span))?;
+        let tag = after[..close].trim();
+        rest = &after[close + 2..];
+
+        match tag.chars().next() {
+            None => return Err(template_error("empty '{{}}' tag", span)),
+            Some('!') => {} // comment
+            Some('#') | Some('^') => {
+                let name = tag[1..].trim().to_string();
+                stack.push((Some(name), tag.starts_with('^'), Vec::new()));
+            }
+            Some('/') => {
+                let name = tag[1..].trim();
+                let (open_name, inverted, children) =
+                    stack.pop().expect("stack never empty");
+                match open_name {
+                    Some(open_name) if open_name == name => {
+                        stack
+                            .last_mut()
+                            .expect("root entry is never popped")
+                            .2
+                            .push(Node::Section {
+                                name: open_name,
+                                inverted,
+                                children,
+                            })
+                    }
+                    Some(open_name) => {
+                        return Err(template_error(
+                            format!("section '{open_name}' closed by '{name}'"),
+                            span,
+                        ))
+                    }
+                    None => {
+                        return Err(template_error(
+                            format!("closing tag '{name}' without an open section"),
+                            span,
+                        ))
+                    }
+                }
+            }
+            Some(_) => stack
+                .last_mut()
+                .expect("stack never empty")
+                .2
+                .push(Node::Interpolate(tag.to_string())),
+        }
+    }
+
+    if !rest.is_empty() {
+        stack
+            .last_mut()
+            .expect("stack never empty")
+            .2
+            .push(Node::Text(rest.to_string()));
+    }
+
+    match stack.pop() {
+        Some((None, _, nodes)) if stack.is_empty() => Ok(nodes),
+        Some((Some(name), _, _)) => Err(template_error(format!("unclosed section '{name}'"), span)),
+        _ => Err(template_error("unbalanced sections", span)),
+    }
+}
+
+// `.` is the current context; anything else is a dot-separated key path into it
+fn lookup(context: &Value, name: &str) -> Option<Value> {
+    if name == "." {
+        return Some(context.clone());
+    }
+    let mut current = context.clone();
+    for key in name.split('.') {
+        current = current.get_data_by_key(key)?;
+    }
+    Some(current)
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Nothing { .. } => false,
+        Value::Bool { val, .. } => *val,
+        Value::String { val, .. } => !val.is_empty(),
+        Value::List { vals, .. } => !vals.is_empty(),
+        Value::Record { cols, .. } => !cols.is_empty(),
+        _ => true,
+    }
+}
+
+fn render(nodes: &[Node], context: &Value, config: &Config) -> Result<String, ShellError> {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Interpolate(name) => {
+                if let Some(value) = lookup(context, name) {
+                    if let Value::Error { error } = value {
+                        return Err(error);
+                    }
+                    out.push_str(&value.into_string(", ", config));
+                }
+            }
+            Node::Section {
+                name,
+                inverted,
+                children,
+            } => {
+                let value = lookup(context, name);
+                let truthy = value.as_ref().map_or(false, is_truthy);
+                if *inverted {
+                    if !truthy {
+                        out.push_str(&render(children, context, config)?);
+                    }
+                } else if let (true, Some(value)) = (truthy, value) {
+                    match value {
+                        Value::List { vals, .. } => {
+                            for element in vals {
+                                out.push_str(&render(children, &element, config)?);
+                            }
+                        }
+                        // a record section scopes lookups to that record
+                        record @ Value::Record { .. } => {
+                            out.push_str(&render(children, &record, config)?)
+                        }
+                        _ => out.push_str(&render(children, context, config)?),
+                    }
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FormatTemplate {})
+    }
+
+    fn record(cols: &[&str], vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: cols.iter().map(|c| c.to_string()).collect(),
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    fn run(template: &str, context: &Value) -> String {
+        let nodes = parse_template(template, Span::test_data()).expect("template parses");
+        render(&nodes, context, &Config::default()).expect("template renders")
+    }
+
+    #[test]
+    fn sections_nest_and_scope_to_their_element() {
+        let context = record(
+            &["crates"],
+            vec![Value::List {
+                vals: vec![
+                    record(
+                        &["name", "default"],
+                        vec![Value::test_string("nu-command"), Value::test_bool(true)],
+                    ),
+                    record(
+                        &["name", "default"],
+                        vec![Value::test_string("nu-json"), Value::test_bool(false)],
+                    ),
+                ],
+                span: Span::test_data(),
+            }],
+        );
+        let rendered = run(
+            "{{#crates}}{{name}}{{#default}}*{{/default}};{{/crates}}",
+            &context,
+        );
+        assert_eq!(rendered, "nu-command*;nu-json;");
+    }
+
+    #[test]
+    fn missing_keys_render_empty_and_invert() {
+        let context = record(&["a"], vec![Value::test_int(1)]);
+        assert_eq!(run("<{{missing}}>", &context), "<>");
+        assert_eq!(run("{{^missing}}none{{/missing}}", &context), "none");
+    }
+
+    #[test]
+    fn mismatched_sections_error() {
+        assert!(parse_template("{{#a}}{{/b}}", Span::test_data()).is_err());
+        assert!(parse_template("{{#a}}", Span::test_data()).is_err());
+        assert!(parse_template("{{a}", Span::test_data()).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use fancy_regex::Regex;
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
@@ -30,6 +31,17 @@ impl Command for SubCommand {
                 "the character or string that denotes what separates columns",
             )
             .switch("collapse-empty", "remove empty columns", Some('c'))
+            .switch(
+                "regex",
+                "separator is a regular expression instead of a literal string",
+                Some('r'),
+            )
+            .named(
+                "max-splits",
+                SyntaxShape::Int,
+                "split into at most this many columns; the rest of the line lands in the last one",
+                Some('m'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::String,
@@ -43,7 +55,7 @@ impl Command for SubCommand {
     }
 
     fn search_terms(&self) -> Vec<&str> {
-        vec!["separate", "divide"]
+        vec!["separate", "divide", "regex"]
     }
 
     fn run(
@@ -98,6 +110,26 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Split on a run of whitespace, keeping the message in one column",
+                example: "'127.0.0.1 - GET /index.html' | split column --regex '\\s+' --max-splits 3 ip dash request",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec![
+                            "ip".to_string(),
+                            "dash".to_string(),
+                            "request".to_string(),
+                        ],
+                        vals: vec![
+                            Value::test_string("127.0.0.1"),
+                            Value::test_string("-"),
+                            Value::test_string("GET /index.html"),
+                        ],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Split a list of strings into a table",
                 example: "['a-b' 'c-d'] | split column -",
@@ -131,25 +163,86 @@ fn split_column(
     let separator: Spanned<String> = call.req(engine_state, stack, 0)?;
     let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 1)?;
     let collapse_empty = call.has_flag("collapse-empty");
+    let max_splits: Option<usize> = call.get_flag(engine_state, stack, "max-splits")?;
+
+    let separator = if call.has_flag("regex") {
+        Separator::Regex(Regex::new(&separator.item).map_err(|e| {
+            ShellError::GenericError(
+                "Error with regular expression".into(),
+                e.to_string(),
+                Some(separator.span),
+                None,
+                Vec::new(),
+            )
+        })?)
+    } else {
+        Separator::String(separator.item)
+    };
 
     input.flat_map(
-        move |x| split_column_helper(&x, &separator, &rest, collapse_empty, name_span),
+        move |x| split_column_helper(&x, &separator, &rest, collapse_empty, max_splits, name_span),
         engine_state.ctrlc.clone(),
     )
 }
 
+enum Separator {
+    String(String),
+    Regex(Regex),
+}
+
+// Like Regex::split, but stops after max parts; a failed (catastrophic) match
+// surfaces as an error rather than silently truncating the splits.
+pub(crate) fn regex_split<'a>(
+    s: &'a str,
+    regex: &Regex,
+    max: Option<usize>,
+    span: Span,
+) -> Result<Vec<&'a str>, ShellError> {
+    let mut parts = Vec::new();
+    let mut last = 0;
+    for found in regex.find_iter(s) {
+        let found = found.map_err(|err| {
+            ShellError::GenericError(
+                "Error with regular expression".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        if max.map_or(false, |max| parts.len() + 1 >= max) {
+            break;
+        }
+        parts.push(&s[last..found.start()]);
+        last = found.end();
+    }
+    parts.push(&s[last..]);
+    Ok(parts)
+}
+
 fn split_column_helper(
     v: &Value,
-    separator: &Spanned<String>,
+    separator: &Separator,
     rest: &[Spanned<String>],
     collapse_empty: bool,
+    max_splits: Option<usize>,
     head: Span,
 ) -> Vec<Value> {
     if let Ok(s) = v.as_string() {
+        let split_result: Vec<_> = match separator {
+            Separator::String(sep) => match max_splits {
+                Some(max) => s.splitn(max, sep).collect(),
+                None => s.split(sep).collect(),
+            },
+            Separator::Regex(regex) => match regex_split(&s, regex, max_splits, head) {
+                Ok(parts) => parts,
+                Err(error) => return vec![Value::Error { error }],
+            },
+        };
         let split_result: Vec<_> = if collapse_empty {
-            s.split(&separator.item).filter(|s| !s.is_empty()).collect()
+            split_result.into_iter().filter(|s| !s.is_empty()).collect()
         } else {
-            s.split(&separator.item).collect()
+            split_result
         };
 
         let positional: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
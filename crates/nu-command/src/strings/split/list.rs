@@ -1,9 +1,9 @@
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
     ast::Call,
-    engine::{Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Type, Value,
+    engine::{Closure, Command, EngineState, Stack},
+    Category, Example, FromValue, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -23,7 +23,12 @@ impl Command for SubCommand {
             .required(
                 "separator",
                 SyntaxShape::Any,
-                "the value that denotes what separates the list",
+                "the value that denotes what separates the list, or a closure that returns true on a separator",
+            )
+            .switch(
+                "keep",
+                "keep the separator elements, each starting the sublist that follows it",
+                Some('k'),
             )
             .category(Category::Filters)
     }
@@ -32,6 +37,10 @@ impl Command for SubCommand {
         "Split a list into multiple lists using a separator."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Separator elements are dropped unless --keep is given. A closure separator makes it possible to split on a condition, like blank lines between log sections."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["separate", "divide"]
     }
@@ -73,6 +82,27 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Split a list where a closure matches",
+                example: "[1 2 9 3 4 9 5] | split list {|e| $e == 9 }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::List {
+                            vals: vec![Value::test_int(1), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(3), Value::test_int(4)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(5)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Split a list of lists into two lists of lists",
                 example: "[[1,2], [2,3], [3,4]] | split list [2,3]",
@@ -132,11 +162,46 @@ fn split_list(
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let separator: Value = call.req(engine_state, stack, 0)?;
+    let keep = call.has_flag("keep");
+
+    // A closure separator splits wherever the closure returns true
+    let mut matcher: Box<dyn FnMut(&Value) -> Result<bool, ShellError>> =
+        if separator.as_block().is_ok() {
+            let capture_block: Closure = FromValue::from_value(&separator)?;
+            let block = engine_state.get_block(capture_block.block_id).clone();
+            let redirect_stdout = call.redirect_stdout;
+            let redirect_stderr = call.redirect_stderr;
+            let head = call.head;
+            let engine_state = engine_state.clone();
+            let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+            Box::new(move |val: &Value| {
+                if let Some(var) = block.signature.get_positional(0) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, val.clone())
+                    }
+                }
+
+                Ok(eval_block(
+                    &engine_state,
+                    &mut stack,
+                    &block,
+                    val.clone().into_pipeline_data(),
+                    redirect_stdout,
+                    redirect_stderr,
+                )?
+                .into_value(head)
+                .is_true())
+            })
+        } else {
+            Box::new(move |val: &Value| Ok(*val == separator))
+        };
+
     let mut temp_list = Vec::new();
     let mut returned_list = Vec::new();
     let iter = input.into_interruptible_iter(engine_state.ctrlc.clone());
     for val in iter {
-        if val == separator {
+        if matcher(&val)? {
             if !temp_list.is_empty() {
                 returned_list.push(Value::List {
                     vals: temp_list.clone(),
@@ -144,6 +209,9 @@ fn split_list(
                 });
                 temp_list = Vec::new();
             }
+            if keep {
+                temp_list.push(val);
+            }
         } else {
             temp_list.push(val);
         }
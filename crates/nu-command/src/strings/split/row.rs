@@ -1,3 +1,5 @@
+use super::column::regex_split;
+use fancy_regex::Regex;
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
@@ -29,6 +31,11 @@ impl Command for SubCommand {
                 "Split into maximum number of items",
                 Some('n'),
             )
+            .switch(
+                "regex",
+                "separator is a regular expression instead of a literal string",
+                Some('r'),
+            )
             .category(Category::Strings)
     }
 
@@ -37,7 +44,7 @@ impl Command for SubCommand {
     }
 
     fn search_terms(&self) -> Vec<&str> {
-        vec!["separate", "divide"]
+        vec!["separate", "divide", "regex"]
     }
 
     fn run(
@@ -78,6 +85,18 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Split a string into fields by any run of whitespace",
+                example: "'a   b        c' | split row --regex '\\s+'",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("a"),
+                        Value::test_string("b"),
+                        Value::test_string("c"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Split a string by '-'",
                 example: "'-a-b-c-' | split row '-'",
@@ -105,8 +124,21 @@ fn split_row(
     let name_span = call.head;
     let separator: Spanned<String> = call.req(engine_state, stack, 0)?;
     let max_split: Option<usize> = call.get_flag(engine_state, stack, "number")?;
+    let regex = if call.has_flag("regex") {
+        Some(Regex::new(&separator.item).map_err(|err| {
+            ShellError::GenericError(
+                "Error with regular expression".into(),
+                err.to_string(),
+                Some(separator.span),
+                None,
+                Vec::new(),
+            )
+        })?)
+    } else {
+        None
+    };
     input.flat_map(
-        move |x| split_row_helper(&x, &separator, max_split, name_span),
+        move |x| split_row_helper(&x, &separator, regex.as_ref(), max_split, name_span),
         engine_state.ctrlc.clone(),
     )
 }
@@ -114,18 +146,26 @@ fn split_row(
 fn split_row_helper(
     v: &Value,
     separator: &Spanned<String>,
+    regex: Option<&Regex>,
     max_split: Option<usize>,
     name: Span,
 ) -> Vec<Value> {
     match v.span() {
         Ok(v_span) => {
             if let Ok(s) = v.as_string() {
-                match max_split {
-                    Some(max_split) => s
+                match (regex, max_split) {
+                    (Some(regex), max_split) => match regex_split(&s, regex, max_split, name) {
+                        Ok(parts) => parts
+                            .into_iter()
+                            .map(|s| Value::string(s, v_span))
+                            .collect(),
+                        Err(error) => vec![Value::Error { error }],
+                    },
+                    (None, Some(max_split)) => s
                         .splitn(max_split, &separator.item)
                         .map(|s| Value::string(s, v_span))
                         .collect(),
-                    None => s
+                    (None, None) => s
                         .split(&separator.item)
                         .map(|s| Value::string(s, v_span))
                         .collect(),
@@ -0,0 +1,230 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    start: String,
+    end: String,
+    all: bool,
+    inclusive: bool,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str between"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str between")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::String, Type::Nothing),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .allow_variants_without_examples(true)
+            .vectorizes_over_list(true)
+            .required("start", SyntaxShape::String, "the opening marker")
+            .required("end", SyntaxShape::String, "the closing marker")
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, extract strings at the given cell paths, and replace with result",
+            )
+            .switch(
+                "all",
+                "return a list with every delimited substring instead of the first",
+                Some('a'),
+            )
+            .switch(
+                "inclusive",
+                "include the markers themselves in the output",
+                Some('i'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Extract the substring between two markers, or null if they aren't found."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The first occurrence of the opening marker and the first closing marker after it delimit the result. With --all, scanning continues after each closing marker, so occurrences never overlap."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["extract", "substring", "delimiter", "inside"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 2)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            start: call.req(engine_state, stack, 0)?,
+            end: call.req(engine_state, stack, 1)?,
+            all: call.has_flag("all"),
+            inclusive: call.has_flag("inclusive"),
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Extract the text between two markers",
+                example: "'name: [nushell] lang: [rust]' | str between '[' ']'",
+                result: Some(Value::test_string("nushell")),
+            },
+            Example {
+                description: "Extract every delimited substring",
+                example: "'name: [nushell] lang: [rust]' | str between '[' ']' --all",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("nushell"), Value::test_string("rust")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Keep the markers in the output",
+                example: "'print <b>bold</b> text' | str between '<b>' '</b>' --inclusive",
+                result: Some(Value::test_string("<b>bold</b>")),
+            },
+            Example {
+                description: "Missing markers give null",
+                example: "'no markers here' | str between '[' ']'",
+                result: Some(Value::test_nothing()),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => {
+            let mut found = Vec::new();
+            let mut from = 0;
+            while let Some(piece) = between(&val[from..], &args.start, &args.end, args.inclusive) {
+                // advance past the closing marker so occurrences never overlap
+                from += piece.offset
+                    + piece.text.len()
+                    + if args.inclusive { 0 } else { args.end.len() };
+                found.push(Value::string(piece.text, head));
+                if !args.all {
+                    break;
+                }
+            }
+
+            if args.all {
+                Value::List {
+                    vals: found,
+                    span: head,
+                }
+            } else {
+                found
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| Value::nothing(head))
+            }
+        }
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+struct Piece<'a> {
+    text: &'a str,
+    // byte offset of `text` within the searched string
+    offset: usize,
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str, inclusive: bool) -> Option<Piece<'a>> {
+    let open = s.find(start)?;
+    let inner = open + start.len();
+    let close = inner + s[inner..].find(end)?;
+    if inclusive {
+        Some(Piece {
+            text: &s[open..close + end.len()],
+            offset: open,
+        })
+    } else {
+        Some(Piece {
+            text: &s[inner..close],
+            offset: inner,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn occurrences_do_not_overlap() {
+        let args = Arguments {
+            start: "ab".into(),
+            end: "ba".into(),
+            all: true,
+            inclusive: false,
+            cell_paths: None,
+        };
+        // a naive scan would also find the occurrence starting at the third byte
+        let actual = action(&Value::test_string("abababa"), &args, Span::test_data());
+        assert_eq!(
+            actual,
+            Value::List {
+                vals: vec![Value::test_string("a")],
+                span: Span::test_data(),
+            }
+        );
+    }
+
+    #[test]
+    fn unclosed_marker_is_null() {
+        let args = Arguments {
+            start: "[".into(),
+            end: "]".into(),
+            all: false,
+            inclusive: false,
+            cell_paths: None,
+        };
+        let actual = action(&Value::test_string("open [only"), &args, Span::test_data());
+        assert_eq!(actual, Value::test_nothing());
+    }
+}
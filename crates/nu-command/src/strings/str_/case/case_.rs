@@ -0,0 +1,285 @@
+use crate::input_handler::{operate as general_operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+#[derive(Clone, Copy)]
+enum Style {
+    Camel,
+    Kebab,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Title,
+}
+
+struct Arguments {
+    style: Style,
+    delimiter: Option<String>,
+    acronyms: bool,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str case")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .required(
+                "style",
+                SyntaxShape::String,
+                "the target style: camel, kebab, pascal, snake, screaming-snake or title",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, convert strings at the given cell paths",
+            )
+            .named(
+                "delimiter",
+                SyntaxShape::String,
+                "join the words with this delimiter instead of the style's usual one",
+                Some('d'),
+            )
+            .switch(
+                "acronyms",
+                "keep words that are all uppercase in the input, like HTTP, uppercase",
+                Some('a'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a string between naming conventions."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The umbrella behind `str camel-case` and friends: the input is split into words on separators and case changes regardless of its current style, so conversions round-trip."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "convert",
+            "style",
+            "caps",
+            "convention",
+            "camel",
+            "kebab",
+            "snake",
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let style: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let style = match style.item.as_str() {
+            "camel" => Style::Camel,
+            "kebab" => Style::Kebab,
+            "pascal" => Style::Pascal,
+            "snake" => Style::Snake,
+            "screaming-snake" => Style::ScreamingSnake,
+            "title" => Style::Title,
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("unknown style '{other}'; expected camel, kebab, pascal, snake, screaming-snake or title"),
+                    "value originates from here".into(),
+                    call.head,
+                    style.span,
+                ))
+            }
+        };
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            style,
+            delimiter: call.get_flag(engine_state, stack, "delimiter")?,
+            acronyms: call.has_flag("acronyms"),
+            cell_paths,
+        };
+        general_operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a string to snake_case",
+                example: "'NuShell rocks' | str case snake",
+                result: Some(Value::test_string("nu_shell_rocks")),
+            },
+            Example {
+                description: "Camel boundaries are found regardless of the input style",
+                example: "'HTTPServer' | str case kebab",
+                result: Some(Value::test_string("http-server")),
+            },
+            Example {
+                description: "Keep acronyms uppercase",
+                example: "'HTTP_server' | str case pascal --acronyms",
+                result: Some(Value::test_string("HTTPServer")),
+            },
+            Example {
+                description: "Join the words with a custom delimiter",
+                example: "'get host name' | str case snake --delimiter '.'",
+                result: Some(Value::test_string("get.host.name")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::String {
+            val: convert(val, args.style, args.delimiter.as_deref(), args.acronyms),
+            span: head,
+        },
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+// Split into words on non-alphanumeric separators and on camel boundaries,
+// keeping a trailing run of uppercase letters together ("HTTPServer" is
+// "HTTP" + "Server").
+fn split_words(s: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in s.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else if previous.map_or(false, |p| {
+            (p.is_lowercase() || p.is_numeric()) && c.is_uppercase()
+        }) {
+            words.push(std::mem::take(&mut current));
+            current.push(c);
+        } else if previous.map_or(false, |p| p.is_uppercase())
+            && c.is_lowercase()
+            && current.chars().count() > 1
+        {
+            // the last uppercase letter starts this word, not the acronym
+            let start = current.pop().expect("current has more than one char");
+            words.push(std::mem::take(&mut current));
+            current.push(start);
+            current.push(c);
+        } else {
+            current.push(c);
+        }
+        previous = Some(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+// An acronym stays uppercase only when asked to; otherwise words are
+// capitalized like any other.
+fn capitalize_word(word: &str, acronyms: bool) -> String {
+    if acronyms && word.chars().count() > 1 && word.chars().all(|c| !c.is_lowercase()) {
+        return word.to_string();
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn convert(s: &str, style: Style, delimiter: Option<&str>, acronyms: bool) -> String {
+    let words = split_words(s);
+
+    let (cased, default_delimiter): (Vec<String>, &str) = match style {
+        Style::Camel => (
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize_word(word, acronyms)
+                    }
+                })
+                .collect(),
+            "",
+        ),
+        Style::Pascal => (
+            words
+                .iter()
+                .map(|word| capitalize_word(word, acronyms))
+                .collect(),
+            "",
+        ),
+        Style::Snake => (words.iter().map(|word| word.to_lowercase()).collect(), "_"),
+        Style::Kebab => (words.iter().map(|word| word.to_lowercase()).collect(), "-"),
+        Style::ScreamingSnake => (words.iter().map(|word| word.to_uppercase()).collect(), "_"),
+        Style::Title => (
+            words
+                .iter()
+                .map(|word| capitalize_word(word, acronyms))
+                .collect(),
+            " ",
+        ),
+    };
+
+    cased.join(delimiter.unwrap_or(default_delimiter))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let snake = convert("getHostName", Style::Snake, None, false);
+        assert_eq!(snake, "get_host_name");
+        assert_eq!(convert(&snake, Style::Camel, None, false), "getHostName");
+    }
+
+    #[test]
+    fn acronym_runs_split_before_their_last_letter() {
+        assert_eq!(split_words("HTTPServer2Go"), vec!["HTTP", "Server2", "Go"]);
+    }
+}
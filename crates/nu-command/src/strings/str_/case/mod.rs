@@ -1,5 +1,6 @@
 pub mod camel_case;
 pub mod capitalize;
+pub mod case_;
 pub mod downcase;
 pub mod kebab_case;
 pub mod pascal_case;
@@ -11,6 +12,7 @@ pub mod upcase;
 
 pub use camel_case::SubCommand as StrCamelCase;
 pub use capitalize::SubCommand as StrCapitalize;
+pub use case_::SubCommand as StrCase;
 pub use downcase::SubCommand as StrDowncase;
 pub use kebab_case::SubCommand as StrKebabCase;
 pub use pascal_case::SubCommand as StrPascalCase;
@@ -0,0 +1,147 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    fallback: Value,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str coalesce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str coalesce")
+            .input_output_types(vec![
+                (Type::String, Type::Any),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
+            .vectorizes_over_list(true)
+            .required(
+                "fallback",
+                SyntaxShape::Any,
+                "the value to use in place of empty or whitespace-only strings",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, replace empty strings at the given cell paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Replace empty or whitespace-only strings with a fallback value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Non-empty strings pass through unchanged, so this can be applied to whole columns without a conditional closure."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["default", "empty", "blank", "fallback"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let fallback: Value = call.req(engine_state, stack, 0)?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            fallback,
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Replace an empty string",
+                example: "'' | str coalesce 'n/a'",
+                result: Some(Value::test_string("n/a")),
+            },
+            Example {
+                description: "Whitespace-only strings count as empty",
+                example: "'   ' | str coalesce 'n/a'",
+                result: Some(Value::test_string("n/a")),
+            },
+            Example {
+                description: "Fill in blanks in a column",
+                example: "[[name]; [''] [amy]] | str coalesce unknown name",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["name".into()],
+                            vals: vec![Value::test_string("unknown")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["name".into()],
+                            vals: vec![Value::test_string("amy")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => {
+            if val.trim().is_empty() {
+                args.fallback.clone()
+            } else {
+                input.clone()
+            }
+        }
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
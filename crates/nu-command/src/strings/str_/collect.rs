@@ -22,6 +22,7 @@ impl Command for StrCollect {
                 SyntaxShape::String,
                 "optional separator to use when creating string",
             )
+            .deprecated("str join", "0.78.0")
             .category(Category::Deprecated)
     }
 
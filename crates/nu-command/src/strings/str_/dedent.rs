@@ -0,0 +1,175 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    tab_size: usize,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str dedent"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str dedent")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, dedent strings at the given cell paths, and replace with result",
+            )
+            .named(
+                "tab-size",
+                SyntaxShape::Int,
+                "how many spaces a leading tab counts as (defaults to 8)",
+                Some('t'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Remove the common leading whitespace from every line of a multiline string."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Leading tabs are expanded to spaces before the common indentation is measured, and lines with only whitespace are emptied but otherwise ignored. Useful for embedding indented scripts or templates in nu source."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["indent", "whitespace", "heredoc", "strip", "trim"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let tab_size: Option<usize> = call.get_flag(engine_state, stack, "tab-size")?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            tab_size: tab_size.unwrap_or(8),
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove the common indentation from a multiline string",
+                example: r#""    line1\n        line2\n    line3" | str dedent"#,
+                result: Some(Value::test_string("line1\n    line2\nline3")),
+            },
+            Example {
+                description: "Tabs count as a configurable number of spaces",
+                example: r#""\tfoo\n\t\tbar" | str dedent --tab-size 4"#,
+                result: Some(Value::test_string("foo\n    bar")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(dedent(val, args.tab_size), head),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+fn expand_leading_tabs(line: &str, tab_size: usize) -> String {
+    let mut expanded = String::new();
+    for (idx, c) in line.char_indices() {
+        match c {
+            '\t' => expanded.push_str(&" ".repeat(tab_size)),
+            ' ' => expanded.push(' '),
+            _ => {
+                expanded.push_str(&line[idx..]);
+                break;
+            }
+        }
+    }
+    expanded
+}
+
+fn dedent(s: &str, tab_size: usize) -> String {
+    let lines: Vec<String> = s
+        .lines()
+        .map(|line| expand_leading_tabs(line, tab_size))
+        .collect();
+
+    // whitespace-only lines don't count towards the common indentation
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+
+    let mut dedented: Vec<&str> = lines
+        .iter()
+        .map(|line| {
+            if line.trim().is_empty() {
+                ""
+            } else {
+                &line[indent..]
+            }
+        })
+        .collect();
+
+    // str::lines drops a trailing newline, so put it back
+    if s.ends_with('\n') {
+        dedented.push("");
+    }
+
+    dedented.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn blank_lines_are_ignored_and_emptied() {
+        assert_eq!(dedent("  a\n   \n  b\n", 8), "a\n\nb\n");
+    }
+
+    #[test]
+    fn unindented_input_is_unchanged() {
+        assert_eq!(dedent("a\n  b", 8), "a\n  b");
+    }
+}
@@ -3,15 +3,22 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, CellPath},
     engine::{Command, EngineState, Stack},
-    levenshtein_distance, Category, Example, PipelineData, ShellError, Signature, Span,
-    SyntaxShape, Type, Value,
+    jaro_winkler_similarity, levenshtein_distance, Category, Example, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
 pub struct SubCommand;
 
+#[derive(Clone, Copy)]
+enum DistanceAlgorithm {
+    Levenshtein,
+    JaroWinkler,
+}
+
 struct Arguments {
     compare_string: String,
+    algorithm: DistanceAlgorithm,
     cell_paths: Option<Vec<CellPath>>,
 }
 
@@ -28,7 +35,11 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("str distance")
-            .input_output_types(vec![(Type::String, Type::Int)])
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (Type::String, Type::Float),
+            ])
+            .allow_variants_without_examples(true)
             .required(
                 "compare-string",
                 SyntaxShape::String,
@@ -39,15 +50,21 @@ impl Command for SubCommand {
                 SyntaxShape::CellPath,
                 "For a data structure input, check strings at the given cell paths, and replace with result",
             )
+            .named(
+                "algorithm",
+                SyntaxShape::String,
+                "the algorithm to use: levenshtein (default, an edit distance) or jaro-winkler (a similarity between 0 and 1)",
+                Some('a'),
+            )
             .category(Category::Strings)
     }
 
     fn usage(&self) -> &str {
-        "Compare two strings and return the edit distance/Levenshtein distance."
+        "Compare two strings and return their distance or similarity."
     }
 
     fn search_terms(&self) -> Vec<&str> {
-        vec!["edit", "levenshtein"]
+        vec!["edit", "levenshtein", "jaro", "fuzzy", "similarity"]
     }
 
     fn run(
@@ -60,8 +77,25 @@ impl Command for SubCommand {
         let compare_string: String = call.req(engine_state, stack, 0)?;
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let algorithm: Option<Spanned<String>> = call.get_flag(engine_state, stack, "algorithm")?;
+        let algorithm = match algorithm {
+            Some(Spanned { item, span }) => match item.as_str() {
+                "levenshtein" => DistanceAlgorithm::Levenshtein,
+                "jaro-winkler" => DistanceAlgorithm::JaroWinkler,
+                _ => {
+                    return Err(ShellError::UnsupportedInput(
+                        format!("'{item}' is not a supported algorithm"),
+                        "supported algorithms are levenshtein and jaro-winkler".into(),
+                        call.head,
+                        span,
+                    ))
+                }
+            },
+            None => DistanceAlgorithm::Levenshtein,
+        };
         let args = Arguments {
             compare_string,
+            algorithm,
             cell_paths,
         };
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
@@ -86,6 +120,11 @@ impl Command for SubCommand {
                 ],
                 span: Span::test_data(),
             }),
+        },
+        Example {
+            description: "Compute how similar two strings are, as a value between 0 and 1",
+            example: "'nushell' | str distance 'nutshell' --algorithm jaro-winkler",
+            result: None,
         }]
     }
 }
@@ -93,10 +132,15 @@ impl Command for SubCommand {
 fn action(input: &Value, args: &Arguments, head: Span) -> Value {
     let compare_string = &args.compare_string;
     match input {
-        Value::String { val, .. } => {
-            let distance = levenshtein_distance(val, compare_string);
-            Value::int(distance as i64, head)
-        }
+        Value::String { val, .. } => match args.algorithm {
+            DistanceAlgorithm::Levenshtein => {
+                let distance = levenshtein_distance(val, compare_string);
+                Value::int(distance as i64, head)
+            }
+            DistanceAlgorithm::JaroWinkler => {
+                Value::float(jaro_winkler_similarity(val, compare_string), head)
+            }
+        },
         Value::Error { .. } => input.clone(),
         _ => Value::Error {
             error: ShellError::OnlySupportsThisInputType {
@@ -0,0 +1,224 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    context: Context,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+/// The contexts `str escape` and `str unescape` know about
+#[derive(Clone, Copy)]
+pub(crate) enum Context {
+    Shell,
+    Regex,
+    Html,
+    Json,
+    Url,
+}
+
+impl Context {
+    pub(crate) fn from_spanned(context: &Spanned<String>) -> Result<Self, ShellError> {
+        match context.item.as_str() {
+            "shell" => Ok(Context::Shell),
+            "regex" => Ok(Context::Regex),
+            "html" => Ok(Context::Html),
+            "json" => Ok(Context::Json),
+            "url" => Ok(Context::Url),
+            other => Err(ShellError::UnsupportedInput(
+                "the context must be 'shell', 'regex', 'html', 'json' or 'url'".into(),
+                format!("value: '{other}'"),
+                context.span,
+                context.span,
+            )),
+        }
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str escape"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str escape")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .vectorizes_over_list(true)
+            .required_named(
+                "for",
+                SyntaxShape::String,
+                "the context to escape for: 'shell', 'regex', 'html', 'json' or 'url'",
+                Some('f'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, escape strings at the given cell paths, and replace with result",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Escape a string so it can be embedded safely in a given context."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"'shell' wraps the string in single quotes the POSIX way, 'regex' backslash-escapes
+regex metacharacters, 'html' replaces the five significant markup characters with
+entities, 'json' escapes the contents of a JSON string literal (without the
+surrounding quotes), and 'url' percent-encodes every non-alphanumeric byte."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["quote", "sanitize", "encode", "entity", "percent"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let context: Spanned<String> = call
+            .get_flag(engine_state, stack, "for")?
+            .expect("required named argument");
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            context: Context::from_spanned(&context)?,
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Quote a string for a shell command line",
+                example: "'it calls itself \"nushell\"' | str escape --for shell",
+                result: Some(Value::test_string("'it calls itself \"nushell\"'")),
+            },
+            Example {
+                description: "Escape regex metacharacters",
+                example: "'1+1=2?' | str escape --for regex",
+                result: Some(Value::test_string("1\\+1=2\\?")),
+            },
+            Example {
+                description: "Escape markup characters for HTML",
+                example: "'a < b & c' | str escape --for html",
+                result: Some(Value::test_string("a &lt; b &amp; c")),
+            },
+            Example {
+                description: "Escape the contents of a JSON string literal",
+                example: "'say \"hi\"' | str escape --for json",
+                result: Some(Value::test_string("say \\\"hi\\\"")),
+            },
+            Example {
+                description: "Percent-encode a value for a URL",
+                example: "'hello world&x=1' | str escape --for url",
+                result: Some(Value::test_string("hello%20world%26x%3D1")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(escape(val, args.context), head),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+pub(crate) fn escape(val: &str, context: Context) -> String {
+    match context {
+        // wrap in single quotes; a literal single quote becomes '\''
+        Context::Shell => format!("'{}'", val.replace('\'', "'\\''")),
+        Context::Regex => regex::escape(val),
+        Context::Html => {
+            let mut out = String::with_capacity(val.len());
+            for c in val.chars() {
+                match c {
+                    '&' => out.push_str("&amp;"),
+                    '<' => out.push_str("&lt;"),
+                    '>' => out.push_str("&gt;"),
+                    '"' => out.push_str("&quot;"),
+                    '\'' => out.push_str("&#39;"),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        Context::Json => {
+            let mut out = String::with_capacity(val.len());
+            for c in val.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if (c as u32) < 0x20 => {
+                        out.push_str(&format!("\\u{:04x}", c as u32));
+                    }
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+        Context::Url => {
+            percent_encoding::utf8_percent_encode(val, percent_encoding::NON_ALPHANUMERIC)
+                .to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn shell_escape_embedded_single_quotes() {
+        assert_eq!(escape("it's", Context::Shell), r"'it'\''s'");
+    }
+
+    #[test]
+    fn json_escape_control_characters() {
+        assert_eq!(escape("a\nb\u{1}", Context::Json), r"a\nb\u0001");
+    }
+}
@@ -0,0 +1,333 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str expand")
+            .input_output_types(vec![(Type::String, Type::List(Box::new(Type::String)))])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, expand strings at the given cell paths, and replace with result",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate all possible combinations defined in brace expansion syntax."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Supports comma alternatives like {txt,md}, numeric ranges like {1..3} or {10..0..2}, character ranges like {a..e}, and nesting. Braces that don't form a valid expansion are kept literally, as in bash."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["brace", "combination", "glob", "generate", "sequence"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments { cell_paths };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Expand a comma alternative",
+                example: "'file.{txt,md}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("file.txt"),
+                        Value::test_string("file.md"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Expand a numeric range",
+                example: "'file{1..3}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("file1"),
+                        Value::test_string("file2"),
+                        Value::test_string("file3"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Ranges can count by a step, or backwards",
+                example: "'{5..1..2}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("5"),
+                        Value::test_string("3"),
+                        Value::test_string("1"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Alternatives can nest",
+                example: "'a{b,c{d,e}}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("ab"),
+                        Value::test_string("acd"),
+                        Value::test_string("ace"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::List {
+            vals: expand(val)
+                .into_iter()
+                .map(|s| Value::string(s, head))
+                .collect(),
+            span: head,
+        },
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+// Finds the matching `}` for the `{` at `open`, or None if it is unbalanced.
+fn matching_brace(s: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, c) in s[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// Splits the body of a brace group on commas that aren't inside nested braces.
+fn split_alternatives(body: &str) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (idx, c) in body.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&body[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    // a group without any top-level comma is not an alternative list
+    if parts.is_empty() {
+        return None;
+    }
+    parts.push(&body[start..]);
+    Some(parts)
+}
+
+// Parses `a..b` or `a..b..step` bodies into the sequence they generate:
+// numeric (with zero padding carried over) or single-character ranges.
+fn expand_range(body: &str) -> Option<Vec<String>> {
+    let mut parts = body.split("..");
+    let from = parts.next()?;
+    let to = parts.next()?;
+    let step = match parts.next() {
+        Some(step) => step.parse::<u64>().ok().filter(|s| *s > 0)?,
+        None => 1,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    if let (Ok(from_num), Ok(to_num)) = (from.parse::<i64>(), to.parse::<i64>()) {
+        // {08..10} keeps the zero padding of its widest bound
+        let digits = |bound: &str| bound.trim_start_matches('-').len();
+        let padded =
+            |bound: &str| digits(bound) > 1 && bound.trim_start_matches('-').starts_with('0');
+        let width = if padded(from) || padded(to) {
+            digits(from).max(digits(to))
+        } else {
+            0
+        };
+        let (from, to) = (from_num, to_num);
+
+        let mut out = Vec::new();
+        let mut current = from;
+        loop {
+            let digits = current.abs().to_string();
+            let mut item = String::new();
+            if current < 0 {
+                item.push('-');
+            }
+            for _ in digits.len()..width {
+                item.push('0');
+            }
+            item.push_str(&digits);
+            out.push(item);
+
+            if current == to || out.len() > 100_000 {
+                break;
+            }
+            current = if from <= to {
+                current.saturating_add(step as i64)
+            } else {
+                current.saturating_sub(step as i64)
+            };
+            if (from <= to && current > to) || (from > to && current < to) {
+                break;
+            }
+        }
+        return Some(out);
+    }
+
+    // character ranges like {a..e}
+    let (from, to) = match (
+        from.chars().collect::<Vec<_>>(),
+        to.chars().collect::<Vec<_>>(),
+    ) {
+        (f, t) if f.len() == 1 && t.len() == 1 => (f[0], t[0]),
+        _ => return None,
+    };
+    if !from.is_ascii_alphabetic() || !to.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let range: Vec<u32> = if from <= to {
+        (from as u32..=to as u32).step_by(step as usize).collect()
+    } else {
+        (to as u32..=from as u32)
+            .rev()
+            .step_by(step as usize)
+            .collect()
+    };
+    Some(
+        range
+            .into_iter()
+            .filter_map(char::from_u32)
+            .map(String::from)
+            .collect(),
+    )
+}
+
+pub fn expand(s: &str) -> Vec<String> {
+    let mut search_from = 0;
+    while let Some(open) = s[search_from..].find('{').map(|idx| search_from + idx) {
+        let Some(close) = matching_brace(s, open) else {
+            break;
+        };
+        let body = &s[open + 1..close];
+
+        let alternatives = match split_alternatives(body) {
+            Some(parts) => parts.iter().flat_map(|part| expand(part)).collect(),
+            None => match expand_range(body) {
+                Some(range) => range,
+                None => {
+                    // not an expansion; keep the braces and scan further right
+                    search_from = open + 1;
+                    continue;
+                }
+            },
+        };
+
+        let prefix = &s[..open];
+        return expand(&s[close + 1..])
+            .iter()
+            .flat_map(|rest| {
+                alternatives
+                    .iter()
+                    .map(move |alternative| format!("{prefix}{alternative}{rest}"))
+            })
+            .collect();
+    }
+
+    vec![s.to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn expands_the_cartesian_product_of_groups() {
+        assert_eq!(
+            expand("file{1..2}.{txt,md}"),
+            vec!["file1.txt", "file1.md", "file2.txt", "file2.md"]
+        );
+    }
+
+    #[test]
+    fn zero_padding_is_kept() {
+        assert_eq!(expand("{08..10}"), vec!["08", "09", "10"]);
+    }
+
+    #[test]
+    fn character_ranges_expand() {
+        assert_eq!(expand("{a..c}"), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn braces_without_an_expansion_are_literal() {
+        assert_eq!(expand("{abc}"), vec!["{abc}"]);
+        assert_eq!(expand("a{b"), vec!["a{b"]);
+    }
+}
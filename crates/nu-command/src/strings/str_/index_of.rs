@@ -10,7 +10,9 @@ use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShap
 use unicode_segmentation::UnicodeSegmentation;
 
 struct Arguments {
+    all: bool,
     end: bool,
+    not_found: bool,
     substring: String,
     range: Option<Value>,
     cell_paths: Option<Vec<CellPath>>,
@@ -27,7 +29,7 @@ impl CmdArgument for Arguments {
 pub struct SubCommand;
 
 #[derive(Clone)]
-pub struct IndexOfOptionalBounds(i32, i32);
+pub struct IndexOfOptionalBounds(i64, i64);
 
 impl Command for SubCommand {
     fn name(&self) -> &str {
@@ -36,7 +38,12 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("str index-of")
-            .input_output_types(vec![(Type::String, Type::Int)])
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (Type::String, Type::List(Box::new(Type::Int))),
+                (Type::String, Type::Nothing),
+            ])
+            .allow_variants_without_examples(true)
             .vectorizes_over_list(true) // TODO: no test coverage
             .required("string", SyntaxShape::String, "the string to find in the input")
             .switch(
@@ -57,10 +64,20 @@ impl Command for SubCommand {
             .named(
                 "range",
                 SyntaxShape::Any,
-                "optional start and/or end index",
+                "optional start and/or end index, as a range (1..4) or a comma string ('1,4'); negative indexes count from the end",
                 Some('r'),
             )
             .switch("end", "search from the end of the input", Some('e'))
+            .switch(
+                "all",
+                "return the indexes of every occurrence instead of only the first",
+                Some('a'),
+            )
+            .switch(
+                "not-found",
+                "return null instead of -1 when there is no match",
+                Some('n'),
+            )
             .category(Category::Strings)
     }
 
@@ -68,6 +85,10 @@ impl Command for SubCommand {
         "Returns start index of first occurrence of string in input, or -1 if no match."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With the --not-found flag, a miss is reported as null instead of -1, so it composes with `default` and null-propagation."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["match", "find", "search"]
     }
@@ -85,7 +106,9 @@ impl Command for SubCommand {
         let args = Arguments {
             substring: substring.item,
             range: call.get_flag(engine_state, stack, "range")?,
+            all: call.has_flag("all"),
             end: call.has_flag("end"),
+            not_found: call.has_flag("not-found"),
             cell_paths,
             graphemes: grapheme_flags(call)?,
         };
@@ -104,6 +127,14 @@ impl Command for SubCommand {
                 example: "'🇯🇵ほげ ふが ぴよ' | str index-of -g 'ふが'",
                 result: Some(Value::test_int(4)),
             },
+            Example {
+                description: "Returns the indexes of all occurrences of string in input",
+                example: " '.rb.rb' | str index-of '.rb' --all",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(3)],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Returns index of string in input with start index",
                 example: " '.rb.rb' | str index-of '.rb' -r '1,'",
@@ -114,6 +145,11 @@ impl Command for SubCommand {
                 example: " '123456' | str index-of '6' -r ',4'",
                 result: Some(Value::test_int(-1)),
             },
+            Example {
+                description: "Report a miss as null instead of -1",
+                example: " '123456' | str index-of '7' --not-found",
+                result: Some(Value::test_nothing()),
+            },
             Example {
                 description: "Returns index of string in input with start and end index",
                 example: " '123456' | str index-of '3' -r '1,4'",
@@ -124,6 +160,16 @@ impl Command for SubCommand {
                 example: " '123456' | str index-of '3' -r [1 4]",
                 result: Some(Value::test_int(2)),
             },
+            Example {
+                description: "Returns index of string in input with a range",
+                example: " '123456' | str index-of '3' -r 1..4",
+                result: Some(Value::test_int(2)),
+            },
+            Example {
+                description: "Returns index of string, searching only the last two characters",
+                example: " '123456' | str index-of '6' -r '-2,'",
+                result: Some(Value::test_int(5)),
+            },
             Example {
                 description: "Returns index of string in input",
                 example: " '/this/is/some/path/file.txt' | str index-of '/' -e",
@@ -138,8 +184,10 @@ fn action(
     Arguments {
         ref substring,
         range,
+        all,
         end,
         graphemes,
+        not_found,
         ..
     }: &Arguments,
     head: Span,
@@ -158,6 +206,26 @@ fn action(
                 Err(e) => return Value::Error { error: e },
             };
 
+            if *all {
+                // every non-overlapping occurrence inside the range, from the
+                // end of the input when -e is given
+                let mut indexes: Vec<Value> = s[start_index..end_index]
+                    .match_indices(&**substring)
+                    .map(|(idx, _)| byte_to_output_index(s, idx + start_index, *graphemes))
+                    .map(|idx| Value::int(idx, head))
+                    .collect();
+                if *end {
+                    indexes.reverse();
+                }
+                if *not_found && indexes.is_empty() {
+                    return Value::nothing(head);
+                }
+                return Value::List {
+                    vals: indexes,
+                    span: head,
+                };
+            }
+
             // When the -e flag is present, search using rfind instead of find.s
             if let Some(result) = if *end {
                 s[start_index..end_index].rfind(&**substring)
@@ -165,21 +233,9 @@ fn action(
                 s[start_index..end_index].find(&**substring)
             } {
                 let result = result + start_index;
-                Value::int(
-                    if *graphemes {
-                        // Having found the substring's byte index, convert to grapheme index.
-                        // grapheme_indices iterates graphemes alongside their UTF-8 byte indices, so .enumerate()
-                        // is used to get the grapheme index alongside it.
-                        s.grapheme_indices(true)
-                            .enumerate()
-                            .find(|e| e.1 .0 >= result)
-                            .expect("No grapheme index for substring")
-                            .0
-                    } else {
-                        result
-                    } as i64,
-                    head,
-                )
+                Value::int(byte_to_output_index(s, result, *graphemes), head)
+            } else if *not_found {
+                Value::nothing(head)
             } else {
                 Value::int(-1, head)
             }
@@ -196,71 +252,132 @@ fn action(
     }
 }
 
+// Having found a substring's byte index, convert it to a grapheme index when
+// counting in grapheme clusters. grapheme_indices iterates graphemes alongside
+// their UTF-8 byte indices, so .enumerate() is used to get the grapheme index
+// alongside it.
+fn byte_to_output_index(s: &str, byte_index: usize, graphemes: bool) -> i64 {
+    if graphemes {
+        s.grapheme_indices(true)
+            .enumerate()
+            .find(|e| e.1 .0 >= byte_index)
+            .expect("No grapheme index for substring")
+            .0 as i64
+    } else {
+        byte_index as i64
+    }
+}
+
 fn process_range(
     input: &Value,
     range: &Value,
     head: Span,
 ) -> Result<IndexOfOptionalBounds, ShellError> {
     let input_len = match input {
-        Value::String { val: s, .. } => s.len(),
+        Value::String { val: s, .. } => s.len() as i64,
         _ => 0,
     };
-    let min_index_str = String::from("0");
-    let max_index_str = input_len.to_string();
-    let r = match range {
+
+    // Missing bounds default to the whole input; a range's inclusive end is
+    // converted to the exclusive end the '1,4' comma form has always used.
+    let (start_index, end_index) = match range {
+        Value::Range { val, .. } => {
+            let start = match &val.from {
+                Value::Nothing { .. } => 0,
+                from => from.as_integer()?,
+            };
+            let end = match &val.to {
+                Value::Nothing { .. } => input_len,
+                to => {
+                    let to = to.as_integer()?;
+                    let to = normalize_index(to, input_len);
+                    if val.is_end_inclusive() {
+                        to + 1
+                    } else {
+                        to
+                    }
+                }
+            };
+
+            (normalize_index(start, input_len), end)
+        }
         Value::String { val: s, .. } => {
             let indexes: Vec<&str> = s.split(',').collect();
 
-            let start_index = indexes.first().unwrap_or(&&min_index_str[..]).to_string();
-
-            let end_index = indexes.get(1).unwrap_or(&&max_index_str[..]).to_string();
+            let start = indexes.first().and_then(|s| s.parse::<i64>().ok());
+            let end = indexes.get(1).and_then(|s| s.parse::<i64>().ok());
 
-            Ok((start_index, end_index))
+            (
+                normalize_index(start.unwrap_or(0), input_len),
+                normalize_index(end.unwrap_or(input_len), input_len),
+            )
         }
         Value::List { vals, .. } => {
             if vals.len() > 2 {
-                Err(ShellError::TypeMismatch {
+                return Err(ShellError::TypeMismatch {
                     err_message: String::from("there shouldn't be more than two indexes"),
                     span: head,
-                })
-            } else {
-                let idx: Vec<String> = vals
-                    .iter()
-                    .map(|v| v.as_string().unwrap_or_else(|_| String::from("")))
-                    .collect();
+                });
+            }
 
-                let start_index = idx.get(0).unwrap_or(&min_index_str).to_string();
-                let end_index = idx.get(1).unwrap_or(&max_index_str).to_string();
+            let start = vals.first().and_then(index_from_value);
+            let end = vals.get(1).and_then(index_from_value);
 
-                Ok((start_index, end_index))
-            }
+            (
+                normalize_index(start.unwrap_or(0), input_len),
+                normalize_index(end.unwrap_or(input_len), input_len),
+            )
         }
-        Value::Error { error } => Err(error.clone()),
-        _ => Err(ShellError::OnlySupportsThisInputType {
-            exp_input_type: "string".into(),
-            wrong_type: input.get_type().to_string(),
-            dst_span: head,
-            src_span: input.expect_span(),
-        }),
-    }?;
-
-    let start_index = r.0.parse::<i32>().unwrap_or(0);
-    let end_index = r.1.parse::<i32>().unwrap_or(input_len as i32);
-
-    if start_index < 0 || start_index > end_index {
+        Value::Error { error } => return Err(error.clone()),
+        _ => {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            })
+        }
+    };
+
+    if start_index > end_index {
         return Err(ShellError::TypeMismatch {
-            err_message: String::from("start index can't be negative or greater than end index"),
+            err_message: format!(
+                "start index ({start_index}) is greater than end index ({end_index})"
+            ),
             span: head,
         });
     }
 
-    if end_index < 0 || end_index < start_index || end_index > input_len as i32 {
-        return Err(ShellError::TypeMismatch { err_message: String::from(
-            "end index can't be negative, smaller than start index or greater than input length"), span: head });
+    if start_index < 0 || end_index > input_len {
+        return Err(ShellError::TypeMismatch {
+            err_message: format!(
+                "range {start_index}..<{end_index} is out of bounds for an input of length {input_len}"
+            ),
+            span: head,
+        });
     }
+
     Ok(IndexOfOptionalBounds(start_index, end_index))
 }
 
+// A negative index counts backwards from the end of the input, so -1 is the
+// last byte. Positive indexes are returned unchanged.
+fn normalize_index(index: i64, input_len: i64) -> i64 {
+    if index < 0 {
+        input_len + index
+    } else {
+        index
+    }
+}
+
+fn index_from_value(v: &Value) -> Option<i64> {
+    match v {
+        Value::Int { val, .. } => Some(*val),
+        Value::String { val, .. } => val.parse::<i64>().ok(),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,8 +402,10 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -305,8 +424,10 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -314,6 +435,26 @@ mod tests {
         assert_eq!(actual, Value::test_int(-1));
     }
 
+    #[test]
+    fn missing_substring_is_null_with_not_found() {
+        let word = Value::test_string("Cargo.tomL");
+
+        let options = Arguments {
+            substring: String::from("Lm"),
+
+            range: None,
+            cell_paths: None,
+            all: false,
+            end: false,
+            graphemes: false,
+            not_found: true,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+
+        assert_eq!(actual, Value::test_nothing());
+    }
+
     #[test]
     fn returns_index_of_next_substring() {
         let word = Value::test_string("Cargo.Cargo");
@@ -326,8 +467,10 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -346,8 +489,10 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -366,8 +511,10 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -386,14 +533,85 @@ mod tests {
                 span: Span::test_data(),
             }),
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
         assert_eq!(actual, Value::test_int(-1));
     }
 
+    #[test]
+    fn returns_index_of_substring_counting_from_the_end() {
+        let word = Value::test_string("Cargo.tomL");
+
+        let options = Arguments {
+            substring: String::from("tomL"),
+
+            range: Some(Value::String {
+                val: String::from("-4,"),
+                span: Span::test_data(),
+            }),
+            cell_paths: None,
+            all: false,
+            end: false,
+            graphemes: false,
+            not_found: false,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(actual, Value::test_int(6));
+    }
+
+    #[test]
+    fn reversed_range_is_an_error() {
+        let word = Value::test_string("123456");
+
+        let options = Arguments {
+            substring: String::from("3"),
+
+            range: Some(Value::String {
+                val: String::from("4,2"),
+                span: Span::test_data(),
+            }),
+            cell_paths: None,
+            all: false,
+            end: false,
+            graphemes: false,
+            not_found: false,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert!(matches!(actual, Value::Error { .. }));
+    }
+
+    #[test]
+    fn returns_all_indexes_of_substring() {
+        let word = Value::test_string("Cargo.Cargo");
+
+        let options = Arguments {
+            substring: String::from("Cargo"),
+
+            range: None,
+            cell_paths: None,
+            all: true,
+            end: false,
+            graphemes: false,
+            not_found: false,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(
+            actual,
+            Value::List {
+                vals: vec![Value::test_int(0), Value::test_int(6)],
+                span: Span::test_data(),
+            }
+        );
+    }
+
     #[test]
     fn use_utf8_bytes() {
         let word = Value::String {
@@ -406,8 +624,10 @@ mod tests {
 
             range: None,
             cell_paths: None,
+            all: false,
             end: false,
             graphemes: false,
+            not_found: false,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -0,0 +1,236 @@
+use crate::input_handler::{operate, CmdArgument};
+use fancy_regex::Regex;
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    regex: Regex,
+    all: bool,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str match"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str match")
+            .input_output_types(vec![
+                (Type::String, Type::Record(vec![])),
+                (Type::String, Type::Table(vec![])),
+            ])
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the regex pattern; capture groups become record columns, named via (?P<name>...)",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, match strings at the given cell paths, and replace with result",
+            )
+            .switch(
+                "all",
+                "return a table with one row per match instead of the first match",
+                Some('a'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Match a string against a regex and return the capture groups as a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Named capture groups become record columns; unnamed groups are named capture0, capture1, and so on. Returns nothing when the pattern does not match."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["regex", "capture", "group", "find", "parse"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let pattern: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let regex = Regex::new(&pattern.item).map_err(|err| {
+            ShellError::GenericError(
+                "Error with regular expression".into(),
+                err.to_string(),
+                Some(pattern.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let args = Arguments {
+            regex,
+            all: call.has_flag("all"),
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Match named capture groups into a record",
+                example: "'nushell 0.76.1' | str match '(?P<name>\\w+) (?P<version>[\\d.]+)'",
+                result: Some(Value::Record {
+                    cols: vec!["name".to_string(), "version".to_string()],
+                    vals: vec![Value::test_string("nushell"), Value::test_string("0.76.1")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Match every occurrence as a row",
+                example: "'1-2 3-4' | str match --all '(?P<left>\\d)-(?P<right>\\d)'",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["left".to_string(), "right".to_string()],
+                            vals: vec![Value::test_string("1"), Value::test_string("2")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["left".to_string(), "right".to_string()],
+                            vals: vec![Value::test_string("3"), Value::test_string("4")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Unnamed groups are numbered",
+                example: "'key=value' | str match '(\\w+)=(\\w+)'",
+                result: Some(Value::Record {
+                    cols: vec!["capture0".to_string(), "capture1".to_string()],
+                    vals: vec![Value::test_string("key"), Value::test_string("value")],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => {
+            let cols = column_names(&args.regex);
+
+            if args.all {
+                let mut vals = Vec::new();
+                for captures in args.regex.captures_iter(val) {
+                    match captures {
+                        Ok(captures) => vals.push(captures_to_record(&captures, &cols, head)),
+                        Err(err) => return regex_error(err, head),
+                    }
+                }
+                Value::List { vals, span: head }
+            } else {
+                match args.regex.captures(val) {
+                    Ok(Some(captures)) => captures_to_record(&captures, &cols, head),
+                    Ok(None) => Value::nothing(head),
+                    Err(err) => regex_error(err, head),
+                }
+            }
+        }
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+// The same naming scheme `parse --regex` uses: named groups keep their name,
+// unnamed ones are numbered from capture0.
+fn column_names(regex: &Regex) -> Vec<String> {
+    regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(i, name)| {
+            name.map(String::from)
+                .unwrap_or_else(|| format!("capture{}", i - 1))
+        })
+        .collect()
+}
+
+fn captures_to_record(captures: &fancy_regex::Captures, cols: &[String], head: Span) -> Value {
+    let vals = captures
+        .iter()
+        .skip(1)
+        .map(|capture| Value::string(capture.map(|m| m.as_str()).unwrap_or(""), head))
+        .collect();
+
+    Value::Record {
+        cols: cols.to_vec(),
+        vals,
+        span: head,
+    }
+}
+
+fn regex_error(err: fancy_regex::Error, head: Span) -> Value {
+    Value::Error {
+        error: ShellError::GenericError(
+            "Error with regular expression".into(),
+            err.to_string(),
+            Some(head),
+            None,
+            Vec::new(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn no_match_returns_nothing() {
+        let word = Value::test_string("bare words");
+
+        let options = Arguments {
+            regex: Regex::new(r"\d+").expect("valid pattern"),
+            all: false,
+            cell_paths: None,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(actual, Value::nothing(Span::test_data()));
+    }
+}
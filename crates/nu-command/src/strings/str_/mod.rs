@@ -1,27 +1,55 @@
+mod between;
 mod case;
+mod coalesce;
 mod collect;
 mod contains;
+mod dedent;
 mod distance;
 mod ends_with;
+mod escape;
+mod expand;
 mod index_of;
 mod join;
 mod length;
+mod match_;
+mod normalize;
+mod pad;
 mod replace;
 mod reverse;
+mod score;
 mod starts_with;
+mod stats;
 mod substring;
 mod trim;
+mod truncate;
+mod unescape;
+mod unidecode;
+mod wrap;
 
+pub use between::SubCommand as StrBetween;
 pub use case::*;
+pub use coalesce::SubCommand as StrCoalesce;
 pub use collect::*;
 pub use contains::SubCommand as StrContains;
+pub use dedent::SubCommand as StrDedent;
 pub use distance::SubCommand as StrDistance;
 pub use ends_with::SubCommand as StrEndswith;
+pub use escape::SubCommand as StrEscape;
+pub use expand::SubCommand as StrExpand;
 pub use index_of::SubCommand as StrIndexOf;
 pub use join::*;
 pub use length::SubCommand as StrLength;
+pub use match_::SubCommand as StrMatch;
+pub use normalize::SubCommand as StrNormalize;
+pub use pad::SubCommand as StrPad;
 pub use replace::SubCommand as StrReplace;
 pub use reverse::SubCommand as StrReverse;
+pub use score::SubCommand as StrScore;
 pub use starts_with::SubCommand as StrStartsWith;
+pub use stats::SubCommand as StrStats;
 pub use substring::SubCommand as StrSubstring;
 pub use trim::Trim as StrTrim;
+pub use truncate::SubCommand as StrTruncate;
+pub use unescape::SubCommand as StrUnescape;
+pub use unidecode::SubCommand as StrUnidecode;
+pub use wrap::SubCommand as StrWrap;
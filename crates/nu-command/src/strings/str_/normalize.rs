@@ -0,0 +1,177 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+#[derive(Clone, Copy)]
+enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+struct Arguments {
+    form: Form,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str normalize")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, normalize strings at the given cell paths, and replace with result",
+            )
+            .named(
+                "form",
+                SyntaxShape::String,
+                "the normalization form: nfc (default), nfd, nfkc or nfkd",
+                Some('f'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Normalize a string to a Unicode normalization form."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Visually identical strings can differ byte-wise (a precomposed \u{00e9} versus e plus a combining accent); normalizing both sides makes filename and user-input comparisons reliable."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "nfc", "nfd", "compose", "decompose", "canonical"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let form: Option<Spanned<String>> = call.get_flag(engine_state, stack, "form")?;
+        let form = match form {
+            None => Form::Nfc,
+            Some(form) => match form.item.to_ascii_lowercase().as_str() {
+                "nfc" => Form::Nfc,
+                "nfd" => Form::Nfd,
+                "nfkc" => Form::Nfkc,
+                "nfkd" => Form::Nfkd,
+                other => {
+                    return Err(ShellError::UnsupportedInput(
+                        format!(
+                            "unknown normalization form '{other}'; expected nfc, nfd, nfkc or nfkd"
+                        ),
+                        "value originates from here".into(),
+                        call.head,
+                        form.span,
+                    ))
+                }
+            },
+        };
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments { form, cell_paths };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compose a string to NFC, the usual form for comparisons",
+                example: "'cafe\u{301}' | str normalize",
+                result: Some(Value::test_string("caf\u{e9}")),
+            },
+            Example {
+                description: "Decompose a string to NFD",
+                example: "'caf\u{e9}' | str normalize --form nfd",
+                result: Some(Value::test_string("cafe\u{301}")),
+            },
+            Example {
+                description: "NFKC also folds compatibility characters like ligatures",
+                example: "'\u{fb01}le' | str normalize --form nfkc",
+                result: Some(Value::test_string("file")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::String {
+            val: match args.form {
+                Form::Nfc => val.nfc().collect(),
+                Form::Nfd => val.nfd().collect(),
+                Form::Nfkc => val.nfkc().collect(),
+                Form::Nfkd => val.nfkd().collect(),
+            },
+            span: head,
+        },
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn composition_round_trips() {
+        let decomposed = "cafe\u{301}";
+        let composed = "caf\u{e9}";
+        let args = Arguments {
+            form: Form::Nfc,
+            cell_paths: None,
+        };
+        assert_eq!(
+            action(&Value::test_string(decomposed), &args, Span::test_data()),
+            Value::test_string(composed)
+        );
+        let args = Arguments {
+            form: Form::Nfd,
+            cell_paths: None,
+        };
+        assert_eq!(
+            action(&Value::test_string(composed), &args, Span::test_data()),
+            Value::test_string(decomposed)
+        );
+    }
+}
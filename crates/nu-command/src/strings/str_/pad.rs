@@ -0,0 +1,227 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+#[derive(Clone, Copy)]
+enum PadSide {
+    Left,
+    Right,
+    Both,
+}
+
+struct Arguments {
+    width: usize,
+    character: String,
+    side: PadSide,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str pad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str pad")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, pad strings at the given cell paths, and replace with result",
+            )
+            .required_named(
+                "width",
+                SyntaxShape::Int,
+                "the target display width to pad to",
+                Some('w'),
+            )
+            .named(
+                "char",
+                SyntaxShape::String,
+                "the character to pad with (defaults to a space)",
+                Some('c'),
+            )
+            .switch("left", "pad on the left side", Some('l'))
+            .switch("right", "pad on the right side (the default)", Some('r'))
+            .switch("both", "pad evenly on both sides", Some('b'))
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Pad a string to a target width."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Width is measured in display columns using grapheme clusters, so wide characters count as two and strings that are already wide enough are returned unchanged."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["align", "fill", "lpad", "rpad", "justify"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let width: usize = call
+            .get_flag(engine_state, stack, "width")?
+            .expect("required named argument");
+        let character: Option<String> = call.get_flag(engine_state, stack, "char")?;
+        let character = character.unwrap_or_else(|| " ".to_string());
+
+        let side = match (
+            call.has_flag("left"),
+            call.has_flag("right"),
+            call.has_flag("both"),
+        ) {
+            (false, false, false) | (false, true, false) => PadSide::Right,
+            (true, false, false) => PadSide::Left,
+            (false, false, true) => PadSide::Both,
+            _ => {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "only one of --left, --right and --both can be used".to_string(),
+                    span: call.head,
+                })
+            }
+        };
+
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            width,
+            character,
+            side,
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pad a string on the right to a width of 5",
+                example: "'nu' | str pad --width 5 --char '-'",
+                result: Some(Value::test_string("nu---")),
+            },
+            Example {
+                description: "Pad a string on the left to a width of 5",
+                example: "'nu' | str pad --left --width 5",
+                result: Some(Value::test_string("   nu")),
+            },
+            Example {
+                description: "Pad a string evenly on both sides",
+                example: "'nu' | str pad --both --width 6 --char '*'",
+                result: Some(Value::test_string("**nu**")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => {
+            Value::string(pad(val, args.width, &args.character, args.side), head)
+        }
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+// The display width of a string: the sum of the column widths of its grapheme
+// clusters, so multi-codepoint graphemes count once and wide chars count twice.
+fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+fn pad(s: &str, width: usize, pad_char: &str, side: PadSide) -> String {
+    let cols = display_width(s);
+    if cols >= width {
+        return s.to_string();
+    }
+
+    let diff = width - cols;
+    let (left_pad, right_pad) = match side {
+        PadSide::Left => (diff, 0),
+        PadSide::Right => (0, diff),
+        PadSide::Both => (diff / 2, diff - diff / 2),
+    };
+
+    let mut padded = String::new();
+    for _ in 0..left_pad {
+        padded.push_str(pad_char);
+    }
+    padded.push_str(s);
+    for _ in 0..right_pad {
+        padded.push_str(pad_char);
+    }
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn wide_characters_count_as_two_columns() {
+        let word = Value::test_string("ほげ");
+
+        let options = Arguments {
+            width: 6,
+            character: String::from(" "),
+            side: PadSide::Left,
+            cell_paths: None,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(actual, Value::test_string("  ほげ"));
+    }
+
+    #[test]
+    fn wider_input_is_returned_unchanged() {
+        let word = Value::test_string("nushell");
+
+        let options = Arguments {
+            width: 3,
+            character: String::from("-"),
+            side: PadSide::Right,
+            cell_paths: None,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(actual, Value::test_string("nushell"));
+    }
+}
@@ -1,11 +1,11 @@
 use crate::input_handler::{operate, CmdArgument};
-use fancy_regex::{NoExpand, Regex};
-use nu_engine::CallExt;
+use fancy_regex::{Captures, NoExpand, Regex};
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
     ast::{Call, CellPath},
-    engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
-    Value,
+    engine::{Closure, Command, EngineState, Stack},
+    Category, Example, FromValue, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 
 struct Arguments {
@@ -36,7 +36,11 @@ impl Command for SubCommand {
             .input_output_types(vec![(Type::String, Type::String)])
             .vectorizes_over_list(true)
             .required("find", SyntaxShape::String, "the pattern to find")
-            .required("replace", SyntaxShape::String, "the replacement string")
+            .required(
+                "replace",
+                SyntaxShape::Any,
+                "the replacement string, or a closure run for each match to produce it",
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -60,6 +64,10 @@ impl Command for SubCommand {
         "Find and replace text."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The replacement string can reference capture groups as $1 or $name. When the replacement is a closure, it is run once per match with a record of the match text, its bounds, and its capture groups."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["search", "shift", "switch"]
     }
@@ -72,12 +80,31 @@ impl Command for SubCommand {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let find: Spanned<String> = call.req(engine_state, stack, 0)?;
-        let replace: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let replacement: Value = call.req(engine_state, stack, 1)?;
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 2)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
         let literal_replace = call.has_flag("no-expand");
         let no_regex = call.has_flag("string");
 
+        if replacement.as_block().is_ok() {
+            if no_regex {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "closure replacement needs a regular expression, so it can't be combined with --string".to_string(),
+                    span: call.head,
+                });
+            }
+            return replace_with_closure(
+                engine_state,
+                stack,
+                call,
+                input,
+                find,
+                replacement,
+                cell_paths,
+            );
+        }
+        let replace: Spanned<String> = FromValue::from_value(&replacement)?;
+
         let args = Arguments {
             all: call.has_flag("all"),
             find,
@@ -96,6 +123,21 @@ impl Command for SubCommand {
                 example: "'my_library.rb' | str replace '(.+).rb' '$1.nu'",
                 result: Some(Value::test_string("my_library.nu")),
             },
+            Example {
+                description: "Find and replace with named capture groups",
+                example: "'2023-01-15' | str replace '(?<y>\\d+)-(?<m>\\d+)-(?<d>\\d+)' '$m/$d/$y'",
+                result: Some(Value::test_string("01/15/2023")),
+            },
+            Example {
+                description: "Compute the replacement from each match with a closure",
+                example: "'nu shell' | str replace -a '\\w+' {|m| $m.match | str length | into string }",
+                result: Some(Value::test_string("2 5")),
+            },
+            Example {
+                description: "Increment every number in a string",
+                example: "'version 1 build 41' | str replace -a '\\d+' {|m| $m.match | into int | $in + 1 | into string }",
+                result: None,
+            },
             Example {
                 description: "Find and replace all occurrences of find string",
                 example: "'abc abc abc' | str replace -a 'b' 'z'",
@@ -229,6 +271,144 @@ fn action(
     }
 }
 
+// The record handed to a replacement closure: the matched text, its byte
+// bounds, and every capture group, named like `parse` names them.
+fn match_record(caps: &Captures, regex: &Regex, head: Span) -> Value {
+    let mut cols = vec!["match".to_string(), "begin".to_string(), "end".to_string()];
+    let full = caps.get(0).expect("capture 0 always participates");
+    let mut vals = vec![
+        Value::string(full.as_str(), head),
+        Value::int(full.start() as i64, head),
+        Value::int(full.end() as i64, head),
+    ];
+
+    let mut names = regex.capture_names();
+    names.next(); // skip the implicit group 0
+    for (i, name) in names.enumerate() {
+        cols.push(match name {
+            Some(name) => name.to_string(),
+            None => format!("capture{i}"),
+        });
+        vals.push(match caps.get(i + 1) {
+            Some(group) => Value::string(group.as_str(), head),
+            None => Value::nothing(head),
+        });
+    }
+
+    Value::Record {
+        cols,
+        vals,
+        span: head,
+    }
+}
+
+fn replace_with_closure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    find: Spanned<String>,
+    replacement: Value,
+    cell_paths: Option<Vec<CellPath>>,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let all = call.has_flag("all");
+    let regex = Regex::new(&find.item).map_err(|e| ShellError::IncorrectValue {
+        msg: format!("Regex error: {e}"),
+        span: find.span,
+    })?;
+
+    let capture_block: Closure = FromValue::from_value(&replacement)?;
+    let block = engine_state.get_block(capture_block.block_id).clone();
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+    let engine_state = engine_state.clone();
+    let ctrlc = engine_state.ctrlc.clone();
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+    let orig_env_vars = stack.env_vars.clone();
+    let orig_env_hidden = stack.env_hidden.clone();
+
+    input.map(
+        move |v| {
+            // with_env() is used here to ensure that each iteration uses
+            // a different set of environment variables.
+            stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+            let mut run_closure = |record: Value| -> Result<String, ShellError> {
+                if let Some(var) = block.signature.get_positional(0) {
+                    if let Some(var_id) = &var.var_id {
+                        stack.add_var(*var_id, record.clone())
+                    }
+                }
+
+                eval_block(
+                    &engine_state,
+                    &mut stack,
+                    &block,
+                    record.into_pipeline_data(),
+                    redirect_stdout,
+                    redirect_stderr,
+                )?
+                .into_value(head)
+                .as_string()
+            };
+
+            let mut replace_in = |s: &str| -> Result<String, ShellError> {
+                let mut out = String::new();
+                let mut last = 0;
+                for caps in regex.captures_iter(s) {
+                    let caps = caps.map_err(|e| ShellError::IncorrectValue {
+                        msg: format!("Regex error: {e}"),
+                        span: find.span,
+                    })?;
+                    let full = caps.get(0).expect("capture 0 always participates");
+                    out.push_str(&s[last..full.start()]);
+                    out.push_str(&run_closure(match_record(&caps, &regex, head))?);
+                    last = full.end();
+                    if !all {
+                        break;
+                    }
+                }
+                out.push_str(&s[last..]);
+                Ok(out)
+            };
+
+            let mut replace_value = |old: &Value| -> Value {
+                match old {
+                    Value::String { val, .. } => match replace_in(val) {
+                        Ok(replaced) => Value::string(replaced, head),
+                        Err(error) => Value::Error { error },
+                    },
+                    Value::Error { .. } => old.clone(),
+                    _ => Value::Error {
+                        error: ShellError::OnlySupportsThisInputType {
+                            exp_input_type: "string".into(),
+                            wrong_type: old.get_type().to_string(),
+                            dst_span: head,
+                            src_span: old.expect_span(),
+                        },
+                    },
+                }
+            };
+
+            match &cell_paths {
+                None => replace_value(&v),
+                Some(cell_paths) => {
+                    let mut v = v;
+                    for path in cell_paths {
+                        let r = v.update_cell_path(&path.members, Box::new(&mut replace_value));
+                        if let Err(error) = r {
+                            return Value::Error { error };
+                        }
+                    }
+                    v
+                }
+            }
+        },
+        ctrlc,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
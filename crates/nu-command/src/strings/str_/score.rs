@@ -0,0 +1,214 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    needle: String,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str score"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str score")
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (Type::String, Type::Nothing),
+            ])
+            .allow_variants_without_examples(true)
+            .vectorizes_over_list(true)
+            .required(
+                "needle",
+                SyntaxShape::String,
+                "the pattern to score the input against",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, score strings at the given cell paths, and replace with result",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Score how well the input fuzzy-matches a pattern, or null if it doesn't."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Uses fzf-style subsequence scoring: every character of the needle must appear in order, and runs of adjacent matches or matches on word boundaries score higher. Matching is case-insensitive unless the needle contains an uppercase letter. Combine with `sort-by` or `where` for interactive-style filtering."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["fuzzy", "match", "rank", "filter", "similarity"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let needle: String = call.req(engine_state, stack, 0)?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments { needle, cell_paths };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Adjacent matches score higher than scattered ones",
+                example: "'foo_bar' | str score bar",
+                result: Some(Value::test_int(80)),
+            },
+            Example {
+                description: "A needle that isn't a subsequence gives null",
+                example: "'foo' | str score bar",
+                result: Some(Value::test_nothing()),
+            },
+            Example {
+                description: "Rank a list of candidates",
+                example: "['foo_bar' 'barn' 'brain'] | wrap name | sort-by {|it| $it.name | str score bar } -r",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => match score(val, &args.needle) {
+            Some(score) => Value::int(score, head),
+            None => Value::nothing(head),
+        },
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+const MATCH_SCORE: i64 = 16;
+const ADJACENT_BONUS: i64 = 8;
+const BOUNDARY_BONUS: i64 = 16;
+const GAP_PENALTY: i64 = 1;
+
+// The start of the haystack, a character after a separator, and an uppercase
+// letter following a lowercase one all count as word boundaries.
+fn is_boundary(previous: Option<char>, current: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric() && current.is_alphanumeric()
+                || previous.is_lowercase() && current.is_uppercase()
+        }
+    }
+}
+
+// A greedy fzf-style subsequence score: `None` when the needle isn't a
+// subsequence of the haystack. Case-insensitive unless the needle contains an
+// uppercase letter (smart case).
+pub fn score(haystack: &str, needle: &str) -> Option<i64> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    let case_sensitive = needle.chars().any(|c| c.is_uppercase());
+    let fold = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_ascii_lowercase()
+        }
+    };
+
+    let mut needle_chars = needle.chars().map(fold).peekable();
+    let mut total = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+    let mut gap = 0;
+
+    for current in haystack.chars() {
+        match needle_chars.peek() {
+            Some(wanted) if *wanted == fold(current) => {
+                needle_chars.next();
+                total += MATCH_SCORE;
+                if previous_matched {
+                    total += ADJACENT_BONUS;
+                }
+                if is_boundary(previous, current) {
+                    total += BOUNDARY_BONUS;
+                }
+                // only gaps between matches count, not the unmatched tail
+                total -= gap * GAP_PENALTY;
+                gap = 0;
+                previous_matched = true;
+            }
+            Some(_) => {
+                if needle_chars.peek().is_some() && total > 0 {
+                    gap += 1;
+                }
+                previous_matched = false;
+            }
+            None => break,
+        }
+        previous = Some(current);
+    }
+
+    needle_chars.peek().is_none().then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn adjacent_and_boundary_matches_rank_higher() {
+        // a match at a word boundary beats the same characters mid-word
+        assert!(score("foo_bar", "bar") > score("foobar", "bar"));
+        // adjacent matches beat scattered ones
+        assert!(score("abcdef", "abc") > score("axbxcx", "abc"));
+    }
+
+    #[test]
+    fn non_subsequence_is_none() {
+        assert_eq!(score("foo", "bar"), None);
+        assert_eq!(score("ab", "ba"), None);
+    }
+
+    #[test]
+    fn smart_case() {
+        assert!(score("FooBar", "foobar").is_some());
+        assert_eq!(score("foobar", "FooBar"), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::input_handler::{operate, CellPathOnlyArgs};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str stats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str stats")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, count strings at the given cell paths, and replace with result",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Count the bytes, chars, graphemes, words and lines of a string."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A native replacement for wc: words are whitespace-separated, and a trailing newline doesn't count as an extra line."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["count", "wc", "length", "words", "lines"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Count the parts of a string",
+                example: "'There are seven words in this sentence' | str stats",
+                result: Some(Value::Record {
+                    cols: vec![
+                        "bytes".into(),
+                        "chars".into(),
+                        "graphemes".into(),
+                        "words".into(),
+                        "lines".into(),
+                    ],
+                    vals: vec![
+                        Value::test_int(38),
+                        Value::test_int(38),
+                        Value::test_int(38),
+                        Value::test_int(7),
+                        Value::test_int(1),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Chars, graphemes and bytes count multi-byte characters differently",
+                example: "'Amélie' | str stats | get bytes",
+                result: Some(Value::test_int(7)),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => stats(val, head),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+fn stats(s: &str, head: Span) -> Value {
+    Value::Record {
+        cols: vec![
+            "bytes".into(),
+            "chars".into(),
+            "graphemes".into(),
+            "words".into(),
+            "lines".into(),
+        ],
+        vals: vec![
+            Value::int(s.len() as i64, head),
+            Value::int(s.chars().count() as i64, head),
+            Value::int(s.graphemes(true).count() as i64, head),
+            Value::int(s.split_whitespace().count() as i64, head),
+            Value::int(s.lines().count() as i64, head),
+        ],
+        span: head,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn graphemes_group_combining_characters() {
+        // a flag emoji is one grapheme, two chars and eight bytes
+        let counts = stats("🇯🇵", Span::test_data());
+        assert_eq!(
+            counts,
+            Value::Record {
+                cols: vec![
+                    "bytes".into(),
+                    "chars".into(),
+                    "graphemes".into(),
+                    "words".into(),
+                    "lines".into(),
+                ],
+                vals: vec![
+                    Value::test_int(8),
+                    Value::test_int(2),
+                    Value::test_int(1),
+                    Value::test_int(1),
+                    Value::test_int(1),
+                ],
+                span: Span::test_data(),
+            }
+        );
+    }
+}
@@ -130,6 +130,11 @@ impl Command for SubCommand {
                 example: " '🇯🇵ほげ ふが ぴよ' | str substring -g 4..6",
                 result: Some(Value::test_string("ふが")),
             },
+            Example {
+                description: "Negative indexes count graphemes back from the end",
+                example: " '🇯🇵ほげ ふが ぴよ' | str substring -g ',-3'",
+                result: Some(Value::test_string("🇯🇵ほげ ふが")),
+            },
         ]
     }
 }
@@ -138,7 +143,13 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
     let options = &args.indexes;
     match input {
         Value::String { val: s, .. } => {
-            let len: isize = s.len() as isize;
+            // negative indices count back from the end in the same unit the
+            // slicing uses, so -1 under -g is the last grapheme, not byte
+            let len: isize = if args.graphemes {
+                s.graphemes(true).count() as isize
+            } else {
+                s.len() as isize
+            };
 
             let start: isize = if options.0 < 0 {
                 options.0 + len
@@ -393,4 +404,21 @@ mod tests {
         let actual = action(&word, &options, Span::test_data());
         assert_eq!(actual, Value::test_string("�"));
     }
+
+    #[test]
+    fn negative_indexes_count_graphemes() {
+        let word = Value::String {
+            val: String::from("🇯🇵ほげ ふが ぴよ"),
+            span: Span::test_data(),
+        };
+
+        let options = Arguments {
+            cell_paths: None,
+            indexes: Substring(-2, isize::max_value()),
+            graphemes: true,
+        };
+
+        let actual = action(&word, &options, Span::test_data());
+        assert_eq!(actual, Value::test_string("ぴよ"));
+    }
 }
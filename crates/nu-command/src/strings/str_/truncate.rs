@@ -0,0 +1,172 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    width: usize,
+    suffix: String,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str truncate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str truncate")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, truncate strings at the given cell paths, and replace with result",
+            )
+            .required_named(
+                "width",
+                SyntaxShape::Int,
+                "the maximum display width of the result",
+                Some('w'),
+            )
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "text appended when the string is shortened (defaults to nothing)",
+                Some('s'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Shorten a string to a maximum width."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Width is measured in display columns, so wide characters count as two and the cut never lands inside a grapheme cluster. The suffix counts towards the width, and strings that already fit are returned unchanged."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cut", "shorten", "ellipsis", "limit"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let width: usize = call
+            .get_flag(engine_state, stack, "width")?
+            .expect("required named argument");
+        let suffix: Option<String> = call.get_flag(engine_state, stack, "suffix")?;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            width,
+            suffix: suffix.unwrap_or_default(),
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Truncate a string to 8 columns",
+                example: "'hello, world' | str truncate --width 8",
+                result: Some(Value::test_string("hello, w")),
+            },
+            Example {
+                description: "Mark the cut with an ellipsis, which counts towards the width",
+                example: "'hello, world' | str truncate --width 8 --suffix '…'",
+                result: Some(Value::test_string("hello, …")),
+            },
+            Example {
+                description: "Strings that already fit are unchanged",
+                example: "'hello' | str truncate --width 8 --suffix '…'",
+                result: Some(Value::test_string("hello")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(truncate(val, args.width, &args.suffix), head),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+fn truncate(s: &str, width: usize, suffix: &str) -> String {
+    if display_width(s) <= width {
+        return s.to_string();
+    }
+
+    let budget = width.saturating_sub(display_width(suffix));
+    let mut truncated = String::new();
+    let mut cols = 0;
+
+    for grapheme in s.graphemes(true) {
+        let grapheme_cols = UnicodeWidthStr::width(grapheme);
+        if cols + grapheme_cols > budget {
+            break;
+        }
+        truncated.push_str(grapheme);
+        cols += grapheme_cols;
+    }
+
+    truncated.push_str(suffix);
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn never_cuts_a_wide_character_in_half() {
+        // the second character would straddle the limit, so it is dropped
+        assert_eq!(truncate("aほげ", 4, ""), "aほ");
+    }
+
+    #[test]
+    fn suffix_wider_than_the_limit_still_fits_nothing_else() {
+        assert_eq!(truncate("hello, world", 1, "…"), "…");
+    }
+}
@@ -0,0 +1,292 @@
+use super::escape::Context;
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    context: Context,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str unescape"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str unescape")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .vectorizes_over_list(true)
+            .required_named(
+                "for",
+                SyntaxShape::String,
+                "the context to unescape: 'shell', 'regex', 'html', 'json' or 'url'",
+                Some('f'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, unescape strings at the given cell paths, and replace with result",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Undo the escaping applied for a given context."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This is the inverse of `str escape`: it removes shell quoting, drops regex backslashes, decodes HTML entities, interprets JSON string escapes, and percent-decodes URLs."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unquote", "decode", "entity", "percent"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let context: Spanned<String> = call
+            .get_flag(engine_state, stack, "for")?
+            .expect("required named argument");
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            context: Context::from_spanned(&context)?,
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove shell quoting",
+                example: "\"'hello world'\" | str unescape --for shell",
+                result: Some(Value::test_string("hello world")),
+            },
+            Example {
+                description: "Decode HTML entities",
+                example: "'a &lt; b &amp; c' | str unescape --for html",
+                result: Some(Value::test_string("a < b & c")),
+            },
+            Example {
+                description: "Interpret JSON string escapes",
+                example: "'say \\\"hi\\\"' | str unescape --for json",
+                result: Some(Value::test_string("say \"hi\"")),
+            },
+            Example {
+                description: "Percent-decode a URL component",
+                example: "'hello%20world' | str unescape --for url",
+                result: Some(Value::test_string("hello world")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => match unescape(val, args.context) {
+            Ok(unescaped) => Value::string(unescaped, head),
+            Err(msg) => Value::Error {
+                error: ShellError::UnsupportedInput(
+                    msg,
+                    "value originates from here".into(),
+                    head,
+                    input.expect_span(),
+                ),
+            },
+        },
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+pub(crate) fn unescape(val: &str, context: Context) -> Result<String, String> {
+    match context {
+        Context::Shell => unescape_shell(val),
+        Context::Regex => {
+            let mut out = String::with_capacity(val.len());
+            let mut chars = val.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    match chars.next() {
+                        Some(next) => out.push(next),
+                        None => return Err("trailing backslash".into()),
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            Ok(out)
+        }
+        Context::Html => {
+            htmlescape::decode_html(val).map_err(|err| format!("invalid HTML entity: {err:?}"))
+        }
+        Context::Json => unescape_json(val),
+        Context::Url => percent_encoding::percent_decode_str(val)
+            .decode_utf8()
+            .map(|decoded| decoded.to_string())
+            .map_err(|_| "percent-decoded value is not valid UTF-8".into()),
+    }
+}
+
+/// Interpret POSIX shell quoting: single quotes are literal, double quotes
+/// allow backslash escapes, and a bare backslash escapes the next character
+fn unescape_shell(val: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(val.len());
+    let mut chars = val.chars();
+    loop {
+        match chars.next() {
+            None => return Ok(out),
+            Some('\'') => loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => out.push(c),
+                    None => return Err("unclosed single quote".into()),
+                }
+            },
+            Some('"') => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(c) if matches!(c, '"' | '\\' | '$' | '`') => out.push(c),
+                        Some(c) => {
+                            out.push('\\');
+                            out.push(c);
+                        }
+                        None => return Err("trailing backslash".into()),
+                    },
+                    Some(c) => out.push(c),
+                    None => return Err("unclosed double quote".into()),
+                }
+            },
+            Some('\\') => match chars.next() {
+                Some(c) => out.push(c),
+                None => return Err("trailing backslash".into()),
+            },
+            Some(c) => out.push(c),
+        }
+    }
+}
+
+fn unescape_json(val: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(val.len());
+    let mut chars = val.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('/') => out.push('/'),
+            Some('b') => out.push('\u{8}'),
+            Some('f') => out.push('\u{c}'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('u') => {
+                let unit = read_code_unit(&mut chars)?;
+                let code = if (0xd800..0xdc00).contains(&unit) {
+                    // a high surrogate must be followed by `\uXXXX` holding the
+                    // low surrogate
+                    if chars.next() != Some('\\') || chars.next() != Some('u') {
+                        return Err("unpaired surrogate in \\u escape".into());
+                    }
+                    let low = read_code_unit(&mut chars)?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err("unpaired surrogate in \\u escape".into());
+                    }
+                    0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00)
+                } else {
+                    unit
+                };
+                match char::from_u32(code) {
+                    Some(c) => out.push(c),
+                    None => return Err("invalid \\u escape".into()),
+                }
+            }
+            Some(c) => return Err(format!("unknown escape '\\{c}'")),
+            None => return Err("trailing backslash".into()),
+        }
+    }
+    Ok(out)
+}
+
+fn read_code_unit(chars: &mut std::str::Chars) -> Result<u32, String> {
+    let mut unit = 0;
+    for _ in 0..4 {
+        let digit = chars
+            .next()
+            .and_then(|c| c.to_digit(16))
+            .ok_or_else(|| "\\u must be followed by four hex digits".to_string())?;
+        unit = unit * 16 + digit;
+    }
+    Ok(unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn shell_unescape_round_trips() {
+        let original = "it's a \"test\"";
+        let escaped = super::super::escape::escape(original, Context::Shell);
+        assert_eq!(unescape(&escaped, Context::Shell).unwrap(), original);
+    }
+
+    #[test]
+    fn json_unescape_surrogate_pair() {
+        assert_eq!(
+            unescape(r"\ud83d\ude00", Context::Json).unwrap(),
+            "\u{1f600}"
+        );
+        assert!(unescape(r"\ud83d", Context::Json).is_err());
+    }
+
+    #[test]
+    fn url_unescape_rejects_invalid_utf8() {
+        assert!(unescape("%ff%fe", Context::Url).is_err());
+    }
+}
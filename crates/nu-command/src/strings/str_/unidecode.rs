@@ -0,0 +1,187 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str unidecode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str unidecode")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, transliterate strings at the given cell paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Transliterate text to its closest ASCII representation."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Accented letters lose their marks (é becomes e), ligatures and special
+letters expand (ß becomes ss, æ becomes ae), and typographic punctuation
+falls back to its plain form. Characters with no reasonable ASCII
+counterpart are dropped."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ascii", "transliterate", "slug", "accent", "diacritic"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let args = Arguments { cell_paths };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Strip accents from a string",
+                example: "'café München' | str unidecode",
+                result: Some(Value::test_string("cafe Munchen")),
+            },
+            Example {
+                description: "Expand special letters",
+                example: "'Straße' | str unidecode",
+                result: Some(Value::test_string("Strasse")),
+            },
+            Example {
+                description: "Transliterate every string in a list",
+                example: "['señor' 'naïve'] | str unidecode",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("senor"), Value::test_string("naive")],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, span } => Value::string(unidecode(val), *span),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+/// ASCII replacements for characters that compatibility decomposition does
+/// not reach
+fn special_replacement(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'đ' => "d",
+        'Đ' => "D",
+        'ð' => "d",
+        'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "Th",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ł' => "l",
+        'Ł' => "L",
+        'ı' => "i",
+        'ŋ' => "ng",
+        'Ŋ' => "NG",
+        '\u{2018}' | '\u{2019}' => "'",
+        '\u{201c}' | '\u{201d}' => "\"",
+        '\u{2013}' | '\u{2014}' => "-",
+        '\u{2026}' => "...",
+        '\u{00a0}' => " ",
+        '\u{00d7}' => "x",
+        _ => return None,
+    })
+}
+
+fn unidecode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.nfkd() {
+        if c.is_ascii() {
+            out.push(c);
+        } else if let Some(replacement) = special_replacement(c) {
+            out.push_str(replacement);
+        }
+        // combining marks and anything else without an ASCII counterpart
+        // are dropped
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_combining_marks_after_decomposition() {
+        assert_eq!(unidecode("ñandú"), "nandu");
+    }
+
+    #[test]
+    fn drops_characters_without_a_counterpart() {
+        assert_eq!(unidecode("nu 🚀 shell"), "nu  shell");
+    }
+
+    #[test]
+    fn flattens_typographic_punctuation() {
+        assert_eq!(unidecode("“it’s” — fine…"), "\"it's\" - fine...");
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
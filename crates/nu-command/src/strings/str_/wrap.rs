@@ -0,0 +1,196 @@
+use crate::input_handler::{operate, CmdArgument};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+struct Arguments {
+    width: usize,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str wrap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str wrap")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .vectorizes_over_list(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, wrap strings at the given cell paths, and replace with result",
+            )
+            .required_named(
+                "width",
+                SyntaxShape::Int,
+                "the maximum display width of each line",
+                Some('w'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Soft-wrap a string at word boundaries to a maximum width."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Width is measured in display columns, so wide characters count as two. Existing line breaks are kept, and a single word wider than the limit is broken rather than overflowing."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["fold", "linebreak", "word", "columns"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let width: usize = call
+            .get_flag(engine_state, stack, "width")?
+            .expect("required named argument");
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let args = Arguments {
+            width: width.max(1),
+            cell_paths,
+        };
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Wrap a sentence to lines of at most 10 columns",
+                example: "'the quick brown fox' | str wrap --width 10",
+                result: Some(Value::test_string("the quick\nbrown fox")),
+            },
+            Example {
+                description: "Words wider than the limit are broken",
+                example: "'antidisestablishmentarianism' | str wrap --width 10",
+                result: Some(Value::test_string("antidisest\nablishment\narianism")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(wrap(val, args.width), head),
+        Value::Error { .. } => input.clone(),
+        _ => Value::Error {
+            error: ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: input.get_type().to_string(),
+                dst_span: head,
+                src_span: input.expect_span(),
+            },
+        },
+    }
+}
+
+fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+// Break a single word that is wider than the limit into width-sized pieces,
+// splitting between grapheme clusters.
+fn break_word(word: &str, width: usize, lines: &mut Vec<String>) {
+    let mut piece = String::new();
+    let mut cols = 0;
+    for grapheme in word.graphemes(true) {
+        let grapheme_cols = UnicodeWidthStr::width(grapheme);
+        if cols + grapheme_cols > width && !piece.is_empty() {
+            lines.push(std::mem::take(&mut piece));
+            cols = 0;
+        }
+        piece.push_str(grapheme);
+        cols += grapheme_cols;
+    }
+    if !piece.is_empty() {
+        lines.push(piece);
+    }
+}
+
+fn wrap_line(line: &str, width: usize, lines: &mut Vec<String>) {
+    let mut current = String::new();
+    let mut cols = 0;
+
+    for word in line.split_whitespace() {
+        let word_cols = display_width(word);
+        let separator = usize::from(!current.is_empty());
+
+        if cols + separator + word_cols <= width {
+            if separator == 1 {
+                current.push(' ');
+            }
+            current.push_str(word);
+            cols += separator + word_cols;
+        } else {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            if word_cols > width {
+                break_word(word, width, lines);
+                // continue the line from the last broken piece
+                current = lines.pop().unwrap_or_default();
+                cols = display_width(&current);
+            } else {
+                current = word.to_string();
+                cols = word_cols;
+            }
+        }
+    }
+
+    lines.push(current);
+}
+
+fn wrap(s: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    for line in s.lines() {
+        wrap_line(line, width, &mut lines);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn existing_line_breaks_are_kept() {
+        assert_eq!(wrap("one two\nthree four", 9), "one two\nthree\nfour");
+    }
+
+    #[test]
+    fn wide_characters_wrap_by_display_width() {
+        // each character is two columns wide, so only two fit on a line
+        assert_eq!(wrap("ほげほげ", 4), "ほげ\nほげ");
+    }
+}
@@ -0,0 +1,319 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ExternStub;
+
+impl Command for ExternStub {
+    fn name(&self) -> &str {
+        "extern-stub"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("extern-stub")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .required(
+                "command",
+                SyntaxShape::String,
+                "the external command to generate a stub for",
+            )
+            .named(
+                "help-flag",
+                SyntaxShape::String,
+                "the flag that makes the command print its help text (default: --help)",
+                None,
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate an extern signature stub from an external command's help output."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The command is run with its help flag and the output is scanned for flags and
+subcommands. The stub is a best-effort starting point: review it, fix up the
+types, then `source` it to get completion and call validation for the tool."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["signature", "completion", "generate", "bootstrap"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let command: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let help_flag: Option<String> = call.get_flag(engine_state, stack, "help-flag")?;
+        let help_flag = help_flag.unwrap_or_else(|| "--help".into());
+
+        let output = std::process::Command::new(&command.item)
+            .arg(&help_flag)
+            .output()
+            .map_err(|err| {
+                ShellError::GenericError(
+                    format!("Failed to run {} {}", command.item, help_flag),
+                    err.to_string(),
+                    Some(command.span),
+                    None,
+                    vec![],
+                )
+            })?;
+
+        // some tools print their help text to stderr
+        let mut help_text = String::from_utf8_lossy(&output.stdout).to_string();
+        help_text.push_str(&String::from_utf8_lossy(&output.stderr));
+
+        let stub = parse_help_text(&help_text);
+
+        Ok(Value::String {
+            val: render_stub(&command.item, &stub),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate an extern stub for git and save it for sourcing",
+                example: "extern-stub git | save git-extern.nu",
+                result: None,
+            },
+            Example {
+                description: "Probe a tool that prints its help behind -h",
+                example: "extern-stub zig --help-flag '-h'",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct HelpStub {
+    flags: Vec<FlagStub>,
+    subcommands: Vec<String>,
+}
+
+struct FlagStub {
+    long: Option<String>,
+    short: Option<char>,
+    takes_value: bool,
+    description: String,
+}
+
+/// Scan help output for flag definitions and subcommand listings.
+///
+/// Flags are lines starting with a dash; subcommands are indented names under
+/// a header mentioning "commands". Both are heuristics tuned for the layout
+/// most argument parsers print, so unusual tools may need manual stubs.
+fn parse_help_text(help: &str) -> HelpStub {
+    let mut flags: Vec<FlagStub> = vec![];
+    let mut subcommands: Vec<String> = vec![];
+    let mut in_subcommands = false;
+
+    for line in help.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.ends_with(':') && !trimmed.starts_with('-') {
+            in_subcommands = trimmed.to_lowercase().contains("command");
+            continue;
+        }
+
+        if trimmed.starts_with('-') {
+            in_subcommands = false;
+            if let Some(flag) = parse_flag_line(trimmed) {
+                let seen = flags.iter().any(|known| {
+                    (flag.long.is_some() && known.long == flag.long)
+                        || (flag.long.is_none() && known.short == flag.short)
+                });
+                if !seen {
+                    flags.push(flag);
+                }
+            }
+            continue;
+        }
+
+        if in_subcommands && line.starts_with(char::is_whitespace) {
+            if let Some(name) = trimmed.split_whitespace().next() {
+                if is_command_name(name) && !subcommands.iter().any(|known| known == name) {
+                    subcommands.push(name.to_string());
+                }
+            }
+        } else if !line.starts_with(char::is_whitespace) {
+            in_subcommands = false;
+        }
+    }
+
+    HelpStub { flags, subcommands }
+}
+
+fn is_command_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn parse_flag_line(line: &str) -> Option<FlagStub> {
+    // the flag spec is separated from its description by at least two spaces
+    let (spec, description) = match line.split_once("  ") {
+        Some((spec, description)) => (spec, description.trim().to_string()),
+        None => (line, String::new()),
+    };
+
+    let mut long = None;
+    let mut short = None;
+    let mut takes_value = false;
+
+    for token in spec.split([' ', ',', '=']).filter(|t| !t.is_empty()) {
+        if let Some(rest) = token.strip_prefix("--") {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+                .collect();
+            if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                return None;
+            }
+            // a trailing `=VALUE` or `[=VALUE]` means the flag takes a value
+            if rest.len() > name.len() {
+                takes_value = true;
+            }
+            long = Some(name);
+        } else if let Some(rest) = token.strip_prefix('-') {
+            let mut chars = rest.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => short = Some(c),
+                // a negative number or similar; this is not a flag line
+                _ => return None,
+            }
+        } else if token.starts_with('<')
+            || token.chars().all(|c| c.is_ascii_uppercase() || c == '_')
+        {
+            takes_value = true;
+        } else {
+            // ran into prose; keep whatever was parsed so far
+            break;
+        }
+    }
+
+    if long.is_some() || short.is_some() {
+        Some(FlagStub {
+            long,
+            short,
+            takes_value,
+            description,
+        })
+    } else {
+        None
+    }
+}
+
+fn render_stub(tool: &str, stub: &HelpStub) -> String {
+    let mut out = format!(
+        "# An extern stub for {tool}, generated from its help output.\n\
+         # Review the flags and types before sourcing this file.\n\
+         export extern \"{tool}\" [\n"
+    );
+
+    for flag in &stub.flags {
+        // nushell signatures have no short-only flags, so those are dropped
+        let Some(long) = &flag.long else { continue };
+        out.push_str(&format!("    --{long}"));
+        if let Some(short) = flag.short {
+            out.push_str(&format!("(-{short})"));
+        }
+        if flag.takes_value {
+            out.push_str(": string");
+        }
+        if !flag.description.is_empty() {
+            out.push_str(&format!(" # {}", flag.description));
+        }
+        out.push('\n');
+    }
+    out.push_str("    ...args: any\n]\n");
+
+    for subcommand in &stub.subcommands {
+        out.push_str(&format!(
+            "\nexport extern \"{tool} {subcommand}\" [\n    ...args: any\n]\n"
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HELP_TEXT: &str = "\
+A fictional tool
+
+USAGE:
+    tool [OPTIONS] <path>
+
+OPTIONS:
+    -v, --verbose            Print more output
+    -c, --config <FILE>      Path to the configuration file
+        --color <WHEN>       Coloring: auto, always, never
+    -h, --help               Print help information
+
+SUBCOMMANDS:
+    build    Compile the thing
+    run      Run the thing
+";
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ExternStub {})
+    }
+
+    #[test]
+    fn parses_flags_from_help_text() {
+        let stub = parse_help_text(HELP_TEXT);
+        let config = stub
+            .flags
+            .iter()
+            .find(|flag| flag.long.as_deref() == Some("config"))
+            .expect("--config not found");
+
+        assert_eq!(config.short, Some('c'));
+        assert!(config.takes_value);
+        assert_eq!(config.description, "Path to the configuration file");
+
+        let verbose = stub
+            .flags
+            .iter()
+            .find(|flag| flag.long.as_deref() == Some("verbose"))
+            .expect("--verbose not found");
+        assert!(!verbose.takes_value);
+    }
+
+    #[test]
+    fn parses_subcommands_from_help_text() {
+        let stub = parse_help_text(HELP_TEXT);
+        assert_eq!(stub.subcommands, vec!["build", "run"]);
+    }
+
+    #[test]
+    fn renders_extern_definitions() {
+        let rendered = render_stub("tool", &parse_help_text(HELP_TEXT));
+        assert!(rendered.contains("export extern \"tool\" ["));
+        assert!(rendered.contains("--config(-c): string # Path to the configuration file"));
+        assert!(rendered.contains("export extern \"tool build\" ["));
+    }
+}
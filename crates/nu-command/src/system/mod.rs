@@ -1,6 +1,7 @@
 mod complete;
 #[cfg(unix)]
 mod exec;
+mod extern_stub;
 mod nu_check;
 #[cfg(any(
     target_os = "android",
@@ -12,12 +13,15 @@ mod ps;
 #[cfg(windows)]
 mod registry_query;
 mod run_external;
+#[cfg(feature = "self-update")]
+mod self_update;
 mod sys;
 mod which_;
 
 pub use complete::Complete;
 #[cfg(unix)]
 pub use exec::Exec;
+pub use extern_stub::ExternStub;
 pub use nu_check::NuCheck;
 #[cfg(any(
     target_os = "android",
@@ -29,5 +33,7 @@ pub use ps::Ps;
 #[cfg(windows)]
 pub use registry_query::RegistryQuery;
 pub use run_external::{External, ExternalCommand};
+#[cfg(feature = "self-update")]
+pub use self_update::SelfUpdate;
 pub use sys::Sys;
 pub use which_::Which;
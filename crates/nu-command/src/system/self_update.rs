@@ -0,0 +1,353 @@
+use crate::network::{extract_tag_name, is_newer};
+use flate2::read::{DeflateDecoder, GzDecoder};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+
+const RELEASE_FEED_URL: &str = "https://api.github.com/repos/nushell/nushell/releases/latest";
+const DOWNLOAD_URL_BASE: &str = "https://github.com/nushell/nushell/releases/download";
+
+#[derive(Clone)]
+pub struct SelfUpdate;
+
+impl Command for SelfUpdate {
+    fn name(&self) -> &str {
+        "self update"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("self update")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+            .switch(
+                "force",
+                "reinstall even when the running version is already the latest",
+                Some('f'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Update nu in place to the latest release."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Downloads the release archive for this platform, verifies its checksum, and atomically replaces the running binary. Intended for installs made outside a package manager; installs managed by one should be updated through it instead."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["upgrade", "install", "release", "binary"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let force = call.has_flag("force");
+        let current = env!("CARGO_PKG_VERSION");
+
+        let agent = build_agent();
+
+        let feed = fetch(&agent, RELEASE_FEED_URL, span)?;
+        let latest = extract_tag_name(&String::from_utf8_lossy(&feed)).ok_or_else(|| {
+            ShellError::NetworkFailure(
+                "The release feed did not contain a version tag".into(),
+                span,
+            )
+        })?;
+
+        if !force && !is_newer(&latest, current) {
+            return Ok(Value::string(
+                format!("nu {current} is already the latest release"),
+                span,
+            )
+            .into_pipeline_data());
+        }
+
+        let target = release_target(span)?;
+        let (archive_name, binary_name) = if cfg!(windows) {
+            (format!("nu-{latest}-{target}.zip"), "nu.exe")
+        } else {
+            (format!("nu-{latest}-{target}.tar.gz"), "nu")
+        };
+
+        let archive = fetch(
+            &agent,
+            &format!("{DOWNLOAD_URL_BASE}/{latest}/{archive_name}"),
+            span,
+        )?;
+        let checksums = fetch(
+            &agent,
+            &format!("{DOWNLOAD_URL_BASE}/{latest}/SHA256SUMS"),
+            span,
+        )?;
+        verify_checksum(
+            &archive,
+            &String::from_utf8_lossy(&checksums),
+            &archive_name,
+            span,
+        )?;
+
+        let binary = if cfg!(windows) {
+            extract_from_zip(&archive, binary_name, span)?
+        } else {
+            extract_from_tar_gz(&archive, binary_name, span)?
+        };
+
+        replace_current_binary(&binary)?;
+
+        Ok(Value::string(
+            format!("Updated nu {current} to {latest}; restart your shell to use the new version"),
+            span,
+        )
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Update nu to the latest release",
+            example: "self update",
+            result: None,
+        }]
+    }
+}
+
+fn build_agent() -> ureq::Agent {
+    let tls = native_tls::TlsConnector::builder()
+        .build()
+        .expect("Failed to build network tls");
+
+    ureq::builder()
+        .user_agent("nushell")
+        .tls_connector(std::sync::Arc::new(tls))
+        .build()
+}
+
+fn fetch(agent: &ureq::Agent, url: &str, span: Span) -> Result<Vec<u8>, ShellError> {
+    let response = agent.get(url).call().map_err(|e| {
+        ShellError::NetworkFailure(format!("Unable to download '{url}': {e}"), span)
+    })?;
+
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(|e| ShellError::IOError(format!("Unable to read '{url}': {e}")))?;
+    Ok(bytes)
+}
+
+fn release_target(span: Span) -> Result<String, ShellError> {
+    let arch = std::env::consts::ARCH;
+
+    match std::env::consts::OS {
+        "linux" => Ok(format!("{arch}-unknown-linux-gnu")),
+        "macos" => Ok(format!("{arch}-apple-darwin")),
+        "windows" => Ok(format!("{arch}-pc-windows-msvc")),
+        os => Err(ShellError::GenericError(
+            "Unsupported platform".into(),
+            format!("no release artifacts are published for {os}"),
+            Some(span),
+            None,
+            Vec::new(),
+        )),
+    }
+}
+
+fn verify_checksum(
+    archive: &[u8],
+    checksums: &str,
+    archive_name: &str,
+    span: Span,
+) -> Result<(), ShellError> {
+    // each line of the checksum manifest is "<sha256>  <file name>"
+    let expected = checksums
+        .lines()
+        .find_map(|line| {
+            let mut parts = line.split_whitespace();
+            let hash = parts.next()?;
+            let file = parts.next()?;
+            (file.trim_start_matches('*').ends_with(archive_name)).then(|| hash.to_lowercase())
+        })
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                "Checksum missing".into(),
+                format!("the checksum manifest has no entry for {archive_name}"),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(archive);
+    let actual = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ShellError::GenericError(
+            "Checksum mismatch".into(),
+            format!("expected {expected} but the download hashed to {actual}"),
+            Some(span),
+            Some("the download may be corrupted or tampered with; not installing it".into()),
+            Vec::new(),
+        ))
+    }
+}
+
+// A minimal ustar reader: headers are 512-byte blocks with the name at offset
+// 0 and the octal size at offset 124, and file data is padded to block size.
+fn extract_from_tar_gz(
+    archive: &[u8],
+    binary_name: &str,
+    span: Span,
+) -> Result<Vec<u8>, ShellError> {
+    let mut tar = Vec::new();
+    GzDecoder::new(archive)
+        .read_to_end(&mut tar)
+        .map_err(|e| ShellError::IOError(format!("Unable to decompress the archive: {e}")))?;
+
+    let mut offset = 0;
+    while offset + 512 <= tar.len() {
+        let header = &tar[offset..offset + 512];
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+
+        let name_len = header[..100].iter().position(|b| *b == 0).unwrap_or(100);
+        let name = String::from_utf8_lossy(&header[..name_len]).to_string();
+        let size = header[124..136]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .fold(0usize, |acc, b| acc * 8 + (b - b'0') as usize);
+
+        let data_start = offset + 512;
+        let is_file = header[156] == b'0' || header[156] == 0;
+        if is_file
+            && name.rsplit('/').next() == Some(binary_name)
+            && data_start + size <= tar.len()
+        {
+            return Ok(tar[data_start..data_start + size].to_vec());
+        }
+
+        offset = data_start + (size + 511) / 512 * 512;
+    }
+
+    Err(binary_not_found(binary_name, span))
+}
+
+// A minimal zip reader walking the local file headers; enough for the release
+// archives, which use plain deflate without data descriptors.
+fn extract_from_zip(archive: &[u8], binary_name: &str, span: Span) -> Result<Vec<u8>, ShellError> {
+    let u16_at = |at: usize| u16::from_le_bytes([archive[at], archive[at + 1]]) as usize;
+    let u32_at = |at: usize| {
+        u32::from_le_bytes([
+            archive[at],
+            archive[at + 1],
+            archive[at + 2],
+            archive[at + 3],
+        ]) as usize
+    };
+
+    let mut offset = 0;
+    while offset + 30 <= archive.len() && &archive[offset..offset + 4] == b"PK\x03\x04" {
+        let method = u16_at(offset + 8);
+        let compressed_size = u32_at(offset + 18);
+        let name_len = u16_at(offset + 26);
+        let extra_len = u16_at(offset + 28);
+
+        let name_start = offset + 30;
+        let data_start = name_start + name_len + extra_len;
+        if data_start + compressed_size > archive.len() {
+            break;
+        }
+
+        let name = String::from_utf8_lossy(&archive[name_start..name_start + name_len]);
+        if name.rsplit(['/', '\\']).next() == Some(binary_name) {
+            let data = &archive[data_start..data_start + compressed_size];
+            return match method {
+                // stored
+                0 => Ok(data.to_vec()),
+                // deflate
+                8 => {
+                    let mut binary = Vec::new();
+                    DeflateDecoder::new(data).read_to_end(&mut binary).map_err(|e| {
+                        ShellError::IOError(format!("Unable to decompress the archive: {e}"))
+                    })?;
+                    Ok(binary)
+                }
+                _ => Err(ShellError::IOError(format!(
+                    "Unsupported compression method {method} in the archive"
+                ))),
+            };
+        }
+
+        offset = data_start + compressed_size;
+    }
+
+    Err(binary_not_found(binary_name, span))
+}
+
+fn binary_not_found(binary_name: &str, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Binary not found".into(),
+        format!("the release archive does not contain {binary_name}"),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn replace_current_binary(binary: &[u8]) -> Result<(), ShellError> {
+    let current = std::env::current_exe()
+        .map_err(|e| ShellError::IOError(format!("Unable to locate the running binary: {e}")))?;
+    let staging = current.with_extension("update");
+
+    std::fs::write(&staging, binary)
+        .map_err(|e| ShellError::IOError(format!("Unable to write the new binary: {e}")))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(&staging, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| ShellError::IOError(format!("Unable to mark the binary executable: {e}")))?;
+
+        // rename over the running binary is atomic, so a concurrent launch
+        // sees either the old or the new version
+        std::fs::rename(&staging, &current)
+            .map_err(|e| ShellError::IOError(format!("Unable to install the new binary: {e}")))?;
+    }
+
+    #[cfg(windows)]
+    {
+        // Windows refuses to overwrite a running executable but allows
+        // renaming it away, so park the old binary next to the new one
+        let parked = current.with_extension("old.exe");
+        let _ = std::fs::remove_file(&parked);
+        std::fs::rename(&current, &parked)
+            .map_err(|e| ShellError::IOError(format!("Unable to move the old binary: {e}")))?;
+
+        if let Err(e) = std::fs::rename(&staging, &current) {
+            // roll back so the existing install keeps working
+            let _ = std::fs::rename(&parked, &current);
+            return Err(ShellError::IOError(format!(
+                "Unable to install the new binary: {e}"
+            )));
+        }
+    }
+
+    Ok(())
+}
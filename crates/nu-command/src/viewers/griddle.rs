@@ -277,6 +277,7 @@ fn convert_to_list(
                             &[PathMember::String {
                                 val: header.into(),
                                 span: head,
+                                optional: false,
                             }],
                             false,
                             false,
@@ -90,6 +90,12 @@ impl Command for Table {
                 "sets a separator when 'flatten' used",
                 None,
             )
+            .named(
+                "footer-calc",
+                SyntaxShape::Record,
+                "append a computed summary row, e.g. {price: sum, name: count}",
+                None,
+            )
             .switch(
                 "collapse",
                 "expand the table structure in collapse mode.\nBe aware collapse mode currently doesn't support width control",
@@ -118,6 +124,29 @@ impl Command for Table {
         let flatten: bool = call.has_flag("flatten");
         let flatten_separator: Option<String> =
             call.get_flag(engine_state, stack, "flatten-separator")?;
+        let footer_calc: Option<Value> = call.get_flag(engine_state, stack, "footer-calc")?;
+
+        // a summary row needs every row, so the calculation collects the input
+        let input = match footer_calc {
+            Some(calc) => match input {
+                PipelineData::Value(Value::List { vals, span }, metadata) => {
+                    let vals = crate::append_summary_footer(vals, &calc, call.head)?;
+                    PipelineData::Value(Value::List { vals, span }, metadata)
+                }
+                PipelineData::ListStream(stream, metadata) => {
+                    let vals = crate::append_summary_footer(stream.collect(), &calc, call.head)?;
+                    PipelineData::Value(
+                        Value::List {
+                            vals,
+                            span: call.head,
+                        },
+                        metadata,
+                    )
+                }
+                other => other,
+            },
+            None => input,
+        };
 
         let table_view = match (expand, collapse) {
             (false, false) => TableView::General,
@@ -805,7 +834,7 @@ fn make_clickable_link(
 // Currently, the other table forms don't use this.
 // Because of how table-specific this is, I don't think this can be pushed into StyleComputer itself.
 enum DeferredStyleComputation {
-    Value { value: Value },
+    Value { value: Value, row: Option<Value> },
     Header { text: String },
     RowIndex { text: String },
     Empty {},
@@ -815,16 +844,16 @@ impl DeferredStyleComputation {
     // This is only run inside a par_extend().
     fn compute(&self, config: &Config, style_computer: &StyleComputer) -> NuText {
         match self {
-            DeferredStyleComputation::Value { value } => {
+            DeferredStyleComputation::Value { value, row } => {
                 match value {
                     // Float precision is required here.
                     Value::Float { val, .. } => (
-                        format!("{:.prec$}", val, prec = config.float_precision as usize),
-                        style_computer.style_primitive(value),
+                        nu_protocol::format_float_from_conf(*val, config),
+                        style_computer.style_primitive_in_row(value, row.as_ref()),
                     ),
                     _ => (
                         value.into_abbreviated_string(config),
-                        style_computer.style_primitive(value),
+                        style_computer.style_primitive_in_row(value, row.as_ref()),
                     ),
                 }
             }
@@ -923,6 +952,7 @@ fn convert_to_table(
         if !with_header {
             row.push(DeferredStyleComputation::Value {
                 value: item.clone(),
+                row: None,
             });
         } else {
             let skip_num = usize::from(with_index);
@@ -934,16 +964,21 @@ fn convert_to_table(
                             let path = PathMember::String {
                                 val: text.clone(),
                                 span: head,
+                                optional: false,
                             };
                             let val = item.clone().follow_cell_path(&[path], false, false);
 
                             match val {
-                                Ok(val) => DeferredStyleComputation::Value { value: val },
+                                Ok(val) => DeferredStyleComputation::Value {
+                                    value: val,
+                                    row: Some(item.clone()),
+                                },
                                 Err(_) => DeferredStyleComputation::Empty {},
                             }
                         }
                         _ => DeferredStyleComputation::Value {
                             value: item.clone(),
+                            row: None,
                         },
                     });
                 }
@@ -1321,7 +1356,11 @@ fn create_table2_entry(
     match item {
         Value::Record { .. } => {
             let val = header.to_owned();
-            let path = PathMember::String { val, span: head };
+            let path = PathMember::String {
+                val,
+                span: head,
+                optional: false,
+            };
             let val = item.clone().follow_cell_path(&[path], false, false);
 
             match val {
@@ -1335,7 +1374,7 @@ fn create_table2_entry(
                     flatten_sep,
                     width,
                 ),
-                Err(_) => error_sign(style_computer),
+                Err(_) => error_sign(style_computer, config),
             }
         }
         _ => convert_to_table2_entry(
@@ -1351,8 +1390,8 @@ fn create_table2_entry(
     }
 }
 
-fn error_sign(style_computer: &StyleComputer) -> (String, TextStyle) {
-    make_styled_string(style_computer, String::from("❎"), None, 0)
+fn error_sign(style_computer: &StyleComputer, config: &Config) -> (String, TextStyle) {
+    make_styled_string(style_computer, String::from("❎"), None, config)
 }
 
 fn wrap_text(text: &str, width: usize, config: &Config) -> String {
@@ -1471,12 +1510,11 @@ fn value_to_styled_string(
     // to save reallocation (because it's presumably being used upstream).
     style_computer: &StyleComputer,
 ) -> NuText {
-    let float_precision = config.float_precision as usize;
     make_styled_string(
         style_computer,
         value.into_abbreviated_string(config),
         Some(value),
-        float_precision,
+        config,
     )
 }
 
@@ -1484,14 +1522,14 @@ fn make_styled_string(
     style_computer: &StyleComputer,
     text: String,
     value: Option<&Value>, // None represents table holes.
-    float_precision: usize,
+    config: &Config,
 ) -> NuText {
     match value {
         Some(value) => {
             match value {
                 Value::Float { .. } => {
                     // set dynamic precision from config
-                    let precise_number = match convert_with_precision(&text, float_precision) {
+                    let precise_number = match convert_with_precision(&text, config) {
                         Ok(num) => num,
                         Err(e) => e.to_string(),
                     };
@@ -1518,7 +1556,7 @@ fn make_index_string(text: String, style_computer: &StyleComputer) -> NuText {
     (text, TextStyle::with_style(Alignment::Right, style))
 }
 
-fn convert_with_precision(val: &str, precision: usize) -> Result<String, ShellError> {
+fn convert_with_precision(val: &str, config: &Config) -> Result<String, ShellError> {
     // vall will always be a f64 so convert it with precision formatting
     let val_float = match val.trim().parse::<f64>() {
         Ok(f) => f,
@@ -1532,7 +1570,7 @@ fn convert_with_precision(val: &str, precision: usize) -> Result<String, ShellEr
             ));
         }
     };
-    Ok(format!("{val_float:.precision$}"))
+    Ok(nu_protocol::format_float_from_conf(val_float, config))
 }
 
 fn is_cfg_trim_keep_words(config: &Config) -> bool {
@@ -0,0 +1,45 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn guess_lines_up_right_aligned_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            $"NAME   PID(char nl)alice  77(char nl)bob     1"
+            | detect columns --guess
+            | where NAME == bob
+            | get 0.PID
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn combine_columns_merges_overflowed_fields() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            $"CMD(char nl)ls -la /tmp"
+            | detect columns --combine-columns 0..
+            | get 0.CMD
+        "#
+    ));
+
+    assert_eq!(actual.out, "ls -la /tmp");
+}
+
+#[test]
+fn guess_fills_missing_cells_with_empty_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            $"a  b(char nl)1"
+            | detect columns --guess
+            | get 0.b
+            | str length
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
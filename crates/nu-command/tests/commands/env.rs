@@ -0,0 +1,58 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn snapshot_diff_reports_added_var() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let before = (env snapshot)
+            let-env SNAPSHOT_TEST_VAR = hello
+            env diff $before (env snapshot)
+            | where name == SNAPSHOT_TEST_VAR
+            | get 0
+            | $"($in.change):($in.after)"
+        "#
+    ));
+
+    assert_eq!(actual.out, "added:hello");
+}
+
+#[test]
+fn snapshot_diff_reports_removed_var() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let-env SNAPSHOT_TEST_VAR = hello
+            let before = (env snapshot)
+            hide-env SNAPSHOT_TEST_VAR
+            env diff $before (env snapshot)
+            | where name == SNAPSHOT_TEST_VAR
+            | get 0.change
+        "#
+    ));
+
+    assert_eq!(actual.out, "removed");
+}
+
+#[test]
+fn identical_snapshots_diff_to_an_empty_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let snap = (env snapshot)
+            env diff $snap $snap | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn trust_errors_when_no_env_file_governs_the_directory() {
+    Playground::setup("env_trust_test", |dirs, _sandbox| {
+        let actual = nu!(cwd: dirs.test(), "env trust");
+
+        assert!(actual.err.contains("No .nu-env file found"));
+    });
+}
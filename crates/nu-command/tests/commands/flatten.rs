@@ -182,3 +182,94 @@ fn flatten_more_than_one_column_that_are_subtables_not_supported() {
         assert!(actual.err.contains("but is flattened already"));
     })
 }
+
+#[test]
+fn flatten_deep_produces_dotted_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{user: {name: nu, job: {title: shell}}}]
+            | flatten --separator '.'
+            | get 0
+            | columns
+            | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "user.name,user.job.title");
+}
+
+#[test]
+fn flatten_deep_respects_the_depth_limit() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: {b: {c: 1}}}] | flatten --depth 1 --separator '.' | get 0 | get "a.b" | describe
+        "#
+    ));
+
+    assert_eq!(actual.out, "record<c: int>");
+}
+
+#[test]
+fn flatten_deep_with_a_custom_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: {b: 1}}] | flatten --separator '_' | get 0 | get a_b
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn flatten_deep_only_touches_requested_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: {b: 1}, c: {d: 2}}] | flatten a --separator '.' | get 0 | columns | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "a.b,c");
+}
+
+#[test]
+fn unflatten_round_trips_flatten() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{user: {name: nu, job: {title: shell}}}]
+            | flatten --separator '.'
+            | unflatten
+            | get 0.user.job.title
+        "#
+    ));
+
+    assert_eq!(actual.out, "shell");
+}
+
+#[test]
+fn unflatten_with_a_custom_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            { 'a/b': 1, 'a/c': 2 } | unflatten --separator '/' | get a | columns | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "b,c");
+}
+
+#[test]
+fn unflatten_nested_columns_win_over_a_leaf() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            { a: 1, 'a.b': 2 } | unflatten | get a.b
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
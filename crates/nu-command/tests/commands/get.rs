@@ -241,3 +241,49 @@ fn get_does_not_delve_too_deep_in_nested_lists() {
 
     assert!(actual.err.contains("cannot find column"));
 }
+
+#[test]
+fn optional_member_gives_null_instead_of_error() {
+    let actual = nu!(
+        cwd: ".",
+        r#"{a: 1} | get b? | to nuon"#
+    );
+
+    assert_eq!(actual.out, "null");
+}
+
+#[test]
+fn optional_member_short_circuits_the_rest_of_the_path() {
+    let actual = nu!(
+        cwd: ".",
+        r#"{a: 1} | get b?.c | to nuon"#
+    );
+
+    assert_eq!(actual.out, "null");
+}
+
+#[test]
+fn wildcard_broadcasts_over_table_rows() {
+    let actual = nu!(
+        cwd: ".",
+        r#"[{name: foo} {name: bar}] | get *.name | str join ','"#
+    );
+
+    assert_eq!(actual.out, "foo,bar");
+}
+
+#[test]
+fn negative_index_counts_from_the_end() {
+    let actual = nu!(
+        cwd: ".",
+        r#"[1 2 3] | get -1"#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn negative_index_beyond_the_start_errors() {
+    let actual = nu!(cwd: ".", pipeline(r#"[1 2 3] | get -4"#));
+    assert!(actual.err.contains("too large"));
+}
@@ -110,3 +110,47 @@ fn errors_if_input_empty() {
         assert!(actual.err.contains("expected table from pipeline"));
     });
 }
+
+#[test]
+fn groups_by_several_columns_nested() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[foo bar]; [a x] [a y] [b x]]
+            | group-by foo bar
+            | get a.y.0.bar
+        "#
+    ));
+
+    assert_eq!(actual.out, "y");
+}
+
+#[test]
+fn groups_by_closure() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [1 2 3 4]
+            | group-by { if $in mod 2 == 0 { "even" } else { "odd" } }
+            | get even
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn to_table_returns_group_and_items_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[type]; [f] [d] [f]]
+            | group-by type --to-table
+            | where group == f
+            | get items.0 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
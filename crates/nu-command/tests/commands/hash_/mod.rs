@@ -1,3 +1,5 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
 #[test]
@@ -97,3 +99,70 @@ fn sha256_works_with_file() {
         "2f5050e7eea415c1f3d80b5d93355efd15043ec9157a2bb167a9e73f2ae651f2"
     );
 }
+
+#[test]
+fn hash_dir_lists_files_in_sorted_order() {
+    Playground::setup("hash_dir_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("b.txt", "two"),
+            FileWithContent("sub/a.txt", "one"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            hash dir | get path | str join ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "b.txt,sub/a.txt");
+    });
+}
+
+#[test]
+fn hash_dir_verify_reports_changed_missing_and_new() {
+    Playground::setup("hash_dir_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("keep.txt", "same"),
+            FileWithContent("edit.txt", "before"),
+            FileWithContent("gone.txt", "bye"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            hash dir --exclude manifest.nuon | to nuon | save manifest.nuon;
+            'after' | save --force edit.txt;
+            rm gone.txt;
+            'hi' | save fresh.txt;
+            hash dir --exclude manifest.nuon --verify manifest.nuon
+                | each { |row| $"($row.path):($row.status)" }
+                | str join ','
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            "edit.txt:changed,fresh.txt:new,gone.txt:missing"
+        );
+    });
+}
+
+#[test]
+fn hash_dir_include_glob_filters_the_manifest() {
+    Playground::setup("hash_dir_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("a.rs", "fn main() {}"),
+            FileWithContent("a.md", "# hi"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+            hash dir --include '*.rs' | get path | str join ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "a.rs");
+    });
+}
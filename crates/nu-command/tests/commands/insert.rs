@@ -95,3 +95,23 @@ fn insert_uses_enumerate_index() {
 
     assert_eq!(actual.out, "[[index, a, b]; [0, 7, 8], [1, 6, 8]]");
 }
+
+#[test]
+fn insert_closure_sees_null_for_the_new_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[[a]; [1] [2]] | insert b {|row, old, index| $old == null } | get b | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "[true, true]");
+}
+
+#[test]
+fn insert_closure_receives_the_row_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[[a]; [5] [5] [5]] | insert b {|row, old, index| $index } | get b | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "[0, 1, 2]");
+}
@@ -0,0 +1,75 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn inner_join_drops_unmatched_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[id name]; [1 apple] [2 pear] [3 plum]]
+            | join [[id price]; [1 10] [3 30]] id
+            | get name
+            | str join ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "apple,plum");
+}
+
+#[test]
+fn left_join_fills_missing_cells_with_null() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[id name]; [1 apple] [2 pear]]
+            | join [[id price]; [1 10]] id --left
+            | where price == null
+            | get name.0
+        "#
+    ));
+
+    assert_eq!(actual.out, "pear");
+}
+
+#[test]
+fn joins_on_multiple_key_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[a b v]; [1 1 left] [1 2 left]]
+            | join [[a b w]; [1 2 right]] [a b]
+            | get w.0
+        "#
+    ));
+
+    assert_eq!(actual.out, "right");
+}
+
+#[test]
+fn colliding_columns_get_the_suffix() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[id v]; [1 old]]
+            | join [[id v]; [1 new]] id
+            | columns
+            | str join ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "id,v,v_");
+}
+
+#[test]
+fn outer_join_keeps_unmatched_rows_of_both_tables() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: 1, b: left}] | join [{a: 2, c: right}] a --outer | to nuon
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "[{a: 1, b: left, c: null}, {a: 2, b: null, c: right}]"
+    );
+}
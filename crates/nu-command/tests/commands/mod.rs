@@ -13,12 +13,14 @@ mod cp;
 mod date;
 mod def;
 mod default;
+mod detect_columns;
 mod do_;
 mod drop;
 mod each;
 mod echo;
 mod empty;
 mod enter;
+mod env;
 mod error_make;
 mod every;
 #[cfg(not(windows))]
@@ -41,6 +43,7 @@ mod histogram;
 mod insert;
 mod into_filesize;
 mod into_int;
+mod join;
 mod last;
 mod length;
 mod let_;
@@ -57,6 +60,8 @@ mod network;
 mod nu_check;
 mod open;
 mod p;
+mod package;
+mod par_each;
 mod parse;
 mod path;
 mod platform;
@@ -76,7 +81,9 @@ mod rm;
 mod roll;
 mod rotate;
 mod run_external;
+mod sample;
 mod save;
+mod scan;
 mod select;
 mod semicolon;
 mod seq;
@@ -90,9 +97,12 @@ mod split_by;
 mod split_column;
 mod split_row;
 mod str_;
+mod summarize_footer;
 mod table;
 mod take;
+mod tee;
 mod to_text;
+mod top;
 mod touch;
 mod transpose;
 mod try_;
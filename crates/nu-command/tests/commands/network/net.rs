@@ -0,0 +1,52 @@
+use nu_test_support::nu;
+
+#[test]
+fn send_and_listen_roundtrip() {
+    // a port unlikely to collide with other tests running in parallel
+    let port = 20000 + std::process::id() % 10000;
+
+    let listener = std::thread::spawn(
+        move || nu!(cwd: ".", &format!("net listen 127.0.0.1:{port} | math sum")),
+    );
+
+    // the listener needs a moment to bind; retry until it accepts
+    let mut sent = false;
+    for _ in 0..50 {
+        let actual = nu!(cwd: ".", &format!("[1 2 3] | net send 127.0.0.1:{port}"));
+        if actual.err.is_empty() {
+            sent = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(sent, "could not connect to the listener");
+
+    let actual = listener.join().expect("listener thread panicked");
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn records_arrive_typed() {
+    let port = 30000 + std::process::id() % 10000;
+
+    let listener = std::thread::spawn(
+        move || nu!(cwd: ".", &format!("net listen 127.0.0.1:{port} | get count | math sum")),
+    );
+
+    let mut sent = false;
+    for _ in 0..50 {
+        let actual = nu!(
+            cwd: ".",
+            &format!("[[name count]; [a 1] [b 2]] | net send 127.0.0.1:{port}")
+        );
+        if actual.err.is_empty() {
+            sent = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(sent, "could not connect to the listener");
+
+    let actual = listener.join().expect("listener thread panicked");
+    assert_eq!(actual.out, "3");
+}
@@ -0,0 +1,126 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+fn registry_and_module(sandbox: &mut Playground, registry: &str) {
+    sandbox.with_files(vec![
+        FileWithContentToBeTrimmed("registry.nuon", registry),
+        FileWithContentToBeTrimmed(
+            "greet.nu",
+            r#"
+                export def hello [] { "hello" }
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn install_copies_the_module_and_records_it() {
+    Playground::setup("package_test_1", |dirs, sandbox| {
+        registry_and_module(
+            sandbox,
+            r#"
+                [[name, version, url, sha256, deps]; [greet, "0.1.0", "greet.nu", null, []]]
+            "#,
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                let-env NU_PKG_DIR = 'pkgs'
+                let-env NU_PKG_REGISTRY = 'registry.nuon'
+                package install greet | ignore
+                package list | get name.0
+            "#
+        );
+
+        assert_eq!(actual.out, "greet");
+        assert!(dirs
+            .test()
+            .join("pkgs")
+            .join("greet")
+            .join("mod.nu")
+            .exists());
+    })
+}
+
+#[test]
+fn install_pulls_in_dependencies_first() {
+    Playground::setup("package_test_2", |dirs, sandbox| {
+        registry_and_module(
+            sandbox,
+            r#"
+                [[name, version, url, sha256, deps]; [greet, "0.1.0", "greet.nu", null, []], [top, "1.0.0", "greet.nu", null, [greet]]]
+            "#,
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                let-env NU_PKG_DIR = 'pkgs'
+                let-env NU_PKG_REGISTRY = 'registry.nuon'
+                package install top | get name | str join ','
+            "#
+        );
+
+        assert_eq!(actual.out, "greet,top");
+    })
+}
+
+#[test]
+fn install_rejects_a_module_with_the_wrong_hash() {
+    Playground::setup("package_test_3", |dirs, sandbox| {
+        registry_and_module(
+            sandbox,
+            r#"
+                [[name, version, url, sha256, deps]; [greet, "0.1.0", "greet.nu", "0000", []]]
+            "#,
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                let-env NU_PKG_DIR = 'pkgs'
+                let-env NU_PKG_REGISTRY = 'registry.nuon'
+                package install greet
+            "#
+        );
+
+        assert!(actual.err.contains("hash mismatch"));
+    })
+}
+
+#[test]
+fn install_errors_without_a_registry() {
+    let actual = nu!(
+        cwd: ".",
+        "hide-env -i NU_PKG_REGISTRY; package install greet"
+    );
+
+    assert!(actual.err.contains("NU_PKG_REGISTRY"));
+}
+
+#[test]
+fn update_moves_to_the_newest_version() {
+    Playground::setup("package_test_4", |dirs, sandbox| {
+        registry_and_module(
+            sandbox,
+            r#"
+                [[name, version, url, sha256, deps]; [greet, "0.1.0", "greet.nu", null, []], [greet, "0.2.0", "greet.nu", null, []]]
+            "#,
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                let-env NU_PKG_DIR = 'pkgs'
+                let-env NU_PKG_REGISTRY = 'registry.nuon'
+                package install greet --version 0.1.0 | ignore
+                package update | ignore
+                package list | get version.0
+            "#
+        );
+
+        assert_eq!(actual.out, "0.2.0");
+    })
+}
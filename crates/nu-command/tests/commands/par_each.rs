@@ -0,0 +1,31 @@
+use nu_test_support::nu;
+
+#[test]
+fn keep_order_preserves_the_input_order() {
+    let actual = nu!(
+        cwd: ".",
+        "[3 1 2] | par-each --keep-order {|e| $e * 2 } | to nuon"
+    );
+
+    assert_eq!(actual.out, "[6, 2, 4]");
+}
+
+#[test]
+fn thread_count_can_be_limited() {
+    let actual = nu!(
+        cwd: ".",
+        "[1 2 3] | par-each --threads 1 {|e| $e * 2 } | math sum"
+    );
+
+    assert_eq!(actual.out, "12");
+}
+
+#[test]
+fn negative_thread_count_is_rejected() {
+    let actual = nu!(
+        cwd: ".",
+        "[1 2 3] | par-each --threads -1 {|e| $e }"
+    );
+
+    assert!(!actual.err.is_empty());
+}
@@ -0,0 +1,65 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn filters_rows_and_projects_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {users: [{name: alice, age: 30} {name: bob, age: 20} {name: carol, age: 40}]}
+            | query json 'users[?(@.age > 25)].name'
+            | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "alice,carol");
+}
+
+#[test]
+fn recursive_descent_collects_from_any_depth() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: {price: 1}, b: {c: {price: 2}}}
+            | query json '..price'
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn slice_flattens_into_matches() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {items: [10 20 30 40]} | query json 'items[1:3]' | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "50");
+}
+
+#[test]
+fn negative_index_counts_from_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {items: [10 20 30 40]} | query json 'items[-2]'
+        "#
+    ));
+
+    assert_eq!(actual.out, "30");
+}
+
+#[test]
+fn malformed_query_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: 1} | query json 'a[?(@.x ~ 3)]'
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid query expression"));
+}
@@ -1 +1,2 @@
 mod db;
+mod json;
@@ -153,3 +153,29 @@ fn enumerate_reduce_example() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn reduce_until_stops_early() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        [1 2 3 4 5] | reduce --until {|acc| $acc >= 6 } {|it, acc| $acc + $it }
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn reduce_until_checks_the_folded_accumulator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        [1 2 3] | reduce -f 100 --until {|acc| $acc > 0 } {|it, acc| $acc + $it }
+        "#
+        )
+    );
+
+    assert_eq!(actual.out, "101");
+}
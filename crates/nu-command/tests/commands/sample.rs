@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn sample_keeps_the_requested_count() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 100 | sample 5 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn sample_values_come_from_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 100 | sample 10 | all {|it| $it >= 1 and $it <= 100 }
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn seeded_fraction_is_reproducible() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            (seq 1 100 | sample --fraction 0.5 --seed 7 | math sum) == (seq 1 100 | sample --fraction 0.5 --seed 7 | math sum)
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn count_and_fraction_are_mutually_exclusive() {
+    let actual = nu!(cwd: ".", pipeline("seq 1 10 | sample 3 --fraction 0.5"));
+
+    assert!(actual.err.contains("not both"));
+}
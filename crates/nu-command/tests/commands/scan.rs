@@ -0,0 +1,21 @@
+use nu_test_support::nu;
+
+#[test]
+fn scan_emits_every_accumulator() {
+    let actual = nu!(
+        cwd: ".",
+        "[1 2 3 4] | scan 0 {|it, acc| $acc + $it } | to nuon"
+    );
+
+    assert_eq!(actual.out, "[1, 3, 6, 10]");
+}
+
+#[test]
+fn scan_is_lazy() {
+    let actual = nu!(
+        cwd: ".",
+        "1.. | scan 0 {|it, acc| $acc + $it } | take 3 | to nuon"
+    );
+
+    assert_eq!(actual.out, "[1, 3, 6]");
+}
@@ -118,3 +118,13 @@ fn sort_record_values_insensitive_reverse() {
 
     assert_eq!(actual.out, r#"{"2": zed, "3": ABE, "1": abe}"#);
 }
+
+#[test]
+fn sort_table_natural() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[[name]; [file10] [file2] [file1]] | sort --natural | get name | to nuon"#
+    ));
+
+    assert_eq!(actual.out, r#"["file1", "file2", "file10"]"#);
+}
@@ -126,3 +126,42 @@ fn fail_on_non_iterator() {
 
     assert!(actual.err.contains("only_supports_this_input_type"));
 }
+
+#[test]
+fn mixed_direction_sort() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[name priority]; [b 1] [a 1] [c 2]]
+            | sort-by {column: priority, reverse: true} name
+            | get name
+            | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "c,a,b");
+}
+
+#[test]
+fn custom_comparator_sort() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [foo hello ab] | sort-by --custom {|a, b| ($a | str length) < ($b | str length) } | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "ab,foo,hello");
+}
+
+#[test]
+fn custom_comparator_surfaces_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [1 2] | sort-by --custom {|a, b| error make {msg: 'broken comparator'} }
+        "#
+    ));
+
+    assert!(actual.err.contains("broken comparator"));
+}
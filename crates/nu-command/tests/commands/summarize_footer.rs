@@ -0,0 +1,59 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn footer_holds_the_requested_aggregates() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[item price]; [apple 2] [pear 3]]
+            | summarize-footer {item: count, price: sum}
+            | last
+            | $"($in.item) ($in.price)"
+        "#
+    ));
+
+    assert_eq!(actual.out, "2 5");
+}
+
+#[test]
+fn footer_leaves_other_columns_empty() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[item price]; [apple 2]] | summarize-footer {price: sum} | last | get item | describe
+        "#
+    ));
+
+    assert_eq!(actual.out, "nothing");
+}
+
+#[test]
+fn footer_mean_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[n]; [1] [2] [6]] | summarize-footer {n: avg} | last | get n
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn unknown_calculation_errors() {
+    let actual = nu!(cwd: ".", pipeline("[[n]; [1]] | summarize-footer {n: median}"));
+
+    assert!(actual.err.contains("unknown footer calculation"));
+}
+
+#[test]
+fn table_footer_calc_renders_the_summary_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[item price]; [apple 2] [pear 3]] | table --footer-calc {price: sum} | into string | str contains '5'
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
@@ -0,0 +1,26 @@
+use nu_test_support::fs::file_contents;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn stream_is_passed_through_unchanged() {
+    let actual = nu!(
+        cwd: ".",
+        "[1 2 3] | tee { ignore } | math sum"
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn side_closure_sees_a_copy_of_the_stream() {
+    Playground::setup("tee_test_1", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "[1 2 3] | tee { math sum | save copy.txt } | math sum"
+        );
+
+        assert_eq!(actual.out, "6");
+        assert_eq!(file_contents(dirs.test().join("copy.txt")).trim(), "6");
+    })
+}
@@ -0,0 +1,37 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn top_keeps_the_largest_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[name size]; [a 10] [b 30] [c 20]] | top 2 --by size | get name | str join ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "b,c");
+}
+
+#[test]
+fn top_streams_without_collecting_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 100000 | top 3 | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "299997");
+}
+
+#[test]
+fn bottom_keeps_the_smallest_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[name size]; [a 10] [b 30] [c 20]] | bottom 1 --by size | get 0.name
+        "#
+    ));
+
+    assert_eq!(actual.out, "a");
+}
@@ -300,3 +300,23 @@ fn table_with_ignore_case() {
     assert_eq!(actual.out, expected.out);
     assert_eq!(actual.out, expected.out);
 }
+
+#[test]
+fn uniq_natural() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"['file7' 'file07' 'file8' 'file008'] | uniq --natural | to nuon"#
+    ));
+
+    assert_eq!(actual.out, r#"["file7", "file8"]"#);
+}
+
+#[test]
+fn uniq_natural_counts_matching_numbers_together() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"['7' '07' '8'] | uniq --natural --count | where value == '7' | get 0.count"#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
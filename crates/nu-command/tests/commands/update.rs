@@ -124,3 +124,33 @@ fn update_uses_enumerate_index() {
 
     assert_eq!(actual.out, "[[index, a]; [0, 8], [1, 8]]");
 }
+
+#[test]
+fn update_negative_index_updates_the_last_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[1 2 3] | update -1 9 | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "[1, 2, 9]");
+}
+
+#[test]
+fn update_optional_member_is_a_noop_when_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"{a: 1} | update b? 2 | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "{a: 1}");
+}
+
+#[test]
+fn update_closure_receives_the_old_value_and_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[[count]; [10] [20]] | update count {|row, old, index| $old + $index } | get count | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "[10, 21]");
+}
@@ -107,3 +107,13 @@ fn upsert_empty() {
 
     assert!(actual.err.contains("index too large (max: 0)"));
 }
+
+#[test]
+fn upsert_closure_distinguishes_existing_and_missing_cells() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"[{a: 1, b: 9} {a: 2}] | upsert b {|row, old, index| if $old == null { $index } else { $old } } | get b | to nuon"#
+    ));
+
+    assert_eq!(actual.out, "[9, 1]");
+}
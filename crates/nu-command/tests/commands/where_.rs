@@ -194,3 +194,39 @@ fn fail_on_non_iterator() {
 
     assert!(actual.err.contains("only_supports_this_input_type"));
 }
+
+#[test]
+fn where_accepts_a_stored_closure() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            let even = {|x| $x mod 2 == 0 }; [1 2 3 4] | where $even | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2, 4]");
+}
+
+#[test]
+fn where_optional_access_skips_missing_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: 1} {b: 2} {a: 1, c: 3}] | where a? == 1 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn where_optional_nested_access() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{config: {port: 80}} {config: {}} {other: 1}] | where config?.port? == 80 | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
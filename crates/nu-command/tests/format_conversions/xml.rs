@@ -8,7 +8,38 @@ fn table_to_xml_text_and_from_xml_text_back_into_table() {
             open jonathan.xml
             | to xml
             | from xml
-            | get rss.children.channel.children.0.3.item.children.guid.4.attributes.isPermaLink
+            | get content.0.content.3.content.4.attributes.isPermaLink
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn namespaces_and_comments_round_trip() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<root xmlns:a="urn:x"><!--hi--><a:item>1</a:item></root>'
+            | from xml
+            | to xml
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"<root xmlns:a="urn:x"><!--hi--><a:item>1</a:item></root>"#
+    );
+}
+
+#[test]
+fn query_xml_extracts_across_depths() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open jonathan.xml
+            | query xml '//guid/@isPermaLink'
+            | get 0
         "#
     ));
 
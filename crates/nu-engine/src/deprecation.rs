@@ -0,0 +1,48 @@
+use nu_protocol::ast::Call;
+use nu_protocol::{Config, Deprecation, Signature};
+use std::sync::Mutex;
+
+/// Deprecated commands and flags that have already been warned about, so each
+/// warning is only printed once per session.
+static WARNED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Prints a one-time warning to stderr for each deprecated command or flag
+/// used in a call.
+pub fn warn_if_deprecated(signature: &Signature, call: &Call, config: &Config) {
+    let mut used: Vec<(String, &Deprecation)> = vec![];
+
+    if let Some(deprecation) = signature.command_deprecation() {
+        used.push((signature.name.clone(), deprecation));
+    }
+
+    for (flag, _, _) in call.named_iter() {
+        if let Some(deprecation) = signature.flag_deprecation(&flag.item) {
+            used.push((format!("{} --{}", signature.name, flag.item), deprecation));
+        }
+    }
+
+    for (what, deprecation) in used {
+        if let Ok(mut warned) = WARNED.lock() {
+            if warned.iter().any(|w| w == &what) {
+                continue;
+            }
+            warned.push(what.clone());
+        }
+
+        let mut message = format!("{what} is deprecated");
+        if let Some(version) = &deprecation.removal_version {
+            message.push_str(&format!(" and will be removed in {version}"));
+        }
+        if let Some(replacement) = &deprecation.replacement {
+            message.push_str(&format!("; use {replacement} instead"));
+        }
+
+        // only style the warning when stderr is a terminal that wants color
+        let (yellow, reset) = if config.use_ansi_coloring && atty::is(atty::Stream::Stderr) {
+            ("\x1b[33m", "\x1b[0m")
+        } else {
+            ("", "")
+        };
+        eprintln!("{yellow}Warning{reset}: {message}.");
+    }
+}
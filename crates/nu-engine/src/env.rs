@@ -319,10 +319,12 @@ fn get_converted_value(
             PathMember::String {
                 val: name.to_string(),
                 span: env_span,
+                optional: false,
             },
             PathMember::String {
                 val: direction.to_string(),
                 span: env_span,
+                optional: false,
             },
         ];
 
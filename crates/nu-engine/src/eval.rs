@@ -158,6 +158,8 @@ pub fn eval_call(
 
         result
     } else {
+        crate::deprecation::warn_if_deprecated(&decl.signature(), call, engine_state.get_config());
+
         // We pass caller_stack here with the knowledge that internal commands
         // are going to be specifically looking for global state in the stack
         // rather than any local state.
@@ -376,10 +378,28 @@ pub fn eval_expression(
                         }
                     }
                 }
+                Operator::Coalesce => {
+                    let lhs = eval_expression(engine_state, stack, lhs)?;
+                    if lhs.is_nothing() {
+                        eval_expression(engine_state, stack, rhs)
+                    } else {
+                        Ok(lhs)
+                    }
+                }
                 Operator::Math(math) => {
                     let lhs = eval_expression(engine_state, stack, lhs)?;
                     let rhs = eval_expression(engine_state, stack, rhs)?;
 
+                    // Null propagates through arithmetic unless the config asks
+                    // for the strict operator mismatch error instead. `++` is
+                    // excluded: appending to null has never been arithmetic.
+                    if (lhs.is_nothing() || rhs.is_nothing())
+                        && !matches!(math, Math::Append)
+                        && !engine_state.get_config().strict_null_math
+                    {
+                        return Ok(Value::nothing(expr.span));
+                    }
+
                     match math {
                         Math::Plus => lhs.add(op_span, &rhs, expr.span),
                         Math::Minus => lhs.sub(op_span, &rhs, expr.span),
@@ -401,6 +421,8 @@ pub fn eval_expression(
                         Comparison::GreaterThanOrEqual => lhs.gte(op_span, &rhs, expr.span),
                         Comparison::Equal => lhs.eq(op_span, &rhs, expr.span),
                         Comparison::NotEqual => lhs.ne(op_span, &rhs, expr.span),
+                        Comparison::StrictEqual => lhs.strict_eq(op_span, &rhs, expr.span),
+                        Comparison::StrictNotEqual => lhs.strict_ne(op_span, &rhs, expr.span),
                         Comparison::In => lhs.r#in(op_span, &rhs, expr.span),
                         Comparison::NotIn => lhs.not_in(op_span, &rhs, expr.span),
                         Comparison::RegexMatch => {
@@ -700,6 +722,18 @@ pub fn eval_expression_with_input(
             input = eval_subexpression(engine_state, stack, block, input)?;
         }
 
+        Expression {
+            expr: Expr::BinaryOp(lhs, op, rhs),
+            ..
+        } if matches!(op.expr, Expr::Operator(Operator::Math(Math::Append))) => {
+            // `++` at the head of a pipeline chains the sides lazily instead of
+            // collecting either one into a list first
+            let op_span = op.span;
+            let lhs = eval_operand_as_pipeline(engine_state, stack, lhs)?;
+            let rhs = eval_operand_as_pipeline(engine_state, stack, rhs)?;
+            input = append_pipelines(engine_state, lhs, rhs, op_span, expr.span)?;
+        }
+
         elem => {
             input = eval_expression(engine_state, stack, elem)?.into_pipeline_data();
         }
@@ -720,6 +754,59 @@ fn might_consume_external_result(input: PipelineData) -> (PipelineData, bool) {
     input.is_external_failed()
 }
 
+// Evaluate one side of a lazy `++` keeping subexpression output as a stream.
+fn eval_operand_as_pipeline(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    expr: &Expression,
+) -> Result<PipelineData, ShellError> {
+    match &expr.expr {
+        Expr::Subexpression(block_id) => {
+            let block = engine_state.get_block(*block_id);
+            eval_subexpression(engine_state, stack, block, PipelineData::empty())
+        }
+        // a parenthesized subexpression parses as a cell path with an empty tail
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => {
+            if let Expr::Subexpression(block_id) = &cell_path.head.expr {
+                let block = engine_state.get_block(*block_id);
+                eval_subexpression(engine_state, stack, block, PipelineData::empty())
+            } else {
+                Ok(eval_expression(engine_state, stack, expr)?.into_pipeline_data())
+            }
+        }
+        _ => Ok(eval_expression(engine_state, stack, expr)?.into_pipeline_data()),
+    }
+}
+
+// When either side of `++` is a list or a stream the sides are chained without
+// collecting; otherwise fall back to `Value::append` so strings and binary
+// keep their concatenation semantics.
+fn append_pipelines(
+    engine_state: &EngineState,
+    lhs: PipelineData,
+    rhs: PipelineData,
+    op_span: Span,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    let list_like = |data: &PipelineData| {
+        matches!(
+            data,
+            PipelineData::ListStream(..) | PipelineData::Value(Value::List { .. }, ..)
+        )
+    };
+
+    if list_like(&lhs) || list_like(&rhs) {
+        Ok(lhs
+            .into_iter()
+            .chain(rhs.into_iter())
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    } else {
+        let lhs = lhs.into_value(span);
+        let rhs = rhs.into_value(span);
+        Ok(lhs.append(op_span, &rhs, span)?.into_pipeline_data())
+    }
+}
+
 pub fn eval_element_with_input(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -981,6 +1068,68 @@ pub fn eval_block_with_early_return(
 }
 
 pub fn eval_block(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    block: &Block,
+    input: PipelineData,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+) -> Result<PipelineData, ShellError> {
+    let defer_marker = stack.defers.len();
+
+    let result = eval_block_inner(
+        engine_state,
+        stack,
+        block,
+        input,
+        redirect_stdout,
+        redirect_stderr,
+    );
+
+    run_defers(engine_state, stack, defer_marker, result)
+}
+
+/// Run the closures `defer` registered while this block evaluated, most
+/// recent first. They run whether the block succeeded or failed; the block's
+/// own error wins over any error a deferred closure raises.
+fn run_defers(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    marker: usize,
+    result: Result<PipelineData, ShellError>,
+) -> Result<PipelineData, ShellError> {
+    if stack.defers.len() <= marker {
+        return result;
+    }
+
+    let mut defer_err = None;
+    while stack.defers.len() > marker {
+        let closure = stack.defers.pop().expect("defer marker out of sync");
+        let block = engine_state.get_block(closure.block_id).clone();
+        let mut defer_stack = stack.captures_to_stack(&closure.captures);
+
+        match eval_block(
+            engine_state,
+            &mut defer_stack,
+            &block,
+            PipelineData::empty(),
+            true,
+            true,
+        ) {
+            Ok(data) => data.into_iter().for_each(drop),
+            Err(err) => {
+                defer_err.get_or_insert(err);
+            }
+        }
+    }
+
+    match (result, defer_err) {
+        (Ok(_), Some(err)) => Err(err),
+        (result, _) => result,
+    }
+}
+
+fn eval_block_inner(
     engine_state: &EngineState,
     stack: &mut Stack,
     block: &Block,
@@ -990,13 +1139,14 @@ pub fn eval_block(
 ) -> Result<PipelineData, ShellError> {
     // if Block contains recursion, make sure we don't recurse too deeply (to avoid stack overflow)
     if let Some(recursive) = block.recursive {
-        // picked 50 arbitrarily, should work on all architectures
-        const RECURSION_LIMIT: u64 = 50;
+        // the default is 50, which should work on all architectures, but it can
+        // be overridden with $env.config.recursion_limit
+        let recursion_limit = engine_state.get_config().recursion_limit as u64;
         if recursive {
-            if *stack.recursion_count >= RECURSION_LIMIT {
+            if *stack.recursion_count >= recursion_limit {
                 stack.recursion_count = Box::new(0);
                 return Err(ShellError::RecursionLimitReached {
-                    recursion_limit: RECURSION_LIMIT,
+                    recursion_limit,
                     span: block.span,
                 });
             }
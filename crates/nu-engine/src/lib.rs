@@ -1,5 +1,6 @@
 mod call_ext;
 pub mod column;
+pub mod deprecation;
 pub mod documentation;
 pub mod env;
 mod eval;
@@ -8,6 +9,7 @@ mod nu_variable;
 pub mod scope;
 
 pub use call_ext::CallExt;
+pub use deprecation::warn_if_deprecated;
 pub use column::get_columns;
 pub use documentation::get_full_help;
 pub use env::*;
@@ -1,4 +1,5 @@
 use std::io::{self, Result};
+use std::sync::Mutex;
 
 use nu_protocol::{
     engine::{EngineState, Stack},
@@ -7,13 +8,43 @@ use nu_protocol::{
 use tui::layout::Rect;
 
 use crate::{
-    nu_common::{collect_pipeline, has_simple_value, run_command_with_value},
+    nu_common::{collect_pipeline, has_simple_value, run_command_with_value, NuSpan},
     pager::Frame,
     views::{Layout, Orientation, Preview, RecordView, View, ViewConfig},
 };
 
 use super::{HelpExample, HelpManual, ViewCommand};
 
+// History of the pipeline fragments applied so far in this session, so a
+// filter can be inspected and reapplied without retyping it.
+static COMMAND_HISTORY: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn history_push(command: &str) {
+    let mut history = COMMAND_HISTORY
+        .lock()
+        .expect("internal error: nu command history mutex poisoned");
+    if history.last().map(String::as_str) != Some(command) {
+        history.push(command.to_owned());
+    }
+}
+
+fn history_get(index: Option<usize>) -> Option<String> {
+    let history = COMMAND_HISTORY
+        .lock()
+        .expect("internal error: nu command history mutex poisoned");
+    match index {
+        Some(index) => history.get(index).cloned(),
+        None => history.last().cloned(),
+    }
+}
+
+fn history_list() -> Vec<String> {
+    COMMAND_HISTORY
+        .lock()
+        .expect("internal error: nu command history mutex poisoned")
+        .clone()
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NuCmd {
     command: String,
@@ -46,6 +77,9 @@ impl ViewCommand for NuCmd {
                 "where type == 'file'",
                 "Filter data to show only rows whose type is 'file'",
             ),
+            HelpExample::new("history", "Show the pipelines applied so far"),
+            HelpExample::new("!", "Reapply the most recent pipeline"),
+            HelpExample::new("!2", "Reapply entry 2 of the history"),
             HelpExample::new(
                 "get scope.examples",
                 "Navigate to a deeper value inside the data",
@@ -82,9 +116,44 @@ impl ViewCommand for NuCmd {
     ) -> Result<Self::View> {
         let value = value.unwrap_or_default();
 
-        let pipeline = run_command_with_value(&self.command, &value, engine_state, stack)
+        if self.command == "history" {
+            let values = history_list()
+                .into_iter()
+                .enumerate()
+                .map(|(i, command)| {
+                    vec![
+                        Value::int(i as i64, NuSpan::unknown()),
+                        Value::string(command, NuSpan::unknown()),
+                    ]
+                })
+                .collect();
+
+            return Ok(NuView::Records(RecordView::new(
+                vec![String::from("index"), String::from("command")],
+                values,
+            )));
+        }
+
+        let command = if let Some(index) = self.command.strip_prefix('!') {
+            let index = if index.is_empty() {
+                None
+            } else {
+                Some(index.parse::<usize>().map_err(|_| {
+                    io::Error::new(io::ErrorKind::Other, "expected a history index after !")
+                })?)
+            };
+
+            history_get(index)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "no such history entry"))?
+        } else {
+            self.command.clone()
+        };
+
+        let pipeline = run_command_with_value(&command, &value, engine_state, stack)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
+        history_push(&command);
+
         let is_record = matches!(pipeline, PipelineData::Value(Value::Record { .. }, ..));
 
         let (columns, values) = collect_pipeline(pipeline);
@@ -599,7 +599,11 @@ fn create_table2_entry_basic(
     match item {
         Value::Record { .. } => {
             let val = header.to_owned();
-            let path = PathMember::String { val, span: head };
+            let path = PathMember::String {
+                val,
+                span: head,
+                optional: false,
+            };
             let val = item.clone().follow_cell_path(&[path], false, false);
 
             match val {
@@ -627,7 +631,11 @@ fn create_table2_entry(
     match item {
         Value::Record { .. } => {
             let val = header.to_owned();
-            let path = PathMember::String { val, span: head };
+            let path = PathMember::String {
+                val,
+                span: head,
+                optional: false,
+            };
             let val = item.clone().follow_cell_path(&[path], false, false);
 
             match val {
@@ -20,10 +20,15 @@ enum State {
 }
 
 /// A structure that deserializes Hjson into Rust values.
+/// How deeply values may nest before parsing is aborted with
+/// `ErrorCode::ExceededDepthLimit` instead of risking a stack overflow.
+const MAX_DEPTH: usize = 500;
+
 pub struct Deserializer<Iter: Iterator<Item = u8>> {
     rdr: StringReader<Iter>,
     str_buf: Vec<u8>,
     state: State,
+    depth: usize,
 }
 
 // macro_rules! try_or_invalid {
@@ -46,6 +51,7 @@ where
             rdr: StringReader::new(rdr),
             str_buf: Vec::with_capacity(128),
             state: State::Normal,
+            depth: 0,
         }
     }
 
@@ -118,6 +124,19 @@ where
     }
 
     fn parse_value<'de, V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.rdr.error(ErrorCode::ExceededDepthLimit));
+        }
+        let ret = self.parse_value_inner(visitor);
+        self.depth -= 1;
+        ret
+    }
+
+    fn parse_value_inner<'de, V>(&mut self, visitor: V) -> Result<V::Value>
     where
         V: de::Visitor<'de>,
     {
@@ -68,6 +68,9 @@ pub enum ErrorCode {
 
     /// Found a punctuator character when expecting a quoteless string.
     PunctuatorInQlString,
+
+    /// The value is nested deeper than the parser's depth limit allows.
+    ExceededDepthLimit,
 }
 
 impl fmt::Debug for ErrorCode {
@@ -97,6 +100,7 @@ impl fmt::Debug for ErrorCode {
             ErrorCode::PunctuatorInQlString => {
                 "found a punctuator character when expecting a quoteless string".fmt(f)
             }
+            ErrorCode::ExceededDepthLimit => "exceeded nesting depth limit".fmt(f),
         }
     }
 }
@@ -411,12 +411,27 @@ pub enum ParseError {
     #[error("{0}")]
     #[diagnostic()]
     LabeledError(String, String, #[label("{1}")] Span),
+
+    /// The parser found several independent errors in one pass.
+    ///
+    /// ## Resolution
+    ///
+    /// Fix the related errors listed below; each one is reported with its own span.
+    #[error("Multiple parse errors.")]
+    #[diagnostic(code(nu::parser::multiple_errors))]
+    MultipleErrors {
+        #[label("first error occurred here")]
+        span: Span,
+        #[related]
+        errors: Vec<ParseError>,
+    },
 }
 
 impl ParseError {
     pub fn span(&self) -> Span {
         match self {
             ParseError::ExtraTokens(s) => *s,
+            ParseError::MultipleErrors { span, .. } => *span,
             ParseError::ExtraPositional(_, s) => *s,
             ParseError::UnexpectedEof(_, s) => *s,
             ParseError::Unclosed(_, s) => *s,
@@ -8,6 +8,7 @@ mod lite_parser;
 mod parse_keywords;
 mod parser;
 mod type_check;
+mod url_module;
 
 pub use deparse::{escape_for_script_arg, escape_quote_string};
 pub use errors::ParseError;
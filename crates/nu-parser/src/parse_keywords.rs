@@ -54,6 +54,7 @@ pub const UNALIASABLE_PARSER_KEYWORDS: &[&[u8]] = &[
     b"const",
     b"mut",
     b"source",
+    b"state load",
     b"where",
     b"register",
 ];
@@ -926,10 +927,12 @@ pub fn parse_old_alias(
             PathMember::String {
                 val: "scope".to_string(),
                 span: Span::new(0, 0),
+                optional: false,
             },
             PathMember::String {
                 val: "aliases".to_string(),
                 span: Span::new(0, 0),
+                optional: false,
             },
         ];
         let expr = Expression {
@@ -2093,6 +2096,15 @@ pub fn parse_module(
     }
 }
 
+/// The constant value of a `--sha256` pin, for loading modules from URLs
+fn get_sha256_flag(call: &Call) -> Option<String> {
+    call.get_flag_expr("sha256")
+        .and_then(|expr| match expr.expr {
+            Expr::String(s) => Some(s),
+            _ => None,
+        })
+}
+
 pub fn parse_use(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -2212,9 +2224,30 @@ pub fn parse_use(
             unescape_unquote_string(&import_pattern.head.name, import_pattern.head.span);
 
         if err.is_none() {
-            if let Some(module_path) =
+            let module_path = if crate::url_module::is_url(&module_filename) {
+                match crate::url_module::resolve_url_module(
+                    &module_filename,
+                    get_sha256_flag(&call).as_deref(),
+                    working_set.permanent_state.get_config().allow_url_sources,
+                ) {
+                    Ok(path) => Some(path),
+                    Err(msg) => {
+                        return (
+                            garbage_pipeline(spans),
+                            vec![],
+                            Some(ParseError::LabeledError(
+                                "Could not load module from URL".into(),
+                                msg,
+                                import_pattern.head.span,
+                            )),
+                        )
+                    }
+                }
+            } else {
                 find_in_dirs(&module_filename, working_set, &cwd, LIB_DIRS_ENV)
-            {
+            };
+
+            if let Some(module_path) = module_path {
                 if let Some(i) = working_set
                     .parsed_module_files
                     .iter()
@@ -3320,7 +3353,17 @@ pub fn parse_source(
     let mut error = None;
     let name = working_set.get_span_contents(spans[0]);
 
-    if name == b"source" || name == b"source-env" {
+    // `state load` is a two-word keyword that otherwise parses like `source`
+    let (name, args_start) = if name == b"state"
+        && spans.len() > 1
+        && working_set.get_span_contents(spans[1]) == b"load"
+    {
+        (&b"state load"[..], 2)
+    } else {
+        (name, 1)
+    };
+
+    if name == b"source" || name == b"source-env" || name == b"state load" {
         let scoped = name == b"source-env";
 
         if let Some(decl_id) = working_set.find_decl(name, &Type::Any) {
@@ -3334,8 +3377,8 @@ pub fn parse_source(
                 output,
             } = parse_internal_call(
                 working_set,
-                spans[0],
-                &spans[1..],
+                span(&spans[0..args_start]),
+                &spans[args_start..],
                 decl_id,
                 expand_aliases_denylist,
             );
@@ -3354,10 +3397,10 @@ pub fn parse_source(
             }
 
             // Command and one file name
-            if spans.len() >= 2 {
+            if spans.len() > args_start {
                 let (expr, err) = parse_value(
                     working_set,
-                    spans[1],
+                    spans[args_start],
                     &SyntaxShape::Any,
                     expand_aliases_denylist,
                 );
@@ -3370,7 +3413,7 @@ pub fn parse_source(
                         return (
                             Pipeline::from_vec(vec![Expression {
                                 expr: Expr::Call(call),
-                                span: span(&spans[1..]),
+                                span: span(&spans[args_start..]),
                                 ty: Type::Any,
                                 custom_completion: None,
                             }]),
@@ -3379,13 +3422,13 @@ pub fn parse_source(
                     }
                 };
 
-                let filename = match value_as_string(val, spans[1]) {
+                let filename = match value_as_string(val, spans[args_start]) {
                     Ok(s) => s,
                     Err(err) => {
                         return (
                             Pipeline::from_vec(vec![Expression {
                                 expr: Expr::Call(call),
-                                span: span(&spans[1..]),
+                                span: span(&spans[args_start..]),
                                 ty: Type::Any,
                                 custom_completion: None,
                             }]),
@@ -3394,7 +3437,34 @@ pub fn parse_source(
                     }
                 };
 
-                if let Some(path) = find_in_dirs(&filename, working_set, &cwd, LIB_DIRS_ENV) {
+                let path = if crate::url_module::is_url(&filename) {
+                    match crate::url_module::resolve_url_module(
+                        &filename,
+                        get_sha256_flag(&call).as_deref(),
+                        working_set.permanent_state.get_config().allow_url_sources,
+                    ) {
+                        Ok(path) => Some(path),
+                        Err(msg) => {
+                            return (
+                                Pipeline::from_vec(vec![Expression {
+                                    expr: Expr::Call(call),
+                                    span: span(&spans[args_start..]),
+                                    ty: Type::Any,
+                                    custom_completion: None,
+                                }]),
+                                Some(ParseError::LabeledError(
+                                    "Could not load file from URL".into(),
+                                    msg,
+                                    spans[args_start],
+                                )),
+                            )
+                        }
+                    }
+                } else {
+                    find_in_dirs(&filename, working_set, &cwd, LIB_DIRS_ENV)
+                };
+
+                if let Some(path) = path {
                     if let Ok(contents) = std::fs::read(&path) {
                         // Change currently parsed directory
                         let prev_currently_parsed_cwd = if let Some(parent) = path.parent() {
@@ -3425,7 +3495,7 @@ pub fn parse_source(
                             return (
                                 Pipeline::from_vec(vec![Expression {
                                     expr: Expr::Call(call),
-                                    span: span(&spans[1..]),
+                                    span: span(&spans[args_start..]),
                                     ty: Type::Any,
                                     custom_completion: None,
                                 }]),
@@ -3442,7 +3512,7 @@ pub fn parse_source(
                             // after writing `source example.nu`
                             call_with_block.add_parser_info(Expression {
                                 expr: Expr::Int(block_id as i64),
-                                span: spans[1],
+                                span: spans[args_start],
                                 ty: Type::Any,
                                 custom_completion: None,
                             });
@@ -3459,7 +3529,8 @@ pub fn parse_source(
                         }
                     }
                 } else {
-                    error = error.or(Some(ParseError::SourcedFileNotFound(filename, spans[1])));
+                    error =
+                        error.or(Some(ParseError::SourcedFileNotFound(filename, spans[args_start])));
                 }
             }
             return (
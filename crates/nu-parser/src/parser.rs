@@ -496,7 +496,11 @@ fn parse_short_flags(
         if found_short_flags.is_empty() {
             // check to see if we have a negative number
             if let Some(positional) = sig.get_positional(positional_idx) {
-                if positional.shape == SyntaxShape::Int || positional.shape == SyntaxShape::Number {
+                if positional.shape == SyntaxShape::Int
+                    || positional.shape == SyntaxShape::Number
+                    // cell paths accept negative indices, like `get -1`
+                    || positional.shape == SyntaxShape::CellPath
+                {
                     if String::from_utf8_lossy(arg_contents).parse::<f64>().is_ok() {
                         return (None, None);
                     } else if let Some(first) = unmatched_short_flags.first() {
@@ -1964,7 +1968,19 @@ pub fn parse_cell_path(
         } else {
             expect_dot = true;
 
-            match parse_int(bytes, path_element.span) {
+            // A trailing `?` marks the member as optional: a missed lookup
+            // yields null instead of an error
+            let (bytes, member_span, optional) = if bytes.len() > 1 && bytes.ends_with(b"?") {
+                (
+                    &bytes[..bytes.len() - 1],
+                    Span::new(path_element.span.start, path_element.span.end - 1),
+                    true,
+                )
+            } else {
+                (bytes, path_element.span, false)
+            };
+
+            match parse_int(bytes, member_span) {
                 (
                     Expression {
                         expr: Expr::Int(val),
@@ -1972,13 +1988,32 @@ pub fn parse_cell_path(
                         ..
                     },
                     None,
-                ) => tail.push(PathMember::Int {
+                ) if val >= 0 => tail.push(PathMember::Int {
                     val: val as usize,
                     span,
+                    optional,
                 }),
+                (
+                    Expression {
+                        expr: Expr::Int(_),
+                        span,
+                        ..
+                    },
+                    None,
+                ) => {
+                    // A negative index counts from the end of a list. Its
+                    // meaning depends on the length of the value it is applied
+                    // to, so keep the raw text and resolve it at evaluation
+                    // time.
+                    tail.push(PathMember::String {
+                        val: String::from_utf8_lossy(bytes).to_string(),
+                        span,
+                        optional,
+                    });
+                }
                 _ => {
                     let (result, err) =
-                        parse_string(working_set, path_element.span, expand_aliases_denylist);
+                        parse_string(working_set, member_span, expand_aliases_denylist);
                     error = error.or(err);
                     match result {
                         Expression {
@@ -1986,7 +2021,11 @@ pub fn parse_cell_path(
                             span,
                             ..
                         } => {
-                            tail.push(PathMember::String { val: string, span });
+                            tail.push(PathMember::String {
+                                val: string,
+                                span,
+                                optional,
+                            });
                         }
                         _ => {
                             error =
@@ -4733,6 +4772,8 @@ pub fn parse_operator(
         b"/=" => Operator::Assignment(Assignment::DivideAssign),
         b"==" => Operator::Comparison(Comparison::Equal),
         b"!=" => Operator::Comparison(Comparison::NotEqual),
+        b"===" => Operator::Comparison(Comparison::StrictEqual),
+        b"!==" => Operator::Comparison(Comparison::StrictNotEqual),
         b"<" => Operator::Comparison(Comparison::LessThan),
         b"<=" => Operator::Comparison(Comparison::LessThanOrEqual),
         b">" => Operator::Comparison(Comparison::GreaterThan),
@@ -4759,6 +4800,7 @@ pub fn parse_operator(
         b"or" => Operator::Boolean(Boolean::Or),
         b"xor" => Operator::Boolean(Boolean::Xor),
         b"**" => Operator::Math(Math::Pow),
+        b"??" => Operator::Coalesce,
         // WARNING: not actual operators below! Error handling only
         pow @ (b"^" | b"pow") => {
             return (
@@ -4774,15 +4816,11 @@ pub fn parse_operator(
                 )),
             );
         }
-        equality @ (b"is" | b"===") => {
+        b"is" => {
             return (
                 garbage(span),
                 Some(ParseError::UnknownOperator(
-                    match equality {
-                        b"is" => "is",
-                        b"===" => "===",
-                        _ => unreachable!(),
-                    },
+                    "is",
                     "Did you mean '=='?",
                     span,
                 )),
@@ -5383,6 +5421,12 @@ pub fn parse_builtin_commands(
         b"source" | b"source-env" => {
             parse_source(working_set, &lite_command.parts, expand_aliases_denylist)
         }
+        b"state"
+            if lite_command.parts.len() > 1
+                && working_set.get_span_contents(lite_command.parts[1]) == b"load" =>
+        {
+            parse_source(working_set, &lite_command.parts, expand_aliases_denylist)
+        }
         b"export" => parse_export_in_block(working_set, lite_command, expand_aliases_denylist),
         b"hide" => parse_hide(working_set, &lite_command.parts, expand_aliases_denylist),
         b"where" => parse_where(working_set, &lite_command.parts, expand_aliases_denylist),
@@ -5494,6 +5538,10 @@ pub fn parse_block(
     is_subexpression: bool,
 ) -> (Block, Option<ParseError>) {
     let mut error = None;
+    // Errors from individual pipelines are collected here instead of stopping
+    // at the first one, so a single bad line doesn't hide the diagnostics (or
+    // the best-effort AST) for the rest of the block.
+    let mut errors: Vec<ParseError> = vec![];
 
     let (lite_block, err) = lite_parse(tokens);
     error = error.or(err);
@@ -5545,8 +5593,8 @@ pub fn parse_block(
                             );
                             working_set.type_scope.add_type(expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err;
+                            if let Some(err) = err {
+                                errors.push(err);
                             }
 
                             PipelineElement::Expression(*span, expr)
@@ -5561,8 +5609,8 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err;
+                            if let Some(err) = err {
+                                errors.push(err);
                             }
 
                             PipelineElement::Redirection(*span, redirection.clone(), expr)
@@ -5580,8 +5628,8 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(out_expr.ty.clone());
 
-                            if error.is_none() {
-                                error = out_err;
+                            if let Some(err) = out_err {
+                                errors.push(err);
                             }
 
                             let (err_expr, err_err) = parse_string(
@@ -5592,8 +5640,8 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(err_expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err_err;
+                            if let Some(err) = err_err {
+                                errors.push(err);
                             }
 
                             PipelineElement::SeparateRedirection {
@@ -5681,8 +5729,8 @@ pub fn parse_block(
                             }
                         }
 
-                        if error.is_none() {
-                            error = err;
+                        if let Some(err) = err {
+                            errors.push(err);
                         }
 
                         pipeline
@@ -5697,7 +5745,25 @@ pub fn parse_block(
     }
     working_set.type_scope.exit_scope();
 
-    (block, error)
+    if let Some(err) = error {
+        errors.insert(0, err);
+    }
+
+    (block, combine_parse_errors(errors))
+}
+
+// Folds the errors collected across a block into one: `None` for none, the
+// error itself for exactly one, and a `MultipleErrors` carrying all of them
+// as related diagnostics otherwise.
+fn combine_parse_errors(mut errors: Vec<ParseError>) -> Option<ParseError> {
+    match errors.len() {
+        0 => None,
+        1 => errors.pop(),
+        _ => {
+            let span = errors[0].span();
+            Some(ParseError::MultipleErrors { span, errors })
+        }
+    }
 }
 
 pub fn discover_captures_in_closure(
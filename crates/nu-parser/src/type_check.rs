@@ -40,6 +40,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 (Type::Int, _) => {
                     let ty = rhs.ty.clone();
                     *rhs = Expression::garbage(rhs.span);
@@ -116,6 +120,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 _ => {
                     *op = Expression::garbage(op.span);
                     (
@@ -153,6 +161,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 _ => {
                     *op = Expression::garbage(op.span);
                     (
@@ -178,6 +190,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 _ => {
                     *op = Expression::garbage(op.span);
                     (
@@ -210,6 +226,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 _ => {
                     *op = Expression::garbage(op.span);
                     (
@@ -238,6 +258,10 @@ pub fn math_result_type(
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
+                // a null operand propagates (or errors under strict_null_math)
+                // at runtime, so either branch can only be typed as any
+                (Type::Nothing, _) => (Type::Any, None),
+                (_, Type::Nothing) => (Type::Any, None),
                 _ => {
                     *op = Expression::garbage(op.span);
                     (
@@ -404,6 +428,10 @@ pub fn math_result_type(
 
                 _ => (Type::Bool, None),
             },
+            // strict equality accepts any operand types: a type mismatch is how
+            // it reports inequality, not an error
+            Operator::Comparison(Comparison::StrictEqual) => (Type::Bool, None),
+            Operator::Comparison(Comparison::StrictNotEqual) => (Type::Bool, None),
             Operator::Comparison(Comparison::RegexMatch) => match (&lhs.ty, &rhs.ty) {
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::Any, _) => (Type::Bool, None),
@@ -565,6 +593,13 @@ pub fn math_result_type(
                     )
                 }
             },
+            Operator::Coalesce => match (&lhs.ty, &rhs.ty) {
+                (Type::Nothing, ty) => (ty.clone(), None),
+                (x, y) if x == y => (x.clone(), None),
+                // the left side may or may not be null at runtime, so either
+                // type can flow through
+                _ => (Type::Any, None),
+            },
             Operator::Assignment(_) => match (&lhs.ty, &rhs.ty) {
                 (x, y) if x == y => (Type::Nothing, None),
                 (Type::Any, _) => (Type::Nothing, None),
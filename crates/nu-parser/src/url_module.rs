@@ -0,0 +1,146 @@
+//! Loading modules from https URLs for `use`, `source` and `source-env`.
+//!
+//! Fetched files land in a local cache keyed by the URL, so a module pinned
+//! with `--sha256` never touches the network twice and an unpinned one still
+//! works offline once it has been fetched. The whole feature is disabled
+//! unless `allow_url_sources` is set in the config.
+
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+pub(crate) fn is_url(name: &str) -> bool {
+    name.starts_with("https://") || name.starts_with("http://")
+}
+
+/// The local file holding the module behind `url`, fetching it if needed
+pub(crate) fn resolve_url_module(
+    url: &str,
+    pin: Option<&str>,
+    allowed: bool,
+) -> Result<PathBuf, String> {
+    if !allowed {
+        return Err(
+            "sourcing from URLs is disabled; set allow_url_sources to true in the config to enable it"
+                .into(),
+        );
+    }
+
+    let cache = cache_path(url)
+        .ok_or_else(|| "could not find the config directory for the module cache".to_string())?;
+
+    // a pinned module that is already cached never touches the network
+    if let Some(pin) = pin {
+        if let Ok(contents) = std::fs::read_to_string(&cache) {
+            if content_hash(&contents) == pin {
+                return Ok(cache);
+            }
+        }
+    }
+
+    match fetch(url) {
+        Ok(contents) => {
+            if let Some(pin) = pin {
+                let hash = content_hash(&contents);
+                if hash != pin {
+                    return Err(format!(
+                        "content hash mismatch: pinned {pin} but the URL served {hash}"
+                    ));
+                }
+            }
+            if let Some(parent) = cache.parent() {
+                std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+            }
+            std::fs::write(&cache, &contents).map_err(|err| err.to_string())?;
+            Ok(cache)
+        }
+        Err(fetch_error) => {
+            // offline fallback: an unpinned cached copy beats failing outright
+            match std::fs::read_to_string(&cache) {
+                Ok(contents)
+                    if pin
+                        .map(|pin| content_hash(&contents) == pin)
+                        .unwrap_or(true) =>
+                {
+                    Ok(cache)
+                }
+                _ => Err(fetch_error),
+            }
+        }
+    }
+}
+
+fn fetch(url: &str) -> Result<String, String> {
+    ureq::get(url)
+        .call()
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| err.to_string())
+}
+
+fn cache_path(url: &str) -> Option<PathBuf> {
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("url-modules");
+    path.push(&content_hash(url)[..16]);
+    path.push(file_name(url));
+    Some(path)
+}
+
+/// The cached file's stem becomes the module name, so keep the URL's one
+/// whenever it is a sane file name
+fn file_name(url: &str) -> String {
+    let base = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(url)
+        .rsplit('/')
+        .next()
+        .unwrap_or("");
+
+    if !base.is_empty()
+        && base
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._-".contains(c))
+    {
+        base.to_string()
+    } else {
+        "mod.nu".into()
+    }
+}
+
+pub(crate) fn content_hash(contents: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(contents.as_bytes());
+    let hash = hasher.finalize();
+    hash.iter().fold(String::new(), |mut output, b| {
+        output.push_str(&format!("{b:02x}"));
+        output
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_detection() {
+        assert!(is_url("https://example.com/utils.nu"));
+        assert!(is_url("http://example.com/utils.nu"));
+        assert!(!is_url("spam/utils.nu"));
+    }
+
+    #[test]
+    fn module_name_comes_from_the_url() {
+        assert_eq!(
+            file_name("https://example.com/lib/utils.nu?token=abc"),
+            "utils.nu"
+        );
+        assert_eq!(file_name("https://example.com/"), "mod.nu");
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let result = resolve_url_module("https://example.com/utils.nu", None, false);
+        assert!(result.unwrap_err().contains("allow_url_sources"));
+    }
+}
@@ -5,8 +5,26 @@ use std::fmt::Write;
 
 #[derive(Debug, Clone, PartialOrd, Serialize, Deserialize)]
 pub enum PathMember {
-    String { val: String, span: Span },
-    Int { val: usize, span: Span },
+    String {
+        val: String,
+        span: Span,
+        /// An optional member (written `a.b?`) turns a missed lookup into null
+        /// instead of an error
+        optional: bool,
+    },
+    Int {
+        val: usize,
+        span: Span,
+        optional: bool,
+    },
+}
+
+impl PathMember {
+    pub fn is_optional(&self) -> bool {
+        match self {
+            PathMember::String { optional, .. } | PathMember::Int { optional, .. } => *optional,
+        }
+    }
 }
 
 impl PartialEq for PathMember {
@@ -38,6 +56,9 @@ impl CellPath {
                 }
                 PathMember::String { val, .. } => output.push_str(val),
             }
+            if elem.is_optional() {
+                output.push('?');
+            }
         }
 
         output
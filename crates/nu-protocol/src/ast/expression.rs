@@ -47,6 +47,8 @@ impl Expression {
                     | Operator::Comparison(Comparison::GreaterThanOrEqual)
                     | Operator::Comparison(Comparison::Equal)
                     | Operator::Comparison(Comparison::NotEqual)
+                    | Operator::Comparison(Comparison::StrictEqual)
+                    | Operator::Comparison(Comparison::StrictNotEqual)
                     | Operator::Comparison(Comparison::In)
                     | Operator::Comparison(Comparison::NotIn)
                     | Operator::Math(Math::Append) => 80,
@@ -56,6 +58,7 @@ impl Expression {
                     Operator::Boolean(Boolean::And) => 50,
                     Operator::Boolean(Boolean::Xor) => 45,
                     Operator::Boolean(Boolean::Or) => 40,
+                    Operator::Coalesce => 35,
                     Operator::Assignment(_) => 10,
                 }
             }
@@ -7,6 +7,8 @@ use std::fmt::Display;
 pub enum Comparison {
     Equal,
     NotEqual,
+    StrictEqual,
+    StrictNotEqual,
     LessThan,
     GreaterThan,
     LessThanOrEqual,
@@ -64,6 +66,7 @@ pub enum Operator {
     Boolean(Boolean),
     Bits(Bits),
     Assignment(Assignment),
+    Coalesce,
 }
 
 impl Display for Operator {
@@ -77,6 +80,8 @@ impl Display for Operator {
             Operator::Assignment(Assignment::DivideAssign) => write!(f, "/="),
             Operator::Comparison(Comparison::Equal) => write!(f, "=="),
             Operator::Comparison(Comparison::NotEqual) => write!(f, "!="),
+            Operator::Comparison(Comparison::StrictEqual) => write!(f, "==="),
+            Operator::Comparison(Comparison::StrictNotEqual) => write!(f, "!=="),
             Operator::Comparison(Comparison::LessThan) => write!(f, "<"),
             Operator::Comparison(Comparison::GreaterThan) => write!(f, ">"),
             Operator::Comparison(Comparison::RegexMatch) => write!(f, "=~"),
@@ -98,6 +103,7 @@ impl Display for Operator {
             Operator::Boolean(Boolean::And) => write!(f, "&&"),
             Operator::Boolean(Boolean::Or) => write!(f, "||"),
             Operator::Boolean(Boolean::Xor) => write!(f, "xor"),
+            Operator::Coalesce => write!(f, "??"),
             Operator::Bits(Bits::BitOr) => write!(f, "bit-or"),
             Operator::Bits(Bits::BitXor) => write!(f, "bit-xor"),
             Operator::Bits(Bits::BitAnd) => write!(f, "bit-and"),
@@ -71,6 +71,13 @@ pub struct Config {
     pub use_grid_icons: bool,
     pub footer_mode: FooterMode,
     pub float_precision: i64,
+    pub float_thousands_separators: bool,
+    pub float_scientific_threshold: i64,
+    pub strict_null_math: bool,
+    pub keep_last_output: bool,
+    pub last_output_max_size: i64,
+    pub style_closure_timeout_ms: i64,
+    pub allow_url_sources: bool,
     pub max_external_completion_results: i64,
     pub filesize_format: String,
     pub use_ansi_coloring: bool,
@@ -94,12 +101,16 @@ pub struct Config {
     pub enable_external_completion: bool,
     pub trim_strategy: TrimStrategy,
     pub show_banner: bool,
+    pub allow_version_check: bool,
     pub show_clickable_links_in_ls: bool,
     pub render_right_prompt_on_last_line: bool,
     pub explore: HashMap<String, Value>,
     pub cursor_shape_vi_insert: NuCursorShape,
     pub cursor_shape_vi_normal: NuCursorShape,
     pub cursor_shape_emacs: NuCursorShape,
+    pub recursion_limit: i64,
+    pub progress_bar_style: String,
+    pub abbreviations: HashMap<String, String>,
 }
 
 impl Default for Config {
@@ -114,6 +125,13 @@ impl Default for Config {
             use_grid_icons: false,
             footer_mode: FooterMode::RowCount(25),
             float_precision: 4,
+            float_thousands_separators: false,
+            float_scientific_threshold: 0,
+            strict_null_math: false,
+            keep_last_output: false,
+            last_output_max_size: 1_000_000,
+            style_closure_timeout_ms: 500,
+            allow_url_sources: false,
             max_external_completion_results: 100,
             filesize_format: "auto".into(),
             use_ansi_coloring: true,
@@ -137,12 +155,16 @@ impl Default for Config {
             enable_external_completion: true,
             trim_strategy: TRIM_STRATEGY_DEFAULT,
             show_banner: true,
+            allow_version_check: true,
             show_clickable_links_in_ls: true,
             render_right_prompt_on_last_line: false,
             explore: HashMap::new(),
             cursor_shape_vi_insert: NuCursorShape::Block,
             cursor_shape_vi_normal: NuCursorShape::UnderScore,
             cursor_shape_emacs: NuCursorShape::Line,
+            recursion_limit: 50,
+            progress_bar_style: "#>-".into(),
+            abbreviations: HashMap::new(),
         }
     }
 }
@@ -960,6 +982,24 @@ impl Value {
                             );
                         }
                     }
+                    "abbreviations" => {
+                        if let Ok(map) = create_map(value) {
+                            let mut abbreviations = HashMap::new();
+                            for (key, expansion) in map {
+                                if let Ok(expansion) = expansion.as_string() {
+                                    abbreviations.insert(key, expansion);
+                                } else {
+                                    invalid!(
+                                        expansion.span().ok(),
+                                        "should be a record of strings"
+                                    );
+                                }
+                            }
+                            config.abbreviations = abbreviations;
+                        } else {
+                            invalid!(vals[index].span().ok(), "should be a record");
+                        }
+                    }
                     "explore" => {
                         if let Ok(map) = create_map(value) {
                             config.explore = map;
@@ -1011,6 +1051,51 @@ impl Value {
                     "float_precision" => {
                         try_int!(cols, vals, index, span, float_precision);
                     }
+                    "float_thousands_separators" => {
+                        try_bool!(cols, vals, index, span, float_thousands_separators);
+                    }
+                    "float_scientific_threshold" => {
+                        try_int!(cols, vals, index, span, float_scientific_threshold);
+                    }
+                    "strict_null_math" => {
+                        try_bool!(cols, vals, index, span, strict_null_math);
+                    }
+                    "keep_last_output" => {
+                        try_bool!(cols, vals, index, span, keep_last_output);
+                    }
+                    "last_output_max_size" => {
+                        try_int!(cols, vals, index, span, last_output_max_size);
+                    }
+                    "style_closure_timeout_ms" => {
+                        try_int!(cols, vals, index, span, style_closure_timeout_ms);
+                    }
+                    "allow_url_sources" => {
+                        try_bool!(cols, vals, index, span, allow_url_sources);
+                    }
+                    "recursion_limit" => {
+                        if let Ok(v) = value.as_integer() {
+                            if v > 1 {
+                                config.recursion_limit = v;
+                            } else {
+                                invalid!(Some(*span), "should be an int greater than 1");
+                                // Reconstruct
+                                vals[index] = Value::int(config.recursion_limit, *span);
+                            }
+                        } else {
+                            invalid!(Some(*span), "should be an int greater than 1");
+                            // Reconstruct
+                            vals[index] = Value::int(config.recursion_limit, *span);
+                        }
+                    }
+                    "progress_bar_style" => {
+                        if let Ok(v) = value.as_string() {
+                            config.progress_bar_style = v;
+                        } else {
+                            invalid!(Some(*span), "should be a string");
+                            // Reconstruct
+                            vals[index] = Value::string(config.progress_bar_style.clone(), *span);
+                        }
+                    }
                     "use_ansi_coloring" => {
                         try_bool!(cols, vals, index, span, use_ansi_coloring);
                     }
@@ -1160,6 +1245,9 @@ impl Value {
                     "show_banner" => {
                         try_bool!(cols, vals, index, span, show_banner);
                     }
+                    "allow_version_check" => {
+                        try_bool!(cols, vals, index, span, allow_version_check);
+                    }
                     "render_right_prompt_on_last_line" => {
                         try_bool!(cols, vals, index, span, render_right_prompt_on_last_line);
                     }
@@ -29,6 +29,53 @@ pub enum ReplOperation {
     Append(String),
     Insert(String),
     Replace(String),
+    MoveCursor(usize),
+}
+
+/// One executed line captured by `session record`.
+#[derive(Debug, Clone)]
+pub struct SessionEntry {
+    pub offset_ms: i64,
+    pub duration_ms: i64,
+    pub command: String,
+    pub output: String,
+}
+
+/// An in-progress `session record`, flushed to `path` by `session stop`.
+#[derive(Debug, Clone)]
+pub struct SessionRecording {
+    pub path: PathBuf,
+    pub entries: Vec<SessionEntry>,
+    start: std::time::Instant,
+    armed: bool,
+}
+
+impl SessionRecording {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            entries: vec![],
+            start: std::time::Instant::now(),
+            armed: false,
+        }
+    }
+
+    /// Capture one executed line. The line that started the recording reaches
+    /// here too, so the first call only arms the recorder.
+    pub fn record(&mut self, command: &str, output: &str, duration_ms: i64) {
+        if !self.armed {
+            self.armed = true;
+            return;
+        }
+
+        let elapsed_ms = self.start.elapsed().as_millis() as i64;
+        self.entries.push(SessionEntry {
+            offset_ms: (elapsed_ms - duration_ms).max(0),
+            duration_ms,
+            command: command.to_string(),
+            output: output.to_string(),
+        });
+    }
 }
 
 /// Organizes usage messages for various primitives
@@ -135,7 +182,9 @@ pub struct EngineState {
     pub config: Config,
     pub pipeline_externals_state: Arc<(AtomicU32, AtomicU32)>,
     pub repl_buffer_state: Arc<Mutex<Option<String>>>,
+    pub repl_cursor_pos: Arc<Mutex<usize>>,
     pub repl_operation_queue: Arc<Mutex<VecDeque<ReplOperation>>>,
+    pub session_recording: Arc<Mutex<Option<SessionRecording>>>,
     #[cfg(feature = "plugin")]
     pub plugin_signatures: Option<PathBuf>,
     #[cfg(not(windows))]
@@ -187,7 +236,9 @@ impl EngineState {
             config: Config::default(),
             pipeline_externals_state: Arc::new((AtomicU32::new(0), AtomicU32::new(0))),
             repl_buffer_state: Arc::new(Mutex::new(None)),
+            repl_cursor_pos: Arc::new(Mutex::new(0)),
             repl_operation_queue: Arc::new(Mutex::new(VecDeque::new())),
+            session_recording: Arc::new(Mutex::new(None)),
             #[cfg(feature = "plugin")]
             plugin_signatures: None,
             #[cfg(not(windows))]
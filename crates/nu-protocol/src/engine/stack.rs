@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::engine::Closure;
 use crate::engine::EngineState;
 use crate::engine::DEFAULT_OVERLAY_NAME;
 use crate::{ShellError, Span, Value, VarId};
@@ -74,6 +75,8 @@ pub struct Stack {
     pub active_overlays: Vec<String>,
     pub recursion_count: Box<u64>,
     pub profiling_config: ProfilingConfig,
+    /// Closures registered by `defer`, run when the block that registered them exits
+    pub defers: Vec<Closure>,
 }
 
 impl Stack {
@@ -85,6 +88,7 @@ impl Stack {
             active_overlays: vec![DEFAULT_OVERLAY_NAME.to_string()],
             recursion_count: Box::new(0),
             profiling_config: ProfilingConfig::new(0, false, false),
+            defers: vec![],
         }
     }
 
@@ -169,6 +173,7 @@ impl Stack {
             active_overlays: self.active_overlays.clone(),
             recursion_count: self.recursion_count.to_owned(),
             profiling_config: self.profiling_config.clone(),
+            defers: vec![],
         }
     }
 
@@ -195,6 +200,7 @@ impl Stack {
             active_overlays: self.active_overlays.clone(),
             recursion_count: self.recursion_count.to_owned(),
             profiling_config: self.profiling_config.clone(),
+            defers: vec![],
         }
     }
 
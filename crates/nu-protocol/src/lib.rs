@@ -8,6 +8,7 @@ mod exportable;
 mod id;
 mod lev_distance;
 mod module;
+pub mod operate;
 mod pipeline_data;
 #[cfg(feature = "plugin")]
 mod plugin_signature;
@@ -0,0 +1,128 @@
+//! Shared machinery for commands that apply an action to every value flowing
+//! through a pipeline.
+//!
+//! Most `str` and `into` commands follow the same shape: a small `action`
+//! that turns one [`Value`] into another, vectorized over lists and streams,
+//! and optionally restricted to the cells named by a set of cell paths. The
+//! [`operate`] and [`operate_fallible`] helpers implement that dispatch once,
+//! for internal commands and plugin authors alike.
+
+use crate::ast::CellPath;
+use crate::{PipelineData, ShellError, Span, Value};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// The argument bundle an [`operate`] action is invoked with.
+///
+/// Implementors hand over their cell paths once, up front; everything else in
+/// the bundle stays available to the action on every call.
+pub trait CmdArgument {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>>;
+}
+
+/// Arguments with only cell_path.
+///
+/// If commands is going to use `operate` function, and it only required optional cell_paths
+/// Using this to simplify code.
+pub struct CellPathOnlyArgs {
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for CellPathOnlyArgs {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+impl From<Vec<CellPath>> for CellPathOnlyArgs {
+    fn from(cell_paths: Vec<CellPath>) -> Self {
+        Self {
+            cell_paths: (!cell_paths.is_empty()).then_some(cell_paths),
+        }
+    }
+}
+
+/// A simple wrapper for `PipelineData::map` method.
+///
+/// In detail, for each elements, invoking relative `cmd` with `arg`.
+///
+/// If `arg` tell us that its cell path is not None, only map over data under these columns.
+/// Else it will apply each column inside a table.
+///
+/// The validation of input element should be handle by `cmd` itself.
+pub fn operate<C, A>(
+    cmd: C,
+    arg: A,
+    input: PipelineData,
+    span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
+) -> Result<PipelineData, ShellError>
+where
+    A: CmdArgument + Send + Sync + 'static,
+    C: Fn(&Value, &A, Span) -> Value + Send + Sync + 'static + Clone + Copy,
+{
+    operate_fallible(
+        move |value, arg, span| Ok(cmd(value, arg, span)),
+        arg,
+        input,
+        span,
+        ctrlc,
+    )
+}
+
+/// Like [`operate`], for actions that can fail.
+///
+/// An `Err` from `cmd` is carried through the pipeline as a [`Value::Error`]
+/// in that element's place, so one bad cell reports its own span without
+/// cutting the rest of a stream short. Errors already present in the input
+/// are propagated untouched, never handed to `cmd`.
+pub fn operate_fallible<C, A>(
+    cmd: C,
+    mut arg: A,
+    input: PipelineData,
+    span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
+) -> Result<PipelineData, ShellError>
+where
+    A: CmdArgument + Send + Sync + 'static,
+    C: Fn(&Value, &A, Span) -> Result<Value, ShellError> + Send + Sync + 'static + Clone + Copy,
+{
+    match arg.take_cell_paths() {
+        None => input.map(
+            move |v| {
+                match v {
+                    // Propagate errors inside the input
+                    Value::Error { .. } => v,
+                    _ => cmd(&v, &arg, span).unwrap_or_else(|error| Value::Error { error }),
+                }
+            },
+            ctrlc,
+        ),
+        Some(column_paths) => {
+            let arg = Arc::new(arg);
+            input.map(
+                move |mut v| {
+                    for path in &column_paths {
+                        let opt = arg.clone();
+                        let r = v.update_cell_path(
+                            &path.members,
+                            Box::new(move |old| {
+                                match old {
+                                    // Propagate errors inside the input
+                                    Value::Error { .. } => old.clone(),
+                                    _ => cmd(old, &opt, span)
+                                        .unwrap_or_else(|error| Value::Error { error }),
+                                }
+                            }),
+                        );
+                        if let Err(error) = r {
+                            return Value::Error { error };
+                        }
+                    }
+                    v
+                },
+                ctrlc,
+            )
+        }
+    }
+}
@@ -1098,6 +1098,72 @@ pub fn levenshtein_distance(a: &str, b: &str) -> usize {
         .expect("It is impossible to exceed the supplied limit since all types involved are usize.")
 }
 
+/// Computes the Jaro-Winkler similarity of two strings, a value between 0.0
+/// (nothing in common) and 1.0 (identical), weighted towards strings that
+/// share a common prefix.
+pub fn jaro_winkler_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // characters match when they are equal and no further apart than the window
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for (j, b_match) in b_matched.iter_mut().enumerate().take(end).skip(start) {
+            if !*b_match && b[j] == *ca {
+                *b_match = true;
+                a_matched[i] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0usize;
+    let mut j = 0usize;
+    for (i, matched) in a_matched.iter().enumerate() {
+        if *matched {
+            while !b_matched[j] {
+                j += 1;
+            }
+            if a[i] != b[j] {
+                transpositions += 1;
+            }
+            j += 1;
+        }
+    }
+
+    let m = matches as f64;
+    let jaro =
+        (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64 / 2.0) / m) / 3.0;
+
+    // boost the score for a common prefix of up to four characters
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
 #[cfg(test)]
 mod tests {
     use super::did_you_mean;
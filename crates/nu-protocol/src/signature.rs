@@ -27,6 +27,17 @@ pub struct Flag {
     pub default_value: Option<Expression>,
 }
 
+/// Marks a command, or one of its flags, as deprecated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deprecation {
+    /// The flag this deprecation applies to; `None` deprecates the command itself
+    pub flag: Option<String>,
+    /// What to use instead
+    pub replacement: Option<String>,
+    /// The release in which the deprecated form is planned to be removed
+    pub removal_version: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PositionalArg {
     pub name: String,
@@ -121,6 +132,7 @@ pub struct Signature {
     pub is_filter: bool,
     pub creates_scope: bool,
     pub allows_unknown_args: bool,
+    pub deprecations: Vec<Deprecation>,
     // Signature category used to classify commands stored in the list of declarations
     pub category: Category,
 }
@@ -224,9 +236,51 @@ impl Signature {
             creates_scope: false,
             category: Category::Default,
             allows_unknown_args: false,
+            deprecations: vec![],
         }
     }
 
+    /// Mark this command as deprecated in favor of a replacement
+    pub fn deprecated(
+        mut self,
+        replacement: impl Into<String>,
+        removal_version: impl Into<String>,
+    ) -> Signature {
+        self.deprecations.push(Deprecation {
+            flag: None,
+            replacement: Some(replacement.into()),
+            removal_version: Some(removal_version.into()),
+        });
+        self
+    }
+
+    /// Mark one of this command's flags as deprecated in favor of a replacement
+    pub fn deprecated_flag(
+        mut self,
+        flag: impl Into<String>,
+        replacement: impl Into<String>,
+        removal_version: impl Into<String>,
+    ) -> Signature {
+        self.deprecations.push(Deprecation {
+            flag: Some(flag.into()),
+            replacement: Some(replacement.into()),
+            removal_version: Some(removal_version.into()),
+        });
+        self
+    }
+
+    /// The deprecation covering the command itself, if any
+    pub fn command_deprecation(&self) -> Option<&Deprecation> {
+        self.deprecations.iter().find(|d| d.flag.is_none())
+    }
+
+    /// The deprecation covering the named flag, if any
+    pub fn flag_deprecation(&self, flag: &str) -> Option<&Deprecation> {
+        self.deprecations
+            .iter()
+            .find(|d| d.flag.as_deref() == Some(flag))
+    }
+
     // Add a default help option to a signature
     pub fn add_help(mut self) -> Signature {
         // default help flag
@@ -0,0 +1,87 @@
+use crate::Config;
+
+/// Format a float the way the user's config asks for: rounded to
+/// `float_precision` digits, switched to scientific notation past
+/// `float_scientific_threshold`, and grouped with thousands separators when
+/// `float_thousands_separators` is set.
+pub fn format_float_from_conf(val: f64, config: &Config) -> String {
+    let precision = config.float_precision as usize;
+
+    if config.float_scientific_threshold > 0 && val != 0.0 && val.is_finite() {
+        let magnitude = val.abs().log10().floor() as i64;
+        if magnitude.abs() >= config.float_scientific_threshold {
+            return format!("{val:.precision$e}");
+        }
+    }
+
+    let formatted = format!("{val:.precision$}");
+    if config.float_thousands_separators {
+        group_thousands(&formatted)
+    } else {
+        formatted
+    }
+}
+
+/// Insert `,` separators into the integer part of an already formatted number
+fn group_thousands(formatted: &str) -> String {
+    let (number, fraction) = match formatted.split_once('.') {
+        Some((number, fraction)) => (number, Some(fraction)),
+        None => (formatted, None),
+    };
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number),
+    };
+
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    match fraction {
+        Some(fraction) => format!("{sign}{grouped}.{fraction}"),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_to_the_configured_precision() {
+        let config = Config {
+            float_precision: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(format_float_from_conf(0.1 + 0.2, &config), "0.30");
+    }
+
+    #[test]
+    fn groups_the_integer_part_when_asked() {
+        let config = Config {
+            float_precision: 1,
+            float_thousands_separators: true,
+            ..Default::default()
+        };
+
+        assert_eq!(format_float_from_conf(-1234567.5, &config), "-1,234,567.5");
+    }
+
+    #[test]
+    fn switches_to_scientific_notation_past_the_threshold() {
+        let config = Config {
+            float_precision: 2,
+            float_scientific_threshold: 6,
+            ..Default::default()
+        };
+
+        assert_eq!(format_float_from_conf(12345678.0, &config), "1.23e7");
+        assert_eq!(format_float_from_conf(0.0000005, &config), "5.00e-7");
+        assert_eq!(format_float_from_conf(123.456, &config), "123.46");
+    }
+}
@@ -302,16 +302,26 @@ impl FromValue for CellPath {
                 members: vec![PathMember::String {
                     val: val.clone(),
                     span,
+                    optional: false,
                 }],
             }),
             Value::Int { val, span } => {
                 if val.is_negative() {
-                    Err(ShellError::NeedsPositiveValue(*span))
+                    // a negative index counts from the end of a list; its raw
+                    // text is kept so it can be resolved against the length
+                    Ok(CellPath {
+                        members: vec![PathMember::String {
+                            val: val.to_string(),
+                            span: *span,
+                            optional: false,
+                        }],
+                    })
                 } else {
                     Ok(CellPath {
                         members: vec![PathMember::Int {
                             val: *val as usize,
                             span: *span,
+                            optional: false,
                         }],
                     })
                 }
@@ -1,4 +1,5 @@
 mod custom_value;
+mod float;
 mod from;
 mod from_value;
 mod lazy_record;
@@ -16,6 +17,7 @@ use chrono::{DateTime, Duration, FixedOffset};
 use chrono_humanize::HumanTime;
 pub use custom_value::CustomValue;
 use fancy_regex::Regex;
+pub use float::format_float_from_conf;
 pub use from_value::FromValue;
 use indexmap::map::IndexMap;
 pub use lazy_record::LazyRecord;
@@ -698,22 +700,26 @@ impl Value {
         from_user_input: bool,
     ) -> Result<Value, ShellError> {
         let mut current = self;
-        macro_rules! err_or_null {
-            ($e:expr, $span:expr) => {
-                return if nullify_errors {
-                    Ok(Value::nothing($span))
-                } else {
-                    Err($e)
-                }
-            };
-        }
         for member in cell_path {
+            // An optional member (`a.b?`) short-circuits the whole path to null
+            // when its lookup fails, exactly like nullify_errors does
+            let optional = member.is_optional();
+            macro_rules! err_or_null {
+                ($e:expr, $span:expr) => {
+                    return if nullify_errors || optional {
+                        Ok(Value::nothing($span))
+                    } else {
+                        Err($e)
+                    }
+                };
+            }
             // FIXME: this uses a few extra clones for simplicity, but there may be a way
             // to traverse the path without them
             match member {
                 PathMember::Int {
                     val: count,
                     span: origin_span,
+                    ..
                 } => {
                     // Treat a numeric path member as `select <val>`
                     match &mut current {
@@ -788,7 +794,18 @@ impl Value {
                 PathMember::String {
                     val: column_name,
                     span: origin_span,
+                    ..
                 } => match &mut current {
+                    // `*` matches every column of a record, unless a column is
+                    // literally named `*`
+                    Value::Record { cols, vals, span }
+                        if column_name == "*" && !cols.contains(column_name) =>
+                    {
+                        current = Value::List {
+                            vals: vals.clone(),
+                            span: *span,
+                        };
+                    }
                     Value::Record { cols, vals, span } => {
                         let cols = cols.clone();
                         let span = *span;
@@ -845,6 +862,31 @@ impl Value {
                             );
                         }
                     }
+                    // `*` on a list is the list itself, so `users.*.name` can
+                    // broadcast over the rows
+                    Value::List { .. } if column_name == "*" => {}
+                    // A negative index counts from the end of the list
+                    Value::List { vals, .. } if parse_negative_index(column_name).is_some() => {
+                        let idx = parse_negative_index(column_name).expect("checked by the guard");
+                        match negative_index(idx, vals.len()) {
+                            Some(idx) => current = vals[idx].clone(),
+                            None if vals.is_empty() => {
+                                err_or_null!(
+                                    ShellError::AccessEmptyContent { span: *origin_span },
+                                    *origin_span
+                                )
+                            }
+                            None => {
+                                err_or_null!(
+                                    ShellError::AccessBeyondEnd {
+                                        max_idx: vals.len() - 1,
+                                        span: *origin_span
+                                    },
+                                    *origin_span
+                                )
+                            }
+                        }
+                    }
                     // String access of Lists always means Table access.
                     // Create a List which contains each matching value for contained
                     // records in the source list.
@@ -859,6 +901,7 @@ impl Value {
                                     &[PathMember::String {
                                         val: column_name.clone(),
                                         span: *origin_span,
+                                        optional: false,
                                     }],
                                     insensitive,
                                     nullify_errors,
@@ -876,7 +919,7 @@ impl Value {
                                     // This also means that `[{a:1 b:2} {a:2}].b | reject 1` also doesn't error.
                                     // Anything that needs to use every value inside the list should propagate
                                     // the error outward, though.
-                                    output.push(if nullify_errors {
+                                    output.push(if nullify_errors || optional {
                                         Value::nothing(*origin_span)
                                     } else {
                                         Value::Error {
@@ -890,7 +933,7 @@ impl Value {
                                 }
                             } else {
                                 // See comment above.
-                                output.push(if nullify_errors {
+                                output.push(if nullify_errors || optional {
                                     Value::nothing(*origin_span)
                                 } else {
                                     Value::Error {
@@ -970,7 +1013,26 @@ impl Value {
                 PathMember::String {
                     val: col_name,
                     span,
+                    ..
                 } => match self {
+                    // A negative index counts from the end of the list
+                    Value::List { vals, .. } if parse_negative_index(col_name).is_some() => {
+                        let idx = parse_negative_index(col_name).expect("checked by the guard");
+                        match negative_index(idx, vals.len()) {
+                            Some(idx) => {
+                                vals[idx].upsert_data_at_cell_path(&cell_path[1..], new_val)?
+                            }
+                            None if vals.is_empty() => {
+                                return Err(ShellError::AccessEmptyContent { span: *span })
+                            }
+                            None => {
+                                return Err(ShellError::AccessBeyondEnd {
+                                    max_idx: vals.len() - 1,
+                                    span: *span,
+                                })
+                            }
+                        }
+                    }
                     Value::List { vals, .. } => {
                         for val in vals.iter_mut() {
                             match val {
@@ -1051,7 +1113,9 @@ impl Value {
                         })
                     }
                 },
-                PathMember::Int { val: row_num, span } => match self {
+                PathMember::Int {
+                    val: row_num, span, ..
+                } => match self {
                     Value::List { vals, .. } => {
                         if let Some(v) = vals.get_mut(*row_num) {
                             v.upsert_data_at_cell_path(&cell_path[1..], new_val)?
@@ -1108,7 +1172,28 @@ impl Value {
                 PathMember::String {
                     val: col_name,
                     span,
+                    optional,
                 } => match self {
+                    // A negative index counts from the end of the list
+                    Value::List { vals, .. } if parse_negative_index(col_name).is_some() => {
+                        let idx = parse_negative_index(col_name).expect("checked by the guard");
+                        match negative_index(idx, vals.len()) {
+                            Some(idx) => {
+                                vals[idx].update_data_at_cell_path(&cell_path[1..], new_val)?
+                            }
+                            // an optional miss leaves the value as it was
+                            None if *optional => {}
+                            None if vals.is_empty() => {
+                                return Err(ShellError::AccessEmptyContent { span: *span })
+                            }
+                            None => {
+                                return Err(ShellError::AccessBeyondEnd {
+                                    max_idx: vals.len() - 1,
+                                    span: *span,
+                                })
+                            }
+                        }
+                    }
                     Value::List { vals, .. } => {
                         for val in vals.iter_mut() {
                             match val {
@@ -1127,7 +1212,7 @@ impl Value {
                                             )?
                                         }
                                     }
-                                    if !found {
+                                    if !found && !*optional {
                                         return Err(ShellError::CantFindColumn {
                                             col_name: col_name.to_string(),
                                             span: *span,
@@ -1161,7 +1246,7 @@ impl Value {
                                     .update_data_at_cell_path(&cell_path[1..], new_val.clone())?
                             }
                         }
-                        if !found {
+                        if !found && !*optional {
                             return Err(ShellError::CantFindColumn {
                                 col_name: col_name.to_string(),
                                 span: *span,
@@ -1178,10 +1263,16 @@ impl Value {
                         })
                     }
                 },
-                PathMember::Int { val: row_num, span } => match self {
+                PathMember::Int {
+                    val: row_num,
+                    span,
+                    optional,
+                } => match self {
                     Value::List { vals, .. } => {
                         if let Some(v) = vals.get_mut(*row_num) {
                             v.update_data_at_cell_path(&cell_path[1..], new_val)?
+                        } else if *optional {
+                            // an optional miss leaves the value as it was
                         } else if vals.is_empty() {
                             return Err(ShellError::AccessEmptyContent { span: *span });
                         } else {
@@ -1216,7 +1307,25 @@ impl Value {
                     PathMember::String {
                         val: col_name,
                         span,
+                        optional,
                     } => match self {
+                        Value::List { vals, .. } if parse_negative_index(col_name).is_some() => {
+                            let idx = parse_negative_index(col_name).expect("checked by the guard");
+                            match negative_index(idx, vals.len()) {
+                                Some(idx) => {
+                                    vals.remove(idx);
+                                    Ok(())
+                                }
+                                None if *optional => Ok(()),
+                                None if vals.is_empty() => {
+                                    Err(ShellError::AccessEmptyContent { span: *span })
+                                }
+                                None => Err(ShellError::AccessBeyondEnd {
+                                    max_idx: vals.len() - 1,
+                                    span: *span,
+                                }),
+                            }
+                        }
                         Value::List { vals, .. } => {
                             for val in vals.iter_mut() {
                                 match val {
@@ -1233,7 +1342,7 @@ impl Value {
                                                 found = true;
                                             }
                                         }
-                                        if !found {
+                                        if !found && !*optional {
                                             return Err(ShellError::CantFindColumn {
                                                 col_name: col_name.to_string(),
                                                 span: *span,
@@ -1265,7 +1374,7 @@ impl Value {
                                     found = true;
                                 }
                             }
-                            if !found {
+                            if !found && !*optional {
                                 return Err(ShellError::CantFindColumn {
                                     col_name: col_name.to_string(),
                                     span: *span,
@@ -1280,11 +1389,17 @@ impl Value {
                             src_span: v.span()?,
                         }),
                     },
-                    PathMember::Int { val: row_num, span } => match self {
+                    PathMember::Int {
+                        val: row_num,
+                        span,
+                        optional,
+                    } => match self {
                         Value::List { vals, .. } => {
                             if vals.get_mut(*row_num).is_some() {
                                 vals.remove(*row_num);
                                 Ok(())
+                            } else if *optional {
+                                Ok(())
                             } else if vals.is_empty() {
                                 Err(ShellError::AccessEmptyContent { span: *span })
                             } else {
@@ -1307,7 +1422,22 @@ impl Value {
                     PathMember::String {
                         val: col_name,
                         span,
+                        optional,
                     } => match self {
+                        Value::List { vals, .. } if parse_negative_index(col_name).is_some() => {
+                            let idx = parse_negative_index(col_name).expect("checked by the guard");
+                            match negative_index(idx, vals.len()) {
+                                Some(idx) => vals[idx].remove_data_at_cell_path(&cell_path[1..]),
+                                None if *optional => Ok(()),
+                                None if vals.is_empty() => {
+                                    Err(ShellError::AccessEmptyContent { span: *span })
+                                }
+                                None => Err(ShellError::AccessBeyondEnd {
+                                    max_idx: vals.len() - 1,
+                                    span: *span,
+                                }),
+                            }
+                        }
                         Value::List { vals, .. } => {
                             for val in vals.iter_mut() {
                                 match val {
@@ -1323,7 +1453,7 @@ impl Value {
                                                 col.1.remove_data_at_cell_path(&cell_path[1..])?
                                             }
                                         }
-                                        if !found {
+                                        if !found && !*optional {
                                             return Err(ShellError::CantFindColumn {
                                                 col_name: col_name.to_string(),
                                                 span: *span,
@@ -1356,7 +1486,7 @@ impl Value {
                                     col.1.remove_data_at_cell_path(&cell_path[1..])?
                                 }
                             }
-                            if !found {
+                            if !found && !*optional {
                                 return Err(ShellError::CantFindColumn {
                                     col_name: col_name.to_string(),
                                     span: *span,
@@ -1371,10 +1501,16 @@ impl Value {
                             src_span: v.span()?,
                         }),
                     },
-                    PathMember::Int { val: row_num, span } => match self {
+                    PathMember::Int {
+                        val: row_num,
+                        span,
+                        optional,
+                    } => match self {
                         Value::List { vals, .. } => {
                             if let Some(v) = vals.get_mut(*row_num) {
                                 v.remove_data_at_cell_path(&cell_path[1..])
+                            } else if *optional {
+                                Ok(())
                             } else if vals.is_empty() {
                                 Err(ShellError::AccessEmptyContent { span: *span })
                             } else {
@@ -1405,7 +1541,32 @@ impl Value {
                 PathMember::String {
                     val: col_name,
                     span,
+                    ..
                 } => match self {
+                    // A negative index counts from the end of the list
+                    Value::List { vals, .. } if parse_negative_index(col_name).is_some() => {
+                        let idx = parse_negative_index(col_name).expect("checked by the guard");
+                        match negative_index(idx, vals.len()) {
+                            Some(idx) if cell_path.len() > 1 => vals[idx]
+                                .insert_data_at_cell_path(&cell_path[1..], new_val, head_span)?,
+                            // inserting at an occupied index would overwrite it
+                            Some(_) => {
+                                return Err(ShellError::InsertAfterNextFreeIndex {
+                                    available_idx: vals.len(),
+                                    span: *span,
+                                })
+                            }
+                            None if vals.is_empty() => {
+                                return Err(ShellError::AccessEmptyContent { span: *span })
+                            }
+                            None => {
+                                return Err(ShellError::AccessBeyondEnd {
+                                    max_idx: vals.len() - 1,
+                                    span: *span,
+                                })
+                            }
+                        }
+                    }
                     Value::List { vals, .. } => {
                         for val in vals.iter_mut() {
                             match val {
@@ -1483,7 +1644,9 @@ impl Value {
                         ))
                     }
                 },
-                PathMember::Int { val: row_num, span } => match self {
+                PathMember::Int {
+                    val: row_num, span, ..
+                } => match self {
                     Value::List { vals, .. } => {
                         if let Some(v) = vals.get_mut(*row_num) {
                             v.insert_data_at_cell_path(&cell_path[1..], new_val, head_span)?
@@ -2797,6 +2960,27 @@ impl Value {
         }
     }
 
+    /// `===` — equality without cross-type coercion. Where `1 == 1.0` and
+    /// `1 == '1'` may compare numerically, differing types here always mean
+    /// "not equal"; only same-typed values are compared by content.
+    pub fn strict_eq(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
+        if self.get_type() != rhs.get_type() {
+            return Ok(Value::Bool { val: false, span });
+        }
+
+        self.eq(op, rhs, span)
+    }
+
+    /// `!==` — the negation of [`Value::strict_eq`]: true when the types
+    /// differ, or when same-typed values compare unequal.
+    pub fn strict_ne(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
+        if self.get_type() != rhs.get_type() {
+            return Ok(Value::Bool { val: true, span });
+        }
+
+        self.ne(op, rhs, span)
+    }
+
     pub fn r#in(&self, op: Span, rhs: &Value, span: Span) -> Result<Value, ShellError> {
         match (self, rhs) {
             (lhs, Value::Range { val: rhs, .. }) => Ok(Value::Bool {
@@ -3729,3 +3913,24 @@ mod tests {
         }
     }
 }
+
+/// A negative list index like `items.-1` reaches evaluation as a String path
+/// member, since its meaning depends on the length of the list it is applied to
+fn parse_negative_index(col_name: &str) -> Option<i64> {
+    let idx: i64 = col_name.parse().ok()?;
+    if idx < 0 {
+        Some(idx)
+    } else {
+        None
+    }
+}
+
+/// Resolve a negative index against a list length, counting back from the end
+fn negative_index(idx: i64, len: usize) -> Option<usize> {
+    let idx = len as i64 + idx;
+    if (0..len as i64).contains(&idx) {
+        Some(idx as usize)
+    } else {
+        None
+    }
+}
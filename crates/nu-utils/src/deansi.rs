@@ -43,6 +43,22 @@ pub fn strip_ansi_likely(string: &str) -> Cow<str> {
     Cow::Borrowed(string)
 }
 
+/// Removes ANSI escape codes and some ASCII control characters
+///
+/// Works on raw bytes, so it can be applied to stream chunks that are not
+/// (yet) valid UTF-8.
+///
+/// Keeps `\n` removes `\r`, `\t` etc.
+///
+/// If parsing fails silently returns the input bytes
+pub fn strip_ansi_bytes_likely(bytes: &[u8]) -> Cow<[u8]> {
+    if let Ok(stripped) = strip_ansi_escapes::strip(bytes) {
+        return Cow::Owned(stripped);
+    }
+    // Else case includes failures to parse!
+    Cow::Borrowed(bytes)
+}
+
 /// Removes ANSI escape codes and some ASCII control characters
 ///
 /// Optimized for strings that rarely contain ANSI control chars.
@@ -0,0 +1,70 @@
+use crate::locale::get_system_locale_string;
+
+/// Overrides the locale used to pick an error message catalog.
+pub const MESSAGE_LOCALE_ENV_VAR: &str = "NU_LOCALE";
+
+/// A catalog maps the English display string of an error to its translation,
+/// one entry per line, separated by a tab:
+///
+/// ```text
+/// Type mismatch during operation.<TAB>Discordancia de tipos durante la operación.
+/// ```
+///
+/// Catalogs are compiled in behind the `i18n-catalogs` feature; without it (or
+/// for locales without a catalog) messages stay in English.
+#[cfg(feature = "i18n-catalogs")]
+fn catalog_for(language: &str) -> Option<&'static str> {
+    match language {
+        "de" => Some(include_str!("catalogs/de.catalog")),
+        "es" => Some(include_str!("catalogs/es.catalog")),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "i18n-catalogs"))]
+fn catalog_for(_language: &str) -> Option<&'static str> {
+    None
+}
+
+fn message_language() -> Option<String> {
+    let locale = std::env::var(MESSAGE_LOCALE_ENV_VAR)
+        .ok()
+        .or_else(get_system_locale_string)?;
+
+    // "de_DE.UTF-8" and "de-DE" both select the "de" catalog
+    let language = locale
+        .split(['_', '-', '.'])
+        .next()
+        .unwrap_or(&locale)
+        .to_lowercase();
+
+    if language.is_empty() {
+        None
+    } else {
+        Some(language)
+    }
+}
+
+/// Replaces any known English error messages inside rendered diagnostic output
+/// with their translation for the current locale. Unknown messages, and all
+/// output when no catalog applies, are left untouched.
+pub fn localize_rendered(text: &str) -> String {
+    let catalog = match message_language().and_then(|lang| catalog_for(&lang)) {
+        Some(catalog) => catalog,
+        None => return text.to_string(),
+    };
+
+    let mut localized = text.to_string();
+    for line in catalog.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((msgid, msgstr)) = line.split_once('\t') {
+            if !msgid.is_empty() && !msgstr.is_empty() {
+                localized = localized.replace(msgid, msgstr);
+            }
+        }
+    }
+
+    localized
+}
@@ -1,8 +1,10 @@
 pub mod ctrl_c;
 mod deansi;
+pub mod i18n;
 pub mod locale;
 pub mod utils;
 
+pub use i18n::localize_rendered;
 pub use locale::get_system_locale;
 pub use utils::{
     enable_vt_processing, get_default_config, get_default_env, get_ls_colors,
@@ -10,5 +12,6 @@ pub use utils::{
 };
 
 pub use deansi::{
-    strip_ansi_likely, strip_ansi_string_likely, strip_ansi_string_unlikely, strip_ansi_unlikely,
+    strip_ansi_bytes_likely, strip_ansi_likely, strip_ansi_string_likely,
+    strip_ansi_string_unlikely, strip_ansi_unlikely,
 };
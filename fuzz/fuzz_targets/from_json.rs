@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nu_protocol::Span;
+
+fuzz_target!(|data: &str| {
+    let _ = nu_command::from_json_string_to_value(data.to_string(), Span::test_data());
+});
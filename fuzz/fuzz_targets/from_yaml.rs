@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nu_protocol::Span;
+
+fuzz_target!(|data: &str| {
+    let span = Span::test_data();
+    let _ = nu_command::from_yaml_string_to_value(data.to_string(), span, span);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nu_protocol::engine::{EngineState, StateWorkingSet};
+
+fuzz_target!(|data: &[u8]| {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+    let _ = nu_parser::parse(&mut working_set, None, data, false, &[]);
+});
@@ -102,6 +102,7 @@ pub(crate) fn parse_commandline_args(
             #[cfg(feature = "plugin")]
             let plugin_file: Option<Expression> = call.get_flag_expr("plugin-config");
             let no_config_file = call.get_named_arg("no-config-file");
+            let benchmark_internal = call.get_named_arg("benchmark-internal");
             let config_file: Option<Expression> = call.get_flag_expr("config");
             let env_file: Option<Expression> = call.get_flag_expr("env-config");
             let log_level: Option<Expression> = call.get_flag_expr("log-level");
@@ -176,6 +177,7 @@ pub(crate) fn parse_commandline_args(
                 #[cfg(feature = "plugin")]
                 plugin_file,
                 no_config_file,
+                benchmark_internal,
                 config_file,
                 env_file,
                 log_level,
@@ -208,6 +210,7 @@ pub(crate) struct NushellCliArgs {
     #[cfg(feature = "plugin")]
     pub(crate) plugin_file: Option<Spanned<String>>,
     pub(crate) no_config_file: Option<Spanned<String>>,
+    pub(crate) benchmark_internal: Option<Spanned<String>>,
     pub(crate) config_file: Option<Spanned<String>>,
     pub(crate) env_file: Option<Spanned<String>>,
     pub(crate) log_level: Option<Spanned<String>>,
@@ -307,6 +310,11 @@ impl Command for Nu {
                 "run internal test binary",
                 None,
             )
+            .switch(
+                "benchmark-internal",
+                "print end-to-end evaluation timings to stderr (internal, for benchmarking)",
+                None,
+            )
             .optional(
                 "script file",
                 SyntaxShape::Filepath,
@@ -18,6 +18,7 @@ pub(crate) fn run_commands(
     input: PipelineData,
 ) -> Result<(), miette::ErrReport> {
     let mut stack = nu_protocol::engine::Stack::new();
+    let benchmark_internal = parsed_nu_cli_args.benchmark_internal.is_some();
     let start_time = std::time::Instant::now();
     #[cfg(feature = "plugin")]
     read_plugin_file(
@@ -85,6 +86,9 @@ pub(crate) fn run_commands(
         column!(),
         use_color,
     );
+    if benchmark_internal {
+        eprintln!("benchmark-internal: evaluated in {:?}", start_time.elapsed());
+    }
 
     match ret_val {
         Ok(Some(exit_code)) => std::process::exit(exit_code as i32),
@@ -102,6 +106,7 @@ pub(crate) fn run_file(
     input: PipelineData,
 ) -> Result<(), miette::ErrReport> {
     let mut stack = nu_protocol::engine::Stack::new();
+    let benchmark_internal = parsed_nu_cli_args.benchmark_internal.is_some();
     let start_time = std::time::Instant::now();
 
     #[cfg(feature = "plugin")]
@@ -170,6 +175,9 @@ pub(crate) fn run_file(
         column!(),
         use_color,
     );
+    if benchmark_internal {
+        eprintln!("benchmark-internal: evaluated in {:?}", start_time.elapsed());
+    }
 
     let start_time = std::time::Instant::now();
     let last_exit_code = stack.get_env_var(&*engine_state, "LAST_EXIT_CODE");
@@ -1,11 +1,11 @@
 use std::io::{self, BufRead, Read, Write};
 
-use nu_cli::{eval_env_change_hook, eval_hook};
+use nu_cli::{eval_env_change_hook, eval_hook, store_last_output};
 use nu_command::create_default_context;
 use nu_engine::eval_block;
 use nu_parser::parse;
 use nu_protocol::engine::{EngineState, Stack, StateWorkingSet};
-use nu_protocol::{CliError, PipelineData, Value};
+use nu_protocol::{CliError, PipelineData, Span, Value};
 // use nu_test_support::fs::in_directory;
 
 /// Echo's value of env keys from args
@@ -240,13 +240,34 @@ pub fn nu_repl() {
         let config = engine_state.get_config();
 
         match eval_block(&engine_state, &mut stack, &block, input, false, false) {
-            Ok(pipeline_data) => match pipeline_data.collect_string("", config) {
-                Ok(s) => last_output = s,
-                Err(err) => outcome_err(&engine_state, &err),
-            },
+            Ok(pipeline_data) => {
+                // mirror the REPL: optionally keep the value for $env.LAST_OUTPUT
+                let pipeline_data = if config.keep_last_output {
+                    let value = pipeline_data.into_value(Span::unknown());
+                    store_last_output(&engine_state, &mut stack, &value);
+                    PipelineData::Value(value, None)
+                } else {
+                    pipeline_data
+                };
+
+                match pipeline_data.collect_string("", config) {
+                    Ok(s) => last_output = s,
+                    Err(err) => outcome_err(&engine_state, &err),
+                }
+            }
             Err(err) => outcome_err(&engine_state, &err),
         }
 
+        // mirror the REPL: capture the line for an active session recording
+        if let Some(recording) = engine_state
+            .session_recording
+            .lock()
+            .expect("session recording mutex")
+            .as_mut()
+        {
+            recording.record(line, &last_output, 0);
+        }
+
         if let Some(cwd) = stack.get_env_var(&engine_state, "PWD") {
             let path = cwd
                 .as_string()
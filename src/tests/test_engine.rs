@@ -376,3 +376,43 @@ fn assignment_to_in_var_no_panic() -> TestResult {
 fn assignment_to_env_no_panic() -> TestResult {
     fail_test(r#"$env = 3"#, "cannot_replace_env")
 }
+
+#[test]
+fn defer_runs_at_block_exit() -> TestResult {
+    run_test(
+        r#"do { defer { print -n 'cleanup,' }; print -n 'body,' }; print -n 'after'"#,
+        "body,cleanup,after",
+    )
+}
+
+#[test]
+fn defer_runs_in_reverse_order() -> TestResult {
+    run_test(
+        r#"do { defer { print -n '1' }; defer { print -n '2' } }"#,
+        "21",
+    )
+}
+
+#[test]
+fn defer_runs_when_the_block_errors() -> TestResult {
+    run_test(
+        r#"try { do { defer { print -n 'cleanup,' }; error make {msg: 'oops'} } }; print -n 'done'"#,
+        "cleanup,done",
+    )
+}
+
+#[test]
+fn with_resource_closes_after_use() -> TestResult {
+    run_test(
+        r#"with-resource { 'res' } {|r| $'used ($r)' } {|r| print -n $'closed ($r);' }"#,
+        "closed res;used res",
+    )
+}
+
+#[test]
+fn with_resource_closes_on_error() -> TestResult {
+    run_test(
+        r#"try { with-resource { 'r' } {|r| error make {msg: 'bad'} } {|r| print -n 'closed,' } } catch { print -n 'caught' }"#,
+        "closed,caught",
+    )
+}
@@ -119,3 +119,74 @@ fn precedence_of_or_groups() -> TestResult {
 fn test_filesize_op() -> TestResult {
     run_test("-5kb + 4.5kb", "-500 B")
 }
+
+#[test]
+fn coalesce_returns_left_when_not_null() -> TestResult {
+    run_test("3 ?? 4", "3")
+}
+
+#[test]
+fn coalesce_returns_right_when_null() -> TestResult {
+    run_test("null ?? 4", "4")
+}
+
+#[test]
+fn coalesce_with_optional_cell_path() -> TestResult {
+    run_test("{a: 1} | get -i b | $in ?? 'default'", "default")
+}
+
+#[test]
+fn coalesce_binds_looser_than_or() -> TestResult {
+    run_test("null ?? false or true", "true")
+}
+
+#[test]
+fn null_propagates_through_arithmetic() -> TestResult {
+    run_test("(null + 1) == null", "true")
+}
+
+#[test]
+fn strict_null_math_errors_instead() -> TestResult {
+    fail_test(
+        "$env.config = {strict_null_math: true}; null + 1",
+        "mismatch",
+    )
+}
+
+#[test]
+fn strict_equal_same_type() -> TestResult {
+    run_test("1 === 1", "true")
+}
+
+#[test]
+fn strict_equal_rejects_cross_type() -> TestResult {
+    run_test("1 === '1'", "false")
+}
+
+#[test]
+fn strict_equal_int_vs_float() -> TestResult {
+    run_test("1 === 1.0", "false")
+}
+
+#[test]
+fn loose_equal_still_coerces() -> TestResult {
+    run_test("1 == 1.0", "true")
+}
+
+#[test]
+fn strict_not_equal_cross_type() -> TestResult {
+    run_test("1 !== 1.0", "true")
+}
+
+#[test]
+fn strict_not_equal_same_type() -> TestResult {
+    run_test("2 !== 2", "false")
+}
+
+#[test]
+fn compare_orders_values() -> TestResult {
+    run_test(
+        "[(compare 1 2) (compare 2 2) (compare 3 2)] | to nuon",
+        "[-1, 0, 1]",
+    )
+}
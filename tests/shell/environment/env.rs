@@ -164,3 +164,52 @@ fn hides_env_in_block() {
     assert!(actual.err.contains("column_not_found"));
     assert!(actual_repl.err.contains("column_not_found"));
 }
+
+#[test]
+fn last_output_is_kept_when_opted_in() {
+    let inp = &[
+        "let-env config = { keep_last_output: true }",
+        "[1 2 3] | math sum",
+        "$env.LAST_OUTPUT + 1",
+    ];
+
+    let actual = nu!(cwd: "tests/shell/environment", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "7");
+}
+
+#[test]
+fn last_output_is_not_kept_by_default() {
+    let inp = &["[1 2 3] | math sum", "'LAST_OUTPUT' in $env"];
+
+    let actual = nu!(cwd: "tests/shell/environment", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "false");
+}
+
+#[test]
+fn oversized_last_output_is_dropped() {
+    let inp = &[
+        "let-env config = { keep_last_output: true, last_output_max_size: 4 }",
+        "'a string well over the limit'",
+        "'LAST_OUTPUT' in $env",
+    ];
+
+    let actual = nu!(cwd: "tests/shell/environment", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "false");
+}
+
+#[test]
+fn commands_without_output_keep_the_previous_last_output() {
+    let inp = &[
+        "let-env config = { keep_last_output: true }",
+        "42",
+        "null",
+        "$env.LAST_OUTPUT",
+    ];
+
+    let actual = nu!(cwd: "tests/shell/environment", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "42");
+}
@@ -7,6 +7,8 @@ mod environment;
 
 mod pipeline;
 
+mod session;
+
 //FIXME: jt: we need to focus some fixes on wix as the plugins will differ
 #[ignore]
 #[test]
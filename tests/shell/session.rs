@@ -0,0 +1,54 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_repl_code};
+
+#[test]
+fn session_record_writes_a_nuon_log() {
+    Playground::setup("session_record_log", |dirs, _| {
+        let lines = [
+            "session record log.nuon",
+            "'hello'",
+            "session stop | ignore",
+            "open log.nuon | get command | str join ','",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(&lines));
+
+        assert_eq!(actual.out, "'hello'");
+    });
+}
+
+#[test]
+fn session_record_captures_the_output_text() {
+    Playground::setup("session_record_output", |dirs, _| {
+        let lines = [
+            "session record log.nuon",
+            "[1 2 3] | math sum",
+            "session stop | ignore",
+            "open log.nuon | get output | str join ','",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(&lines));
+
+        assert_eq!(actual.out, "6");
+    });
+}
+
+#[test]
+fn session_stop_without_a_recording_errors() {
+    let actual = nu!(cwd: ".", nu_repl_code(&["session stop"]));
+
+    assert!(actual.err.contains("No session recording"));
+}
+
+#[test]
+fn session_export_renders_a_markdown_transcript() {
+    Playground::setup("session_export_md", |dirs, _| {
+        let lines = [
+            "session record log.nuon",
+            "'hello'",
+            "session stop | ignore",
+            "session export log.nuon | str contains 'hello'",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(&lines));
+
+        assert_eq!(actual.out, "true");
+    });
+}